@@ -18,6 +18,7 @@ fn main() {
         .file("src/c/stdio.c")
         .file("src/c/stdlib.c")
         .file("src/c/unistd.c")
+        .file("src/c/wchar.c")
         .compile("relibc_c");
 
     println!("cargo:rustc-link-lib=static=relibc_c");
@@ -1,7 +1,6 @@
-#define LC_ALL 0
-#define LC_COLLATE 1
-#define LC_CTYPE 2
-#define LC_MESSAGES 3
-#define LC_MONETARY 4
-#define LC_NUMERIC 5
-#define LC_TIME 6
+#ifndef _BITS_LOCALE_H
+#define _BITS_LOCALE_H
+
+#define LC_GLOBAL_LOCALE ((locale_t) -1)
+
+#endif /* _BITS_LOCALE_H */
@@ -13,6 +13,7 @@ extern "C" {
 #endif
 
 int asprintf(char **strp, const char * fmt, ...);
+int dprintf(int fd, const char * fmt, ...);
 int fprintf(FILE * stream, const char * fmt, ...);
 int printf(const char * fmt, ...);
 int snprintf(char *s, size_t n, const char * fmt, ...);
@@ -21,6 +22,12 @@ int fscanf(FILE * stream, const char * fmt, ...);
 int scanf(const char * fmt, ...);
 int sscanf(const char * input, const char * fmt, ...);
 
+// The callbacks of fopencookie
+typedef ssize_t cookie_read_function_t(void *cookie, char *buf, size_t size);
+typedef ssize_t cookie_write_function_t(void *cookie, const char *buf, size_t size);
+typedef int cookie_seek_function_t(void *cookie, off_t *offset, int whence);
+typedef int cookie_close_function_t(void *cookie);
+
 #ifdef __cplusplus
 } // extern "C"
 #endif
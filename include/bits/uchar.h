@@ -0,0 +1,15 @@
+#ifndef _BITS_UCHAR_H
+#define _BITS_UCHAR_H
+
+#include <stdint.h>
+
+// These are keywords in C++11, and char8_t in C++20
+#if !defined(__cplusplus) || __cplusplus < 201103L
+typedef uint_least16_t char16_t;
+typedef uint_least32_t char32_t;
+#endif
+#if !defined(__cplusplus) || !defined(__cpp_char8_t)
+typedef unsigned char char8_t;
+#endif
+
+#endif /* _BITS_UCHAR_H */
@@ -1,6 +1,8 @@
 #ifndef _BITS_WCHAR_H
 #define _BITS_WCHAR_H
+#include <stddef.h>
 #include <stdint.h>
+#include <stdio.h>
 
 #define WEOF (0xffffffffu)
 #define WCHAR_MIN (0)
@@ -11,4 +13,19 @@
 #define __need_wint_t
 #define __need_NULL
 
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+int fwprintf(FILE * stream, const wchar_t * fmt, ...);
+int swprintf(wchar_t * s, size_t n, const wchar_t * fmt, ...);
+int wprintf(const wchar_t * fmt, ...);
+int fwscanf(FILE * stream, const wchar_t * fmt, ...);
+int swscanf(const wchar_t * s, const wchar_t * fmt, ...);
+int wscanf(const wchar_t * fmt, ...);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif
+
 #endif /* _BITS_WCHAR_H */
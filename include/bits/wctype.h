@@ -0,0 +1,8 @@
+#ifndef _BITS_WCTYPE_H
+#define _BITS_WCTYPE_H
+#include <stdint.h>
+
+typedef int64_t wctype_t;
+typedef const int32_t *wctrans_t;
+
+#endif /* _BITS_WCTYPE_H */
@@ -14,6 +14,17 @@ int asprintf(char ** strp, const char * fmt, ...) {
     return ret;
 }
 
+int vdprintf(int fd, const char * fmt, va_list ap);
+
+int dprintf(int fd, const char * fmt, ...) {
+    int ret;
+    va_list ap;
+    va_start(ap, fmt);
+    ret = vdprintf(fd, fmt, ap);
+    va_end(ap);
+    return ret;
+}
+
 int vfprintf(FILE * stream, const char * fmt, va_list ap);
 
 int fprintf(FILE * stream, const char * fmt, ...) {
@@ -90,3 +101,9 @@ int sscanf(const char * input, const char * fmt, ...) {
     va_end(ap);
     return ret;
 }
+
+// Rust can't read a long double argument, so printf reads them with this. The list is taken by
+// pointer so that the caller's list moves past the argument, even where va_list is a struct
+void __relibc_va_arg_long_double(va_list * ap, long double * out) {
+    *out = va_arg(*ap, long double);
+}
@@ -0,0 +1,500 @@
+//! aio.h implementation, following
+//! http://pubs.opengroup.org/onlinepubs/9699919799/basedefs/aio.h.html
+//!
+//! Requests are queued and performed with pread, pwrite and fsync by a bounded pool of worker
+//! threads, which are started on demand and exit after being idle for a while.
+
+use alloc::collections::VecDeque;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::cell::UnsafeCell;
+use core::{intrinsics, mem, ptr, slice};
+
+use header::errno::*;
+use header::fcntl::{F_GETFL, O_DSYNC, O_SYNC};
+use header::signal::{sigevent, sigevent_notify, SIGEV_NONE, SIGEV_SIGNAL, SIGEV_THREAD};
+use header::time::constants::CLOCK_MONOTONIC;
+use header::time::timespec;
+use mutex::{Mutex, FUTEX_WAIT, FUTEX_WAKE};
+use platform;
+use platform::pte::{pthread_create, pthread_detach};
+use platform::types::*;
+use platform::{Pal, Sys};
+
+pub const AIO_CANCELED: c_int = 0;
+pub const AIO_NOTCANCELED: c_int = 1;
+pub const AIO_ALLDONE: c_int = 2;
+
+pub const LIO_READ: c_int = 0;
+pub const LIO_WRITE: c_int = 1;
+pub const LIO_NOP: c_int = 2;
+
+pub const LIO_WAIT: c_int = 0;
+pub const LIO_NOWAIT: c_int = 1;
+
+// Internal opcodes used by aio_fsync
+const OP_FSYNC: c_int = 3;
+const OP_FDATASYNC: c_int = 4;
+
+/// Maximum number of requests waiting for a worker
+const QUEUE_MAX: usize = 1024;
+/// Maximum number of worker threads
+const WORKERS_MAX: usize = 16;
+/// How long a worker waits for a new request before exiting
+const WORKER_IDLE: timespec = timespec {
+    tv_sec: 1,
+    tv_nsec: 0,
+};
+
+#[repr(C)]
+pub struct aiocb {
+    pub aio_fildes: c_int,
+    pub aio_lio_opcode: c_int,
+    pub aio_reqprio: c_int,
+    pub aio_buf: *mut c_void,
+    pub aio_nbytes: size_t,
+    pub aio_sigevent: sigevent,
+    pub aio_offset: off_t,
+    // Status of the request, set by the implementation
+    pub __error_code: c_int,
+    pub __return_value: ssize_t,
+}
+
+/// A group of requests submitted together by lio_listio
+struct List {
+    /// Requests not yet completed, plus one while lio_listio is still submitting
+    remaining: UnsafeCell<c_int>,
+    /// Set if any request could not be queued or failed
+    failed: UnsafeCell<c_int>,
+    /// Notification for LIO_NOWAIT once every request has completed
+    event: Option<sigevent>,
+    pid: pid_t,
+}
+
+impl List {
+    unsafe fn fail(&self) {
+        intrinsics::atomic_store(self.failed.get(), 1);
+    }
+
+    unsafe fn done(&self) {
+        if intrinsics::atomic_xsub(self.remaining.get(), 1) == 1 {
+            if let Some(ref event) = self.event {
+                sigevent_notify(event, self.pid);
+            }
+            Sys::futex(self.remaining.get(), FUTEX_WAKE, c_int::max_value(), 0);
+        }
+    }
+}
+
+struct Request {
+    aiocb: *mut aiocb,
+    opcode: c_int,
+    list: Option<Arc<List>>,
+    /// The process to signal on completion
+    pid: pid_t,
+}
+
+impl Request {
+    fn is_sync(&self) -> bool {
+        self.opcode == OP_FSYNC || self.opcode == OP_FDATASYNC
+    }
+
+    unsafe fn fildes(&self) -> c_int {
+        (*self.aiocb).aio_fildes
+    }
+}
+
+struct Pool {
+    queue: VecDeque<Request>,
+    /// Requests currently being performed by a worker
+    running: Vec<*mut aiocb>,
+    workers: usize,
+    idle: usize,
+}
+unsafe impl Send for Pool {}
+
+impl Pool {
+    /// Remove the first request that can be started. A synchronization request waits until the
+    /// requests queued before it on the same file descriptor have completed
+    unsafe fn take(&mut self) -> Option<Request> {
+        let running = &self.running;
+        let queue = &self.queue;
+        let index = queue.iter().enumerate().position(|(i, req)| {
+            !req.is_sync() || {
+                let fd = req.fildes();
+                !running.iter().any(|&cb| (*cb).aio_fildes == fd)
+                    && !queue.iter().take(i).any(|other| other.fildes() == fd)
+            }
+        })?;
+        let req = self.queue.remove(index)?;
+        self.running.push(req.aiocb);
+        Some(req)
+    }
+}
+
+lazy_static! {
+    static ref POOL: Mutex<Pool> = Mutex::new(Pool {
+        queue: VecDeque::new(),
+        running: Vec::new(),
+        workers: 0,
+        idle: 0,
+    });
+}
+
+/// Incremented when a request is queued, idle workers wait on it
+static mut QUEUED: c_int = 0;
+/// Incremented when a request completes, aio_suspend waits on it
+static mut COMPLETED: c_int = 0;
+
+/// Record the result of a request and send its notifications. The request must no longer be in
+/// the queue or in the running list
+unsafe fn complete(req: Request, ret: ssize_t, error: c_int) {
+    let cb = &mut *req.aiocb;
+    // The aiocb may be reused as soon as its status changes
+    let event = cb.aio_sigevent;
+    cb.__return_value = ret;
+    intrinsics::atomic_store(&mut cb.__error_code, error);
+
+    intrinsics::atomic_xadd(&mut COMPLETED, 1);
+    Sys::futex(&mut COMPLETED, FUTEX_WAKE, c_int::max_value(), 0);
+
+    sigevent_notify(&event, req.pid);
+    if let Some(list) = req.list {
+        if error != 0 {
+            list.fail();
+        }
+        list.done();
+    }
+}
+
+unsafe fn perform(req: Request) {
+    let cb = req.aiocb;
+    let fd = (*cb).aio_fildes;
+    // The errno of the application isn't touched, as it is shared with the workers
+    let ret = match req.opcode {
+        LIO_READ => Sys::pread_raw(
+            fd,
+            slice::from_raw_parts_mut((*cb).aio_buf as *mut u8, (*cb).aio_nbytes),
+            (*cb).aio_offset,
+        ),
+        LIO_WRITE => Sys::pwrite_raw(
+            fd,
+            slice::from_raw_parts((*cb).aio_buf as *const u8, (*cb).aio_nbytes),
+            (*cb).aio_offset,
+        ),
+        OP_FSYNC => Sys::fsync_raw(fd) as ssize_t,
+        OP_FDATASYNC => Sys::fdatasync_raw(fd) as ssize_t,
+        _ => 0,
+    };
+    let (ret, error) = if ret < 0 {
+        (-1, -ret as c_int)
+    } else {
+        (ret, 0)
+    };
+
+    {
+        let mut pool = POOL.lock();
+        if let Some(i) = pool.running.iter().position(|&running| running == cb) {
+            pool.running.swap_remove(i);
+        }
+    }
+    complete(req, ret, error);
+}
+
+extern "C" fn worker(_arg: *mut c_void) -> *mut c_void {
+    unsafe {
+        let mut waited = false;
+        loop {
+            let seq = {
+                let mut pool = POOL.lock();
+                if let Some(req) = pool.take() {
+                    drop(pool);
+                    perform(req);
+                    waited = false;
+                    continue;
+                }
+                if waited {
+                    pool.workers -= 1;
+                    return ptr::null_mut();
+                }
+                pool.idle += 1;
+                intrinsics::atomic_load(&QUEUED)
+            };
+
+            Sys::futex(
+                &mut QUEUED,
+                FUTEX_WAIT,
+                seq,
+                &WORKER_IDLE as *const timespec as usize,
+            );
+            POOL.lock().idle -= 1;
+            waited = true;
+        }
+    }
+}
+
+/// Queue a request, or return the error number of why it can't be
+unsafe fn submit(aiocbp: *mut aiocb, opcode: c_int, list: Option<Arc<List>>) -> Result<(), c_int> {
+    match (*aiocbp).aio_sigevent.sigev_notify {
+        SIGEV_NONE | SIGEV_SIGNAL | SIGEV_THREAD => (),
+        _ => return Err(EINVAL),
+    }
+
+    let mut pool = POOL.lock();
+    if pool.queue.len() >= QUEUE_MAX {
+        return Err(EAGAIN);
+    }
+    if pool.idle == 0 && pool.workers < WORKERS_MAX {
+        let mut thread = mem::zeroed();
+        if pthread_create(&mut thread, ptr::null(), worker, ptr::null_mut()) == 0 {
+            pthread_detach(thread);
+            pool.workers += 1;
+        } else if pool.workers == 0 {
+            return Err(EAGAIN);
+        }
+    }
+
+    (*aiocbp).__return_value = 0;
+    intrinsics::atomic_store(&mut (*aiocbp).__error_code, EINPROGRESS);
+    pool.queue.push_back(Request {
+        aiocb: aiocbp,
+        opcode,
+        list,
+        pid: Sys::getpid(),
+    });
+    drop(pool);
+
+    intrinsics::atomic_xadd(&mut QUEUED, 1);
+    Sys::futex(&mut QUEUED, FUTEX_WAKE, 1, 0);
+    Ok(())
+}
+
+/// Queue a request for one of the aio functions, which set errno if it can't be
+unsafe fn submit_one(aiocbp: *mut aiocb, opcode: c_int) -> c_int {
+    match submit(aiocbp, opcode, None) {
+        Ok(()) => 0,
+        Err(err) => {
+            platform::errno = err;
+            -1
+        }
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn aio_read(aiocbp: *mut aiocb) -> c_int {
+    submit_one(aiocbp, LIO_READ)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn aio_write(aiocbp: *mut aiocb) -> c_int {
+    submit_one(aiocbp, LIO_WRITE)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn lio_listio(
+    mode: c_int,
+    list: *const *mut aiocb,
+    nent: c_int,
+    sig: *mut sigevent,
+) -> c_int {
+    if (mode != LIO_WAIT && mode != LIO_NOWAIT) || nent < 0 {
+        platform::errno = EINVAL;
+        return -1;
+    }
+
+    let group = Arc::new(List {
+        remaining: UnsafeCell::new(1),
+        failed: UnsafeCell::new(0),
+        event: if mode == LIO_NOWAIT && !sig.is_null() {
+            Some(*sig)
+        } else {
+            None
+        },
+        pid: Sys::getpid(),
+    });
+
+    let mut rejected = false;
+    for &aiocbp in slice::from_raw_parts(list, nent as usize) {
+        if aiocbp.is_null() {
+            continue;
+        }
+        let opcode = (*aiocbp).aio_lio_opcode;
+        if opcode == LIO_NOP {
+            continue;
+        }
+
+        intrinsics::atomic_xadd(group.remaining.get(), 1);
+        let ret = if opcode == LIO_READ || opcode == LIO_WRITE {
+            submit(aiocbp, opcode, Some(group.clone()))
+        } else {
+            Err(EINVAL)
+        };
+        if let Err(err) = ret {
+            (*aiocbp).__return_value = -1;
+            intrinsics::atomic_store(&mut (*aiocbp).__error_code, err);
+            group.fail();
+            group.done();
+            rejected = true;
+        }
+    }
+    group.done();
+
+    if mode == LIO_WAIT {
+        loop {
+            let remaining = intrinsics::atomic_load(group.remaining.get());
+            if remaining == 0 {
+                break;
+            }
+            Sys::futex(group.remaining.get(), FUTEX_WAIT, remaining, 0);
+        }
+    }
+
+    // With LIO_NOWAIT only failures to queue a request are reported here
+    let failed = if mode == LIO_WAIT {
+        intrinsics::atomic_load(group.failed.get()) != 0
+    } else {
+        rejected
+    };
+    if failed {
+        platform::errno = EIO;
+        return -1;
+    }
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn aio_error(aiocbp: *const aiocb) -> c_int {
+    intrinsics::atomic_load(&(*aiocbp).__error_code)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn aio_return(aiocbp: *mut aiocb) -> ssize_t {
+    if aio_error(aiocbp) == EINPROGRESS {
+        platform::errno = EINVAL;
+        return -1;
+    }
+    (*aiocbp).__return_value
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn aio_cancel(fildes: c_int, aiocbp: *mut aiocb) -> c_int {
+    if Sys::fcntl(fildes, F_GETFL, 0) < 0 {
+        platform::errno = EBADF;
+        return -1;
+    }
+    if !aiocbp.is_null() && (*aiocbp).aio_fildes != fildes {
+        platform::errno = EINVAL;
+        return -1;
+    }
+    let matches = |cb: *mut aiocb| {
+        if aiocbp.is_null() {
+            (*cb).aio_fildes == fildes
+        } else {
+            cb == aiocbp
+        }
+    };
+
+    let mut canceled = Vec::new();
+    let in_progress = {
+        let mut pool = POOL.lock();
+        let mut i = 0;
+        while i < pool.queue.len() {
+            if matches(pool.queue[i].aiocb) {
+                canceled.extend(pool.queue.remove(i));
+            } else {
+                i += 1;
+            }
+        }
+        pool.running.iter().any(|&cb| matches(cb))
+    };
+
+    let any_canceled = !canceled.is_empty();
+    for req in canceled {
+        complete(req, -1, ECANCELED);
+    }
+
+    if in_progress {
+        AIO_NOTCANCELED
+    } else if any_canceled {
+        AIO_CANCELED
+    } else {
+        AIO_ALLDONE
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn aio_suspend(
+    list: *const *const aiocb,
+    nent: c_int,
+    timeout: *const timespec,
+) -> c_int {
+    if nent < 0 {
+        platform::errno = EINVAL;
+        return -1;
+    }
+    let list = slice::from_raw_parts(list, nent as usize);
+
+    let mut deadline = timespec::default();
+    if !timeout.is_null() {
+        Sys::clock_gettime(CLOCK_MONOTONIC, &mut deadline);
+        deadline.tv_sec += (*timeout).tv_sec;
+        deadline.tv_nsec += (*timeout).tv_nsec;
+        if deadline.tv_nsec >= 1_000_000_000 {
+            deadline.tv_sec += 1;
+            deadline.tv_nsec -= 1_000_000_000;
+        }
+    }
+
+    loop {
+        let seq = intrinsics::atomic_load(&COMPLETED);
+        if list
+            .iter()
+            .any(|&cb| !cb.is_null() && aio_error(cb) != EINPROGRESS)
+        {
+            return 0;
+        }
+
+        let res = if timeout.is_null() {
+            Sys::futex(&mut COMPLETED, FUTEX_WAIT, seq, 0)
+        } else {
+            let mut now = timespec::default();
+            Sys::clock_gettime(CLOCK_MONOTONIC, &mut now);
+            let mut left = timespec {
+                tv_sec: deadline.tv_sec - now.tv_sec,
+                tv_nsec: deadline.tv_nsec - now.tv_nsec,
+            };
+            if left.tv_nsec < 0 {
+                left.tv_sec -= 1;
+                left.tv_nsec += 1_000_000_000;
+            }
+            if left.tv_sec < 0 {
+                platform::errno = EAGAIN;
+                return -1;
+            }
+            Sys::futex(
+                &mut COMPLETED,
+                FUTEX_WAIT,
+                seq,
+                &left as *const timespec as usize,
+            )
+        };
+        // Sys::futex returns the error number negated instead of setting errno
+        if res == -EINTR {
+            platform::errno = EINTR;
+            return -1;
+        }
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn aio_fsync(operation: c_int, aiocbp: *mut aiocb) -> c_int {
+    let opcode = if operation == O_SYNC {
+        OP_FSYNC
+    } else if operation == O_DSYNC {
+        OP_FDATASYNC
+    } else {
+        platform::errno = EINVAL;
+        return -1;
+    };
+    submit_one(aiocbp, opcode)
+}
@@ -1,5 +1,6 @@
 //! ctype implementation for Redox, following http://pubs.opengroup.org/onlinepubs/7908799/xsh/ctype.h.html
 
+use header::locale::locale_t;
 use platform::types::*;
 
 #[no_mangle]
@@ -7,11 +8,22 @@ pub extern "C" fn isalnum(c: c_int) -> c_int {
     (isdigit(c) != 0 || isalpha(c) != 0) as c_int
 }
 
+// Every locale classifies characters like the C locale, so the *_l functions ignore the locale
+#[no_mangle]
+pub extern "C" fn isalnum_l(c: c_int, _loc: locale_t) -> c_int {
+    isalnum(c)
+}
+
 #[no_mangle]
 pub extern "C" fn isalpha(c: c_int) -> c_int {
     (islower(c) != 0 || isupper(c) != 0) as c_int
 }
 
+#[no_mangle]
+pub extern "C" fn isalpha_l(c: c_int, _loc: locale_t) -> c_int {
+    isalpha(c)
+}
+
 #[no_mangle]
 pub extern "C" fn isascii(c: c_int) -> c_int {
     ((c & !0x7f) == 0) as c_int
@@ -22,31 +34,61 @@ pub extern "C" fn isblank(c: c_int) -> c_int {
     (c == ' ' as c_int || c == '\t' as c_int) as c_int
 }
 
+#[no_mangle]
+pub extern "C" fn isblank_l(c: c_int, _loc: locale_t) -> c_int {
+    isblank(c)
+}
+
 #[no_mangle]
 pub extern "C" fn iscntrl(c: c_int) -> c_int {
     ((c >= 0x00 && c <= 0x1f) || c == 0x7f) as c_int
 }
 
+#[no_mangle]
+pub extern "C" fn iscntrl_l(c: c_int, _loc: locale_t) -> c_int {
+    iscntrl(c)
+}
+
 #[no_mangle]
 pub extern "C" fn isdigit(c: c_int) -> c_int {
     (c >= b'0' as c_int && c <= b'9' as c_int) as c_int
 }
 
+#[no_mangle]
+pub extern "C" fn isdigit_l(c: c_int, _loc: locale_t) -> c_int {
+    isdigit(c)
+}
+
 #[no_mangle]
 pub extern "C" fn isgraph(c: c_int) -> c_int {
     (c >= 0x21 && c <= 0x7e) as c_int
 }
 
+#[no_mangle]
+pub extern "C" fn isgraph_l(c: c_int, _loc: locale_t) -> c_int {
+    isgraph(c)
+}
+
 #[no_mangle]
 pub extern "C" fn islower(c: c_int) -> c_int {
     (c >= b'a' as c_int && c <= b'z' as c_int) as c_int
 }
 
+#[no_mangle]
+pub extern "C" fn islower_l(c: c_int, _loc: locale_t) -> c_int {
+    islower(c)
+}
+
 #[no_mangle]
 pub extern "C" fn isprint(c: c_int) -> c_int {
     (c >= 0x20 && c < 0x7f) as c_int
 }
 
+#[no_mangle]
+pub extern "C" fn isprint_l(c: c_int, _loc: locale_t) -> c_int {
+    isprint(c)
+}
+
 #[no_mangle]
 pub extern "C" fn ispunct(c: c_int) -> c_int {
     ((c >= b'!' as c_int && c <= b'/' as c_int)
@@ -55,6 +97,11 @@ pub extern "C" fn ispunct(c: c_int) -> c_int {
         || (c >= b'{' as c_int && c <= b'~' as c_int)) as c_int
 }
 
+#[no_mangle]
+pub extern "C" fn ispunct_l(c: c_int, _loc: locale_t) -> c_int {
+    ispunct(c)
+}
+
 #[no_mangle]
 pub extern "C" fn isspace(c: c_int) -> c_int {
     (c == ' ' as c_int
@@ -65,16 +112,31 @@ pub extern "C" fn isspace(c: c_int) -> c_int {
         || c == 0x0c) as c_int
 }
 
+#[no_mangle]
+pub extern "C" fn isspace_l(c: c_int, _loc: locale_t) -> c_int {
+    isspace(c)
+}
+
 #[no_mangle]
 pub extern "C" fn isupper(c: c_int) -> c_int {
     (c >= b'A' as c_int && c <= b'Z' as c_int) as c_int
 }
 
+#[no_mangle]
+pub extern "C" fn isupper_l(c: c_int, _loc: locale_t) -> c_int {
+    isupper(c)
+}
+
 #[no_mangle]
 pub extern "C" fn isxdigit(c: c_int) -> c_int {
     (isdigit(c) != 0 || (c | 32 >= b'a' as c_int && c | 32 <= 'f' as c_int)) as c_int
 }
 
+#[no_mangle]
+pub extern "C" fn isxdigit_l(c: c_int, _loc: locale_t) -> c_int {
+    isxdigit(c)
+}
+
 #[no_mangle]
 /// The comment in musl:
 /// "nonsense function that should NEVER be used!"
@@ -91,6 +153,11 @@ pub extern "C" fn tolower(c: c_int) -> c_int {
     }
 }
 
+#[no_mangle]
+pub extern "C" fn tolower_l(c: c_int, _loc: locale_t) -> c_int {
+    tolower(c)
+}
+
 #[no_mangle]
 pub extern "C" fn toupper(c: c_int) -> c_int {
     if islower(c) != 0 {
@@ -99,3 +166,8 @@ pub extern "C" fn toupper(c: c_int) -> c_int {
         c
     }
 }
+
+#[no_mangle]
+pub extern "C" fn toupper_l(c: c_int, _loc: locale_t) -> c_int {
+    toupper(c)
+}
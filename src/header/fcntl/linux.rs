@@ -9,6 +9,8 @@ pub const O_EXCL: c_int = 0x0080;
 pub const O_TRUNC: c_int = 0x0200;
 pub const O_APPEND: c_int = 0x0400;
 pub const O_NONBLOCK: c_int = 0x0800;
+pub const O_DSYNC: c_int = 0x1000;
+pub const O_SYNC: c_int = 0x10_1000;
 pub const O_DIRECTORY: c_int = 0x1_0000;
 pub const O_NOFOLLOW: c_int = 0x2_0000;
 pub const O_CLOEXEC: c_int = 0x8_0000;
@@ -0,0 +1,71 @@
+#!/usr/bin/env python3
+"""Generate tables.rs, the character set and transliteration tables of iconv.
+
+Usage: gen_tables.py > tables.rs
+
+The tables come from Python's codecs and unicodedata modules.
+"""
+
+import unicodedata
+
+CHARSETS = [("ISO_8859_{}".format(n), "iso8859_{}".format(n)) for n in list(range(1, 12)) + [13, 14, 15]]
+CHARSETS.append(("CP1252", "cp1252"))
+
+# Transliterations that don't come from compatibility decompositions
+TRANSLIT = {
+    0x00A9: "(C)", 0x00AB: "<<", 0x00AD: "-", 0x00AE: "(R)", 0x00B5: "u", 0x00B7: ".",
+    0x00BB: ">>", 0x00BC: " 1/4 ", 0x00BD: " 1/2 ", 0x00BE: " 3/4 ", 0x00C6: "AE", 0x00D0: "D",
+    0x00D7: "x", 0x00D8: "O", 0x00DE: "TH", 0x00DF: "ss", 0x00E6: "ae", 0x00F0: "d",
+    0x00F7: ":", 0x00F8: "o", 0x00FE: "th", 0x0110: "D", 0x0111: "d", 0x0126: "H",
+    0x0127: "h", 0x0131: "i", 0x0141: "L", 0x0142: "l", 0x0152: "OE", 0x0153: "oe",
+    0x0166: "T", 0x0167: "t", 0x2010: "-", 0x2011: "-", 0x2012: "-", 0x2013: "-",
+    0x2014: "-", 0x2015: "-", 0x2018: "'", 0x2019: "'", 0x201A: ",", 0x201B: "'",
+    0x201C: '"', 0x201D: '"', 0x201E: ",,", 0x201F: '"', 0x2020: "+", 0x2022: "o",
+    0x2039: "<", 0x203A: ">", 0x20AC: "EUR", 0x2122: "TM", 0x2190: "<-", 0x2192: "->",
+    0x2212: "-",
+}
+
+# The ranges whose compatibility decompositions are used for transliteration
+DECOMPOSED = [(0x00A0, 0x024F), (0x1E00, 0x1EFF), (0x2000, 0x206F), (0x2100, 0x218F), (0xFB00, 0xFB06)]
+
+
+def translit():
+    table = {}
+    for first, last in DECOMPOSED:
+        for cp in range(first, last + 1):
+            decomposed = unicodedata.normalize("NFKD", chr(cp))
+            s = "".join(c for c in decomposed if unicodedata.category(c) != "Mn").replace("⁄", "/")
+            if s and s != chr(cp) and all(ord(c) < 0x80 for c in s):
+                table[cp] = s
+    table.update(TRANSLIT)
+    return sorted(table.items())
+
+
+def main():
+    print("//! Character set and transliteration tables, generated by gen_tables.py. Do not edit.")
+    print()
+    print("// The characters of bytes 0x80 to 0xFF in each single-byte character set, or 0 if undefined")
+    for name, codec in CHARSETS:
+        chars = []
+        for b in range(0x80, 0x100):
+            try:
+                chars.append(ord(bytes([b]).decode(codec)))
+            except UnicodeDecodeError:
+                chars.append(0)
+        print()
+        print("#[rustfmt::skip]")
+        print("pub static {}: [u16; 128] = [".format(name))
+        for i in range(0, 128, 8):
+            print("    " + " ".join("0x{:04x},".format(c) for c in chars[i:i + 8]))
+        print("];")
+    print()
+    print("/// ASCII replacements for characters, for //TRANSLIT")
+    print("#[rustfmt::skip]")
+    print("pub static TRANSLIT: &[(u32, &str)] = &[")
+    for cp, s in translit():
+        print("    (0x{:04x}, {}),".format(cp, '"' + s.replace("\\", "\\\\").replace('"', '\\"') + '"'))
+    print("];")
+
+
+if __name__ == "__main__":
+    main()
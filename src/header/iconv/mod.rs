@@ -0,0 +1,465 @@
+//! iconv implementation for Redox, following http://pubs.opengroup.org/onlinepubs/9699919799/basedefs/iconv.h.html
+
+use alloc::boxed::Box;
+use core::{char, slice};
+
+use c_str::CStr;
+use header::errno::{E2BIG, EBADF, EILSEQ, EINVAL};
+use header::wchar::utf8;
+use platform;
+use platform::types::*;
+
+pub mod tables;
+
+pub type iconv_t = *mut c_void;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Endian {
+    Big,
+    Little,
+}
+
+#[cfg(target_endian = "big")]
+const NATIVE: Endian = Endian::Big;
+#[cfg(target_endian = "little")]
+const NATIVE: Endian = Endian::Little;
+
+#[derive(Clone, Copy)]
+enum Charset {
+    Ascii,
+    Utf8,
+    // UTF-16 and UTF-32 with an endianness have no byte order mark. Without one, a byte order
+    // mark is read at the start of the input and written at the start of the output, and the
+    // native endianness is used otherwise
+    Utf16(Option<Endian>),
+    Utf32(Option<Endian>),
+    SingleByte(&'static [u16; 128]),
+}
+
+/// The state of a conversion, which iconv_t points to
+struct Iconv {
+    from: Charset,
+    to: Charset,
+    translit: bool,
+    ignore: bool,
+    // The endianness of the input, once its byte order mark has been read
+    from_endian: Option<Endian>,
+    bom_written: bool,
+}
+
+enum Decoded {
+    Char(u32, usize),
+    // A byte order mark, which is skipped
+    Bom(Endian, usize),
+    Incomplete,
+    Invalid(usize),
+}
+
+enum Encoded {
+    Done(usize),
+    Unrepresentable,
+    TooBig,
+}
+
+/// Find a character set by name, ignoring case and punctuation
+fn charset(name: &[u8]) -> Option<Charset> {
+    let mut buf = [0; 32];
+    let mut len = 0;
+    for &c in name.iter().filter(|c| c.is_ascii_alphanumeric()) {
+        if len == buf.len() {
+            return None;
+        }
+        buf[len] = c.to_ascii_uppercase();
+        len += 1;
+    }
+
+    Some(match &buf[..len] {
+        // The empty name is the codeset of the locale, which is always UTF-8
+        b"" | b"UTF8" => Charset::Utf8,
+        b"ASCII" | b"USASCII" | b"ANSIX341968" | b"646" => Charset::Ascii,
+        b"UTF16" => Charset::Utf16(None),
+        b"UTF16LE" => Charset::Utf16(Some(Endian::Little)),
+        b"UTF16BE" => Charset::Utf16(Some(Endian::Big)),
+        b"UTF32" | b"UCS4" => Charset::Utf32(None),
+        b"UTF32LE" | b"UCS4LE" => Charset::Utf32(Some(Endian::Little)),
+        b"UTF32BE" | b"UCS4BE" => Charset::Utf32(Some(Endian::Big)),
+        b"WCHART" => Charset::Utf32(Some(NATIVE)),
+        b"LATIN1" | b"L1" => Charset::SingleByte(&tables::ISO_8859_1),
+        b"LATIN9" => Charset::SingleByte(&tables::ISO_8859_15),
+        b"CP1252" | b"WINDOWS1252" => Charset::SingleByte(&tables::CP1252),
+        name if name.starts_with(b"ISO8859") => Charset::SingleByte(match &name[7..] {
+            b"1" => &tables::ISO_8859_1,
+            b"2" => &tables::ISO_8859_2,
+            b"3" => &tables::ISO_8859_3,
+            b"4" => &tables::ISO_8859_4,
+            b"5" => &tables::ISO_8859_5,
+            b"6" => &tables::ISO_8859_6,
+            b"7" => &tables::ISO_8859_7,
+            b"8" => &tables::ISO_8859_8,
+            b"9" => &tables::ISO_8859_9,
+            b"10" => &tables::ISO_8859_10,
+            b"11" => &tables::ISO_8859_11,
+            b"13" => &tables::ISO_8859_13,
+            b"14" => &tables::ISO_8859_14,
+            b"15" => &tables::ISO_8859_15,
+            _ => return None,
+        }),
+        _ => return None,
+    })
+}
+
+fn read_u16(s: &[u8], endian: Endian) -> u32 {
+    match endian {
+        Endian::Big => (s[0] as u32) << 8 | s[1] as u32,
+        Endian::Little => (s[1] as u32) << 8 | s[0] as u32,
+    }
+}
+
+fn read_u32(s: &[u8], endian: Endian) -> u32 {
+    match endian {
+        Endian::Big => read_u16(&s[0..], endian) << 16 | read_u16(&s[2..], endian),
+        Endian::Little => read_u16(&s[2..], endian) << 16 | read_u16(&s[0..], endian),
+    }
+}
+
+fn write_u16(out: &mut [u8], n: u32, endian: Endian) {
+    let (hi, lo) = ((n >> 8) as u8, n as u8);
+    match endian {
+        Endian::Big => out[..2].copy_from_slice(&[hi, lo]),
+        Endian::Little => out[..2].copy_from_slice(&[lo, hi]),
+    }
+}
+
+fn write_u32(out: &mut [u8], n: u32, endian: Endian) {
+    match endian {
+        Endian::Big => {
+            write_u16(out, n >> 16, endian);
+            write_u16(&mut out[2..], n & 0xFFFF, endian);
+        }
+        Endian::Little => {
+            write_u16(out, n & 0xFFFF, endian);
+            write_u16(&mut out[2..], n >> 16, endian);
+        }
+    }
+}
+
+impl Iconv {
+    /// Decode the character at the start of the input
+    fn decode(&self, input: &[u8]) -> Decoded {
+        match self.from {
+            Charset::Ascii => match input[0] {
+                c @ 0..=0x7F => Decoded::Char(c as u32, 1),
+                _ => Decoded::Invalid(1),
+            },
+            Charset::Utf8 => match utf8::decode(input) {
+                Ok((c, len)) => Decoded::Char(c, len),
+                Err(utf8::DecodeError::Incomplete) => Decoded::Incomplete,
+                Err(utf8::DecodeError::Invalid) => Decoded::Invalid(1),
+            },
+            Charset::Utf16(endian) => {
+                if input.len() < 2 {
+                    return Decoded::Incomplete;
+                }
+                let endian = endian.or(self.from_endian);
+                if endian.is_none() {
+                    match (input[0], input[1]) {
+                        (0xFE, 0xFF) => return Decoded::Bom(Endian::Big, 2),
+                        (0xFF, 0xFE) => return Decoded::Bom(Endian::Little, 2),
+                        _ => (),
+                    }
+                }
+                let endian = endian.unwrap_or(NATIVE);
+                let unit = read_u16(input, endian);
+                match unit {
+                    0xD800..=0xDBFF => {
+                        if input.len() < 4 {
+                            return Decoded::Incomplete;
+                        }
+                        let low = read_u16(&input[2..], endian);
+                        if low < 0xDC00 || low > 0xDFFF {
+                            return Decoded::Invalid(2);
+                        }
+                        Decoded::Char(0x10000 + ((unit - 0xD800) << 10 | (low - 0xDC00)), 4)
+                    }
+                    0xDC00..=0xDFFF => Decoded::Invalid(2),
+                    _ => Decoded::Char(unit, 2),
+                }
+            }
+            Charset::Utf32(endian) => {
+                if input.len() < 4 {
+                    return Decoded::Incomplete;
+                }
+                let endian = endian.or(self.from_endian);
+                if endian.is_none() {
+                    match input[..4] {
+                        [0, 0, 0xFE, 0xFF] => return Decoded::Bom(Endian::Big, 4),
+                        [0xFF, 0xFE, 0, 0] => return Decoded::Bom(Endian::Little, 4),
+                        _ => (),
+                    }
+                }
+                let c = read_u32(input, endian.unwrap_or(NATIVE));
+                if char::from_u32(c).is_some() {
+                    Decoded::Char(c, 4)
+                } else {
+                    Decoded::Invalid(4)
+                }
+            }
+            Charset::SingleByte(table) => match input[0] {
+                c @ 0..=0x7F => Decoded::Char(c as u32, 1),
+                c => match table[c as usize - 0x80] {
+                    0 => Decoded::Invalid(1),
+                    c => Decoded::Char(c as u32, 1),
+                },
+            },
+        }
+    }
+
+    /// Encode a character at the start of the output, after the byte order mark if it's the first
+    fn encode(&self, c: u32, out: &mut [u8]) -> Encoded {
+        let (bom, bom_len) = match self.to {
+            Charset::Utf16(None) if !self.bom_written => (true, 2),
+            Charset::Utf32(None) if !self.bom_written => (true, 4),
+            _ => (false, 0),
+        };
+        if bom && out.len() < bom_len {
+            return Encoded::TooBig;
+        }
+        let (head, body) = out.split_at_mut(bom_len);
+
+        let len = match self.to {
+            Charset::Ascii => {
+                if c > 0x7F {
+                    return Encoded::Unrepresentable;
+                }
+                if body.is_empty() {
+                    return Encoded::TooBig;
+                }
+                body[0] = c as u8;
+                1
+            }
+            Charset::Utf8 => {
+                // Input characters are always valid
+                let c = char::from_u32(c).unwrap();
+                if body.len() < c.len_utf8() {
+                    return Encoded::TooBig;
+                }
+                c.encode_utf8(body).len()
+            }
+            Charset::Utf16(endian) => {
+                let endian = endian.unwrap_or(NATIVE);
+                if bom {
+                    write_u16(head, 0xFEFF, endian);
+                }
+                if c >= 0x10000 {
+                    if body.len() < 4 {
+                        return Encoded::TooBig;
+                    }
+                    let c = c - 0x10000;
+                    write_u16(body, 0xD800 | c >> 10, endian);
+                    write_u16(&mut body[2..], 0xDC00 | (c & 0x3FF), endian);
+                    4
+                } else {
+                    if body.len() < 2 {
+                        return Encoded::TooBig;
+                    }
+                    write_u16(body, c, endian);
+                    2
+                }
+            }
+            Charset::Utf32(endian) => {
+                let endian = endian.unwrap_or(NATIVE);
+                if body.len() < 4 {
+                    return Encoded::TooBig;
+                }
+                if bom {
+                    write_u32(head, 0xFEFF, endian);
+                }
+                write_u32(body, c, endian);
+                4
+            }
+            Charset::SingleByte(table) => {
+                let byte = if c < 0x80 {
+                    c as u8
+                } else {
+                    match table.iter().position(|&t| t != 0 && t as u32 == c) {
+                        Some(i) => (i + 0x80) as u8,
+                        None => return Encoded::Unrepresentable,
+                    }
+                };
+                if body.is_empty() {
+                    return Encoded::TooBig;
+                }
+                body[0] = byte;
+                1
+            }
+        };
+        Encoded::Done(bom_len + len)
+    }
+
+    /// Encode the transliteration of a character, which may be several characters long
+    fn encode_translit(&self, c: u32, out: &mut [u8]) -> Encoded {
+        let replacement = match tables::TRANSLIT.binary_search_by_key(&c, |&(c, _)| c) {
+            Ok(i) => tables::TRANSLIT[i].1,
+            Err(_) => "?",
+        };
+
+        let mut len = 0;
+        let mut bom_written = self.bom_written;
+        for r in replacement.chars() {
+            let state = Iconv {
+                bom_written,
+                ..*self
+            };
+            match state.encode(r as u32, &mut out[len..]) {
+                Encoded::Done(n) => len += n,
+                Encoded::Unrepresentable if replacement != "?" => {
+                    return self.encode_translit(0xFFFD, out)
+                }
+                other => return other,
+            }
+            bom_written = true;
+        }
+        Encoded::Done(len)
+    }
+
+    /// Convert as much of the input as possible, returning the number of characters converted
+    /// irreversibly, and the number of bytes read and written
+    fn convert(&mut self, input: &[u8], out: &mut [u8]) -> (Result<size_t, c_int>, usize, usize) {
+        let mut read = 0;
+        let mut written = 0;
+        let mut irreversible = 0;
+        let mut ignored = false;
+
+        while read < input.len() {
+            let (c, len) = match self.decode(&input[read..]) {
+                Decoded::Char(c, len) => (c, len),
+                Decoded::Bom(endian, len) => {
+                    self.from_endian = Some(endian);
+                    read += len;
+                    continue;
+                }
+                Decoded::Incomplete => return (Err(EINVAL), read, written),
+                Decoded::Invalid(len) => {
+                    if !self.ignore {
+                        return (Err(EILSEQ), read, written);
+                    }
+                    ignored = true;
+                    read += len;
+                    continue;
+                }
+            };
+            if let Charset::Utf16(None) | Charset::Utf32(None) = self.from {
+                // A byte order mark can only be at the start
+                self.from_endian = Some(self.from_endian.unwrap_or(NATIVE));
+            }
+
+            let mut encoded = self.encode(c, &mut out[written..]);
+            if let Encoded::Unrepresentable = encoded {
+                if self.translit {
+                    encoded = self.encode_translit(c, &mut out[written..]);
+                    irreversible += 1;
+                }
+            }
+            match encoded {
+                Encoded::Done(n) => {
+                    written += n;
+                    self.bom_written = true;
+                }
+                Encoded::TooBig => return (Err(E2BIG), read, written),
+                Encoded::Unrepresentable => {
+                    if !self.ignore {
+                        return (Err(EILSEQ), read, written);
+                    }
+                    ignored = true;
+                }
+            }
+            read += len;
+        }
+
+        // Like in glibc, skipping invalid input is reported once everything is converted
+        if ignored {
+            (Err(EILSEQ), read, written)
+        } else {
+            (Ok(irreversible), read, written)
+        }
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn iconv(
+    cd: iconv_t,
+    inbuf: *mut *mut c_char,
+    inbytesleft: *mut size_t,
+    outbuf: *mut *mut c_char,
+    outbytesleft: *mut size_t,
+) -> size_t {
+    let cd = &mut *(cd as *mut Iconv);
+
+    if inbuf.is_null() || (*inbuf).is_null() {
+        // Return to the initial state. There are no shift sequences to write
+        cd.from_endian = None;
+        cd.bom_written = false;
+        return 0;
+    }
+
+    let input = slice::from_raw_parts(*inbuf as *const u8, *inbytesleft);
+    let out = slice::from_raw_parts_mut(*outbuf as *mut u8, *outbytesleft);
+    let (result, read, written) = cd.convert(input, out);
+    *inbuf = (*inbuf).add(read);
+    *inbytesleft -= read;
+    *outbuf = (*outbuf).add(written);
+    *outbytesleft -= written;
+
+    match result {
+        Ok(irreversible) => irreversible,
+        Err(err) => {
+            platform::errno = err;
+            !0
+        }
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn iconv_close(cd: iconv_t) -> c_int {
+    if cd.is_null() || cd as isize == -1 {
+        platform::errno = EBADF;
+        return -1;
+    }
+    Box::from_raw(cd as *mut Iconv);
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn iconv_open(tocode: *const c_char, fromcode: *const c_char) -> iconv_t {
+    let tocode = CStr::from_ptr(tocode).to_bytes();
+    let fromcode = CStr::from_ptr(fromcode).to_bytes();
+
+    // The target may be followed by //TRANSLIT and //IGNORE, in any order
+    let mut parts = tocode.split(|&c| c == b'/').filter(|part| !part.is_empty());
+    let to = parts.next().unwrap_or(&[]);
+    let mut translit = false;
+    let mut ignore = false;
+    for part in parts {
+        if part.eq_ignore_ascii_case(b"TRANSLIT") {
+            translit = true;
+        } else if part.eq_ignore_ascii_case(b"IGNORE") {
+            ignore = true;
+        }
+    }
+    let from = fromcode.split(|&c| c == b'/').next().unwrap_or(&[]);
+
+    match (charset(to), charset(from)) {
+        (Some(to), Some(from)) => Box::into_raw(Box::new(Iconv {
+            from,
+            to,
+            translit,
+            ignore,
+            from_endian: None,
+            bom_written: false,
+        })) as iconv_t,
+        _ => {
+            platform::errno = EINVAL;
+            -1isize as iconv_t
+        }
+    }
+}
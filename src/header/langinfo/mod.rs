@@ -0,0 +1,120 @@
+//! langinfo implementation for Redox, following http://pubs.opengroup.org/onlinepubs/9699919799/basedefs/langinfo.h.html
+
+use header::locale::{self, locale_t, Locale, LocaleStr};
+use platform::types::*;
+
+pub type nl_item = c_int;
+
+// An item is the category it belongs to, shifted left by 16, plus its index in the category
+pub const CODESET: nl_item = 0x20000;
+
+pub const YESEXPR: nl_item = 0x30000;
+pub const NOEXPR: nl_item = 0x30001;
+pub const YESSTR: nl_item = 0x30002;
+pub const NOSTR: nl_item = 0x30003;
+
+pub const CRNCYSTR: nl_item = 0x40000;
+
+pub const RADIXCHAR: nl_item = 0x50000;
+pub const THOUSEP: nl_item = 0x50001;
+
+pub const ABDAY_1: nl_item = 0x60000;
+pub const ABDAY_2: nl_item = 0x60001;
+pub const ABDAY_3: nl_item = 0x60002;
+pub const ABDAY_4: nl_item = 0x60003;
+pub const ABDAY_5: nl_item = 0x60004;
+pub const ABDAY_6: nl_item = 0x60005;
+pub const ABDAY_7: nl_item = 0x60006;
+pub const DAY_1: nl_item = 0x60007;
+pub const DAY_2: nl_item = 0x60008;
+pub const DAY_3: nl_item = 0x60009;
+pub const DAY_4: nl_item = 0x6000A;
+pub const DAY_5: nl_item = 0x6000B;
+pub const DAY_6: nl_item = 0x6000C;
+pub const DAY_7: nl_item = 0x6000D;
+pub const ABMON_1: nl_item = 0x6000E;
+pub const ABMON_2: nl_item = 0x6000F;
+pub const ABMON_3: nl_item = 0x60010;
+pub const ABMON_4: nl_item = 0x60011;
+pub const ABMON_5: nl_item = 0x60012;
+pub const ABMON_6: nl_item = 0x60013;
+pub const ABMON_7: nl_item = 0x60014;
+pub const ABMON_8: nl_item = 0x60015;
+pub const ABMON_9: nl_item = 0x60016;
+pub const ABMON_10: nl_item = 0x60017;
+pub const ABMON_11: nl_item = 0x60018;
+pub const ABMON_12: nl_item = 0x60019;
+pub const MON_1: nl_item = 0x6001A;
+pub const MON_2: nl_item = 0x6001B;
+pub const MON_3: nl_item = 0x6001C;
+pub const MON_4: nl_item = 0x6001D;
+pub const MON_5: nl_item = 0x6001E;
+pub const MON_6: nl_item = 0x6001F;
+pub const MON_7: nl_item = 0x60020;
+pub const MON_8: nl_item = 0x60021;
+pub const MON_9: nl_item = 0x60022;
+pub const MON_10: nl_item = 0x60023;
+pub const MON_11: nl_item = 0x60024;
+pub const MON_12: nl_item = 0x60025;
+pub const AM_STR: nl_item = 0x60026;
+pub const PM_STR: nl_item = 0x60027;
+pub const D_T_FMT: nl_item = 0x60028;
+pub const D_FMT: nl_item = 0x60029;
+pub const T_FMT: nl_item = 0x6002A;
+pub const T_FMT_AMPM: nl_item = 0x6002B;
+pub const ERA: nl_item = 0x6002C;
+pub const ERA_D_FMT: nl_item = 0x6002D;
+pub const ALT_DIGITS: nl_item = 0x6002E;
+pub const ERA_D_T_FMT: nl_item = 0x6002F;
+pub const ERA_T_FMT: nl_item = 0x60030;
+
+const EMPTY: LocaleStr = LocaleStr("\0");
+
+fn langinfo(loc: &Locale, item: nl_item) -> *mut c_char {
+    let index = (item & 0xFFFF) as usize;
+    let s = match item >> 16 {
+        locale::LC_CTYPE if index == 0 => loc.ctype.codeset,
+        locale::LC_NUMERIC => match index {
+            0 => loc.numeric.decimal_point,
+            1 => loc.numeric.thousands_sep,
+            _ => EMPTY,
+        },
+        locale::LC_MONETARY if index == 0 => loc.monetary.crncystr,
+        locale::LC_MESSAGES => match index {
+            0 => loc.messages.yesexpr,
+            1 => loc.messages.noexpr,
+            2 => loc.messages.yesstr,
+            3 => loc.messages.nostr,
+            _ => EMPTY,
+        },
+        locale::LC_TIME => {
+            let time = loc.time;
+            match index {
+                0..=6 => time.abday[index],
+                7..=13 => time.day[index - 7],
+                14..=25 => time.abmon[index - 14],
+                26..=37 => time.mon[index - 26],
+                38..=39 => time.am_pm[index - 38],
+                40 => time.d_t_fmt,
+                41 => time.d_fmt,
+                42 => time.t_fmt,
+                43 => time.t_fmt_ampm,
+                // There are no eras or alternative digits
+                _ => EMPTY,
+            }
+        }
+        // Unknown items are empty
+        _ => EMPTY,
+    };
+    s.as_ptr() as *mut c_char
+}
+
+#[no_mangle]
+pub extern "C" fn nl_langinfo(item: nl_item) -> *mut c_char {
+    langinfo(locale::current(), item)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn nl_langinfo_l(item: nl_item, loc: locale_t) -> *mut c_char {
+    langinfo(locale::get(loc), item)
+}
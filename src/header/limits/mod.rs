@@ -1,3 +1,6 @@
 //! limits.h implementation for relibc
 
 pub const PATH_MAX: usize = 4096;
+
+/// The highest n of the positional arguments `%n$` of printf
+pub const NL_ARGMAX: usize = 64;
@@ -0,0 +1,49 @@
+#!/usr/bin/env python3
+"""Generate table.rs, the collation elements of the Unicode Collation Algorithm.
+
+Usage: gen_table.py allkeys.txt > table.rs
+
+allkeys.txt is the Default Unicode Collation Element Table, from
+https://www.unicode.org/Public/UCA/latest/allkeys.txt
+"""
+
+import re
+import sys
+
+# The ranges whose collation elements are kept: Latin, Greek, Cyrillic, punctuation and symbols.
+# Other characters are given implicit weights, ordering them by code point after these
+RANGES = [(0x0000, 0x052F), (0x1E00, 0x1FFF), (0x2000, 0x206F), (0x20A0, 0x20BF), (0x2100, 0x214F)]
+
+
+def main():
+    entries = []
+    with open(sys.argv[1]) as f:
+        for line in f:
+            line = line.split("#", 1)[0].strip()
+            if not line or line.startswith("@"):
+                continue
+            chars, weights = line.split(";")
+            chars = chars.split()
+            # Contractions are left out
+            if len(chars) != 1:
+                continue
+            cp = int(chars[0], 16)
+            if not any(first <= cp <= last for first, last in RANGES):
+                continue
+            elements = re.findall(r"\[[.*]([0-9A-F]+)\.([0-9A-F]+)\.([0-9A-F]+)\]", weights)
+            entries.append((cp, [tuple(int(w, 16) for w in element) for element in elements]))
+    entries.sort()
+
+    print("//! The Default Unicode Collation Element Table, generated by gen_table.py. Do not edit.")
+    print()
+    print("/// The collation elements of each character, as [primary, secondary, tertiary] weights")
+    print("#[rustfmt::skip]")
+    print("pub static ELEMENTS: &[(u32, &[[u16; 3]])] = &[")
+    for cp, elements in entries:
+        print("    (0x{:x}, &[{}]),".format(
+            cp, ", ".join("[0x{:x}, 0x{:x}, 0x{:x}]".format(*element) for element in elements)))
+    print("];")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,138 @@
+//! Collation by the Unicode Collation Algorithm, following https://www.unicode.org/reports/tr10/
+//!
+//! Strings are compared by their sort keys, which hold the primary, secondary and tertiary
+//! weights of their collation elements, and then their characters so that only equal strings
+//! compare equal. Variable weighted characters are not ignorable, and contractions are not
+//! supported. Characters missing from the table get implicit weights, which order them by code
+//! point after the characters of the table.
+
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+use header::wchar::utf8;
+use platform::types::*;
+
+use super::LcCollate;
+
+mod table;
+
+// Separates the levels of a sort key, below any byte of a weight
+const LEVEL_SEPARATOR: u8 = 1;
+
+/// The elements of a character in the default table
+fn default_elements(c: u32) -> Option<&'static [[u16; 3]]> {
+    table::ELEMENTS
+        .binary_search_by_key(&c, |&(c, _)| c)
+        .ok()
+        .map(|i| table::ELEMENTS[i].1)
+}
+
+/// The first primary weight of a character in the default table, if it has one
+pub fn primary(c: u32) -> Option<u16> {
+    default_elements(c)?
+        .iter()
+        .map(|element| element[0])
+        .find(|&primary| primary != 0)
+}
+
+/// Push the collation elements of a character. Primary weights have 8 more bits, so that tailored
+/// characters fit between the primary weights of the table
+fn push_elements(c: u32, collate: &LcCollate, out: &mut Vec<[u32; 3]>) {
+    let start = out.len();
+    match default_elements(c) {
+        Some(elements) => out.extend(elements.iter().map(|element| {
+            [
+                (element[0] as u32) << 8,
+                element[1] as u32,
+                element[2] as u32,
+            ]
+        })),
+        None => {
+            let base = match c {
+                0x4E00..=0x9FFF | 0xF900..=0xFAFF => 0xFB40,
+                0x3400..=0x4DBF | 0x20000..=0x2A6DF | 0x2A700..=0x2EBEF | 0x30000..=0x3134F => {
+                    0xFB80
+                }
+                _ => 0xFBC0,
+            };
+            out.push([(base + (c >> 15)) << 8, 0x20, 0x2]);
+            out.push([((c & 0x7FFF) | 0x8000) << 8, 0, 0]);
+        }
+    }
+
+    if let Some(&(_, anchor, rank)) = collate.tailoring.iter().find(|t| t.0 == c) {
+        if let Some(anchor) = primary(anchor) {
+            out[start][0] = (anchor as u32) << 8 | rank as u32;
+        }
+    }
+}
+
+/// Push a weight as big endian groups of 6 bits, each above the level separator
+fn push_weight(key: &mut Vec<u8>, weight: u32, groups: usize) {
+    for i in (0..groups).rev() {
+        key.push((weight >> (6 * i)) as u8 & 0x3F | 0x40);
+    }
+}
+
+/// The sort key of a string of characters. It never contains a NUL, and comparing the keys of two
+/// strings byte by byte orders them like the collation does
+pub fn sort_key<I: Iterator<Item = u32>>(chars: I, collate: &LcCollate) -> Vec<u8> {
+    let chars: Vec<u32> = chars.collect();
+    let mut elements = Vec::with_capacity(chars.len());
+    for &c in &chars {
+        push_elements(c, collate, &mut elements);
+    }
+
+    let mut key = Vec::with_capacity(elements.len() * 6 + chars.len() * 6);
+    for (level, groups) in [4, 3, 1].iter().enumerate() {
+        for element in &elements {
+            if element[level] != 0 {
+                push_weight(&mut key, element[level], *groups);
+            }
+        }
+        key.push(LEVEL_SEPARATOR);
+    }
+    for &c in &chars {
+        push_weight(&mut key, c, 6);
+    }
+    key
+}
+
+/// The characters of a multibyte string. Bytes that aren't valid UTF-8 are kept apart, as the
+/// unpaired surrogates U+DC80 to U+DCFF
+pub fn chars(s: &[u8]) -> impl Iterator<Item = u32> + '_ {
+    let mut i = 0;
+    core::iter::from_fn(move || {
+        if i == s.len() {
+            return None;
+        }
+        match utf8::decode(&s[i..]) {
+            Ok((c, len)) => {
+                i += len;
+                Some(c)
+            }
+            Err(_) => {
+                i += 1;
+                Some(0xDC00 | s[i - 1] as u32)
+            }
+        }
+    })
+}
+
+/// The characters of a wide string
+pub fn wide_chars(s: &[wchar_t]) -> impl Iterator<Item = u32> + '_ {
+    s.iter().map(|&c| c as u32)
+}
+
+/// Compare two strings of characters
+pub fn compare<I, J>(a: I, b: J, collate: &LcCollate) -> c_int
+where
+    I: Iterator<Item = u32>,
+    J: Iterator<Item = u32>,
+{
+    match sort_key(a, collate).cmp(&sort_key(b, collate)) {
+        Ordering::Less => -1,
+        Ordering::Equal => 0,
+        Ordering::Greater => 1,
+    }
+}
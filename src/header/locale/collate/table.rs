@@ -0,0 +1,2037 @@
+//! The Default Unicode Collation Element Table, generated by gen_table.py. Do not edit.
+
+/// The collation elements of each character, as [primary, secondary, tertiary] weights
+#[rustfmt::skip]
+pub static ELEMENTS: &[(u32, &[[u16; 3]])] = &[
+    (0x0, &[[0x0, 0x0, 0x0]]),
+    (0x1, &[[0x0, 0x0, 0x0]]),
+    (0x2, &[[0x0, 0x0, 0x0]]),
+    (0x3, &[[0x0, 0x0, 0x0]]),
+    (0x4, &[[0x0, 0x0, 0x0]]),
+    (0x5, &[[0x0, 0x0, 0x0]]),
+    (0x6, &[[0x0, 0x0, 0x0]]),
+    (0x7, &[[0x0, 0x0, 0x0]]),
+    (0x8, &[[0x0, 0x0, 0x0]]),
+    (0x9, &[[0x201, 0x20, 0x2]]),
+    (0xa, &[[0x202, 0x20, 0x2]]),
+    (0xb, &[[0x203, 0x20, 0x2]]),
+    (0xc, &[[0x204, 0x20, 0x2]]),
+    (0xd, &[[0x205, 0x20, 0x2]]),
+    (0xe, &[[0x0, 0x0, 0x0]]),
+    (0xf, &[[0x0, 0x0, 0x0]]),
+    (0x10, &[[0x0, 0x0, 0x0]]),
+    (0x11, &[[0x0, 0x0, 0x0]]),
+    (0x12, &[[0x0, 0x0, 0x0]]),
+    (0x13, &[[0x0, 0x0, 0x0]]),
+    (0x14, &[[0x0, 0x0, 0x0]]),
+    (0x15, &[[0x0, 0x0, 0x0]]),
+    (0x16, &[[0x0, 0x0, 0x0]]),
+    (0x17, &[[0x0, 0x0, 0x0]]),
+    (0x18, &[[0x0, 0x0, 0x0]]),
+    (0x19, &[[0x0, 0x0, 0x0]]),
+    (0x1a, &[[0x0, 0x0, 0x0]]),
+    (0x1b, &[[0x0, 0x0, 0x0]]),
+    (0x1c, &[[0x0, 0x0, 0x0]]),
+    (0x1d, &[[0x0, 0x0, 0x0]]),
+    (0x1e, &[[0x0, 0x0, 0x0]]),
+    (0x1f, &[[0x0, 0x0, 0x0]]),
+    (0x20, &[[0x209, 0x20, 0x2]]),
+    (0x21, &[[0x267, 0x20, 0x2]]),
+    (0x22, &[[0x31d, 0x20, 0x2]]),
+    (0x23, &[[0x3ac, 0x20, 0x2]]),
+    (0x24, &[[0x1f64, 0x20, 0x2]]),
+    (0x25, &[[0x3ad, 0x20, 0x2]]),
+    (0x26, &[[0x3a9, 0x20, 0x2]]),
+    (0x27, &[[0x316, 0x20, 0x2]]),
+    (0x28, &[[0x328, 0x20, 0x2]]),
+    (0x29, &[[0x329, 0x20, 0x2]]),
+    (0x2a, &[[0x3a1, 0x20, 0x2]]),
+    (0x2b, &[[0x666, 0x20, 0x2]]),
+    (0x2c, &[[0x223, 0x20, 0x2]]),
+    (0x2d, &[[0x20d, 0x20, 0x2]]),
+    (0x2e, &[[0x27e, 0x20, 0x2]]),
+    (0x2f, &[[0x3a6, 0x20, 0x2]]),
+    (0x30, &[[0x1f98, 0x20, 0x2]]),
+    (0x31, &[[0x1f99, 0x20, 0x2]]),
+    (0x32, &[[0x1f9a, 0x20, 0x2]]),
+    (0x33, &[[0x1f9b, 0x20, 0x2]]),
+    (0x34, &[[0x1f9c, 0x20, 0x2]]),
+    (0x35, &[[0x1f9d, 0x20, 0x2]]),
+    (0x36, &[[0x1f9e, 0x20, 0x2]]),
+    (0x37, &[[0x1f9f, 0x20, 0x2]]),
+    (0x38, &[[0x1fa0, 0x20, 0x2]]),
+    (0x39, &[[0x1fa1, 0x20, 0x2]]),
+    (0x3a, &[[0x240, 0x20, 0x2]]),
+    (0x3b, &[[0x23a, 0x20, 0x2]]),
+    (0x3c, &[[0x66a, 0x20, 0x2]]),
+    (0x3d, &[[0x66b, 0x20, 0x2]]),
+    (0x3e, &[[0x66c, 0x20, 0x2]]),
+    (0x3f, &[[0x26d, 0x20, 0x2]]),
+    (0x40, &[[0x3a0, 0x20, 0x2]]),
+    (0x41, &[[0x1fa2, 0x20, 0x8]]),
+    (0x42, &[[0x1fbc, 0x20, 0x8]]),
+    (0x43, &[[0x1fd6, 0x20, 0x8]]),
+    (0x44, &[[0x1feb, 0x20, 0x8]]),
+    (0x45, &[[0x2007, 0x20, 0x8]]),
+    (0x46, &[[0x2042, 0x20, 0x8]]),
+    (0x47, &[[0x2051, 0x20, 0x8]]),
+    (0x48, &[[0x2075, 0x20, 0x8]]),
+    (0x49, &[[0x2090, 0x20, 0x8]]),
+    (0x4a, &[[0x20ab, 0x20, 0x8]]),
+    (0x4b, &[[0x20c4, 0x20, 0x8]]),
+    (0x4c, &[[0x20d6, 0x20, 0x8]]),
+    (0x4d, &[[0x2109, 0x20, 0x8]]),
+    (0x4e, &[[0x2118, 0x20, 0x8]]),
+    (0x4f, &[[0x213c, 0x20, 0x8]]),
+    (0x50, &[[0x216b, 0x20, 0x8]]),
+    (0x51, &[[0x2180, 0x20, 0x8]]),
+    (0x52, &[[0x2193, 0x20, 0x8]]),
+    (0x53, &[[0x21d2, 0x20, 0x8]]),
+    (0x54, &[[0x21f7, 0x20, 0x8]]),
+    (0x55, &[[0x2217, 0x20, 0x8]]),
+    (0x56, &[[0x2247, 0x20, 0x8]]),
+    (0x57, &[[0x2259, 0x20, 0x8]]),
+    (0x58, &[[0x2264, 0x20, 0x8]]),
+    (0x59, &[[0x2270, 0x20, 0x8]]),
+    (0x5a, &[[0x2286, 0x20, 0x8]]),
+    (0x5b, &[[0x32a, 0x20, 0x2]]),
+    (0x5c, &[[0x3a7, 0x20, 0x2]]),
+    (0x5d, &[[0x32b, 0x20, 0x2]]),
+    (0x5e, &[[0x4b7, 0x20, 0x2]]),
+    (0x5f, &[[0x20b, 0x20, 0x2]]),
+    (0x60, &[[0x4b4, 0x20, 0x2]]),
+    (0x61, &[[0x1fa2, 0x20, 0x2]]),
+    (0x62, &[[0x1fbc, 0x20, 0x2]]),
+    (0x63, &[[0x1fd6, 0x20, 0x2]]),
+    (0x64, &[[0x1feb, 0x20, 0x2]]),
+    (0x65, &[[0x2007, 0x20, 0x2]]),
+    (0x66, &[[0x2042, 0x20, 0x2]]),
+    (0x67, &[[0x2051, 0x20, 0x2]]),
+    (0x68, &[[0x2075, 0x20, 0x2]]),
+    (0x69, &[[0x2090, 0x20, 0x2]]),
+    (0x6a, &[[0x20ab, 0x20, 0x2]]),
+    (0x6b, &[[0x20c4, 0x20, 0x2]]),
+    (0x6c, &[[0x20d6, 0x20, 0x2]]),
+    (0x6d, &[[0x2109, 0x20, 0x2]]),
+    (0x6e, &[[0x2118, 0x20, 0x2]]),
+    (0x6f, &[[0x213c, 0x20, 0x2]]),
+    (0x70, &[[0x216b, 0x20, 0x2]]),
+    (0x71, &[[0x2180, 0x20, 0x2]]),
+    (0x72, &[[0x2193, 0x20, 0x2]]),
+    (0x73, &[[0x21d2, 0x20, 0x2]]),
+    (0x74, &[[0x21f7, 0x20, 0x2]]),
+    (0x75, &[[0x2217, 0x20, 0x2]]),
+    (0x76, &[[0x2247, 0x20, 0x2]]),
+    (0x77, &[[0x2259, 0x20, 0x2]]),
+    (0x78, &[[0x2264, 0x20, 0x2]]),
+    (0x79, &[[0x2270, 0x20, 0x2]]),
+    (0x7a, &[[0x2286, 0x20, 0x2]]),
+    (0x7b, &[[0x32c, 0x20, 0x2]]),
+    (0x7c, &[[0x66e, 0x20, 0x2]]),
+    (0x7d, &[[0x32d, 0x20, 0x2]]),
+    (0x7e, &[[0x670, 0x20, 0x2]]),
+    (0x7f, &[[0x0, 0x0, 0x0]]),
+    (0x80, &[[0x0, 0x0, 0x0]]),
+    (0x81, &[[0x0, 0x0, 0x0]]),
+    (0x82, &[[0x0, 0x0, 0x0]]),
+    (0x83, &[[0x0, 0x0, 0x0]]),
+    (0x84, &[[0x0, 0x0, 0x0]]),
+    (0x85, &[[0x206, 0x20, 0x2]]),
+    (0x86, &[[0x0, 0x0, 0x0]]),
+    (0x87, &[[0x0, 0x0, 0x0]]),
+    (0x88, &[[0x0, 0x0, 0x0]]),
+    (0x89, &[[0x0, 0x0, 0x0]]),
+    (0x8a, &[[0x0, 0x0, 0x0]]),
+    (0x8b, &[[0x0, 0x0, 0x0]]),
+    (0x8c, &[[0x0, 0x0, 0x0]]),
+    (0x8d, &[[0x0, 0x0, 0x0]]),
+    (0x8e, &[[0x0, 0x0, 0x0]]),
+    (0x8f, &[[0x0, 0x0, 0x0]]),
+    (0x90, &[[0x0, 0x0, 0x0]]),
+    (0x91, &[[0x0, 0x0, 0x0]]),
+    (0x92, &[[0x0, 0x0, 0x0]]),
+    (0x93, &[[0x0, 0x0, 0x0]]),
+    (0x94, &[[0x0, 0x0, 0x0]]),
+    (0x95, &[[0x0, 0x0, 0x0]]),
+    (0x96, &[[0x0, 0x0, 0x0]]),
+    (0x97, &[[0x0, 0x0, 0x0]]),
+    (0x98, &[[0x0, 0x0, 0x0]]),
+    (0x99, &[[0x0, 0x0, 0x0]]),
+    (0x9a, &[[0x0, 0x0, 0x0]]),
+    (0x9b, &[[0x0, 0x0, 0x0]]),
+    (0x9c, &[[0x0, 0x0, 0x0]]),
+    (0x9d, &[[0x0, 0x0, 0x0]]),
+    (0x9e, &[[0x0, 0x0, 0x0]]),
+    (0x9f, &[[0x0, 0x0, 0x0]]),
+    (0xa0, &[[0x209, 0x20, 0x1b]]),
+    (0xa1, &[[0x268, 0x20, 0x2]]),
+    (0xa2, &[[0x1f63, 0x20, 0x2]]),
+    (0xa3, &[[0x1f65, 0x20, 0x2]]),
+    (0xa4, &[[0x1f62, 0x20, 0x2]]),
+    (0xa5, &[[0x1f66, 0x20, 0x2]]),
+    (0xa6, &[[0x66f, 0x20, 0x2]]),
+    (0xa7, &[[0x39a, 0x20, 0x2]]),
+    (0xa8, &[[0x4bb, 0x20, 0x2]]),
+    (0xa9, &[[0x5d2, 0x20, 0x2]]),
+    (0xaa, &[[0x1fa2, 0x20, 0x14]]),
+    (0xab, &[[0x326, 0x20, 0x2]]),
+    (0xac, &[[0x66d, 0x20, 0x2]]),
+    (0xad, &[[0x0, 0x0, 0x0]]),
+    (0xae, &[[0x5d4, 0x20, 0x2]]),
+    (0xaf, &[[0x4b8, 0x20, 0x2]]),
+    (0xb0, &[[0x52a, 0x20, 0x2]]),
+    (0xb1, &[[0x667, 0x20, 0x2]]),
+    (0xb2, &[[0x1f9a, 0x20, 0x14]]),
+    (0xb3, &[[0x1f9b, 0x20, 0x14]]),
+    (0xb4, &[[0x4b5, 0x20, 0x2]]),
+    (0xb5, &[[0x2330, 0x20, 0x4]]),
+    (0xb6, &[[0x39c, 0x20, 0x2]]),
+    (0xb7, &[[0x293, 0x20, 0x2]]),
+    (0xb8, &[[0x4be, 0x20, 0x2]]),
+    (0xb9, &[[0x1f99, 0x20, 0x14]]),
+    (0xba, &[[0x213c, 0x20, 0x14]]),
+    (0xbb, &[[0x327, 0x20, 0x2]]),
+    (0xbc, &[[0x1f99, 0x20, 0x1e], [0x676, 0x20, 0x1e], [0x1f9c, 0x20, 0x1e]]),
+    (0xbd, &[[0x1f99, 0x20, 0x1e], [0x676, 0x20, 0x1e], [0x1f9a, 0x20, 0x1e]]),
+    (0xbe, &[[0x1f9b, 0x20, 0x1e], [0x676, 0x20, 0x1e], [0x1f9c, 0x20, 0x1e]]),
+    (0xbf, &[[0x26e, 0x20, 0x2]]),
+    (0xc0, &[[0x1fa2, 0x20, 0x8], [0x0, 0x25, 0x2]]),
+    (0xc1, &[[0x1fa2, 0x20, 0x8], [0x0, 0x24, 0x2]]),
+    (0xc2, &[[0x1fa2, 0x20, 0x8], [0x0, 0x27, 0x2]]),
+    (0xc3, &[[0x1fa2, 0x20, 0x8], [0x0, 0x2d, 0x2]]),
+    (0xc4, &[[0x1fa2, 0x20, 0x8], [0x0, 0x2b, 0x2]]),
+    (0xc5, &[[0x1fa2, 0x20, 0x8], [0x0, 0x29, 0x2]]),
+    (0xc6, &[[0x1fa2, 0x20, 0xa], [0x0, 0x118, 0x4], [0x2007, 0x20, 0xa]]),
+    (0xc7, &[[0x1fd6, 0x20, 0x8], [0x0, 0x30, 0x2]]),
+    (0xc8, &[[0x2007, 0x20, 0x8], [0x0, 0x25, 0x2]]),
+    (0xc9, &[[0x2007, 0x20, 0x8], [0x0, 0x24, 0x2]]),
+    (0xca, &[[0x2007, 0x20, 0x8], [0x0, 0x27, 0x2]]),
+    (0xcb, &[[0x2007, 0x20, 0x8], [0x0, 0x2b, 0x2]]),
+    (0xcc, &[[0x2090, 0x20, 0x8], [0x0, 0x25, 0x2]]),
+    (0xcd, &[[0x2090, 0x20, 0x8], [0x0, 0x24, 0x2]]),
+    (0xce, &[[0x2090, 0x20, 0x8], [0x0, 0x27, 0x2]]),
+    (0xcf, &[[0x2090, 0x20, 0x8], [0x0, 0x2b, 0x2]]),
+    (0xd0, &[[0x1feb, 0x20, 0xa], [0x0, 0x118, 0x4]]),
+    (0xd1, &[[0x2118, 0x20, 0x8], [0x0, 0x2d, 0x2]]),
+    (0xd2, &[[0x213c, 0x20, 0x8], [0x0, 0x25, 0x2]]),
+    (0xd3, &[[0x213c, 0x20, 0x8], [0x0, 0x24, 0x2]]),
+    (0xd4, &[[0x213c, 0x20, 0x8], [0x0, 0x27, 0x2]]),
+    (0xd5, &[[0x213c, 0x20, 0x8], [0x0, 0x2d, 0x2]]),
+    (0xd6, &[[0x213c, 0x20, 0x8], [0x0, 0x2b, 0x2]]),
+    (0xd7, &[[0x669, 0x20, 0x2]]),
+    (0xd8, &[[0x213c, 0x20, 0x8], [0x0, 0x2f, 0x2]]),
+    (0xd9, &[[0x2217, 0x20, 0x8], [0x0, 0x25, 0x2]]),
+    (0xda, &[[0x2217, 0x20, 0x8], [0x0, 0x24, 0x2]]),
+    (0xdb, &[[0x2217, 0x20, 0x8], [0x0, 0x27, 0x2]]),
+    (0xdc, &[[0x2217, 0x20, 0x8], [0x0, 0x2b, 0x2]]),
+    (0xdd, &[[0x2270, 0x20, 0x8], [0x0, 0x24, 0x2]]),
+    (0xde, &[[0x22b5, 0x20, 0x8]]),
+    (0xdf, &[[0x21d2, 0x20, 0x4], [0x0, 0x118, 0x4], [0x21d2, 0x20, 0x4]]),
+    (0xe0, &[[0x1fa2, 0x20, 0x2], [0x0, 0x25, 0x2]]),
+    (0xe1, &[[0x1fa2, 0x20, 0x2], [0x0, 0x24, 0x2]]),
+    (0xe2, &[[0x1fa2, 0x20, 0x2], [0x0, 0x27, 0x2]]),
+    (0xe3, &[[0x1fa2, 0x20, 0x2], [0x0, 0x2d, 0x2]]),
+    (0xe4, &[[0x1fa2, 0x20, 0x2], [0x0, 0x2b, 0x2]]),
+    (0xe5, &[[0x1fa2, 0x20, 0x2], [0x0, 0x29, 0x2]]),
+    (0xe6, &[[0x1fa2, 0x20, 0x4], [0x0, 0x118, 0x4], [0x2007, 0x20, 0x4]]),
+    (0xe7, &[[0x1fd6, 0x20, 0x2], [0x0, 0x30, 0x2]]),
+    (0xe8, &[[0x2007, 0x20, 0x2], [0x0, 0x25, 0x2]]),
+    (0xe9, &[[0x2007, 0x20, 0x2], [0x0, 0x24, 0x2]]),
+    (0xea, &[[0x2007, 0x20, 0x2], [0x0, 0x27, 0x2]]),
+    (0xeb, &[[0x2007, 0x20, 0x2], [0x0, 0x2b, 0x2]]),
+    (0xec, &[[0x2090, 0x20, 0x2], [0x0, 0x25, 0x2]]),
+    (0xed, &[[0x2090, 0x20, 0x2], [0x0, 0x24, 0x2]]),
+    (0xee, &[[0x2090, 0x20, 0x2], [0x0, 0x27, 0x2]]),
+    (0xef, &[[0x2090, 0x20, 0x2], [0x0, 0x2b, 0x2]]),
+    (0xf0, &[[0x1feb, 0x20, 0x4], [0x0, 0x118, 0x4]]),
+    (0xf1, &[[0x2118, 0x20, 0x2], [0x0, 0x2d, 0x2]]),
+    (0xf2, &[[0x213c, 0x20, 0x2], [0x0, 0x25, 0x2]]),
+    (0xf3, &[[0x213c, 0x20, 0x2], [0x0, 0x24, 0x2]]),
+    (0xf4, &[[0x213c, 0x20, 0x2], [0x0, 0x27, 0x2]]),
+    (0xf5, &[[0x213c, 0x20, 0x2], [0x0, 0x2d, 0x2]]),
+    (0xf6, &[[0x213c, 0x20, 0x2], [0x0, 0x2b, 0x2]]),
+    (0xf7, &[[0x668, 0x20, 0x2]]),
+    (0xf8, &[[0x213c, 0x20, 0x2], [0x0, 0x2f, 0x2]]),
+    (0xf9, &[[0x2217, 0x20, 0x2], [0x0, 0x25, 0x2]]),
+    (0xfa, &[[0x2217, 0x20, 0x2], [0x0, 0x24, 0x2]]),
+    (0xfb, &[[0x2217, 0x20, 0x2], [0x0, 0x27, 0x2]]),
+    (0xfc, &[[0x2217, 0x20, 0x2], [0x0, 0x2b, 0x2]]),
+    (0xfd, &[[0x2270, 0x20, 0x2], [0x0, 0x24, 0x2]]),
+    (0xfe, &[[0x22b5, 0x20, 0x2]]),
+    (0xff, &[[0x2270, 0x20, 0x2], [0x0, 0x2b, 0x2]]),
+    (0x100, &[[0x1fa2, 0x20, 0x8], [0x0, 0x32, 0x2]]),
+    (0x101, &[[0x1fa2, 0x20, 0x2], [0x0, 0x32, 0x2]]),
+    (0x102, &[[0x1fa2, 0x20, 0x8], [0x0, 0x26, 0x2]]),
+    (0x103, &[[0x1fa2, 0x20, 0x2], [0x0, 0x26, 0x2]]),
+    (0x104, &[[0x1fa2, 0x20, 0x8], [0x0, 0x31, 0x2]]),
+    (0x105, &[[0x1fa2, 0x20, 0x2], [0x0, 0x31, 0x2]]),
+    (0x106, &[[0x1fd6, 0x20, 0x8], [0x0, 0x24, 0x2]]),
+    (0x107, &[[0x1fd6, 0x20, 0x2], [0x0, 0x24, 0x2]]),
+    (0x108, &[[0x1fd6, 0x20, 0x8], [0x0, 0x27, 0x2]]),
+    (0x109, &[[0x1fd6, 0x20, 0x2], [0x0, 0x27, 0x2]]),
+    (0x10a, &[[0x1fd6, 0x20, 0x8], [0x0, 0x2e, 0x2]]),
+    (0x10b, &[[0x1fd6, 0x20, 0x2], [0x0, 0x2e, 0x2]]),
+    (0x10c, &[[0x1fd6, 0x20, 0x8], [0x0, 0x28, 0x2]]),
+    (0x10d, &[[0x1fd6, 0x20, 0x2], [0x0, 0x28, 0x2]]),
+    (0x10e, &[[0x1feb, 0x20, 0x8], [0x0, 0x28, 0x2]]),
+    (0x10f, &[[0x1feb, 0x20, 0x2], [0x0, 0x28, 0x2]]),
+    (0x110, &[[0x1feb, 0x20, 0x8], [0x0, 0x39, 0x2]]),
+    (0x111, &[[0x1feb, 0x20, 0x2], [0x0, 0x39, 0x2]]),
+    (0x112, &[[0x2007, 0x20, 0x8], [0x0, 0x32, 0x2]]),
+    (0x113, &[[0x2007, 0x20, 0x2], [0x0, 0x32, 0x2]]),
+    (0x114, &[[0x2007, 0x20, 0x8], [0x0, 0x26, 0x2]]),
+    (0x115, &[[0x2007, 0x20, 0x2], [0x0, 0x26, 0x2]]),
+    (0x116, &[[0x2007, 0x20, 0x8], [0x0, 0x2e, 0x2]]),
+    (0x117, &[[0x2007, 0x20, 0x2], [0x0, 0x2e, 0x2]]),
+    (0x118, &[[0x2007, 0x20, 0x8], [0x0, 0x31, 0x2]]),
+    (0x119, &[[0x2007, 0x20, 0x2], [0x0, 0x31, 0x2]]),
+    (0x11a, &[[0x2007, 0x20, 0x8], [0x0, 0x28, 0x2]]),
+    (0x11b, &[[0x2007, 0x20, 0x2], [0x0, 0x28, 0x2]]),
+    (0x11c, &[[0x2051, 0x20, 0x8], [0x0, 0x27, 0x2]]),
+    (0x11d, &[[0x2051, 0x20, 0x2], [0x0, 0x27, 0x2]]),
+    (0x11e, &[[0x2051, 0x20, 0x8], [0x0, 0x26, 0x2]]),
+    (0x11f, &[[0x2051, 0x20, 0x2], [0x0, 0x26, 0x2]]),
+    (0x120, &[[0x2051, 0x20, 0x8], [0x0, 0x2e, 0x2]]),
+    (0x121, &[[0x2051, 0x20, 0x2], [0x0, 0x2e, 0x2]]),
+    (0x122, &[[0x2051, 0x20, 0x8], [0x0, 0x30, 0x2]]),
+    (0x123, &[[0x2051, 0x20, 0x2], [0x0, 0x30, 0x2]]),
+    (0x124, &[[0x2075, 0x20, 0x8], [0x0, 0x27, 0x2]]),
+    (0x125, &[[0x2075, 0x20, 0x2], [0x0, 0x27, 0x2]]),
+    (0x126, &[[0x2075, 0x20, 0x8], [0x0, 0x39, 0x2]]),
+    (0x127, &[[0x2075, 0x20, 0x2], [0x0, 0x39, 0x2]]),
+    (0x128, &[[0x2090, 0x20, 0x8], [0x0, 0x2d, 0x2]]),
+    (0x129, &[[0x2090, 0x20, 0x2], [0x0, 0x2d, 0x2]]),
+    (0x12a, &[[0x2090, 0x20, 0x8], [0x0, 0x32, 0x2]]),
+    (0x12b, &[[0x2090, 0x20, 0x2], [0x0, 0x32, 0x2]]),
+    (0x12c, &[[0x2090, 0x20, 0x8], [0x0, 0x26, 0x2]]),
+    (0x12d, &[[0x2090, 0x20, 0x2], [0x0, 0x26, 0x2]]),
+    (0x12e, &[[0x2090, 0x20, 0x8], [0x0, 0x31, 0x2]]),
+    (0x12f, &[[0x2090, 0x20, 0x2], [0x0, 0x31, 0x2]]),
+    (0x130, &[[0x2090, 0x20, 0x8], [0x0, 0x2e, 0x2]]),
+    (0x131, &[[0x2094, 0x20, 0x2]]),
+    (0x132, &[[0x2090, 0x20, 0xa], [0x20ab, 0x20, 0xa]]),
+    (0x133, &[[0x2090, 0x20, 0x4], [0x20ab, 0x20, 0x4]]),
+    (0x134, &[[0x20ab, 0x20, 0x8], [0x0, 0x27, 0x2]]),
+    (0x135, &[[0x20ab, 0x20, 0x2], [0x0, 0x27, 0x2]]),
+    (0x136, &[[0x20c4, 0x20, 0x8], [0x0, 0x30, 0x2]]),
+    (0x137, &[[0x20c4, 0x20, 0x2], [0x0, 0x30, 0x2]]),
+    (0x138, &[[0x218f, 0x20, 0x2]]),
+    (0x139, &[[0x20d6, 0x20, 0x8], [0x0, 0x24, 0x2]]),
+    (0x13a, &[[0x20d6, 0x20, 0x2], [0x0, 0x24, 0x2]]),
+    (0x13b, &[[0x20d6, 0x20, 0x8], [0x0, 0x30, 0x2]]),
+    (0x13c, &[[0x20d6, 0x20, 0x2], [0x0, 0x30, 0x2]]),
+    (0x13d, &[[0x20d6, 0x20, 0x8], [0x0, 0x28, 0x2]]),
+    (0x13e, &[[0x20d6, 0x20, 0x2], [0x0, 0x28, 0x2]]),
+    (0x13f, &[[0x20d6, 0x20, 0x8], [0x0, 0x118, 0x2]]),
+    (0x140, &[[0x20d6, 0x20, 0x2], [0x0, 0x118, 0x2]]),
+    (0x141, &[[0x20d6, 0x20, 0x8], [0x0, 0x39, 0x2]]),
+    (0x142, &[[0x20d6, 0x20, 0x2], [0x0, 0x39, 0x2]]),
+    (0x143, &[[0x2118, 0x20, 0x8], [0x0, 0x24, 0x2]]),
+    (0x144, &[[0x2118, 0x20, 0x2], [0x0, 0x24, 0x2]]),
+    (0x145, &[[0x2118, 0x20, 0x8], [0x0, 0x30, 0x2]]),
+    (0x146, &[[0x2118, 0x20, 0x2], [0x0, 0x30, 0x2]]),
+    (0x147, &[[0x2118, 0x20, 0x8], [0x0, 0x28, 0x2]]),
+    (0x148, &[[0x2118, 0x20, 0x2], [0x0, 0x28, 0x2]]),
+    (0x149, &[[0x22e3, 0x20, 0x4], [0x2118, 0x20, 0x4]]),
+    (0x14a, &[[0x2137, 0x20, 0x8]]),
+    (0x14b, &[[0x2137, 0x20, 0x2]]),
+    (0x14c, &[[0x213c, 0x20, 0x8], [0x0, 0x32, 0x2]]),
+    (0x14d, &[[0x213c, 0x20, 0x2], [0x0, 0x32, 0x2]]),
+    (0x14e, &[[0x213c, 0x20, 0x8], [0x0, 0x26, 0x2]]),
+    (0x14f, &[[0x213c, 0x20, 0x2], [0x0, 0x26, 0x2]]),
+    (0x150, &[[0x213c, 0x20, 0x8], [0x0, 0x2c, 0x2]]),
+    (0x151, &[[0x213c, 0x20, 0x2], [0x0, 0x2c, 0x2]]),
+    (0x152, &[[0x213c, 0x20, 0xa], [0x0, 0x118, 0x4], [0x2007, 0x20, 0xa]]),
+    (0x153, &[[0x213c, 0x20, 0x4], [0x0, 0x118, 0x4], [0x2007, 0x20, 0x4]]),
+    (0x154, &[[0x2193, 0x20, 0x8], [0x0, 0x24, 0x2]]),
+    (0x155, &[[0x2193, 0x20, 0x2], [0x0, 0x24, 0x2]]),
+    (0x156, &[[0x2193, 0x20, 0x8], [0x0, 0x30, 0x2]]),
+    (0x157, &[[0x2193, 0x20, 0x2], [0x0, 0x30, 0x2]]),
+    (0x158, &[[0x2193, 0x20, 0x8], [0x0, 0x28, 0x2]]),
+    (0x159, &[[0x2193, 0x20, 0x2], [0x0, 0x28, 0x2]]),
+    (0x15a, &[[0x21d2, 0x20, 0x8], [0x0, 0x24, 0x2]]),
+    (0x15b, &[[0x21d2, 0x20, 0x2], [0x0, 0x24, 0x2]]),
+    (0x15c, &[[0x21d2, 0x20, 0x8], [0x0, 0x27, 0x2]]),
+    (0x15d, &[[0x21d2, 0x20, 0x2], [0x0, 0x27, 0x2]]),
+    (0x15e, &[[0x21d2, 0x20, 0x8], [0x0, 0x30, 0x2]]),
+    (0x15f, &[[0x21d2, 0x20, 0x2], [0x0, 0x30, 0x2]]),
+    (0x160, &[[0x21d2, 0x20, 0x8], [0x0, 0x28, 0x2]]),
+    (0x161, &[[0x21d2, 0x20, 0x2], [0x0, 0x28, 0x2]]),
+    (0x162, &[[0x21f7, 0x20, 0x8], [0x0, 0x30, 0x2]]),
+    (0x163, &[[0x21f7, 0x20, 0x2], [0x0, 0x30, 0x2]]),
+    (0x164, &[[0x21f7, 0x20, 0x8], [0x0, 0x28, 0x2]]),
+    (0x165, &[[0x21f7, 0x20, 0x2], [0x0, 0x28, 0x2]]),
+    (0x166, &[[0x21fc, 0x20, 0x8]]),
+    (0x167, &[[0x21fc, 0x20, 0x2]]),
+    (0x168, &[[0x2217, 0x20, 0x8], [0x0, 0x2d, 0x2]]),
+    (0x169, &[[0x2217, 0x20, 0x2], [0x0, 0x2d, 0x2]]),
+    (0x16a, &[[0x2217, 0x20, 0x8], [0x0, 0x32, 0x2]]),
+    (0x16b, &[[0x2217, 0x20, 0x2], [0x0, 0x32, 0x2]]),
+    (0x16c, &[[0x2217, 0x20, 0x8], [0x0, 0x26, 0x2]]),
+    (0x16d, &[[0x2217, 0x20, 0x2], [0x0, 0x26, 0x2]]),
+    (0x16e, &[[0x2217, 0x20, 0x8], [0x0, 0x29, 0x2]]),
+    (0x16f, &[[0x2217, 0x20, 0x2], [0x0, 0x29, 0x2]]),
+    (0x170, &[[0x2217, 0x20, 0x8], [0x0, 0x2c, 0x2]]),
+    (0x171, &[[0x2217, 0x20, 0x2], [0x0, 0x2c, 0x2]]),
+    (0x172, &[[0x2217, 0x20, 0x8], [0x0, 0x31, 0x2]]),
+    (0x173, &[[0x2217, 0x20, 0x2], [0x0, 0x31, 0x2]]),
+    (0x174, &[[0x2259, 0x20, 0x8], [0x0, 0x27, 0x2]]),
+    (0x175, &[[0x2259, 0x20, 0x2], [0x0, 0x27, 0x2]]),
+    (0x176, &[[0x2270, 0x20, 0x8], [0x0, 0x27, 0x2]]),
+    (0x177, &[[0x2270, 0x20, 0x2], [0x0, 0x27, 0x2]]),
+    (0x178, &[[0x2270, 0x20, 0x8], [0x0, 0x2b, 0x2]]),
+    (0x179, &[[0x2286, 0x20, 0x8], [0x0, 0x24, 0x2]]),
+    (0x17a, &[[0x2286, 0x20, 0x2], [0x0, 0x24, 0x2]]),
+    (0x17b, &[[0x2286, 0x20, 0x8], [0x0, 0x2e, 0x2]]),
+    (0x17c, &[[0x2286, 0x20, 0x2], [0x0, 0x2e, 0x2]]),
+    (0x17d, &[[0x2286, 0x20, 0x8], [0x0, 0x28, 0x2]]),
+    (0x17e, &[[0x2286, 0x20, 0x2], [0x0, 0x28, 0x2]]),
+    (0x17f, &[[0x21d2, 0x20, 0x4], [0x0, 0x119, 0x4]]),
+    (0x180, &[[0x1fc4, 0x20, 0x2]]),
+    (0x181, &[[0x1fcd, 0x20, 0x8]]),
+    (0x182, &[[0x1fd1, 0x20, 0x8]]),
+    (0x183, &[[0x1fd1, 0x20, 0x2]]),
+    (0x184, &[[0x22d6, 0x20, 0x8]]),
+    (0x185, &[[0x22d6, 0x20, 0x2]]),
+    (0x186, &[[0x214f, 0x20, 0x8]]),
+    (0x187, &[[0x1fe1, 0x20, 0x8]]),
+    (0x188, &[[0x1fe1, 0x20, 0x2]]),
+    (0x189, &[[0x1ff4, 0x20, 0x8]]),
+    (0x18a, &[[0x1ff8, 0x20, 0x8]]),
+    (0x18b, &[[0x1ffd, 0x20, 0x8]]),
+    (0x18c, &[[0x1ffd, 0x20, 0x2]]),
+    (0x18d, &[[0x2286, 0x20, 0x4], [0x2259, 0x20, 0x4]]),
+    (0x18e, &[[0x2015, 0x20, 0x8]]),
+    (0x18f, &[[0x201a, 0x20, 0x8]]),
+    (0x190, &[[0x201f, 0x20, 0x8]]),
+    (0x191, &[[0x204b, 0x20, 0x8]]),
+    (0x192, &[[0x204b, 0x20, 0x2]]),
+    (0x193, &[[0x2063, 0x20, 0x8]]),
+    (0x194, &[[0x206d, 0x20, 0x8]]),
+    (0x195, &[[0x207d, 0x20, 0x2]]),
+    (0x196, &[[0x20a6, 0x20, 0x8]]),
+    (0x197, &[[0x209f, 0x20, 0x8]]),
+    (0x198, &[[0x20ca, 0x20, 0x8]]),
+    (0x199, &[[0x20ca, 0x20, 0x2]]),
+    (0x19a, &[[0x20e1, 0x20, 0x2]]),
+    (0x19b, &[[0x2101, 0x20, 0x2]]),
+    (0x19c, &[[0x2238, 0x20, 0x8]]),
+    (0x19d, &[[0x2123, 0x20, 0x8]]),
+    (0x19e, &[[0x2127, 0x20, 0x2]]),
+    (0x19f, &[[0x215c, 0x20, 0x8]]),
+    (0x1a0, &[[0x213c, 0x20, 0x8], [0x0, 0x3f, 0x2]]),
+    (0x1a1, &[[0x213c, 0x20, 0x2], [0x0, 0x3f, 0x2]]),
+    (0x1a2, &[[0x2071, 0x20, 0x8]]),
+    (0x1a3, &[[0x2071, 0x20, 0x2]]),
+    (0x1a4, &[[0x2174, 0x20, 0x8]]),
+    (0x1a5, &[[0x2174, 0x20, 0x2]]),
+    (0x1a6, &[[0x2198, 0x20, 0x8]]),
+    (0x1a7, &[[0x22ce, 0x20, 0x8]]),
+    (0x1a8, &[[0x22ce, 0x20, 0x2]]),
+    (0x1a9, &[[0x21e4, 0x20, 0x8]]),
+    (0x1aa, &[[0x21ea, 0x20, 0x2]]),
+    (0x1ab, &[[0x2202, 0x20, 0x2]]),
+    (0x1ac, &[[0x2206, 0x20, 0x8]]),
+    (0x1ad, &[[0x2206, 0x20, 0x2]]),
+    (0x1ae, &[[0x220a, 0x20, 0x8]]),
+    (0x1af, &[[0x2217, 0x20, 0x8], [0x0, 0x3f, 0x2]]),
+    (0x1b0, &[[0x2217, 0x20, 0x2], [0x0, 0x3f, 0x2]]),
+    (0x1b1, &[[0x2242, 0x20, 0x8]]),
+    (0x1b2, &[[0x224e, 0x20, 0x8]]),
+    (0x1b3, &[[0x227c, 0x20, 0x8]]),
+    (0x1b4, &[[0x227c, 0x20, 0x2]]),
+    (0x1b5, &[[0x228b, 0x20, 0x8]]),
+    (0x1b6, &[[0x228b, 0x20, 0x2]]),
+    (0x1b7, &[[0x22a3, 0x20, 0x8]]),
+    (0x1b8, &[[0x22a8, 0x20, 0x8]]),
+    (0x1b9, &[[0x22a8, 0x20, 0x2]]),
+    (0x1ba, &[[0x22ad, 0x20, 0x2]]),
+    (0x1bb, &[[0x22c7, 0x20, 0x2]]),
+    (0x1bc, &[[0x22d2, 0x20, 0x8]]),
+    (0x1bd, &[[0x22d2, 0x20, 0x2]]),
+    (0x1be, &[[0x21f7, 0x20, 0x4], [0x21d2, 0x20, 0x4]]),
+    (0x1bf, &[[0x22bb, 0x20, 0x2]]),
+    (0x1c0, &[[0x22fe, 0x20, 0x2]]),
+    (0x1c1, &[[0x2302, 0x20, 0x2]]),
+    (0x1c2, &[[0x2306, 0x20, 0x2]]),
+    (0x1c3, &[[0x230a, 0x20, 0x2]]),
+    (0x1c4, &[[0x1feb, 0x20, 0xa], [0x2286, 0x20, 0xa], [0x0, 0x28, 0x4]]),
+    (0x1c5, &[[0x1feb, 0x20, 0xa], [0x2286, 0x20, 0x4], [0x0, 0x28, 0x4]]),
+    (0x1c6, &[[0x1feb, 0x20, 0x4], [0x2286, 0x20, 0x4], [0x0, 0x28, 0x4]]),
+    (0x1c7, &[[0x20d6, 0x20, 0xa], [0x20ab, 0x20, 0xa]]),
+    (0x1c8, &[[0x20d6, 0x20, 0xa], [0x20ab, 0x20, 0x4]]),
+    (0x1c9, &[[0x20d6, 0x20, 0x4], [0x20ab, 0x20, 0x4]]),
+    (0x1ca, &[[0x2118, 0x20, 0xa], [0x20ab, 0x20, 0xa]]),
+    (0x1cb, &[[0x2118, 0x20, 0xa], [0x20ab, 0x20, 0x4]]),
+    (0x1cc, &[[0x2118, 0x20, 0x4], [0x20ab, 0x20, 0x4]]),
+    (0x1cd, &[[0x1fa2, 0x20, 0x8], [0x0, 0x28, 0x2]]),
+    (0x1ce, &[[0x1fa2, 0x20, 0x2], [0x0, 0x28, 0x2]]),
+    (0x1cf, &[[0x2090, 0x20, 0x8], [0x0, 0x28, 0x2]]),
+    (0x1d0, &[[0x2090, 0x20, 0x2], [0x0, 0x28, 0x2]]),
+    (0x1d1, &[[0x213c, 0x20, 0x8], [0x0, 0x28, 0x2]]),
+    (0x1d2, &[[0x213c, 0x20, 0x2], [0x0, 0x28, 0x2]]),
+    (0x1d3, &[[0x2217, 0x20, 0x8], [0x0, 0x28, 0x2]]),
+    (0x1d4, &[[0x2217, 0x20, 0x2], [0x0, 0x28, 0x2]]),
+    (0x1d5, &[[0x2217, 0x20, 0x8], [0x0, 0x2b, 0x2], [0x0, 0x32, 0x2]]),
+    (0x1d6, &[[0x2217, 0x20, 0x2], [0x0, 0x2b, 0x2], [0x0, 0x32, 0x2]]),
+    (0x1d7, &[[0x2217, 0x20, 0x8], [0x0, 0x2b, 0x2], [0x0, 0x24, 0x2]]),
+    (0x1d8, &[[0x2217, 0x20, 0x2], [0x0, 0x2b, 0x2], [0x0, 0x24, 0x2]]),
+    (0x1d9, &[[0x2217, 0x20, 0x8], [0x0, 0x2b, 0x2], [0x0, 0x28, 0x2]]),
+    (0x1da, &[[0x2217, 0x20, 0x2], [0x0, 0x2b, 0x2], [0x0, 0x28, 0x2]]),
+    (0x1db, &[[0x2217, 0x20, 0x8], [0x0, 0x2b, 0x2], [0x0, 0x25, 0x2]]),
+    (0x1dc, &[[0x2217, 0x20, 0x2], [0x0, 0x2b, 0x2], [0x0, 0x25, 0x2]]),
+    (0x1dd, &[[0x2015, 0x20, 0x2]]),
+    (0x1de, &[[0x1fa2, 0x20, 0x8], [0x0, 0x2b, 0x2], [0x0, 0x32, 0x2]]),
+    (0x1df, &[[0x1fa2, 0x20, 0x2], [0x0, 0x2b, 0x2], [0x0, 0x32, 0x2]]),
+    (0x1e0, &[[0x1fa2, 0x20, 0x8], [0x0, 0x2e, 0x2], [0x0, 0x32, 0x2]]),
+    (0x1e1, &[[0x1fa2, 0x20, 0x2], [0x0, 0x2e, 0x2], [0x0, 0x32, 0x2]]),
+    (0x1e2, &[[0x1fa2, 0x20, 0xa], [0x0, 0x118, 0x4], [0x2007, 0x20, 0xa], [0x0, 0x32, 0x2]]),
+    (0x1e3, &[[0x1fa2, 0x20, 0x4], [0x0, 0x118, 0x4], [0x2007, 0x20, 0x4], [0x0, 0x32, 0x2]]),
+    (0x1e4, &[[0x205e, 0x20, 0x8]]),
+    (0x1e5, &[[0x205e, 0x20, 0x2]]),
+    (0x1e6, &[[0x2051, 0x20, 0x8], [0x0, 0x28, 0x2]]),
+    (0x1e7, &[[0x2051, 0x20, 0x2], [0x0, 0x28, 0x2]]),
+    (0x1e8, &[[0x20c4, 0x20, 0x8], [0x0, 0x28, 0x2]]),
+    (0x1e9, &[[0x20c4, 0x20, 0x2], [0x0, 0x28, 0x2]]),
+    (0x1ea, &[[0x213c, 0x20, 0x8], [0x0, 0x31, 0x2]]),
+    (0x1eb, &[[0x213c, 0x20, 0x2], [0x0, 0x31, 0x2]]),
+    (0x1ec, &[[0x213c, 0x20, 0x8], [0x0, 0x31, 0x2], [0x0, 0x32, 0x2]]),
+    (0x1ed, &[[0x213c, 0x20, 0x2], [0x0, 0x31, 0x2], [0x0, 0x32, 0x2]]),
+    (0x1ee, &[[0x22a3, 0x20, 0x8], [0x0, 0x28, 0x2]]),
+    (0x1ef, &[[0x22a3, 0x20, 0x2], [0x0, 0x28, 0x2]]),
+    (0x1f0, &[[0x20ab, 0x20, 0x2], [0x0, 0x28, 0x2]]),
+    (0x1f1, &[[0x1feb, 0x20, 0xa], [0x2286, 0x20, 0xa]]),
+    (0x1f2, &[[0x1feb, 0x20, 0xa], [0x2286, 0x20, 0x4]]),
+    (0x1f3, &[[0x1feb, 0x20, 0x4], [0x2286, 0x20, 0x4]]),
+    (0x1f4, &[[0x2051, 0x20, 0x8], [0x0, 0x24, 0x2]]),
+    (0x1f5, &[[0x2051, 0x20, 0x2], [0x0, 0x24, 0x2]]),
+    (0x1f6, &[[0x207d, 0x20, 0x8]]),
+    (0x1f7, &[[0x22bb, 0x20, 0x8]]),
+    (0x1f8, &[[0x2118, 0x20, 0x8], [0x0, 0x25, 0x2]]),
+    (0x1f9, &[[0x2118, 0x20, 0x2], [0x0, 0x25, 0x2]]),
+    (0x1fa, &[[0x1fa2, 0x20, 0x8], [0x0, 0x29, 0x2], [0x0, 0x24, 0x2]]),
+    (0x1fb, &[[0x1fa2, 0x20, 0x2], [0x0, 0x29, 0x2], [0x0, 0x24, 0x2]]),
+    (0x1fc, &[[0x1fa2, 0x20, 0xa], [0x0, 0x118, 0x4], [0x2007, 0x20, 0xa], [0x0, 0x24, 0x2]]),
+    (0x1fd, &[[0x1fa2, 0x20, 0x4], [0x0, 0x118, 0x4], [0x2007, 0x20, 0x4], [0x0, 0x24, 0x2]]),
+    (0x1fe, &[[0x213c, 0x20, 0x8], [0x0, 0x2f, 0x2], [0x0, 0x24, 0x2]]),
+    (0x1ff, &[[0x213c, 0x20, 0x2], [0x0, 0x2f, 0x2], [0x0, 0x24, 0x2]]),
+    (0x200, &[[0x1fa2, 0x20, 0x8], [0x0, 0x3c, 0x2]]),
+    (0x201, &[[0x1fa2, 0x20, 0x2], [0x0, 0x3c, 0x2]]),
+    (0x202, &[[0x1fa2, 0x20, 0x8], [0x0, 0x3e, 0x2]]),
+    (0x203, &[[0x1fa2, 0x20, 0x2], [0x0, 0x3e, 0x2]]),
+    (0x204, &[[0x2007, 0x20, 0x8], [0x0, 0x3c, 0x2]]),
+    (0x205, &[[0x2007, 0x20, 0x2], [0x0, 0x3c, 0x2]]),
+    (0x206, &[[0x2007, 0x20, 0x8], [0x0, 0x3e, 0x2]]),
+    (0x207, &[[0x2007, 0x20, 0x2], [0x0, 0x3e, 0x2]]),
+    (0x208, &[[0x2090, 0x20, 0x8], [0x0, 0x3c, 0x2]]),
+    (0x209, &[[0x2090, 0x20, 0x2], [0x0, 0x3c, 0x2]]),
+    (0x20a, &[[0x2090, 0x20, 0x8], [0x0, 0x3e, 0x2]]),
+    (0x20b, &[[0x2090, 0x20, 0x2], [0x0, 0x3e, 0x2]]),
+    (0x20c, &[[0x213c, 0x20, 0x8], [0x0, 0x3c, 0x2]]),
+    (0x20d, &[[0x213c, 0x20, 0x2], [0x0, 0x3c, 0x2]]),
+    (0x20e, &[[0x213c, 0x20, 0x8], [0x0, 0x3e, 0x2]]),
+    (0x20f, &[[0x213c, 0x20, 0x2], [0x0, 0x3e, 0x2]]),
+    (0x210, &[[0x2193, 0x20, 0x8], [0x0, 0x3c, 0x2]]),
+    (0x211, &[[0x2193, 0x20, 0x2], [0x0, 0x3c, 0x2]]),
+    (0x212, &[[0x2193, 0x20, 0x8], [0x0, 0x3e, 0x2]]),
+    (0x213, &[[0x2193, 0x20, 0x2], [0x0, 0x3e, 0x2]]),
+    (0x214, &[[0x2217, 0x20, 0x8], [0x0, 0x3c, 0x2]]),
+    (0x215, &[[0x2217, 0x20, 0x2], [0x0, 0x3c, 0x2]]),
+    (0x216, &[[0x2217, 0x20, 0x8], [0x0, 0x3e, 0x2]]),
+    (0x217, &[[0x2217, 0x20, 0x2], [0x0, 0x3e, 0x2]]),
+    (0x218, &[[0x21d2, 0x20, 0x8], [0x0, 0x45, 0x2]]),
+    (0x219, &[[0x21d2, 0x20, 0x2], [0x0, 0x45, 0x2]]),
+    (0x21a, &[[0x21f7, 0x20, 0x8], [0x0, 0x45, 0x2]]),
+    (0x21b, &[[0x21f7, 0x20, 0x2], [0x0, 0x45, 0x2]]),
+    (0x21c, &[[0x2282, 0x20, 0x8]]),
+    (0x21d, &[[0x2282, 0x20, 0x2]]),
+    (0x21e, &[[0x2075, 0x20, 0x8], [0x0, 0x28, 0x2]]),
+    (0x21f, &[[0x2075, 0x20, 0x2], [0x0, 0x28, 0x2]]),
+    (0x220, &[[0x2127, 0x20, 0x8]]),
+    (0x221, &[[0x2001, 0x20, 0x2]]),
+    (0x222, &[[0x2166, 0x20, 0x8]]),
+    (0x223, &[[0x2166, 0x20, 0x2]]),
+    (0x224, &[[0x2291, 0x20, 0x8]]),
+    (0x225, &[[0x2291, 0x20, 0x2]]),
+    (0x226, &[[0x1fa2, 0x20, 0x8], [0x0, 0x2e, 0x2]]),
+    (0x227, &[[0x1fa2, 0x20, 0x2], [0x0, 0x2e, 0x2]]),
+    (0x228, &[[0x2007, 0x20, 0x8], [0x0, 0x30, 0x2]]),
+    (0x229, &[[0x2007, 0x20, 0x2], [0x0, 0x30, 0x2]]),
+    (0x22a, &[[0x213c, 0x20, 0x8], [0x0, 0x2b, 0x2], [0x0, 0x32, 0x2]]),
+    (0x22b, &[[0x213c, 0x20, 0x2], [0x0, 0x2b, 0x2], [0x0, 0x32, 0x2]]),
+    (0x22c, &[[0x213c, 0x20, 0x8], [0x0, 0x2d, 0x2], [0x0, 0x32, 0x2]]),
+    (0x22d, &[[0x213c, 0x20, 0x2], [0x0, 0x2d, 0x2], [0x0, 0x32, 0x2]]),
+    (0x22e, &[[0x213c, 0x20, 0x8], [0x0, 0x2e, 0x2]]),
+    (0x22f, &[[0x213c, 0x20, 0x2], [0x0, 0x2e, 0x2]]),
+    (0x230, &[[0x213c, 0x20, 0x8], [0x0, 0x2e, 0x2], [0x0, 0x32, 0x2]]),
+    (0x231, &[[0x213c, 0x20, 0x2], [0x0, 0x2e, 0x2], [0x0, 0x32, 0x2]]),
+    (0x232, &[[0x2270, 0x20, 0x8], [0x0, 0x32, 0x2]]),
+    (0x233, &[[0x2270, 0x20, 0x2], [0x0, 0x32, 0x2]]),
+    (0x234, &[[0x20f7, 0x20, 0x2]]),
+    (0x235, &[[0x2131, 0x20, 0x2]]),
+    (0x236, &[[0x220e, 0x20, 0x2]]),
+    (0x237, &[[0x20af, 0x20, 0x2]]),
+    (0x238, &[[0x1feb, 0x20, 0x4], [0x1fbc, 0x20, 0x4]]),
+    (0x239, &[[0x2180, 0x20, 0x4], [0x216b, 0x20, 0x4]]),
+    (0x23a, &[[0x1fa7, 0x20, 0x8]]),
+    (0x23b, &[[0x1fdb, 0x20, 0x8]]),
+    (0x23c, &[[0x1fdb, 0x20, 0x2]]),
+    (0x23d, &[[0x20e1, 0x20, 0x8]]),
+    (0x23e, &[[0x2200, 0x20, 0x8]]),
+    (0x23f, &[[0x21de, 0x20, 0x2]]),
+    (0x240, &[[0x229d, 0x20, 0x2]]),
+    (0x241, &[[0x22de, 0x20, 0x8]]),
+    (0x242, &[[0x22de, 0x20, 0x2]]),
+    (0x243, &[[0x1fc4, 0x20, 0x8]]),
+    (0x244, &[[0x2222, 0x20, 0x8]]),
+    (0x245, &[[0x2255, 0x20, 0x8]]),
+    (0x246, &[[0x200e, 0x20, 0x8]]),
+    (0x247, &[[0x200e, 0x20, 0x2]]),
+    (0x248, &[[0x20b4, 0x20, 0x8]]),
+    (0x249, &[[0x20b4, 0x20, 0x2]]),
+    (0x24a, &[[0x218b, 0x20, 0x8]]),
+    (0x24b, &[[0x218b, 0x20, 0x2]]),
+    (0x24c, &[[0x219f, 0x20, 0x8]]),
+    (0x24d, &[[0x219f, 0x20, 0x2]]),
+    (0x24e, &[[0x2278, 0x20, 0x8]]),
+    (0x24f, &[[0x2278, 0x20, 0x2]]),
+    (0x250, &[[0x1fad, 0x20, 0x2]]),
+    (0x251, &[[0x1fb1, 0x20, 0x2]]),
+    (0x252, &[[0x1fb7, 0x20, 0x2]]),
+    (0x253, &[[0x1fcd, 0x20, 0x2]]),
+    (0x254, &[[0x214f, 0x20, 0x2]]),
+    (0x255, &[[0x1fe5, 0x20, 0x2]]),
+    (0x256, &[[0x1ff4, 0x20, 0x2]]),
+    (0x257, &[[0x1ff8, 0x20, 0x2]]),
+    (0x258, &[[0x2024, 0x20, 0x2]]),
+    (0x259, &[[0x201a, 0x20, 0x2]]),
+    (0x25a, &[[0x2028, 0x20, 0x2]]),
+    (0x25b, &[[0x201f, 0x20, 0x2]]),
+    (0x25c, &[[0x202c, 0x20, 0x2]]),
+    (0x25d, &[[0x2032, 0x20, 0x2]]),
+    (0x25e, &[[0x2036, 0x20, 0x2]]),
+    (0x25f, &[[0x20bc, 0x20, 0x2]]),
+    (0x260, &[[0x2063, 0x20, 0x2]]),
+    (0x261, &[[0x2055, 0x20, 0x2]]),
+    (0x262, &[[0x205a, 0x20, 0x2]]),
+    (0x263, &[[0x206d, 0x20, 0x2]]),
+    (0x264, &[[0x203e, 0x20, 0x2]]),
+    (0x265, &[[0x222c, 0x20, 0x2]]),
+    (0x266, &[[0x2082, 0x20, 0x2]]),
+    (0x267, &[[0x208a, 0x20, 0x2]]),
+    (0x268, &[[0x209f, 0x20, 0x2]]),
+    (0x269, &[[0x20a6, 0x20, 0x2]]),
+    (0x26a, &[[0x2098, 0x20, 0x2]]),
+    (0x26b, &[[0x20e6, 0x20, 0x2]]),
+    (0x26c, &[[0x20ec, 0x20, 0x2]]),
+    (0x26d, &[[0x20f2, 0x20, 0x2]]),
+    (0x26e, &[[0x20fc, 0x20, 0x2]]),
+    (0x26f, &[[0x2238, 0x20, 0x2]]),
+    (0x270, &[[0x223e, 0x20, 0x2]]),
+    (0x271, &[[0x2110, 0x20, 0x2]]),
+    (0x272, &[[0x2123, 0x20, 0x2]]),
+    (0x273, &[[0x212d, 0x20, 0x2]]),
+    (0x274, &[[0x211c, 0x20, 0x2]]),
+    (0x275, &[[0x215c, 0x20, 0x2]]),
+    (0x276, &[[0x2143, 0x20, 0x2]]),
+    (0x277, &[[0x2161, 0x20, 0x2]]),
+    (0x278, &[[0x217b, 0x20, 0x2]]),
+    (0x279, &[[0x21a4, 0x20, 0x2]]),
+    (0x27a, &[[0x21a9, 0x20, 0x2]]),
+    (0x27b, &[[0x21ae, 0x20, 0x2]]),
+    (0x27c, &[[0x21b3, 0x20, 0x2]]),
+    (0x27d, &[[0x21b7, 0x20, 0x2]]),
+    (0x27e, &[[0x21bc, 0x20, 0x2]]),
+    (0x27f, &[[0x21c1, 0x20, 0x2]]),
+    (0x280, &[[0x2198, 0x20, 0x2]]),
+    (0x281, &[[0x21ca, 0x20, 0x2]]),
+    (0x282, &[[0x21da, 0x20, 0x2]]),
+    (0x283, &[[0x21e4, 0x20, 0x2]]),
+    (0x284, &[[0x20c0, 0x20, 0x2]]),
+    (0x285, &[[0x21ee, 0x20, 0x2]]),
+    (0x286, &[[0x21f3, 0x20, 0x2]]),
+    (0x287, &[[0x2213, 0x20, 0x2]]),
+    (0x288, &[[0x220a, 0x20, 0x2]]),
+    (0x289, &[[0x2222, 0x20, 0x2]]),
+    (0x28a, &[[0x2242, 0x20, 0x2]]),
+    (0x28b, &[[0x224e, 0x20, 0x2]]),
+    (0x28c, &[[0x2255, 0x20, 0x2]]),
+    (0x28d, &[[0x2260, 0x20, 0x2]]),
+    (0x28e, &[[0x2105, 0x20, 0x2]]),
+    (0x28f, &[[0x2274, 0x20, 0x2]]),
+    (0x290, &[[0x2295, 0x20, 0x2]]),
+    (0x291, &[[0x2299, 0x20, 0x2]]),
+    (0x292, &[[0x22a3, 0x20, 0x2]]),
+    (0x293, &[[0x22b1, 0x20, 0x2]]),
+    (0x294, &[[0x22da, 0x20, 0x2]]),
+    (0x295, &[[0x22e9, 0x20, 0x2]]),
+    (0x296, &[[0x22fa, 0x20, 0x2]]),
+    (0x297, &[[0x230e, 0x20, 0x2]]),
+    (0x298, &[[0x2312, 0x20, 0x2]]),
+    (0x299, &[[0x1fc0, 0x20, 0x2]]),
+    (0x29a, &[[0x203a, 0x20, 0x2]]),
+    (0x29b, &[[0x2067, 0x20, 0x2]]),
+    (0x29c, &[[0x2079, 0x20, 0x2]]),
+    (0x29d, &[[0x20b8, 0x20, 0x2]]),
+    (0x29e, &[[0x20d2, 0x20, 0x2]]),
+    (0x29f, &[[0x20da, 0x20, 0x2]]),
+    (0x2a0, &[[0x2187, 0x20, 0x2]]),
+    (0x2a1, &[[0x22f2, 0x20, 0x2]]),
+    (0x2a2, &[[0x22f6, 0x20, 0x2]]),
+    (0x2a3, &[[0x1feb, 0x20, 0x4], [0x2286, 0x20, 0x4]]),
+    (0x2a4, &[[0x1feb, 0x20, 0x4], [0x22a3, 0x20, 0x4]]),
+    (0x2a5, &[[0x1feb, 0x20, 0x4], [0x2299, 0x20, 0x4]]),
+    (0x2a6, &[[0x21f7, 0x20, 0x4], [0x21d2, 0x20, 0x4]]),
+    (0x2a7, &[[0x21f7, 0x20, 0x4], [0x21e4, 0x20, 0x4]]),
+    (0x2a8, &[[0x21f7, 0x20, 0x4], [0x1fe5, 0x20, 0x4]]),
+    (0x2a9, &[[0x2042, 0x20, 0x4], [0x2137, 0x20, 0x4]]),
+    (0x2aa, &[[0x20d6, 0x20, 0x4], [0x21d2, 0x20, 0x4]]),
+    (0x2ab, &[[0x20d6, 0x20, 0x4], [0x2286, 0x20, 0x4]]),
+    (0x2ac, &[[0x2316, 0x20, 0x2]]),
+    (0x2ad, &[[0x231a, 0x20, 0x2]]),
+    (0x2ae, &[[0x2230, 0x20, 0x2]]),
+    (0x2af, &[[0x2234, 0x20, 0x2]]),
+    (0x2b0, &[[0x2075, 0x20, 0x14]]),
+    (0x2b1, &[[0x2082, 0x20, 0x14]]),
+    (0x2b2, &[[0x20ab, 0x20, 0x14]]),
+    (0x2b3, &[[0x2193, 0x20, 0x14]]),
+    (0x2b4, &[[0x21a4, 0x20, 0x14]]),
+    (0x2b5, &[[0x21ae, 0x20, 0x14]]),
+    (0x2b6, &[[0x21ca, 0x20, 0x14]]),
+    (0x2b7, &[[0x2259, 0x20, 0x14]]),
+    (0x2b8, &[[0x2270, 0x20, 0x14]]),
+    (0x2b9, &[[0x4c5, 0x20, 0x2]]),
+    (0x2ba, &[[0x4c7, 0x20, 0x2]]),
+    (0x2bb, &[[0x208e, 0x20, 0x2]]),
+    (0x2bc, &[[0x22e3, 0x20, 0x2]]),
+    (0x2bd, &[[0x208f, 0x20, 0x2]]),
+    (0x2be, &[[0x22e5, 0x20, 0x2]]),
+    (0x2bf, &[[0x22ed, 0x20, 0x2]]),
+    (0x2c0, &[[0x22e2, 0x20, 0x2]]),
+    (0x2c1, &[[0x22ee, 0x20, 0x2]]),
+    (0x2c2, &[[0x4c8, 0x20, 0x2]]),
+    (0x2c3, &[[0x4c9, 0x20, 0x2]]),
+    (0x2c4, &[[0x4ca, 0x20, 0x2]]),
+    (0x2c5, &[[0x4cb, 0x20, 0x2]]),
+    (0x2c6, &[[0x4cc, 0x20, 0x2]]),
+    (0x2c7, &[[0x4cd, 0x20, 0x2]]),
+    (0x2c8, &[[0x4ce, 0x20, 0x2]]),
+    (0x2c9, &[[0x4cf, 0x20, 0x2]]),
+    (0x2ca, &[[0x4d0, 0x20, 0x2]]),
+    (0x2cb, &[[0x4d1, 0x20, 0x2]]),
+    (0x2cc, &[[0x4d2, 0x20, 0x2]]),
+    (0x2cd, &[[0x4d3, 0x20, 0x2]]),
+    (0x2ce, &[[0x4d4, 0x20, 0x2]]),
+    (0x2cf, &[[0x4d5, 0x20, 0x2]]),
+    (0x2d0, &[[0x1f46, 0x20, 0x2]]),
+    (0x2d1, &[[0x1f47, 0x20, 0x2]]),
+    (0x2d2, &[[0x4d6, 0x20, 0x2]]),
+    (0x2d3, &[[0x4d7, 0x20, 0x2]]),
+    (0x2d4, &[[0x4d8, 0x20, 0x2]]),
+    (0x2d5, &[[0x4d9, 0x20, 0x2]]),
+    (0x2d6, &[[0x4dc, 0x20, 0x2]]),
+    (0x2d7, &[[0x4dd, 0x20, 0x2]]),
+    (0x2d8, &[[0x4b9, 0x20, 0x2]]),
+    (0x2d9, &[[0x4ba, 0x20, 0x2]]),
+    (0x2da, &[[0x4bc, 0x20, 0x2]]),
+    (0x2db, &[[0x4bf, 0x20, 0x2]]),
+    (0x2dc, &[[0x4b6, 0x20, 0x2]]),
+    (0x2dd, &[[0x4bd, 0x20, 0x2]]),
+    (0x2de, &[[0x4de, 0x20, 0x2]]),
+    (0x2df, &[[0x4df, 0x20, 0x2]]),
+    (0x2e0, &[[0x206d, 0x20, 0x14]]),
+    (0x2e1, &[[0x20d6, 0x20, 0x14]]),
+    (0x2e2, &[[0x21d2, 0x20, 0x14]]),
+    (0x2e3, &[[0x2264, 0x20, 0x14]]),
+    (0x2e4, &[[0x22e9, 0x20, 0x14]]),
+    (0x2e5, &[[0x4e0, 0x20, 0x2]]),
+    (0x2e6, &[[0x4e1, 0x20, 0x2]]),
+    (0x2e7, &[[0x4e2, 0x20, 0x2]]),
+    (0x2e8, &[[0x4e3, 0x20, 0x2]]),
+    (0x2e9, &[[0x4e4, 0x20, 0x2]]),
+    (0x2ea, &[[0x4e5, 0x20, 0x2]]),
+    (0x2eb, &[[0x4e6, 0x20, 0x2]]),
+    (0x2ec, &[[0x4e7, 0x20, 0x2]]),
+    (0x2ed, &[[0x4e8, 0x20, 0x2]]),
+    (0x2ee, &[[0x22e4, 0x20, 0x2]]),
+    (0x2ef, &[[0x4e9, 0x20, 0x2]]),
+    (0x2f0, &[[0x4ea, 0x20, 0x2]]),
+    (0x2f1, &[[0x4eb, 0x20, 0x2]]),
+    (0x2f2, &[[0x4ec, 0x20, 0x2]]),
+    (0x2f3, &[[0x4ed, 0x20, 0x2]]),
+    (0x2f4, &[[0x4ee, 0x20, 0x2]]),
+    (0x2f5, &[[0x4ef, 0x20, 0x2]]),
+    (0x2f6, &[[0x4f0, 0x20, 0x2]]),
+    (0x2f7, &[[0x4f1, 0x20, 0x2]]),
+    (0x2f8, &[[0x4f2, 0x20, 0x2]]),
+    (0x2f9, &[[0x4f3, 0x20, 0x2]]),
+    (0x2fa, &[[0x4f4, 0x20, 0x2]]),
+    (0x2fb, &[[0x4f5, 0x20, 0x2]]),
+    (0x2fc, &[[0x4f6, 0x20, 0x2]]),
+    (0x2fd, &[[0x4f7, 0x20, 0x2]]),
+    (0x2fe, &[[0x4f8, 0x20, 0x2]]),
+    (0x2ff, &[[0x4f9, 0x20, 0x2]]),
+    (0x300, &[[0x0, 0x25, 0x2]]),
+    (0x301, &[[0x0, 0x24, 0x2]]),
+    (0x302, &[[0x0, 0x27, 0x2]]),
+    (0x303, &[[0x0, 0x2d, 0x2]]),
+    (0x304, &[[0x0, 0x32, 0x2]]),
+    (0x305, &[[0x0, 0x3a, 0x2]]),
+    (0x306, &[[0x0, 0x26, 0x2]]),
+    (0x307, &[[0x0, 0x2e, 0x2]]),
+    (0x308, &[[0x0, 0x2b, 0x2]]),
+    (0x309, &[[0x0, 0x3b, 0x2]]),
+    (0x30a, &[[0x0, 0x29, 0x2]]),
+    (0x30b, &[[0x0, 0x2c, 0x2]]),
+    (0x30c, &[[0x0, 0x28, 0x2]]),
+    (0x30d, &[[0x0, 0x33, 0x2]]),
+    (0x30e, &[[0x0, 0x33, 0x2]]),
+    (0x30f, &[[0x0, 0x3c, 0x2]]),
+    (0x310, &[[0x0, 0x3d, 0x2]]),
+    (0x311, &[[0x0, 0x3e, 0x2]]),
+    (0x312, &[[0x0, 0x33, 0x2]]),
+    (0x313, &[[0x0, 0x22, 0x2]]),
+    (0x314, &[[0x0, 0x23, 0x2]]),
+    (0x315, &[[0x0, 0x33, 0x2]]),
+    (0x316, &[[0x0, 0x34, 0x2]]),
+    (0x317, &[[0x0, 0x34, 0x2]]),
+    (0x318, &[[0x0, 0x34, 0x2]]),
+    (0x319, &[[0x0, 0x34, 0x2]]),
+    (0x31a, &[[0x0, 0x33, 0x2]]),
+    (0x31b, &[[0x0, 0x3f, 0x2]]),
+    (0x31c, &[[0x0, 0x34, 0x2]]),
+    (0x31d, &[[0x0, 0x34, 0x2]]),
+    (0x31e, &[[0x0, 0x34, 0x2]]),
+    (0x31f, &[[0x0, 0x34, 0x2]]),
+    (0x320, &[[0x0, 0x34, 0x2]]),
+    (0x321, &[[0x0, 0x40, 0x2]]),
+    (0x322, &[[0x0, 0x41, 0x2]]),
+    (0x323, &[[0x0, 0x42, 0x2]]),
+    (0x324, &[[0x0, 0x43, 0x2]]),
+    (0x325, &[[0x0, 0x44, 0x2]]),
+    (0x326, &[[0x0, 0x45, 0x2]]),
+    (0x327, &[[0x0, 0x30, 0x2]]),
+    (0x328, &[[0x0, 0x31, 0x2]]),
+    (0x329, &[[0x0, 0x34, 0x2]]),
+    (0x32a, &[[0x0, 0x34, 0x2]]),
+    (0x32b, &[[0x0, 0x34, 0x2]]),
+    (0x32c, &[[0x0, 0x34, 0x2]]),
+    (0x32d, &[[0x0, 0x46, 0x2]]),
+    (0x32e, &[[0x0, 0x47, 0x2]]),
+    (0x32f, &[[0x0, 0x34, 0x2]]),
+    (0x330, &[[0x0, 0x48, 0x2]]),
+    (0x331, &[[0x0, 0x49, 0x2]]),
+    (0x332, &[[0x0, 0x21, 0x2]]),
+    (0x333, &[[0x0, 0x34, 0x2]]),
+    (0x334, &[[0x0, 0x4a, 0x2]]),
+    (0x335, &[[0x0, 0x39, 0x2]]),
+    (0x336, &[[0x0, 0x35, 0x2]]),
+    (0x337, &[[0x0, 0x35, 0x2]]),
+    (0x338, &[[0x0, 0x2f, 0x2]]),
+    (0x339, &[[0x0, 0x4b, 0x2]]),
+    (0x33a, &[[0x0, 0x34, 0x2]]),
+    (0x33b, &[[0x0, 0x34, 0x2]]),
+    (0x33c, &[[0x0, 0x34, 0x2]]),
+    (0x33d, &[[0x0, 0x33, 0x2]]),
+    (0x33e, &[[0x0, 0x33, 0x2]]),
+    (0x33f, &[[0x0, 0x33, 0x2]]),
+    (0x340, &[[0x0, 0x25, 0x2]]),
+    (0x341, &[[0x0, 0x24, 0x2]]),
+    (0x342, &[[0x0, 0x2a, 0x2]]),
+    (0x343, &[[0x0, 0x22, 0x2]]),
+    (0x344, &[[0x0, 0x2b, 0x2], [0x0, 0x24, 0x2]]),
+    (0x345, &[[0x0, 0x4c, 0x2]]),
+    (0x346, &[[0x0, 0x33, 0x2]]),
+    (0x347, &[[0x0, 0x34, 0x2]]),
+    (0x348, &[[0x0, 0x34, 0x2]]),
+    (0x349, &[[0x0, 0x34, 0x2]]),
+    (0x34a, &[[0x0, 0x33, 0x2]]),
+    (0x34b, &[[0x0, 0x33, 0x2]]),
+    (0x34c, &[[0x0, 0x33, 0x2]]),
+    (0x34d, &[[0x0, 0x34, 0x2]]),
+    (0x34e, &[[0x0, 0x34, 0x2]]),
+    (0x34f, &[[0x0, 0x0, 0x0]]),
+    (0x350, &[[0x0, 0x33, 0x2]]),
+    (0x351, &[[0x0, 0x33, 0x2]]),
+    (0x352, &[[0x0, 0x33, 0x2]]),
+    (0x353, &[[0x0, 0x34, 0x2]]),
+    (0x354, &[[0x0, 0x34, 0x2]]),
+    (0x355, &[[0x0, 0x34, 0x2]]),
+    (0x356, &[[0x0, 0x34, 0x2]]),
+    (0x357, &[[0x0, 0x33, 0x2]]),
+    (0x358, &[[0x0, 0x4d, 0x2]]),
+    (0x359, &[[0x0, 0x34, 0x2]]),
+    (0x35a, &[[0x0, 0x34, 0x2]]),
+    (0x35b, &[[0x0, 0x33, 0x2]]),
+    (0x35c, &[[0x0, 0x34, 0x2]]),
+    (0x35d, &[[0x0, 0x33, 0x2]]),
+    (0x35e, &[[0x0, 0x33, 0x2]]),
+    (0x35f, &[[0x0, 0x34, 0x2]]),
+    (0x360, &[[0x0, 0x4e, 0x2]]),
+    (0x361, &[[0x0, 0x4f, 0x2]]),
+    (0x362, &[[0x0, 0x34, 0x2]]),
+    (0x363, &[[0x1fa2, 0x20, 0x4]]),
+    (0x364, &[[0x2007, 0x20, 0x4]]),
+    (0x365, &[[0x2090, 0x20, 0x4]]),
+    (0x366, &[[0x213c, 0x20, 0x4]]),
+    (0x367, &[[0x2217, 0x20, 0x4]]),
+    (0x368, &[[0x1fd6, 0x20, 0x4]]),
+    (0x369, &[[0x1feb, 0x20, 0x4]]),
+    (0x36a, &[[0x2075, 0x20, 0x4]]),
+    (0x36b, &[[0x2109, 0x20, 0x4]]),
+    (0x36c, &[[0x2193, 0x20, 0x4]]),
+    (0x36d, &[[0x21f7, 0x20, 0x4]]),
+    (0x36e, &[[0x2247, 0x20, 0x4]]),
+    (0x36f, &[[0x2264, 0x20, 0x4]]),
+    (0x370, &[[0x2328, 0x20, 0x8]]),
+    (0x371, &[[0x2328, 0x20, 0x2]]),
+    (0x372, &[[0x2349, 0x20, 0x8]]),
+    (0x373, &[[0x2349, 0x20, 0x2]]),
+    (0x374, &[[0x4c5, 0x20, 0x2]]),
+    (0x375, &[[0x4c6, 0x20, 0x2]]),
+    (0x376, &[[0x2325, 0x20, 0x8]]),
+    (0x377, &[[0x2325, 0x20, 0x2]]),
+    (0x37a, &[[0x232b, 0x20, 0x4]]),
+    (0x37b, &[[0x233e, 0x20, 0x2]]),
+    (0x37c, &[[0x233d, 0x20, 0x2]]),
+    (0x37d, &[[0x233f, 0x20, 0x2]]),
+    (0x37e, &[[0x23a, 0x20, 0x2]]),
+    (0x37f, &[[0x232c, 0x20, 0x8]]),
+    (0x384, &[[0x4b5, 0x20, 0x2]]),
+    (0x385, &[[0x4bb, 0x20, 0x2], [0x0, 0x24, 0x2]]),
+    (0x386, &[[0x231e, 0x20, 0x8], [0x0, 0x24, 0x2]]),
+    (0x387, &[[0x293, 0x20, 0x2]]),
+    (0x388, &[[0x2323, 0x20, 0x8], [0x0, 0x24, 0x2]]),
+    (0x389, &[[0x2329, 0x20, 0x8], [0x0, 0x24, 0x2]]),
+    (0x38a, &[[0x232b, 0x20, 0x8], [0x0, 0x24, 0x2]]),
+    (0x38c, &[[0x2333, 0x20, 0x8], [0x0, 0x24, 0x2]]),
+    (0x38e, &[[0x2341, 0x20, 0x8], [0x0, 0x24, 0x2]]),
+    (0x38f, &[[0x2346, 0x20, 0x8], [0x0, 0x24, 0x2]]),
+    (0x390, &[[0x232b, 0x20, 0x2], [0x0, 0x2b, 0x2], [0x0, 0x24, 0x2]]),
+    (0x391, &[[0x231e, 0x20, 0x8]]),
+    (0x392, &[[0x231f, 0x20, 0x8]]),
+    (0x393, &[[0x2320, 0x20, 0x8]]),
+    (0x394, &[[0x2322, 0x20, 0x8]]),
+    (0x395, &[[0x2323, 0x20, 0x8]]),
+    (0x396, &[[0x2327, 0x20, 0x8]]),
+    (0x397, &[[0x2329, 0x20, 0x8]]),
+    (0x398, &[[0x232a, 0x20, 0x8]]),
+    (0x399, &[[0x232b, 0x20, 0x8]]),
+    (0x39a, &[[0x232d, 0x20, 0x8]]),
+    (0x39b, &[[0x232e, 0x20, 0x8]]),
+    (0x39c, &[[0x2330, 0x20, 0x8]]),
+    (0x39d, &[[0x2331, 0x20, 0x8]]),
+    (0x39e, &[[0x2332, 0x20, 0x8]]),
+    (0x39f, &[[0x2333, 0x20, 0x8]]),
+    (0x3a0, &[[0x2334, 0x20, 0x8]]),
+    (0x3a1, &[[0x2339, 0x20, 0x8]]),
+    (0x3a3, &[[0x233c, 0x20, 0x8]]),
+    (0x3a4, &[[0x2340, 0x20, 0x8]]),
+    (0x3a5, &[[0x2341, 0x20, 0x8]]),
+    (0x3a6, &[[0x2342, 0x20, 0x8]]),
+    (0x3a7, &[[0x2343, 0x20, 0x8]]),
+    (0x3a8, &[[0x2344, 0x20, 0x8]]),
+    (0x3a9, &[[0x2346, 0x20, 0x8]]),
+    (0x3aa, &[[0x232b, 0x20, 0x8], [0x0, 0x2b, 0x2]]),
+    (0x3ab, &[[0x2341, 0x20, 0x8], [0x0, 0x2b, 0x2]]),
+    (0x3ac, &[[0x231e, 0x20, 0x2], [0x0, 0x24, 0x2]]),
+    (0x3ad, &[[0x2323, 0x20, 0x2], [0x0, 0x24, 0x2]]),
+    (0x3ae, &[[0x2329, 0x20, 0x2], [0x0, 0x24, 0x2]]),
+    (0x3af, &[[0x232b, 0x20, 0x2], [0x0, 0x24, 0x2]]),
+    (0x3b0, &[[0x2341, 0x20, 0x2], [0x0, 0x2b, 0x2], [0x0, 0x24, 0x2]]),
+    (0x3b1, &[[0x231e, 0x20, 0x2]]),
+    (0x3b2, &[[0x231f, 0x20, 0x2]]),
+    (0x3b3, &[[0x2320, 0x20, 0x2]]),
+    (0x3b4, &[[0x2322, 0x20, 0x2]]),
+    (0x3b5, &[[0x2323, 0x20, 0x2]]),
+    (0x3b6, &[[0x2327, 0x20, 0x2]]),
+    (0x3b7, &[[0x2329, 0x20, 0x2]]),
+    (0x3b8, &[[0x232a, 0x20, 0x2]]),
+    (0x3b9, &[[0x232b, 0x20, 0x2]]),
+    (0x3ba, &[[0x232d, 0x20, 0x2]]),
+    (0x3bb, &[[0x232e, 0x20, 0x2]]),
+    (0x3bc, &[[0x2330, 0x20, 0x2]]),
+    (0x3bd, &[[0x2331, 0x20, 0x2]]),
+    (0x3be, &[[0x2332, 0x20, 0x2]]),
+    (0x3bf, &[[0x2333, 0x20, 0x2]]),
+    (0x3c0, &[[0x2334, 0x20, 0x2]]),
+    (0x3c1, &[[0x2339, 0x20, 0x2]]),
+    (0x3c2, &[[0x233c, 0x20, 0x19]]),
+    (0x3c3, &[[0x233c, 0x20, 0x2]]),
+    (0x3c4, &[[0x2340, 0x20, 0x2]]),
+    (0x3c5, &[[0x2341, 0x20, 0x2]]),
+    (0x3c6, &[[0x2342, 0x20, 0x2]]),
+    (0x3c7, &[[0x2343, 0x20, 0x2]]),
+    (0x3c8, &[[0x2344, 0x20, 0x2]]),
+    (0x3c9, &[[0x2346, 0x20, 0x2]]),
+    (0x3ca, &[[0x232b, 0x20, 0x2], [0x0, 0x2b, 0x2]]),
+    (0x3cb, &[[0x2341, 0x20, 0x2], [0x0, 0x2b, 0x2]]),
+    (0x3cc, &[[0x2333, 0x20, 0x2], [0x0, 0x24, 0x2]]),
+    (0x3cd, &[[0x2341, 0x20, 0x2], [0x0, 0x24, 0x2]]),
+    (0x3ce, &[[0x2346, 0x20, 0x2], [0x0, 0x24, 0x2]]),
+    (0x3cf, &[[0x232d, 0x20, 0xa], [0x231e, 0x20, 0x4], [0x232b, 0x20, 0x4]]),
+    (0x3d0, &[[0x231f, 0x20, 0x4]]),
+    (0x3d1, &[[0x232a, 0x20, 0x4]]),
+    (0x3d2, &[[0x2341, 0x20, 0xa]]),
+    (0x3d3, &[[0x2341, 0x20, 0xa], [0x0, 0x24, 0x2]]),
+    (0x3d4, &[[0x2341, 0x20, 0xa], [0x0, 0x2b, 0x2]]),
+    (0x3d5, &[[0x2342, 0x20, 0x4]]),
+    (0x3d6, &[[0x2334, 0x20, 0x4]]),
+    (0x3d7, &[[0x232d, 0x20, 0x4], [0x231e, 0x20, 0x4], [0x232b, 0x20, 0x4]]),
+    (0x3d8, &[[0x2338, 0x20, 0x8]]),
+    (0x3d9, &[[0x2338, 0x20, 0x2]]),
+    (0x3da, &[[0x2326, 0x20, 0x8]]),
+    (0x3db, &[[0x2326, 0x20, 0x2]]),
+    (0x3dc, &[[0x2324, 0x20, 0x8]]),
+    (0x3dd, &[[0x2324, 0x20, 0x2]]),
+    (0x3de, &[[0x2337, 0x20, 0x8]]),
+    (0x3df, &[[0x2337, 0x20, 0x2]]),
+    (0x3e0, &[[0x2348, 0x20, 0x8]]),
+    (0x3e1, &[[0x2348, 0x20, 0x2]]),
+    (0x3e2, &[[0x236a, 0x20, 0x8]]),
+    (0x3e3, &[[0x236a, 0x20, 0x2]]),
+    (0x3e4, &[[0x236f, 0x20, 0x8]]),
+    (0x3e5, &[[0x236f, 0x20, 0x2]]),
+    (0x3e6, &[[0x2370, 0x20, 0x8]]),
+    (0x3e7, &[[0x2370, 0x20, 0x2]]),
+    (0x3e8, &[[0x2373, 0x20, 0x8]]),
+    (0x3e9, &[[0x2373, 0x20, 0x2]]),
+    (0x3ea, &[[0x237a, 0x20, 0x8]]),
+    (0x3eb, &[[0x237a, 0x20, 0x2]]),
+    (0x3ec, &[[0x237d, 0x20, 0x8]]),
+    (0x3ed, &[[0x237d, 0x20, 0x2]]),
+    (0x3ee, &[[0x2381, 0x20, 0x8]]),
+    (0x3ef, &[[0x2381, 0x20, 0x2]]),
+    (0x3f0, &[[0x232d, 0x20, 0x4]]),
+    (0x3f1, &[[0x2339, 0x20, 0x4]]),
+    (0x3f2, &[[0x233c, 0x20, 0x4]]),
+    (0x3f3, &[[0x232c, 0x20, 0x2]]),
+    (0x3f4, &[[0x232a, 0x20, 0xa]]),
+    (0x3f5, &[[0x2323, 0x20, 0x4]]),
+    (0x3f6, &[[0x661, 0x20, 0x2]]),
+    (0x3f7, &[[0x234a, 0x20, 0x8]]),
+    (0x3f8, &[[0x234a, 0x20, 0x2]]),
+    (0x3f9, &[[0x233c, 0x20, 0xa]]),
+    (0x3fa, &[[0x2336, 0x20, 0x8]]),
+    (0x3fb, &[[0x2336, 0x20, 0x2]]),
+    (0x3fc, &[[0x233b, 0x20, 0x2]]),
+    (0x3fd, &[[0x233e, 0x20, 0x8]]),
+    (0x3fe, &[[0x233d, 0x20, 0x8]]),
+    (0x3ff, &[[0x233f, 0x20, 0x8]]),
+    (0x400, &[[0x23bf, 0x20, 0x8], [0x0, 0x25, 0x2]]),
+    (0x401, &[[0x23bf, 0x20, 0x8], [0x0, 0x2b, 0x2]]),
+    (0x402, &[[0x23b5, 0x20, 0x8]]),
+    (0x403, &[[0x239b, 0x20, 0x8], [0x0, 0x24, 0x2]]),
+    (0x404, &[[0x23c3, 0x20, 0x8]]),
+    (0x405, &[[0x23d9, 0x20, 0x8]]),
+    (0x406, &[[0x23ed, 0x20, 0x8]]),
+    (0x407, &[[0x23ed, 0x20, 0x8], [0x0, 0x2b, 0x2]]),
+    (0x408, &[[0x23f6, 0x20, 0x8]]),
+    (0x409, &[[0x2421, 0x20, 0x8]]),
+    (0x40a, &[[0x2447, 0x20, 0x8]]),
+    (0x40b, &[[0x247e, 0x20, 0x8]]),
+    (0x40c, &[[0x23fb, 0x20, 0x8], [0x0, 0x24, 0x2]]),
+    (0x40d, &[[0x23e5, 0x20, 0x8], [0x0, 0x25, 0x2]]),
+    (0x40e, &[[0x2482, 0x20, 0x8], [0x0, 0x26, 0x2]]),
+    (0x40f, &[[0x24e4, 0x20, 0x8]]),
+    (0x410, &[[0x2387, 0x20, 0x8]]),
+    (0x411, &[[0x2393, 0x20, 0x8]]),
+    (0x412, &[[0x2397, 0x20, 0x8]]),
+    (0x413, &[[0x239b, 0x20, 0x8]]),
+    (0x414, &[[0x23af, 0x20, 0x8]]),
+    (0x415, &[[0x23bf, 0x20, 0x8]]),
+    (0x416, &[[0x23c7, 0x20, 0x8]]),
+    (0x417, &[[0x23d1, 0x20, 0x8]]),
+    (0x418, &[[0x23e5, 0x20, 0x8]]),
+    (0x419, &[[0x23f2, 0x20, 0x8]]),
+    (0x41a, &[[0x23fb, 0x20, 0x8]]),
+    (0x41b, &[[0x2415, 0x20, 0x8]]),
+    (0x41c, &[[0x2428, 0x20, 0x8]]),
+    (0x41d, &[[0x2431, 0x20, 0x8]]),
+    (0x41e, &[[0x244c, 0x20, 0x8]]),
+    (0x41f, &[[0x2454, 0x20, 0x8]]),
+    (0x420, &[[0x2461, 0x20, 0x8]]),
+    (0x421, &[[0x246a, 0x20, 0x8]]),
+    (0x422, &[[0x2473, 0x20, 0x8]]),
+    (0x423, &[[0x2482, 0x20, 0x8]]),
+    (0x424, &[[0x2493, 0x20, 0x8]]),
+    (0x425, &[[0x2497, 0x20, 0x8]]),
+    (0x426, &[[0x24be, 0x20, 0x8]]),
+    (0x427, &[[0x24c9, 0x20, 0x8]]),
+    (0x428, &[[0x24e8, 0x20, 0x8]]),
+    (0x429, &[[0x24ed, 0x20, 0x8]]),
+    (0x42a, &[[0x24f4, 0x20, 0x8]]),
+    (0x42b, &[[0x24f9, 0x20, 0x8]]),
+    (0x42c, &[[0x24fd, 0x20, 0x8]]),
+    (0x42d, &[[0x250a, 0x20, 0x8]]),
+    (0x42e, &[[0x250e, 0x20, 0x8]]),
+    (0x42f, &[[0x2514, 0x20, 0x8]]),
+    (0x430, &[[0x2387, 0x20, 0x2]]),
+    (0x431, &[[0x2393, 0x20, 0x2]]),
+    (0x432, &[[0x2397, 0x20, 0x2]]),
+    (0x433, &[[0x239b, 0x20, 0x2]]),
+    (0x434, &[[0x23af, 0x20, 0x2]]),
+    (0x435, &[[0x23bf, 0x20, 0x2]]),
+    (0x436, &[[0x23c7, 0x20, 0x2]]),
+    (0x437, &[[0x23d1, 0x20, 0x2]]),
+    (0x438, &[[0x23e5, 0x20, 0x2]]),
+    (0x439, &[[0x23f2, 0x20, 0x2]]),
+    (0x43a, &[[0x23fb, 0x20, 0x2]]),
+    (0x43b, &[[0x2415, 0x20, 0x2]]),
+    (0x43c, &[[0x2428, 0x20, 0x2]]),
+    (0x43d, &[[0x2431, 0x20, 0x2]]),
+    (0x43e, &[[0x244c, 0x20, 0x2]]),
+    (0x43f, &[[0x2454, 0x20, 0x2]]),
+    (0x440, &[[0x2461, 0x20, 0x2]]),
+    (0x441, &[[0x246a, 0x20, 0x2]]),
+    (0x442, &[[0x2473, 0x20, 0x2]]),
+    (0x443, &[[0x2482, 0x20, 0x2]]),
+    (0x444, &[[0x2493, 0x20, 0x2]]),
+    (0x445, &[[0x2497, 0x20, 0x2]]),
+    (0x446, &[[0x24be, 0x20, 0x2]]),
+    (0x447, &[[0x24c9, 0x20, 0x2]]),
+    (0x448, &[[0x24e8, 0x20, 0x2]]),
+    (0x449, &[[0x24ed, 0x20, 0x2]]),
+    (0x44a, &[[0x24f4, 0x20, 0x2]]),
+    (0x44b, &[[0x24f9, 0x20, 0x2]]),
+    (0x44c, &[[0x24fd, 0x20, 0x2]]),
+    (0x44d, &[[0x250a, 0x20, 0x2]]),
+    (0x44e, &[[0x250e, 0x20, 0x2]]),
+    (0x44f, &[[0x2514, 0x20, 0x2]]),
+    (0x450, &[[0x23bf, 0x20, 0x2], [0x0, 0x25, 0x2]]),
+    (0x451, &[[0x23bf, 0x20, 0x2], [0x0, 0x2b, 0x2]]),
+    (0x452, &[[0x23b5, 0x20, 0x2]]),
+    (0x453, &[[0x239b, 0x20, 0x2], [0x0, 0x24, 0x2]]),
+    (0x454, &[[0x23c3, 0x20, 0x2]]),
+    (0x455, &[[0x23d9, 0x20, 0x2]]),
+    (0x456, &[[0x23ed, 0x20, 0x2]]),
+    (0x457, &[[0x23ed, 0x20, 0x2], [0x0, 0x2b, 0x2]]),
+    (0x458, &[[0x23f6, 0x20, 0x2]]),
+    (0x459, &[[0x2421, 0x20, 0x2]]),
+    (0x45a, &[[0x2447, 0x20, 0x2]]),
+    (0x45b, &[[0x247e, 0x20, 0x2]]),
+    (0x45c, &[[0x23fb, 0x20, 0x2], [0x0, 0x24, 0x2]]),
+    (0x45d, &[[0x23e5, 0x20, 0x2], [0x0, 0x25, 0x2]]),
+    (0x45e, &[[0x2482, 0x20, 0x2], [0x0, 0x26, 0x2]]),
+    (0x45f, &[[0x24e4, 0x20, 0x2]]),
+    (0x460, &[[0x24ad, 0x20, 0x8]]),
+    (0x461, &[[0x24ad, 0x20, 0x2]]),
+    (0x462, &[[0x2505, 0x20, 0x8]]),
+    (0x463, &[[0x2505, 0x20, 0x2]]),
+    (0x464, &[[0x2519, 0x20, 0x8]]),
+    (0x465, &[[0x2519, 0x20, 0x2]]),
+    (0x466, &[[0x251d, 0x20, 0x8]]),
+    (0x467, &[[0x251d, 0x20, 0x2]]),
+    (0x468, &[[0x2527, 0x20, 0x8]]),
+    (0x469, &[[0x2527, 0x20, 0x2]]),
+    (0x46a, &[[0x2522, 0x20, 0x8]]),
+    (0x46b, &[[0x2522, 0x20, 0x2]]),
+    (0x46c, &[[0x252c, 0x20, 0x8]]),
+    (0x46d, &[[0x252c, 0x20, 0x2]]),
+    (0x46e, &[[0x2530, 0x20, 0x8]]),
+    (0x46f, &[[0x2530, 0x20, 0x2]]),
+    (0x470, &[[0x2534, 0x20, 0x8]]),
+    (0x471, &[[0x2534, 0x20, 0x2]]),
+    (0x472, &[[0x2538, 0x20, 0x8]]),
+    (0x473, &[[0x2538, 0x20, 0x2]]),
+    (0x474, &[[0x253c, 0x20, 0x8]]),
+    (0x475, &[[0x253c, 0x20, 0x2]]),
+    (0x476, &[[0x253c, 0x20, 0x8], [0x0, 0x3c, 0x2]]),
+    (0x477, &[[0x253c, 0x20, 0x2], [0x0, 0x3c, 0x2]]),
+    (0x478, &[[0x248f, 0x20, 0x8]]),
+    (0x479, &[[0x248f, 0x20, 0x2]]),
+    (0x47a, &[[0x24ba, 0x20, 0x8]]),
+    (0x47b, &[[0x24ba, 0x20, 0x2]]),
+    (0x47c, &[[0x24b6, 0x20, 0x8]]),
+    (0x47d, &[[0x24b6, 0x20, 0x2]]),
+    (0x47e, &[[0x24b1, 0x20, 0x8]]),
+    (0x47f, &[[0x24b1, 0x20, 0x2]]),
+    (0x480, &[[0x245d, 0x20, 0x8]]),
+    (0x481, &[[0x245d, 0x20, 0x2]]),
+    (0x482, &[[0x52b, 0x20, 0x2]]),
+    (0x483, &[[0x0, 0x50, 0x2]]),
+    (0x484, &[[0x0, 0x33, 0x2]]),
+    (0x485, &[[0x0, 0x23, 0x2]]),
+    (0x486, &[[0x0, 0x22, 0x2]]),
+    (0x487, &[[0x0, 0x33, 0x2]]),
+    (0x488, &[[0x0, 0x0, 0x0]]),
+    (0x489, &[[0x0, 0x0, 0x0]]),
+    (0x48a, &[[0x23e9, 0x20, 0x8]]),
+    (0x48b, &[[0x23e9, 0x20, 0x2]]),
+    (0x48c, &[[0x2501, 0x20, 0x8]]),
+    (0x48d, &[[0x2501, 0x20, 0x2]]),
+    (0x48e, &[[0x2465, 0x20, 0x8]]),
+    (0x48f, &[[0x2465, 0x20, 0x2]]),
+    (0x490, &[[0x239b, 0x20, 0xa], [0x0, 0x119, 0x4]]),
+    (0x491, &[[0x239b, 0x20, 0x4], [0x0, 0x119, 0x4]]),
+    (0x492, &[[0x239f, 0x20, 0x8]]),
+    (0x493, &[[0x239f, 0x20, 0x2]]),
+    (0x494, &[[0x23a7, 0x20, 0x8]]),
+    (0x495, &[[0x23a7, 0x20, 0x2]]),
+    (0x496, &[[0x23cd, 0x20, 0x8]]),
+    (0x497, &[[0x23cd, 0x20, 0x2]]),
+    (0x498, &[[0x23bb, 0x20, 0x8]]),
+    (0x499, &[[0x23bb, 0x20, 0x2]]),
+    (0x49a, &[[0x23ff, 0x20, 0x8]]),
+    (0x49b, &[[0x23ff, 0x20, 0x2]]),
+    (0x49c, &[[0x240f, 0x20, 0x8]]),
+    (0x49d, &[[0x240f, 0x20, 0x2]]),
+    (0x49e, &[[0x240b, 0x20, 0x8]]),
+    (0x49f, &[[0x240b, 0x20, 0x2]]),
+    (0x4a0, &[[0x2407, 0x20, 0x8]]),
+    (0x4a1, &[[0x2407, 0x20, 0x2]]),
+    (0x4a2, &[[0x243a, 0x20, 0x8]]),
+    (0x4a3, &[[0x243a, 0x20, 0x2]]),
+    (0x4a4, &[[0x2443, 0x20, 0x8]]),
+    (0x4a5, &[[0x2443, 0x20, 0x2]]),
+    (0x4a6, &[[0x2459, 0x20, 0x8]]),
+    (0x4a7, &[[0x2459, 0x20, 0x2]]),
+    (0x4a8, &[[0x2541, 0x20, 0x8]]),
+    (0x4a9, &[[0x2541, 0x20, 0x2]]),
+    (0x4aa, &[[0x246f, 0x20, 0x8]]),
+    (0x4ab, &[[0x246f, 0x20, 0x2]]),
+    (0x4ac, &[[0x2479, 0x20, 0x8]]),
+    (0x4ad, &[[0x2479, 0x20, 0x2]]),
+    (0x4ae, &[[0x2486, 0x20, 0x8]]),
+    (0x4af, &[[0x2486, 0x20, 0x2]]),
+    (0x4b0, &[[0x248a, 0x20, 0x8]]),
+    (0x4b1, &[[0x248a, 0x20, 0x2]]),
+    (0x4b2, &[[0x24a3, 0x20, 0x8]]),
+    (0x4b3, &[[0x24a3, 0x20, 0x2]]),
+    (0x4b4, &[[0x24c4, 0x20, 0x8]]),
+    (0x4b5, &[[0x24c4, 0x20, 0x2]]),
+    (0x4b6, &[[0x24cf, 0x20, 0x8]]),
+    (0x4b7, &[[0x24cf, 0x20, 0x2]]),
+    (0x4b8, &[[0x24d7, 0x20, 0x8]]),
+    (0x4b9, &[[0x24d7, 0x20, 0x2]]),
+    (0x4ba, &[[0x24a7, 0x20, 0x8]]),
+    (0x4bb, &[[0x24a7, 0x20, 0x2]]),
+    (0x4bc, &[[0x24dc, 0x20, 0x8]]),
+    (0x4bd, &[[0x24dc, 0x20, 0x2]]),
+    (0x4be, &[[0x24e0, 0x20, 0x8]]),
+    (0x4bf, &[[0x24e0, 0x20, 0x2]]),
+    (0x4c0, &[[0x2546, 0x20, 0x8]]),
+    (0x4c1, &[[0x23c7, 0x20, 0x8], [0x0, 0x26, 0x2]]),
+    (0x4c2, &[[0x23c7, 0x20, 0x2], [0x0, 0x26, 0x2]]),
+    (0x4c3, &[[0x2403, 0x20, 0x8]]),
+    (0x4c4, &[[0x2403, 0x20, 0x2]]),
+    (0x4c5, &[[0x241a, 0x20, 0x8]]),
+    (0x4c6, &[[0x241a, 0x20, 0x2]]),
+    (0x4c7, &[[0x243e, 0x20, 0x8]]),
+    (0x4c8, &[[0x243e, 0x20, 0x2]]),
+    (0x4c9, &[[0x2436, 0x20, 0x8]]),
+    (0x4ca, &[[0x2436, 0x20, 0x2]]),
+    (0x4cb, &[[0x24d3, 0x20, 0x8]]),
+    (0x4cc, &[[0x24d3, 0x20, 0x2]]),
+    (0x4cd, &[[0x242c, 0x20, 0x8]]),
+    (0x4ce, &[[0x242c, 0x20, 0x2]]),
+    (0x4cf, &[[0x2546, 0x20, 0x2]]),
+    (0x4d0, &[[0x2387, 0x20, 0x8], [0x0, 0x26, 0x2]]),
+    (0x4d1, &[[0x2387, 0x20, 0x2], [0x0, 0x26, 0x2]]),
+    (0x4d2, &[[0x2387, 0x20, 0x8], [0x0, 0x2b, 0x2]]),
+    (0x4d3, &[[0x2387, 0x20, 0x2], [0x0, 0x2b, 0x2]]),
+    (0x4d4, &[[0x238f, 0x20, 0x8]]),
+    (0x4d5, &[[0x238f, 0x20, 0x2]]),
+    (0x4d6, &[[0x23bf, 0x20, 0x8], [0x0, 0x26, 0x2]]),
+    (0x4d7, &[[0x23bf, 0x20, 0x2], [0x0, 0x26, 0x2]]),
+    (0x4d8, &[[0x238b, 0x20, 0x8]]),
+    (0x4d9, &[[0x238b, 0x20, 0x2]]),
+    (0x4da, &[[0x238b, 0x20, 0x8], [0x0, 0x2b, 0x2]]),
+    (0x4db, &[[0x238b, 0x20, 0x2], [0x0, 0x2b, 0x2]]),
+    (0x4dc, &[[0x23c7, 0x20, 0x8], [0x0, 0x2b, 0x2]]),
+    (0x4dd, &[[0x23c7, 0x20, 0x2], [0x0, 0x2b, 0x2]]),
+    (0x4de, &[[0x23d1, 0x20, 0x8], [0x0, 0x2b, 0x2]]),
+    (0x4df, &[[0x23d1, 0x20, 0x2], [0x0, 0x2b, 0x2]]),
+    (0x4e0, &[[0x23de, 0x20, 0x8]]),
+    (0x4e1, &[[0x23de, 0x20, 0x2]]),
+    (0x4e2, &[[0x23e5, 0x20, 0x8], [0x0, 0x32, 0x2]]),
+    (0x4e3, &[[0x23e5, 0x20, 0x2], [0x0, 0x32, 0x2]]),
+    (0x4e4, &[[0x23e5, 0x20, 0x8], [0x0, 0x2b, 0x2]]),
+    (0x4e5, &[[0x23e5, 0x20, 0x2], [0x0, 0x2b, 0x2]]),
+    (0x4e6, &[[0x244c, 0x20, 0x8], [0x0, 0x2b, 0x2]]),
+    (0x4e7, &[[0x244c, 0x20, 0x2], [0x0, 0x2b, 0x2]]),
+    (0x4e8, &[[0x2450, 0x20, 0x8]]),
+    (0x4e9, &[[0x2450, 0x20, 0x2]]),
+    (0x4ea, &[[0x2450, 0x20, 0x8], [0x0, 0x2b, 0x2]]),
+    (0x4eb, &[[0x2450, 0x20, 0x2], [0x0, 0x2b, 0x2]]),
+    (0x4ec, &[[0x250a, 0x20, 0x8], [0x0, 0x2b, 0x2]]),
+    (0x4ed, &[[0x250a, 0x20, 0x2], [0x0, 0x2b, 0x2]]),
+    (0x4ee, &[[0x2482, 0x20, 0x8], [0x0, 0x32, 0x2]]),
+    (0x4ef, &[[0x2482, 0x20, 0x2], [0x0, 0x32, 0x2]]),
+    (0x4f0, &[[0x2482, 0x20, 0x8], [0x0, 0x2b, 0x2]]),
+    (0x4f1, &[[0x2482, 0x20, 0x2], [0x0, 0x2b, 0x2]]),
+    (0x4f2, &[[0x2482, 0x20, 0x8], [0x0, 0x2c, 0x2]]),
+    (0x4f3, &[[0x2482, 0x20, 0x2], [0x0, 0x2c, 0x2]]),
+    (0x4f4, &[[0x24c9, 0x20, 0x8], [0x0, 0x2b, 0x2]]),
+    (0x4f5, &[[0x24c9, 0x20, 0x2], [0x0, 0x2b, 0x2]]),
+    (0x4f6, &[[0x23ab, 0x20, 0x8]]),
+    (0x4f7, &[[0x23ab, 0x20, 0x2]]),
+    (0x4f8, &[[0x24f9, 0x20, 0x8], [0x0, 0x2b, 0x2]]),
+    (0x4f9, &[[0x24f9, 0x20, 0x2], [0x0, 0x2b, 0x2]]),
+    (0x4fa, &[[0x23a3, 0x20, 0x8]]),
+    (0x4fb, &[[0x23a3, 0x20, 0x2]]),
+    (0x4fc, &[[0x249b, 0x20, 0x8]]),
+    (0x4fd, &[[0x249b, 0x20, 0x2]]),
+    (0x4fe, &[[0x249f, 0x20, 0x8]]),
+    (0x4ff, &[[0x249f, 0x20, 0x2]]),
+    (0x500, &[[0x23b3, 0x20, 0x8]]),
+    (0x501, &[[0x23b3, 0x20, 0x2]]),
+    (0x502, &[[0x23ba, 0x20, 0x8]]),
+    (0x503, &[[0x23ba, 0x20, 0x2]]),
+    (0x504, &[[0x23d6, 0x20, 0x8]]),
+    (0x505, &[[0x23d6, 0x20, 0x2]]),
+    (0x506, &[[0x23e3, 0x20, 0x8]]),
+    (0x507, &[[0x23e3, 0x20, 0x2]]),
+    (0x508, &[[0x2426, 0x20, 0x8]]),
+    (0x509, &[[0x2426, 0x20, 0x2]]),
+    (0x50a, &[[0x244b, 0x20, 0x8]]),
+    (0x50b, &[[0x244b, 0x20, 0x2]]),
+    (0x50c, &[[0x246e, 0x20, 0x8]]),
+    (0x50d, &[[0x246e, 0x20, 0x2]]),
+    (0x50e, &[[0x2478, 0x20, 0x8]]),
+    (0x50f, &[[0x2478, 0x20, 0x2]]),
+    (0x510, &[[0x23d7, 0x20, 0x8]]),
+    (0x511, &[[0x23d7, 0x20, 0x2]]),
+    (0x512, &[[0x241f, 0x20, 0x8]]),
+    (0x513, &[[0x241f, 0x20, 0x2]]),
+    (0x514, &[[0x2427, 0x20, 0x8]]),
+    (0x515, &[[0x2427, 0x20, 0x2]]),
+    (0x516, &[[0x2469, 0x20, 0x8]]),
+    (0x517, &[[0x2469, 0x20, 0x2]]),
+    (0x518, &[[0x2518, 0x20, 0x8]]),
+    (0x519, &[[0x2518, 0x20, 0x2]]),
+    (0x51a, &[[0x2414, 0x20, 0x8]]),
+    (0x51b, &[[0x2414, 0x20, 0x2]]),
+    (0x51c, &[[0x2545, 0x20, 0x8]]),
+    (0x51d, &[[0x2545, 0x20, 0x2]]),
+    (0x51e, &[[0x2413, 0x20, 0x8]]),
+    (0x51f, &[[0x2413, 0x20, 0x2]]),
+    (0x520, &[[0x2420, 0x20, 0x8]]),
+    (0x521, &[[0x2420, 0x20, 0x2]]),
+    (0x522, &[[0x2442, 0x20, 0x8]]),
+    (0x523, &[[0x2442, 0x20, 0x2]]),
+    (0x524, &[[0x2458, 0x20, 0x8]]),
+    (0x525, &[[0x2458, 0x20, 0x2]]),
+    (0x526, &[[0x24ab, 0x20, 0x8]]),
+    (0x527, &[[0x24ab, 0x20, 0x2]]),
+    (0x528, &[[0x2435, 0x20, 0x8]]),
+    (0x529, &[[0x2435, 0x20, 0x2]]),
+    (0x52a, &[[0x23cb, 0x20, 0x8]]),
+    (0x52b, &[[0x23cb, 0x20, 0x2]]),
+    (0x52c, &[[0x24cd, 0x20, 0x8]]),
+    (0x52d, &[[0x24cd, 0x20, 0x2]]),
+    (0x52e, &[[0x241e, 0x20, 0x8]]),
+    (0x52f, &[[0x241e, 0x20, 0x2]]),
+    (0x1e00, &[[0x1fa2, 0x20, 0x8], [0x0, 0x44, 0x2]]),
+    (0x1e01, &[[0x1fa2, 0x20, 0x2], [0x0, 0x44, 0x2]]),
+    (0x1e02, &[[0x1fbc, 0x20, 0x8], [0x0, 0x2e, 0x2]]),
+    (0x1e03, &[[0x1fbc, 0x20, 0x2], [0x0, 0x2e, 0x2]]),
+    (0x1e04, &[[0x1fbc, 0x20, 0x8], [0x0, 0x42, 0x2]]),
+    (0x1e05, &[[0x1fbc, 0x20, 0x2], [0x0, 0x42, 0x2]]),
+    (0x1e06, &[[0x1fbc, 0x20, 0x8], [0x0, 0x49, 0x2]]),
+    (0x1e07, &[[0x1fbc, 0x20, 0x2], [0x0, 0x49, 0x2]]),
+    (0x1e08, &[[0x1fd6, 0x20, 0x8], [0x0, 0x30, 0x2], [0x0, 0x24, 0x2]]),
+    (0x1e09, &[[0x1fd6, 0x20, 0x2], [0x0, 0x30, 0x2], [0x0, 0x24, 0x2]]),
+    (0x1e0a, &[[0x1feb, 0x20, 0x8], [0x0, 0x2e, 0x2]]),
+    (0x1e0b, &[[0x1feb, 0x20, 0x2], [0x0, 0x2e, 0x2]]),
+    (0x1e0c, &[[0x1feb, 0x20, 0x8], [0x0, 0x42, 0x2]]),
+    (0x1e0d, &[[0x1feb, 0x20, 0x2], [0x0, 0x42, 0x2]]),
+    (0x1e0e, &[[0x1feb, 0x20, 0x8], [0x0, 0x49, 0x2]]),
+    (0x1e0f, &[[0x1feb, 0x20, 0x2], [0x0, 0x49, 0x2]]),
+    (0x1e10, &[[0x1feb, 0x20, 0x8], [0x0, 0x30, 0x2]]),
+    (0x1e11, &[[0x1feb, 0x20, 0x2], [0x0, 0x30, 0x2]]),
+    (0x1e12, &[[0x1feb, 0x20, 0x8], [0x0, 0x46, 0x2]]),
+    (0x1e13, &[[0x1feb, 0x20, 0x2], [0x0, 0x46, 0x2]]),
+    (0x1e14, &[[0x2007, 0x20, 0x8], [0x0, 0x32, 0x2], [0x0, 0x25, 0x2]]),
+    (0x1e15, &[[0x2007, 0x20, 0x2], [0x0, 0x32, 0x2], [0x0, 0x25, 0x2]]),
+    (0x1e16, &[[0x2007, 0x20, 0x8], [0x0, 0x32, 0x2], [0x0, 0x24, 0x2]]),
+    (0x1e17, &[[0x2007, 0x20, 0x2], [0x0, 0x32, 0x2], [0x0, 0x24, 0x2]]),
+    (0x1e18, &[[0x2007, 0x20, 0x8], [0x0, 0x46, 0x2]]),
+    (0x1e19, &[[0x2007, 0x20, 0x2], [0x0, 0x46, 0x2]]),
+    (0x1e1a, &[[0x2007, 0x20, 0x8], [0x0, 0x48, 0x2]]),
+    (0x1e1b, &[[0x2007, 0x20, 0x2], [0x0, 0x48, 0x2]]),
+    (0x1e1c, &[[0x2007, 0x20, 0x8], [0x0, 0x30, 0x2], [0x0, 0x26, 0x2]]),
+    (0x1e1d, &[[0x2007, 0x20, 0x2], [0x0, 0x30, 0x2], [0x0, 0x26, 0x2]]),
+    (0x1e1e, &[[0x2042, 0x20, 0x8], [0x0, 0x2e, 0x2]]),
+    (0x1e1f, &[[0x2042, 0x20, 0x2], [0x0, 0x2e, 0x2]]),
+    (0x1e20, &[[0x2051, 0x20, 0x8], [0x0, 0x32, 0x2]]),
+    (0x1e21, &[[0x2051, 0x20, 0x2], [0x0, 0x32, 0x2]]),
+    (0x1e22, &[[0x2075, 0x20, 0x8], [0x0, 0x2e, 0x2]]),
+    (0x1e23, &[[0x2075, 0x20, 0x2], [0x0, 0x2e, 0x2]]),
+    (0x1e24, &[[0x2075, 0x20, 0x8], [0x0, 0x42, 0x2]]),
+    (0x1e25, &[[0x2075, 0x20, 0x2], [0x0, 0x42, 0x2]]),
+    (0x1e26, &[[0x2075, 0x20, 0x8], [0x0, 0x2b, 0x2]]),
+    (0x1e27, &[[0x2075, 0x20, 0x2], [0x0, 0x2b, 0x2]]),
+    (0x1e28, &[[0x2075, 0x20, 0x8], [0x0, 0x30, 0x2]]),
+    (0x1e29, &[[0x2075, 0x20, 0x2], [0x0, 0x30, 0x2]]),
+    (0x1e2a, &[[0x2075, 0x20, 0x8], [0x0, 0x47, 0x2]]),
+    (0x1e2b, &[[0x2075, 0x20, 0x2], [0x0, 0x47, 0x2]]),
+    (0x1e2c, &[[0x2090, 0x20, 0x8], [0x0, 0x48, 0x2]]),
+    (0x1e2d, &[[0x2090, 0x20, 0x2], [0x0, 0x48, 0x2]]),
+    (0x1e2e, &[[0x2090, 0x20, 0x8], [0x0, 0x2b, 0x2], [0x0, 0x24, 0x2]]),
+    (0x1e2f, &[[0x2090, 0x20, 0x2], [0x0, 0x2b, 0x2], [0x0, 0x24, 0x2]]),
+    (0x1e30, &[[0x20c4, 0x20, 0x8], [0x0, 0x24, 0x2]]),
+    (0x1e31, &[[0x20c4, 0x20, 0x2], [0x0, 0x24, 0x2]]),
+    (0x1e32, &[[0x20c4, 0x20, 0x8], [0x0, 0x42, 0x2]]),
+    (0x1e33, &[[0x20c4, 0x20, 0x2], [0x0, 0x42, 0x2]]),
+    (0x1e34, &[[0x20c4, 0x20, 0x8], [0x0, 0x49, 0x2]]),
+    (0x1e35, &[[0x20c4, 0x20, 0x2], [0x0, 0x49, 0x2]]),
+    (0x1e36, &[[0x20d6, 0x20, 0x8], [0x0, 0x42, 0x2]]),
+    (0x1e37, &[[0x20d6, 0x20, 0x2], [0x0, 0x42, 0x2]]),
+    (0x1e38, &[[0x20d6, 0x20, 0x8], [0x0, 0x42, 0x2], [0x0, 0x32, 0x2]]),
+    (0x1e39, &[[0x20d6, 0x20, 0x2], [0x0, 0x42, 0x2], [0x0, 0x32, 0x2]]),
+    (0x1e3a, &[[0x20d6, 0x20, 0x8], [0x0, 0x49, 0x2]]),
+    (0x1e3b, &[[0x20d6, 0x20, 0x2], [0x0, 0x49, 0x2]]),
+    (0x1e3c, &[[0x20d6, 0x20, 0x8], [0x0, 0x46, 0x2]]),
+    (0x1e3d, &[[0x20d6, 0x20, 0x2], [0x0, 0x46, 0x2]]),
+    (0x1e3e, &[[0x2109, 0x20, 0x8], [0x0, 0x24, 0x2]]),
+    (0x1e3f, &[[0x2109, 0x20, 0x2], [0x0, 0x24, 0x2]]),
+    (0x1e40, &[[0x2109, 0x20, 0x8], [0x0, 0x2e, 0x2]]),
+    (0x1e41, &[[0x2109, 0x20, 0x2], [0x0, 0x2e, 0x2]]),
+    (0x1e42, &[[0x2109, 0x20, 0x8], [0x0, 0x42, 0x2]]),
+    (0x1e43, &[[0x2109, 0x20, 0x2], [0x0, 0x42, 0x2]]),
+    (0x1e44, &[[0x2118, 0x20, 0x8], [0x0, 0x2e, 0x2]]),
+    (0x1e45, &[[0x2118, 0x20, 0x2], [0x0, 0x2e, 0x2]]),
+    (0x1e46, &[[0x2118, 0x20, 0x8], [0x0, 0x42, 0x2]]),
+    (0x1e47, &[[0x2118, 0x20, 0x2], [0x0, 0x42, 0x2]]),
+    (0x1e48, &[[0x2118, 0x20, 0x8], [0x0, 0x49, 0x2]]),
+    (0x1e49, &[[0x2118, 0x20, 0x2], [0x0, 0x49, 0x2]]),
+    (0x1e4a, &[[0x2118, 0x20, 0x8], [0x0, 0x46, 0x2]]),
+    (0x1e4b, &[[0x2118, 0x20, 0x2], [0x0, 0x46, 0x2]]),
+    (0x1e4c, &[[0x213c, 0x20, 0x8], [0x0, 0x2d, 0x2], [0x0, 0x24, 0x2]]),
+    (0x1e4d, &[[0x213c, 0x20, 0x2], [0x0, 0x2d, 0x2], [0x0, 0x24, 0x2]]),
+    (0x1e4e, &[[0x213c, 0x20, 0x8], [0x0, 0x2d, 0x2], [0x0, 0x2b, 0x2]]),
+    (0x1e4f, &[[0x213c, 0x20, 0x2], [0x0, 0x2d, 0x2], [0x0, 0x2b, 0x2]]),
+    (0x1e50, &[[0x213c, 0x20, 0x8], [0x0, 0x32, 0x2], [0x0, 0x25, 0x2]]),
+    (0x1e51, &[[0x213c, 0x20, 0x2], [0x0, 0x32, 0x2], [0x0, 0x25, 0x2]]),
+    (0x1e52, &[[0x213c, 0x20, 0x8], [0x0, 0x32, 0x2], [0x0, 0x24, 0x2]]),
+    (0x1e53, &[[0x213c, 0x20, 0x2], [0x0, 0x32, 0x2], [0x0, 0x24, 0x2]]),
+    (0x1e54, &[[0x216b, 0x20, 0x8], [0x0, 0x24, 0x2]]),
+    (0x1e55, &[[0x216b, 0x20, 0x2], [0x0, 0x24, 0x2]]),
+    (0x1e56, &[[0x216b, 0x20, 0x8], [0x0, 0x2e, 0x2]]),
+    (0x1e57, &[[0x216b, 0x20, 0x2], [0x0, 0x2e, 0x2]]),
+    (0x1e58, &[[0x2193, 0x20, 0x8], [0x0, 0x2e, 0x2]]),
+    (0x1e59, &[[0x2193, 0x20, 0x2], [0x0, 0x2e, 0x2]]),
+    (0x1e5a, &[[0x2193, 0x20, 0x8], [0x0, 0x42, 0x2]]),
+    (0x1e5b, &[[0x2193, 0x20, 0x2], [0x0, 0x42, 0x2]]),
+    (0x1e5c, &[[0x2193, 0x20, 0x8], [0x0, 0x42, 0x2], [0x0, 0x32, 0x2]]),
+    (0x1e5d, &[[0x2193, 0x20, 0x2], [0x0, 0x42, 0x2], [0x0, 0x32, 0x2]]),
+    (0x1e5e, &[[0x2193, 0x20, 0x8], [0x0, 0x49, 0x2]]),
+    (0x1e5f, &[[0x2193, 0x20, 0x2], [0x0, 0x49, 0x2]]),
+    (0x1e60, &[[0x21d2, 0x20, 0x8], [0x0, 0x2e, 0x2]]),
+    (0x1e61, &[[0x21d2, 0x20, 0x2], [0x0, 0x2e, 0x2]]),
+    (0x1e62, &[[0x21d2, 0x20, 0x8], [0x0, 0x42, 0x2]]),
+    (0x1e63, &[[0x21d2, 0x20, 0x2], [0x0, 0x42, 0x2]]),
+    (0x1e64, &[[0x21d2, 0x20, 0x8], [0x0, 0x24, 0x2], [0x0, 0x2e, 0x2]]),
+    (0x1e65, &[[0x21d2, 0x20, 0x2], [0x0, 0x24, 0x2], [0x0, 0x2e, 0x2]]),
+    (0x1e66, &[[0x21d2, 0x20, 0x8], [0x0, 0x28, 0x2], [0x0, 0x2e, 0x2]]),
+    (0x1e67, &[[0x21d2, 0x20, 0x2], [0x0, 0x28, 0x2], [0x0, 0x2e, 0x2]]),
+    (0x1e68, &[[0x21d2, 0x20, 0x8], [0x0, 0x42, 0x2], [0x0, 0x2e, 0x2]]),
+    (0x1e69, &[[0x21d2, 0x20, 0x2], [0x0, 0x42, 0x2], [0x0, 0x2e, 0x2]]),
+    (0x1e6a, &[[0x21f7, 0x20, 0x8], [0x0, 0x2e, 0x2]]),
+    (0x1e6b, &[[0x21f7, 0x20, 0x2], [0x0, 0x2e, 0x2]]),
+    (0x1e6c, &[[0x21f7, 0x20, 0x8], [0x0, 0x42, 0x2]]),
+    (0x1e6d, &[[0x21f7, 0x20, 0x2], [0x0, 0x42, 0x2]]),
+    (0x1e6e, &[[0x21f7, 0x20, 0x8], [0x0, 0x49, 0x2]]),
+    (0x1e6f, &[[0x21f7, 0x20, 0x2], [0x0, 0x49, 0x2]]),
+    (0x1e70, &[[0x21f7, 0x20, 0x8], [0x0, 0x46, 0x2]]),
+    (0x1e71, &[[0x21f7, 0x20, 0x2], [0x0, 0x46, 0x2]]),
+    (0x1e72, &[[0x2217, 0x20, 0x8], [0x0, 0x43, 0x2]]),
+    (0x1e73, &[[0x2217, 0x20, 0x2], [0x0, 0x43, 0x2]]),
+    (0x1e74, &[[0x2217, 0x20, 0x8], [0x0, 0x48, 0x2]]),
+    (0x1e75, &[[0x2217, 0x20, 0x2], [0x0, 0x48, 0x2]]),
+    (0x1e76, &[[0x2217, 0x20, 0x8], [0x0, 0x46, 0x2]]),
+    (0x1e77, &[[0x2217, 0x20, 0x2], [0x0, 0x46, 0x2]]),
+    (0x1e78, &[[0x2217, 0x20, 0x8], [0x0, 0x2d, 0x2], [0x0, 0x24, 0x2]]),
+    (0x1e79, &[[0x2217, 0x20, 0x2], [0x0, 0x2d, 0x2], [0x0, 0x24, 0x2]]),
+    (0x1e7a, &[[0x2217, 0x20, 0x8], [0x0, 0x32, 0x2], [0x0, 0x2b, 0x2]]),
+    (0x1e7b, &[[0x2217, 0x20, 0x2], [0x0, 0x32, 0x2], [0x0, 0x2b, 0x2]]),
+    (0x1e7c, &[[0x2247, 0x20, 0x8], [0x0, 0x2d, 0x2]]),
+    (0x1e7d, &[[0x2247, 0x20, 0x2], [0x0, 0x2d, 0x2]]),
+    (0x1e7e, &[[0x2247, 0x20, 0x8], [0x0, 0x42, 0x2]]),
+    (0x1e7f, &[[0x2247, 0x20, 0x2], [0x0, 0x42, 0x2]]),
+    (0x1e80, &[[0x2259, 0x20, 0x8], [0x0, 0x25, 0x2]]),
+    (0x1e81, &[[0x2259, 0x20, 0x2], [0x0, 0x25, 0x2]]),
+    (0x1e82, &[[0x2259, 0x20, 0x8], [0x0, 0x24, 0x2]]),
+    (0x1e83, &[[0x2259, 0x20, 0x2], [0x0, 0x24, 0x2]]),
+    (0x1e84, &[[0x2259, 0x20, 0x8], [0x0, 0x2b, 0x2]]),
+    (0x1e85, &[[0x2259, 0x20, 0x2], [0x0, 0x2b, 0x2]]),
+    (0x1e86, &[[0x2259, 0x20, 0x8], [0x0, 0x2e, 0x2]]),
+    (0x1e87, &[[0x2259, 0x20, 0x2], [0x0, 0x2e, 0x2]]),
+    (0x1e88, &[[0x2259, 0x20, 0x8], [0x0, 0x42, 0x2]]),
+    (0x1e89, &[[0x2259, 0x20, 0x2], [0x0, 0x42, 0x2]]),
+    (0x1e8a, &[[0x2264, 0x20, 0x8], [0x0, 0x2e, 0x2]]),
+    (0x1e8b, &[[0x2264, 0x20, 0x2], [0x0, 0x2e, 0x2]]),
+    (0x1e8c, &[[0x2264, 0x20, 0x8], [0x0, 0x2b, 0x2]]),
+    (0x1e8d, &[[0x2264, 0x20, 0x2], [0x0, 0x2b, 0x2]]),
+    (0x1e8e, &[[0x2270, 0x20, 0x8], [0x0, 0x2e, 0x2]]),
+    (0x1e8f, &[[0x2270, 0x20, 0x2], [0x0, 0x2e, 0x2]]),
+    (0x1e90, &[[0x2286, 0x20, 0x8], [0x0, 0x27, 0x2]]),
+    (0x1e91, &[[0x2286, 0x20, 0x2], [0x0, 0x27, 0x2]]),
+    (0x1e92, &[[0x2286, 0x20, 0x8], [0x0, 0x42, 0x2]]),
+    (0x1e93, &[[0x2286, 0x20, 0x2], [0x0, 0x42, 0x2]]),
+    (0x1e94, &[[0x2286, 0x20, 0x8], [0x0, 0x49, 0x2]]),
+    (0x1e95, &[[0x2286, 0x20, 0x2], [0x0, 0x49, 0x2]]),
+    (0x1e96, &[[0x2075, 0x20, 0x2], [0x0, 0x49, 0x2]]),
+    (0x1e97, &[[0x21f7, 0x20, 0x2], [0x0, 0x2b, 0x2]]),
+    (0x1e98, &[[0x2259, 0x20, 0x2], [0x0, 0x29, 0x2]]),
+    (0x1e99, &[[0x2270, 0x20, 0x2], [0x0, 0x29, 0x2]]),
+    (0x1e9a, &[[0x1fa2, 0x20, 0x4], [0x22e5, 0x20, 0x4]]),
+    (0x1e9b, &[[0x21d2, 0x20, 0x4], [0x0, 0x119, 0x4], [0x0, 0x2e, 0x2]]),
+    (0x1e9c, &[[0x21e2, 0x20, 0x2]]),
+    (0x1e9d, &[[0x21e3, 0x20, 0x2]]),
+    (0x1e9e, &[[0x21d2, 0x20, 0xa], [0x0, 0x118, 0x4], [0x21d2, 0x20, 0xa]]),
+    (0x1e9f, &[[0x2006, 0x20, 0x2]]),
+    (0x1ea0, &[[0x1fa2, 0x20, 0x8], [0x0, 0x42, 0x2]]),
+    (0x1ea1, &[[0x1fa2, 0x20, 0x2], [0x0, 0x42, 0x2]]),
+    (0x1ea2, &[[0x1fa2, 0x20, 0x8], [0x0, 0x3b, 0x2]]),
+    (0x1ea3, &[[0x1fa2, 0x20, 0x2], [0x0, 0x3b, 0x2]]),
+    (0x1ea4, &[[0x1fa2, 0x20, 0x8], [0x0, 0x27, 0x2], [0x0, 0x24, 0x2]]),
+    (0x1ea5, &[[0x1fa2, 0x20, 0x2], [0x0, 0x27, 0x2], [0x0, 0x24, 0x2]]),
+    (0x1ea6, &[[0x1fa2, 0x20, 0x8], [0x0, 0x27, 0x2], [0x0, 0x25, 0x2]]),
+    (0x1ea7, &[[0x1fa2, 0x20, 0x2], [0x0, 0x27, 0x2], [0x0, 0x25, 0x2]]),
+    (0x1ea8, &[[0x1fa2, 0x20, 0x8], [0x0, 0x27, 0x2], [0x0, 0x3b, 0x2]]),
+    (0x1ea9, &[[0x1fa2, 0x20, 0x2], [0x0, 0x27, 0x2], [0x0, 0x3b, 0x2]]),
+    (0x1eaa, &[[0x1fa2, 0x20, 0x8], [0x0, 0x27, 0x2], [0x0, 0x2d, 0x2]]),
+    (0x1eab, &[[0x1fa2, 0x20, 0x2], [0x0, 0x27, 0x2], [0x0, 0x2d, 0x2]]),
+    (0x1eac, &[[0x1fa2, 0x20, 0x8], [0x0, 0x42, 0x2], [0x0, 0x27, 0x2]]),
+    (0x1ead, &[[0x1fa2, 0x20, 0x2], [0x0, 0x42, 0x2], [0x0, 0x27, 0x2]]),
+    (0x1eae, &[[0x1fa2, 0x20, 0x8], [0x0, 0x26, 0x2], [0x0, 0x24, 0x2]]),
+    (0x1eaf, &[[0x1fa2, 0x20, 0x2], [0x0, 0x26, 0x2], [0x0, 0x24, 0x2]]),
+    (0x1eb0, &[[0x1fa2, 0x20, 0x8], [0x0, 0x26, 0x2], [0x0, 0x25, 0x2]]),
+    (0x1eb1, &[[0x1fa2, 0x20, 0x2], [0x0, 0x26, 0x2], [0x0, 0x25, 0x2]]),
+    (0x1eb2, &[[0x1fa2, 0x20, 0x8], [0x0, 0x26, 0x2], [0x0, 0x3b, 0x2]]),
+    (0x1eb3, &[[0x1fa2, 0x20, 0x2], [0x0, 0x26, 0x2], [0x0, 0x3b, 0x2]]),
+    (0x1eb4, &[[0x1fa2, 0x20, 0x8], [0x0, 0x26, 0x2], [0x0, 0x2d, 0x2]]),
+    (0x1eb5, &[[0x1fa2, 0x20, 0x2], [0x0, 0x26, 0x2], [0x0, 0x2d, 0x2]]),
+    (0x1eb6, &[[0x1fa2, 0x20, 0x8], [0x0, 0x42, 0x2], [0x0, 0x26, 0x2]]),
+    (0x1eb7, &[[0x1fa2, 0x20, 0x2], [0x0, 0x42, 0x2], [0x0, 0x26, 0x2]]),
+    (0x1eb8, &[[0x2007, 0x20, 0x8], [0x0, 0x42, 0x2]]),
+    (0x1eb9, &[[0x2007, 0x20, 0x2], [0x0, 0x42, 0x2]]),
+    (0x1eba, &[[0x2007, 0x20, 0x8], [0x0, 0x3b, 0x2]]),
+    (0x1ebb, &[[0x2007, 0x20, 0x2], [0x0, 0x3b, 0x2]]),
+    (0x1ebc, &[[0x2007, 0x20, 0x8], [0x0, 0x2d, 0x2]]),
+    (0x1ebd, &[[0x2007, 0x20, 0x2], [0x0, 0x2d, 0x2]]),
+    (0x1ebe, &[[0x2007, 0x20, 0x8], [0x0, 0x27, 0x2], [0x0, 0x24, 0x2]]),
+    (0x1ebf, &[[0x2007, 0x20, 0x2], [0x0, 0x27, 0x2], [0x0, 0x24, 0x2]]),
+    (0x1ec0, &[[0x2007, 0x20, 0x8], [0x0, 0x27, 0x2], [0x0, 0x25, 0x2]]),
+    (0x1ec1, &[[0x2007, 0x20, 0x2], [0x0, 0x27, 0x2], [0x0, 0x25, 0x2]]),
+    (0x1ec2, &[[0x2007, 0x20, 0x8], [0x0, 0x27, 0x2], [0x0, 0x3b, 0x2]]),
+    (0x1ec3, &[[0x2007, 0x20, 0x2], [0x0, 0x27, 0x2], [0x0, 0x3b, 0x2]]),
+    (0x1ec4, &[[0x2007, 0x20, 0x8], [0x0, 0x27, 0x2], [0x0, 0x2d, 0x2]]),
+    (0x1ec5, &[[0x2007, 0x20, 0x2], [0x0, 0x27, 0x2], [0x0, 0x2d, 0x2]]),
+    (0x1ec6, &[[0x2007, 0x20, 0x8], [0x0, 0x42, 0x2], [0x0, 0x27, 0x2]]),
+    (0x1ec7, &[[0x2007, 0x20, 0x2], [0x0, 0x42, 0x2], [0x0, 0x27, 0x2]]),
+    (0x1ec8, &[[0x2090, 0x20, 0x8], [0x0, 0x3b, 0x2]]),
+    (0x1ec9, &[[0x2090, 0x20, 0x2], [0x0, 0x3b, 0x2]]),
+    (0x1eca, &[[0x2090, 0x20, 0x8], [0x0, 0x42, 0x2]]),
+    (0x1ecb, &[[0x2090, 0x20, 0x2], [0x0, 0x42, 0x2]]),
+    (0x1ecc, &[[0x213c, 0x20, 0x8], [0x0, 0x42, 0x2]]),
+    (0x1ecd, &[[0x213c, 0x20, 0x2], [0x0, 0x42, 0x2]]),
+    (0x1ece, &[[0x213c, 0x20, 0x8], [0x0, 0x3b, 0x2]]),
+    (0x1ecf, &[[0x213c, 0x20, 0x2], [0x0, 0x3b, 0x2]]),
+    (0x1ed0, &[[0x213c, 0x20, 0x8], [0x0, 0x27, 0x2], [0x0, 0x24, 0x2]]),
+    (0x1ed1, &[[0x213c, 0x20, 0x2], [0x0, 0x27, 0x2], [0x0, 0x24, 0x2]]),
+    (0x1ed2, &[[0x213c, 0x20, 0x8], [0x0, 0x27, 0x2], [0x0, 0x25, 0x2]]),
+    (0x1ed3, &[[0x213c, 0x20, 0x2], [0x0, 0x27, 0x2], [0x0, 0x25, 0x2]]),
+    (0x1ed4, &[[0x213c, 0x20, 0x8], [0x0, 0x27, 0x2], [0x0, 0x3b, 0x2]]),
+    (0x1ed5, &[[0x213c, 0x20, 0x2], [0x0, 0x27, 0x2], [0x0, 0x3b, 0x2]]),
+    (0x1ed6, &[[0x213c, 0x20, 0x8], [0x0, 0x27, 0x2], [0x0, 0x2d, 0x2]]),
+    (0x1ed7, &[[0x213c, 0x20, 0x2], [0x0, 0x27, 0x2], [0x0, 0x2d, 0x2]]),
+    (0x1ed8, &[[0x213c, 0x20, 0x8], [0x0, 0x42, 0x2], [0x0, 0x27, 0x2]]),
+    (0x1ed9, &[[0x213c, 0x20, 0x2], [0x0, 0x42, 0x2], [0x0, 0x27, 0x2]]),
+    (0x1eda, &[[0x213c, 0x20, 0x8], [0x0, 0x3f, 0x2], [0x0, 0x24, 0x2]]),
+    (0x1edb, &[[0x213c, 0x20, 0x2], [0x0, 0x3f, 0x2], [0x0, 0x24, 0x2]]),
+    (0x1edc, &[[0x213c, 0x20, 0x8], [0x0, 0x3f, 0x2], [0x0, 0x25, 0x2]]),
+    (0x1edd, &[[0x213c, 0x20, 0x2], [0x0, 0x3f, 0x2], [0x0, 0x25, 0x2]]),
+    (0x1ede, &[[0x213c, 0x20, 0x8], [0x0, 0x3f, 0x2], [0x0, 0x3b, 0x2]]),
+    (0x1edf, &[[0x213c, 0x20, 0x2], [0x0, 0x3f, 0x2], [0x0, 0x3b, 0x2]]),
+    (0x1ee0, &[[0x213c, 0x20, 0x8], [0x0, 0x3f, 0x2], [0x0, 0x2d, 0x2]]),
+    (0x1ee1, &[[0x213c, 0x20, 0x2], [0x0, 0x3f, 0x2], [0x0, 0x2d, 0x2]]),
+    (0x1ee2, &[[0x213c, 0x20, 0x8], [0x0, 0x3f, 0x2], [0x0, 0x42, 0x2]]),
+    (0x1ee3, &[[0x213c, 0x20, 0x2], [0x0, 0x3f, 0x2], [0x0, 0x42, 0x2]]),
+    (0x1ee4, &[[0x2217, 0x20, 0x8], [0x0, 0x42, 0x2]]),
+    (0x1ee5, &[[0x2217, 0x20, 0x2], [0x0, 0x42, 0x2]]),
+    (0x1ee6, &[[0x2217, 0x20, 0x8], [0x0, 0x3b, 0x2]]),
+    (0x1ee7, &[[0x2217, 0x20, 0x2], [0x0, 0x3b, 0x2]]),
+    (0x1ee8, &[[0x2217, 0x20, 0x8], [0x0, 0x3f, 0x2], [0x0, 0x24, 0x2]]),
+    (0x1ee9, &[[0x2217, 0x20, 0x2], [0x0, 0x3f, 0x2], [0x0, 0x24, 0x2]]),
+    (0x1eea, &[[0x2217, 0x20, 0x8], [0x0, 0x3f, 0x2], [0x0, 0x25, 0x2]]),
+    (0x1eeb, &[[0x2217, 0x20, 0x2], [0x0, 0x3f, 0x2], [0x0, 0x25, 0x2]]),
+    (0x1eec, &[[0x2217, 0x20, 0x8], [0x0, 0x3f, 0x2], [0x0, 0x3b, 0x2]]),
+    (0x1eed, &[[0x2217, 0x20, 0x2], [0x0, 0x3f, 0x2], [0x0, 0x3b, 0x2]]),
+    (0x1eee, &[[0x2217, 0x20, 0x8], [0x0, 0x3f, 0x2], [0x0, 0x2d, 0x2]]),
+    (0x1eef, &[[0x2217, 0x20, 0x2], [0x0, 0x3f, 0x2], [0x0, 0x2d, 0x2]]),
+    (0x1ef0, &[[0x2217, 0x20, 0x8], [0x0, 0x3f, 0x2], [0x0, 0x42, 0x2]]),
+    (0x1ef1, &[[0x2217, 0x20, 0x2], [0x0, 0x3f, 0x2], [0x0, 0x42, 0x2]]),
+    (0x1ef2, &[[0x2270, 0x20, 0x8], [0x0, 0x25, 0x2]]),
+    (0x1ef3, &[[0x2270, 0x20, 0x2], [0x0, 0x25, 0x2]]),
+    (0x1ef4, &[[0x2270, 0x20, 0x8], [0x0, 0x42, 0x2]]),
+    (0x1ef5, &[[0x2270, 0x20, 0x2], [0x0, 0x42, 0x2]]),
+    (0x1ef6, &[[0x2270, 0x20, 0x8], [0x0, 0x3b, 0x2]]),
+    (0x1ef7, &[[0x2270, 0x20, 0x2], [0x0, 0x3b, 0x2]]),
+    (0x1ef8, &[[0x2270, 0x20, 0x8], [0x0, 0x2d, 0x2]]),
+    (0x1ef9, &[[0x2270, 0x20, 0x2], [0x0, 0x2d, 0x2]]),
+    (0x1efa, &[[0x20d6, 0x20, 0xa], [0x20d6, 0x20, 0xa]]),
+    (0x1efb, &[[0x20d6, 0x20, 0x4], [0x20d6, 0x20, 0x4]]),
+    (0x1efc, &[[0x2254, 0x20, 0x8]]),
+    (0x1efd, &[[0x2254, 0x20, 0x2]]),
+    (0x1efe, &[[0x2280, 0x20, 0x8]]),
+    (0x1eff, &[[0x2280, 0x20, 0x2]]),
+    (0x1f00, &[[0x231e, 0x20, 0x2], [0x0, 0x22, 0x2]]),
+    (0x1f01, &[[0x231e, 0x20, 0x2], [0x0, 0x23, 0x2]]),
+    (0x1f02, &[[0x231e, 0x20, 0x2], [0x0, 0x22, 0x2], [0x0, 0x25, 0x2]]),
+    (0x1f03, &[[0x231e, 0x20, 0x2], [0x0, 0x23, 0x2], [0x0, 0x25, 0x2]]),
+    (0x1f04, &[[0x231e, 0x20, 0x2], [0x0, 0x22, 0x2], [0x0, 0x24, 0x2]]),
+    (0x1f05, &[[0x231e, 0x20, 0x2], [0x0, 0x23, 0x2], [0x0, 0x24, 0x2]]),
+    (0x1f06, &[[0x231e, 0x20, 0x2], [0x0, 0x22, 0x2], [0x0, 0x2a, 0x2]]),
+    (0x1f07, &[[0x231e, 0x20, 0x2], [0x0, 0x23, 0x2], [0x0, 0x2a, 0x2]]),
+    (0x1f08, &[[0x231e, 0x20, 0x8], [0x0, 0x22, 0x2]]),
+    (0x1f09, &[[0x231e, 0x20, 0x8], [0x0, 0x23, 0x2]]),
+    (0x1f0a, &[[0x231e, 0x20, 0x8], [0x0, 0x22, 0x2], [0x0, 0x25, 0x2]]),
+    (0x1f0b, &[[0x231e, 0x20, 0x8], [0x0, 0x23, 0x2], [0x0, 0x25, 0x2]]),
+    (0x1f0c, &[[0x231e, 0x20, 0x8], [0x0, 0x22, 0x2], [0x0, 0x24, 0x2]]),
+    (0x1f0d, &[[0x231e, 0x20, 0x8], [0x0, 0x23, 0x2], [0x0, 0x24, 0x2]]),
+    (0x1f0e, &[[0x231e, 0x20, 0x8], [0x0, 0x22, 0x2], [0x0, 0x2a, 0x2]]),
+    (0x1f0f, &[[0x231e, 0x20, 0x8], [0x0, 0x23, 0x2], [0x0, 0x2a, 0x2]]),
+    (0x1f10, &[[0x2323, 0x20, 0x2], [0x0, 0x22, 0x2]]),
+    (0x1f11, &[[0x2323, 0x20, 0x2], [0x0, 0x23, 0x2]]),
+    (0x1f12, &[[0x2323, 0x20, 0x2], [0x0, 0x22, 0x2], [0x0, 0x25, 0x2]]),
+    (0x1f13, &[[0x2323, 0x20, 0x2], [0x0, 0x23, 0x2], [0x0, 0x25, 0x2]]),
+    (0x1f14, &[[0x2323, 0x20, 0x2], [0x0, 0x22, 0x2], [0x0, 0x24, 0x2]]),
+    (0x1f15, &[[0x2323, 0x20, 0x2], [0x0, 0x23, 0x2], [0x0, 0x24, 0x2]]),
+    (0x1f18, &[[0x2323, 0x20, 0x8], [0x0, 0x22, 0x2]]),
+    (0x1f19, &[[0x2323, 0x20, 0x8], [0x0, 0x23, 0x2]]),
+    (0x1f1a, &[[0x2323, 0x20, 0x8], [0x0, 0x22, 0x2], [0x0, 0x25, 0x2]]),
+    (0x1f1b, &[[0x2323, 0x20, 0x8], [0x0, 0x23, 0x2], [0x0, 0x25, 0x2]]),
+    (0x1f1c, &[[0x2323, 0x20, 0x8], [0x0, 0x22, 0x2], [0x0, 0x24, 0x2]]),
+    (0x1f1d, &[[0x2323, 0x20, 0x8], [0x0, 0x23, 0x2], [0x0, 0x24, 0x2]]),
+    (0x1f20, &[[0x2329, 0x20, 0x2], [0x0, 0x22, 0x2]]),
+    (0x1f21, &[[0x2329, 0x20, 0x2], [0x0, 0x23, 0x2]]),
+    (0x1f22, &[[0x2329, 0x20, 0x2], [0x0, 0x22, 0x2], [0x0, 0x25, 0x2]]),
+    (0x1f23, &[[0x2329, 0x20, 0x2], [0x0, 0x23, 0x2], [0x0, 0x25, 0x2]]),
+    (0x1f24, &[[0x2329, 0x20, 0x2], [0x0, 0x22, 0x2], [0x0, 0x24, 0x2]]),
+    (0x1f25, &[[0x2329, 0x20, 0x2], [0x0, 0x23, 0x2], [0x0, 0x24, 0x2]]),
+    (0x1f26, &[[0x2329, 0x20, 0x2], [0x0, 0x22, 0x2], [0x0, 0x2a, 0x2]]),
+    (0x1f27, &[[0x2329, 0x20, 0x2], [0x0, 0x23, 0x2], [0x0, 0x2a, 0x2]]),
+    (0x1f28, &[[0x2329, 0x20, 0x8], [0x0, 0x22, 0x2]]),
+    (0x1f29, &[[0x2329, 0x20, 0x8], [0x0, 0x23, 0x2]]),
+    (0x1f2a, &[[0x2329, 0x20, 0x8], [0x0, 0x22, 0x2], [0x0, 0x25, 0x2]]),
+    (0x1f2b, &[[0x2329, 0x20, 0x8], [0x0, 0x23, 0x2], [0x0, 0x25, 0x2]]),
+    (0x1f2c, &[[0x2329, 0x20, 0x8], [0x0, 0x22, 0x2], [0x0, 0x24, 0x2]]),
+    (0x1f2d, &[[0x2329, 0x20, 0x8], [0x0, 0x23, 0x2], [0x0, 0x24, 0x2]]),
+    (0x1f2e, &[[0x2329, 0x20, 0x8], [0x0, 0x22, 0x2], [0x0, 0x2a, 0x2]]),
+    (0x1f2f, &[[0x2329, 0x20, 0x8], [0x0, 0x23, 0x2], [0x0, 0x2a, 0x2]]),
+    (0x1f30, &[[0x232b, 0x20, 0x2], [0x0, 0x22, 0x2]]),
+    (0x1f31, &[[0x232b, 0x20, 0x2], [0x0, 0x23, 0x2]]),
+    (0x1f32, &[[0x232b, 0x20, 0x2], [0x0, 0x22, 0x2], [0x0, 0x25, 0x2]]),
+    (0x1f33, &[[0x232b, 0x20, 0x2], [0x0, 0x23, 0x2], [0x0, 0x25, 0x2]]),
+    (0x1f34, &[[0x232b, 0x20, 0x2], [0x0, 0x22, 0x2], [0x0, 0x24, 0x2]]),
+    (0x1f35, &[[0x232b, 0x20, 0x2], [0x0, 0x23, 0x2], [0x0, 0x24, 0x2]]),
+    (0x1f36, &[[0x232b, 0x20, 0x2], [0x0, 0x22, 0x2], [0x0, 0x2a, 0x2]]),
+    (0x1f37, &[[0x232b, 0x20, 0x2], [0x0, 0x23, 0x2], [0x0, 0x2a, 0x2]]),
+    (0x1f38, &[[0x232b, 0x20, 0x8], [0x0, 0x22, 0x2]]),
+    (0x1f39, &[[0x232b, 0x20, 0x8], [0x0, 0x23, 0x2]]),
+    (0x1f3a, &[[0x232b, 0x20, 0x8], [0x0, 0x22, 0x2], [0x0, 0x25, 0x2]]),
+    (0x1f3b, &[[0x232b, 0x20, 0x8], [0x0, 0x23, 0x2], [0x0, 0x25, 0x2]]),
+    (0x1f3c, &[[0x232b, 0x20, 0x8], [0x0, 0x22, 0x2], [0x0, 0x24, 0x2]]),
+    (0x1f3d, &[[0x232b, 0x20, 0x8], [0x0, 0x23, 0x2], [0x0, 0x24, 0x2]]),
+    (0x1f3e, &[[0x232b, 0x20, 0x8], [0x0, 0x22, 0x2], [0x0, 0x2a, 0x2]]),
+    (0x1f3f, &[[0x232b, 0x20, 0x8], [0x0, 0x23, 0x2], [0x0, 0x2a, 0x2]]),
+    (0x1f40, &[[0x2333, 0x20, 0x2], [0x0, 0x22, 0x2]]),
+    (0x1f41, &[[0x2333, 0x20, 0x2], [0x0, 0x23, 0x2]]),
+    (0x1f42, &[[0x2333, 0x20, 0x2], [0x0, 0x22, 0x2], [0x0, 0x25, 0x2]]),
+    (0x1f43, &[[0x2333, 0x20, 0x2], [0x0, 0x23, 0x2], [0x0, 0x25, 0x2]]),
+    (0x1f44, &[[0x2333, 0x20, 0x2], [0x0, 0x22, 0x2], [0x0, 0x24, 0x2]]),
+    (0x1f45, &[[0x2333, 0x20, 0x2], [0x0, 0x23, 0x2], [0x0, 0x24, 0x2]]),
+    (0x1f48, &[[0x2333, 0x20, 0x8], [0x0, 0x22, 0x2]]),
+    (0x1f49, &[[0x2333, 0x20, 0x8], [0x0, 0x23, 0x2]]),
+    (0x1f4a, &[[0x2333, 0x20, 0x8], [0x0, 0x22, 0x2], [0x0, 0x25, 0x2]]),
+    (0x1f4b, &[[0x2333, 0x20, 0x8], [0x0, 0x23, 0x2], [0x0, 0x25, 0x2]]),
+    (0x1f4c, &[[0x2333, 0x20, 0x8], [0x0, 0x22, 0x2], [0x0, 0x24, 0x2]]),
+    (0x1f4d, &[[0x2333, 0x20, 0x8], [0x0, 0x23, 0x2], [0x0, 0x24, 0x2]]),
+    (0x1f50, &[[0x2341, 0x20, 0x2], [0x0, 0x22, 0x2]]),
+    (0x1f51, &[[0x2341, 0x20, 0x2], [0x0, 0x23, 0x2]]),
+    (0x1f52, &[[0x2341, 0x20, 0x2], [0x0, 0x22, 0x2], [0x0, 0x25, 0x2]]),
+    (0x1f53, &[[0x2341, 0x20, 0x2], [0x0, 0x23, 0x2], [0x0, 0x25, 0x2]]),
+    (0x1f54, &[[0x2341, 0x20, 0x2], [0x0, 0x22, 0x2], [0x0, 0x24, 0x2]]),
+    (0x1f55, &[[0x2341, 0x20, 0x2], [0x0, 0x23, 0x2], [0x0, 0x24, 0x2]]),
+    (0x1f56, &[[0x2341, 0x20, 0x2], [0x0, 0x22, 0x2], [0x0, 0x2a, 0x2]]),
+    (0x1f57, &[[0x2341, 0x20, 0x2], [0x0, 0x23, 0x2], [0x0, 0x2a, 0x2]]),
+    (0x1f59, &[[0x2341, 0x20, 0x8], [0x0, 0x23, 0x2]]),
+    (0x1f5b, &[[0x2341, 0x20, 0x8], [0x0, 0x23, 0x2], [0x0, 0x25, 0x2]]),
+    (0x1f5d, &[[0x2341, 0x20, 0x8], [0x0, 0x23, 0x2], [0x0, 0x24, 0x2]]),
+    (0x1f5f, &[[0x2341, 0x20, 0x8], [0x0, 0x23, 0x2], [0x0, 0x2a, 0x2]]),
+    (0x1f60, &[[0x2346, 0x20, 0x2], [0x0, 0x22, 0x2]]),
+    (0x1f61, &[[0x2346, 0x20, 0x2], [0x0, 0x23, 0x2]]),
+    (0x1f62, &[[0x2346, 0x20, 0x2], [0x0, 0x22, 0x2], [0x0, 0x25, 0x2]]),
+    (0x1f63, &[[0x2346, 0x20, 0x2], [0x0, 0x23, 0x2], [0x0, 0x25, 0x2]]),
+    (0x1f64, &[[0x2346, 0x20, 0x2], [0x0, 0x22, 0x2], [0x0, 0x24, 0x2]]),
+    (0x1f65, &[[0x2346, 0x20, 0x2], [0x0, 0x23, 0x2], [0x0, 0x24, 0x2]]),
+    (0x1f66, &[[0x2346, 0x20, 0x2], [0x0, 0x22, 0x2], [0x0, 0x2a, 0x2]]),
+    (0x1f67, &[[0x2346, 0x20, 0x2], [0x0, 0x23, 0x2], [0x0, 0x2a, 0x2]]),
+    (0x1f68, &[[0x2346, 0x20, 0x8], [0x0, 0x22, 0x2]]),
+    (0x1f69, &[[0x2346, 0x20, 0x8], [0x0, 0x23, 0x2]]),
+    (0x1f6a, &[[0x2346, 0x20, 0x8], [0x0, 0x22, 0x2], [0x0, 0x25, 0x2]]),
+    (0x1f6b, &[[0x2346, 0x20, 0x8], [0x0, 0x23, 0x2], [0x0, 0x25, 0x2]]),
+    (0x1f6c, &[[0x2346, 0x20, 0x8], [0x0, 0x22, 0x2], [0x0, 0x24, 0x2]]),
+    (0x1f6d, &[[0x2346, 0x20, 0x8], [0x0, 0x23, 0x2], [0x0, 0x24, 0x2]]),
+    (0x1f6e, &[[0x2346, 0x20, 0x8], [0x0, 0x22, 0x2], [0x0, 0x2a, 0x2]]),
+    (0x1f6f, &[[0x2346, 0x20, 0x8], [0x0, 0x23, 0x2], [0x0, 0x2a, 0x2]]),
+    (0x1f70, &[[0x231e, 0x20, 0x2], [0x0, 0x25, 0x2]]),
+    (0x1f71, &[[0x231e, 0x20, 0x2], [0x0, 0x24, 0x2]]),
+    (0x1f72, &[[0x2323, 0x20, 0x2], [0x0, 0x25, 0x2]]),
+    (0x1f73, &[[0x2323, 0x20, 0x2], [0x0, 0x24, 0x2]]),
+    (0x1f74, &[[0x2329, 0x20, 0x2], [0x0, 0x25, 0x2]]),
+    (0x1f75, &[[0x2329, 0x20, 0x2], [0x0, 0x24, 0x2]]),
+    (0x1f76, &[[0x232b, 0x20, 0x2], [0x0, 0x25, 0x2]]),
+    (0x1f77, &[[0x232b, 0x20, 0x2], [0x0, 0x24, 0x2]]),
+    (0x1f78, &[[0x2333, 0x20, 0x2], [0x0, 0x25, 0x2]]),
+    (0x1f79, &[[0x2333, 0x20, 0x2], [0x0, 0x24, 0x2]]),
+    (0x1f7a, &[[0x2341, 0x20, 0x2], [0x0, 0x25, 0x2]]),
+    (0x1f7b, &[[0x2341, 0x20, 0x2], [0x0, 0x24, 0x2]]),
+    (0x1f7c, &[[0x2346, 0x20, 0x2], [0x0, 0x25, 0x2]]),
+    (0x1f7d, &[[0x2346, 0x20, 0x2], [0x0, 0x24, 0x2]]),
+    (0x1f80, &[[0x231e, 0x20, 0x2], [0x0, 0x22, 0x2], [0x0, 0x4c, 0x2]]),
+    (0x1f81, &[[0x231e, 0x20, 0x2], [0x0, 0x23, 0x2], [0x0, 0x4c, 0x2]]),
+    (0x1f82, &[[0x231e, 0x20, 0x2], [0x0, 0x22, 0x2], [0x0, 0x25, 0x2], [0x0, 0x4c, 0x2]]),
+    (0x1f83, &[[0x231e, 0x20, 0x2], [0x0, 0x23, 0x2], [0x0, 0x25, 0x2], [0x0, 0x4c, 0x2]]),
+    (0x1f84, &[[0x231e, 0x20, 0x2], [0x0, 0x22, 0x2], [0x0, 0x24, 0x2], [0x0, 0x4c, 0x2]]),
+    (0x1f85, &[[0x231e, 0x20, 0x2], [0x0, 0x23, 0x2], [0x0, 0x24, 0x2], [0x0, 0x4c, 0x2]]),
+    (0x1f86, &[[0x231e, 0x20, 0x2], [0x0, 0x22, 0x2], [0x0, 0x2a, 0x2], [0x0, 0x4c, 0x2]]),
+    (0x1f87, &[[0x231e, 0x20, 0x2], [0x0, 0x23, 0x2], [0x0, 0x2a, 0x2], [0x0, 0x4c, 0x2]]),
+    (0x1f88, &[[0x231e, 0x20, 0x8], [0x0, 0x22, 0x2], [0x0, 0x4c, 0x2]]),
+    (0x1f89, &[[0x231e, 0x20, 0x8], [0x0, 0x23, 0x2], [0x0, 0x4c, 0x2]]),
+    (0x1f8a, &[[0x231e, 0x20, 0x8], [0x0, 0x22, 0x2], [0x0, 0x25, 0x2], [0x0, 0x4c, 0x2]]),
+    (0x1f8b, &[[0x231e, 0x20, 0x8], [0x0, 0x23, 0x2], [0x0, 0x25, 0x2], [0x0, 0x4c, 0x2]]),
+    (0x1f8c, &[[0x231e, 0x20, 0x8], [0x0, 0x22, 0x2], [0x0, 0x24, 0x2], [0x0, 0x4c, 0x2]]),
+    (0x1f8d, &[[0x231e, 0x20, 0x8], [0x0, 0x23, 0x2], [0x0, 0x24, 0x2], [0x0, 0x4c, 0x2]]),
+    (0x1f8e, &[[0x231e, 0x20, 0x8], [0x0, 0x22, 0x2], [0x0, 0x2a, 0x2], [0x0, 0x4c, 0x2]]),
+    (0x1f8f, &[[0x231e, 0x20, 0x8], [0x0, 0x23, 0x2], [0x0, 0x2a, 0x2], [0x0, 0x4c, 0x2]]),
+    (0x1f90, &[[0x2329, 0x20, 0x2], [0x0, 0x22, 0x2], [0x0, 0x4c, 0x2]]),
+    (0x1f91, &[[0x2329, 0x20, 0x2], [0x0, 0x23, 0x2], [0x0, 0x4c, 0x2]]),
+    (0x1f92, &[[0x2329, 0x20, 0x2], [0x0, 0x22, 0x2], [0x0, 0x25, 0x2], [0x0, 0x4c, 0x2]]),
+    (0x1f93, &[[0x2329, 0x20, 0x2], [0x0, 0x23, 0x2], [0x0, 0x25, 0x2], [0x0, 0x4c, 0x2]]),
+    (0x1f94, &[[0x2329, 0x20, 0x2], [0x0, 0x22, 0x2], [0x0, 0x24, 0x2], [0x0, 0x4c, 0x2]]),
+    (0x1f95, &[[0x2329, 0x20, 0x2], [0x0, 0x23, 0x2], [0x0, 0x24, 0x2], [0x0, 0x4c, 0x2]]),
+    (0x1f96, &[[0x2329, 0x20, 0x2], [0x0, 0x22, 0x2], [0x0, 0x2a, 0x2], [0x0, 0x4c, 0x2]]),
+    (0x1f97, &[[0x2329, 0x20, 0x2], [0x0, 0x23, 0x2], [0x0, 0x2a, 0x2], [0x0, 0x4c, 0x2]]),
+    (0x1f98, &[[0x2329, 0x20, 0x8], [0x0, 0x22, 0x2], [0x0, 0x4c, 0x2]]),
+    (0x1f99, &[[0x2329, 0x20, 0x8], [0x0, 0x23, 0x2], [0x0, 0x4c, 0x2]]),
+    (0x1f9a, &[[0x2329, 0x20, 0x8], [0x0, 0x22, 0x2], [0x0, 0x25, 0x2], [0x0, 0x4c, 0x2]]),
+    (0x1f9b, &[[0x2329, 0x20, 0x8], [0x0, 0x23, 0x2], [0x0, 0x25, 0x2], [0x0, 0x4c, 0x2]]),
+    (0x1f9c, &[[0x2329, 0x20, 0x8], [0x0, 0x22, 0x2], [0x0, 0x24, 0x2], [0x0, 0x4c, 0x2]]),
+    (0x1f9d, &[[0x2329, 0x20, 0x8], [0x0, 0x23, 0x2], [0x0, 0x24, 0x2], [0x0, 0x4c, 0x2]]),
+    (0x1f9e, &[[0x2329, 0x20, 0x8], [0x0, 0x22, 0x2], [0x0, 0x2a, 0x2], [0x0, 0x4c, 0x2]]),
+    (0x1f9f, &[[0x2329, 0x20, 0x8], [0x0, 0x23, 0x2], [0x0, 0x2a, 0x2], [0x0, 0x4c, 0x2]]),
+    (0x1fa0, &[[0x2346, 0x20, 0x2], [0x0, 0x22, 0x2], [0x0, 0x4c, 0x2]]),
+    (0x1fa1, &[[0x2346, 0x20, 0x2], [0x0, 0x23, 0x2], [0x0, 0x4c, 0x2]]),
+    (0x1fa2, &[[0x2346, 0x20, 0x2], [0x0, 0x22, 0x2], [0x0, 0x25, 0x2], [0x0, 0x4c, 0x2]]),
+    (0x1fa3, &[[0x2346, 0x20, 0x2], [0x0, 0x23, 0x2], [0x0, 0x25, 0x2], [0x0, 0x4c, 0x2]]),
+    (0x1fa4, &[[0x2346, 0x20, 0x2], [0x0, 0x22, 0x2], [0x0, 0x24, 0x2], [0x0, 0x4c, 0x2]]),
+    (0x1fa5, &[[0x2346, 0x20, 0x2], [0x0, 0x23, 0x2], [0x0, 0x24, 0x2], [0x0, 0x4c, 0x2]]),
+    (0x1fa6, &[[0x2346, 0x20, 0x2], [0x0, 0x22, 0x2], [0x0, 0x2a, 0x2], [0x0, 0x4c, 0x2]]),
+    (0x1fa7, &[[0x2346, 0x20, 0x2], [0x0, 0x23, 0x2], [0x0, 0x2a, 0x2], [0x0, 0x4c, 0x2]]),
+    (0x1fa8, &[[0x2346, 0x20, 0x8], [0x0, 0x22, 0x2], [0x0, 0x4c, 0x2]]),
+    (0x1fa9, &[[0x2346, 0x20, 0x8], [0x0, 0x23, 0x2], [0x0, 0x4c, 0x2]]),
+    (0x1faa, &[[0x2346, 0x20, 0x8], [0x0, 0x22, 0x2], [0x0, 0x25, 0x2], [0x0, 0x4c, 0x2]]),
+    (0x1fab, &[[0x2346, 0x20, 0x8], [0x0, 0x23, 0x2], [0x0, 0x25, 0x2], [0x0, 0x4c, 0x2]]),
+    (0x1fac, &[[0x2346, 0x20, 0x8], [0x0, 0x22, 0x2], [0x0, 0x24, 0x2], [0x0, 0x4c, 0x2]]),
+    (0x1fad, &[[0x2346, 0x20, 0x8], [0x0, 0x23, 0x2], [0x0, 0x24, 0x2], [0x0, 0x4c, 0x2]]),
+    (0x1fae, &[[0x2346, 0x20, 0x8], [0x0, 0x22, 0x2], [0x0, 0x2a, 0x2], [0x0, 0x4c, 0x2]]),
+    (0x1faf, &[[0x2346, 0x20, 0x8], [0x0, 0x23, 0x2], [0x0, 0x2a, 0x2], [0x0, 0x4c, 0x2]]),
+    (0x1fb0, &[[0x231e, 0x20, 0x2], [0x0, 0x26, 0x2]]),
+    (0x1fb1, &[[0x231e, 0x20, 0x2], [0x0, 0x32, 0x2]]),
+    (0x1fb2, &[[0x231e, 0x20, 0x2], [0x0, 0x25, 0x2], [0x0, 0x4c, 0x2]]),
+    (0x1fb3, &[[0x231e, 0x20, 0x2], [0x0, 0x4c, 0x2]]),
+    (0x1fb4, &[[0x231e, 0x20, 0x2], [0x0, 0x24, 0x2], [0x0, 0x4c, 0x2]]),
+    (0x1fb6, &[[0x231e, 0x20, 0x2], [0x0, 0x2a, 0x2]]),
+    (0x1fb7, &[[0x231e, 0x20, 0x2], [0x0, 0x2a, 0x2], [0x0, 0x4c, 0x2]]),
+    (0x1fb8, &[[0x231e, 0x20, 0x8], [0x0, 0x26, 0x2]]),
+    (0x1fb9, &[[0x231e, 0x20, 0x8], [0x0, 0x32, 0x2]]),
+    (0x1fba, &[[0x231e, 0x20, 0x8], [0x0, 0x25, 0x2]]),
+    (0x1fbb, &[[0x231e, 0x20, 0x8], [0x0, 0x24, 0x2]]),
+    (0x1fbc, &[[0x231e, 0x20, 0x8], [0x0, 0x4c, 0x2]]),
+    (0x1fbd, &[[0x4c0, 0x20, 0x2]]),
+    (0x1fbe, &[[0x232b, 0x20, 0x2]]),
+    (0x1fbf, &[[0x4c0, 0x20, 0x2]]),
+    (0x1fc0, &[[0x4c2, 0x20, 0x2]]),
+    (0x1fc1, &[[0x4bb, 0x20, 0x2], [0x0, 0x2a, 0x2]]),
+    (0x1fc2, &[[0x2329, 0x20, 0x2], [0x0, 0x25, 0x2], [0x0, 0x4c, 0x2]]),
+    (0x1fc3, &[[0x2329, 0x20, 0x2], [0x0, 0x4c, 0x2]]),
+    (0x1fc4, &[[0x2329, 0x20, 0x2], [0x0, 0x24, 0x2], [0x0, 0x4c, 0x2]]),
+    (0x1fc6, &[[0x2329, 0x20, 0x2], [0x0, 0x2a, 0x2]]),
+    (0x1fc7, &[[0x2329, 0x20, 0x2], [0x0, 0x2a, 0x2], [0x0, 0x4c, 0x2]]),
+    (0x1fc8, &[[0x2323, 0x20, 0x8], [0x0, 0x25, 0x2]]),
+    (0x1fc9, &[[0x2323, 0x20, 0x8], [0x0, 0x24, 0x2]]),
+    (0x1fca, &[[0x2329, 0x20, 0x8], [0x0, 0x25, 0x2]]),
+    (0x1fcb, &[[0x2329, 0x20, 0x8], [0x0, 0x24, 0x2]]),
+    (0x1fcc, &[[0x2329, 0x20, 0x8], [0x0, 0x4c, 0x2]]),
+    (0x1fcd, &[[0x4c0, 0x20, 0x2], [0x0, 0x25, 0x2]]),
+    (0x1fce, &[[0x4c0, 0x20, 0x2], [0x0, 0x24, 0x2]]),
+    (0x1fcf, &[[0x4c0, 0x20, 0x2], [0x0, 0x2a, 0x2]]),
+    (0x1fd0, &[[0x232b, 0x20, 0x2], [0x0, 0x26, 0x2]]),
+    (0x1fd1, &[[0x232b, 0x20, 0x2], [0x0, 0x32, 0x2]]),
+    (0x1fd2, &[[0x232b, 0x20, 0x2], [0x0, 0x2b, 0x2], [0x0, 0x25, 0x2]]),
+    (0x1fd3, &[[0x232b, 0x20, 0x2], [0x0, 0x2b, 0x2], [0x0, 0x24, 0x2]]),
+    (0x1fd6, &[[0x232b, 0x20, 0x2], [0x0, 0x2a, 0x2]]),
+    (0x1fd7, &[[0x232b, 0x20, 0x2], [0x0, 0x2b, 0x2], [0x0, 0x2a, 0x2]]),
+    (0x1fd8, &[[0x232b, 0x20, 0x8], [0x0, 0x26, 0x2]]),
+    (0x1fd9, &[[0x232b, 0x20, 0x8], [0x0, 0x32, 0x2]]),
+    (0x1fda, &[[0x232b, 0x20, 0x8], [0x0, 0x25, 0x2]]),
+    (0x1fdb, &[[0x232b, 0x20, 0x8], [0x0, 0x24, 0x2]]),
+    (0x1fdd, &[[0x4c1, 0x20, 0x2], [0x0, 0x25, 0x2]]),
+    (0x1fde, &[[0x4c1, 0x20, 0x2], [0x0, 0x24, 0x2]]),
+    (0x1fdf, &[[0x4c1, 0x20, 0x2], [0x0, 0x2a, 0x2]]),
+    (0x1fe0, &[[0x2341, 0x20, 0x2], [0x0, 0x26, 0x2]]),
+    (0x1fe1, &[[0x2341, 0x20, 0x2], [0x0, 0x32, 0x2]]),
+    (0x1fe2, &[[0x2341, 0x20, 0x2], [0x0, 0x2b, 0x2], [0x0, 0x25, 0x2]]),
+    (0x1fe3, &[[0x2341, 0x20, 0x2], [0x0, 0x2b, 0x2], [0x0, 0x24, 0x2]]),
+    (0x1fe4, &[[0x2339, 0x20, 0x2], [0x0, 0x22, 0x2]]),
+    (0x1fe5, &[[0x2339, 0x20, 0x2], [0x0, 0x23, 0x2]]),
+    (0x1fe6, &[[0x2341, 0x20, 0x2], [0x0, 0x2a, 0x2]]),
+    (0x1fe7, &[[0x2341, 0x20, 0x2], [0x0, 0x2b, 0x2], [0x0, 0x2a, 0x2]]),
+    (0x1fe8, &[[0x2341, 0x20, 0x8], [0x0, 0x26, 0x2]]),
+    (0x1fe9, &[[0x2341, 0x20, 0x8], [0x0, 0x32, 0x2]]),
+    (0x1fea, &[[0x2341, 0x20, 0x8], [0x0, 0x25, 0x2]]),
+    (0x1feb, &[[0x2341, 0x20, 0x8], [0x0, 0x24, 0x2]]),
+    (0x1fec, &[[0x2339, 0x20, 0x8], [0x0, 0x23, 0x2]]),
+    (0x1fed, &[[0x4bb, 0x20, 0x2], [0x0, 0x25, 0x2]]),
+    (0x1fee, &[[0x4bb, 0x20, 0x2], [0x0, 0x24, 0x2]]),
+    (0x1fef, &[[0x4b4, 0x20, 0x2]]),
+    (0x1ff2, &[[0x2346, 0x20, 0x2], [0x0, 0x25, 0x2], [0x0, 0x4c, 0x2]]),
+    (0x1ff3, &[[0x2346, 0x20, 0x2], [0x0, 0x4c, 0x2]]),
+    (0x1ff4, &[[0x2346, 0x20, 0x2], [0x0, 0x24, 0x2], [0x0, 0x4c, 0x2]]),
+    (0x1ff6, &[[0x2346, 0x20, 0x2], [0x0, 0x2a, 0x2]]),
+    (0x1ff7, &[[0x2346, 0x20, 0x2], [0x0, 0x2a, 0x2], [0x0, 0x4c, 0x2]]),
+    (0x1ff8, &[[0x2333, 0x20, 0x8], [0x0, 0x25, 0x2]]),
+    (0x1ff9, &[[0x2333, 0x20, 0x8], [0x0, 0x24, 0x2]]),
+    (0x1ffa, &[[0x2346, 0x20, 0x8], [0x0, 0x25, 0x2]]),
+    (0x1ffb, &[[0x2346, 0x20, 0x8], [0x0, 0x24, 0x2]]),
+    (0x1ffc, &[[0x2346, 0x20, 0x8], [0x0, 0x4c, 0x2]]),
+    (0x1ffd, &[[0x4b5, 0x20, 0x2]]),
+    (0x1ffe, &[[0x4c1, 0x20, 0x2]]),
+    (0x2000, &[[0x209, 0x20, 0x4]]),
+    (0x2001, &[[0x209, 0x20, 0x4]]),
+    (0x2002, &[[0x209, 0x20, 0x4]]),
+    (0x2003, &[[0x209, 0x20, 0x4]]),
+    (0x2004, &[[0x209, 0x20, 0x4]]),
+    (0x2005, &[[0x209, 0x20, 0x4]]),
+    (0x2006, &[[0x209, 0x20, 0x4]]),
+    (0x2007, &[[0x209, 0x20, 0x1b]]),
+    (0x2008, &[[0x209, 0x20, 0x4]]),
+    (0x2009, &[[0x209, 0x20, 0x4]]),
+    (0x200a, &[[0x209, 0x20, 0x4]]),
+    (0x200b, &[[0x0, 0x0, 0x0]]),
+    (0x200c, &[[0x0, 0x0, 0x0]]),
+    (0x200d, &[[0x0, 0x0, 0x0]]),
+    (0x200e, &[[0x0, 0x0, 0x0]]),
+    (0x200f, &[[0x0, 0x0, 0x0]]),
+    (0x2010, &[[0x213, 0x20, 0x2]]),
+    (0x2011, &[[0x213, 0x20, 0x1b]]),
+    (0x2012, &[[0x214, 0x20, 0x2]]),
+    (0x2013, &[[0x215, 0x20, 0x2]]),
+    (0x2014, &[[0x216, 0x20, 0x2]]),
+    (0x2015, &[[0x217, 0x20, 0x2]]),
+    (0x2016, &[[0x394, 0x20, 0x2]]),
+    (0x2017, &[[0x20c, 0x20, 0x2]]),
+    (0x2018, &[[0x317, 0x20, 0x2]]),
+    (0x2019, &[[0x318, 0x20, 0x2]]),
+    (0x201a, &[[0x319, 0x20, 0x2]]),
+    (0x201b, &[[0x31a, 0x20, 0x2]]),
+    (0x201c, &[[0x31e, 0x20, 0x2]]),
+    (0x201d, &[[0x31f, 0x20, 0x2]]),
+    (0x201e, &[[0x320, 0x20, 0x2]]),
+    (0x201f, &[[0x321, 0x20, 0x2]]),
+    (0x2020, &[[0x3b3, 0x20, 0x2]]),
+    (0x2021, &[[0x3b4, 0x20, 0x2]]),
+    (0x2022, &[[0x3b9, 0x20, 0x2]]),
+    (0x2023, &[[0x3ba, 0x20, 0x2]]),
+    (0x2024, &[[0x27e, 0x20, 0x4]]),
+    (0x2025, &[[0x27e, 0x20, 0x4], [0x27e, 0x20, 0x4]]),
+    (0x2026, &[[0x27e, 0x20, 0x4], [0x27e, 0x20, 0x4], [0x27e, 0x20, 0x4]]),
+    (0x2027, &[[0x3bb, 0x20, 0x2]]),
+    (0x2028, &[[0x207, 0x20, 0x2]]),
+    (0x2029, &[[0x208, 0x20, 0x2]]),
+    (0x202a, &[[0x0, 0x0, 0x0]]),
+    (0x202b, &[[0x0, 0x0, 0x0]]),
+    (0x202c, &[[0x0, 0x0, 0x0]]),
+    (0x202d, &[[0x0, 0x0, 0x0]]),
+    (0x202e, &[[0x0, 0x0, 0x0]]),
+    (0x202f, &[[0x209, 0x20, 0x1b]]),
+    (0x2030, &[[0x3af, 0x20, 0x2]]),
+    (0x2031, &[[0x3b1, 0x20, 0x2]]),
+    (0x2032, &[[0x3bf, 0x20, 0x2]]),
+    (0x2033, &[[0x3bf, 0x20, 0x4], [0x3bf, 0x20, 0x4]]),
+    (0x2034, &[[0x3bf, 0x20, 0x4], [0x3bf, 0x20, 0x4], [0x3bf, 0x20, 0x4]]),
+    (0x2035, &[[0x3c0, 0x20, 0x2]]),
+    (0x2036, &[[0x3c0, 0x20, 0x4], [0x3c0, 0x20, 0x4]]),
+    (0x2037, &[[0x3c0, 0x20, 0x4], [0x3c0, 0x20, 0x4], [0x3c0, 0x20, 0x4]]),
+    (0x2038, &[[0x3c3, 0x20, 0x2]]),
+    (0x2039, &[[0x31b, 0x20, 0x2]]),
+    (0x203a, &[[0x31c, 0x20, 0x2]]),
+    (0x203b, &[[0x3c4, 0x20, 0x2]]),
+    (0x203c, &[[0x267, 0x20, 0x4], [0x267, 0x20, 0x4]]),
+    (0x203d, &[[0x27c, 0x20, 0x2]]),
+    (0x203e, &[[0x20a, 0x20, 0x2]]),
+    (0x203f, &[[0x3c5, 0x20, 0x2]]),
+    (0x2040, &[[0x3c7, 0x20, 0x2]]),
+    (0x2041, &[[0x3c9, 0x20, 0x2]]),
+    (0x2042, &[[0x3ca, 0x20, 0x2]]),
+    (0x2043, &[[0x3bc, 0x20, 0x2]]),
+    (0x2044, &[[0x676, 0x20, 0x2]]),
+    (0x2045, &[[0x334, 0x20, 0x2]]),
+    (0x2046, &[[0x335, 0x20, 0x2]]),
+    (0x2047, &[[0x26d, 0x20, 0x4], [0x26d, 0x20, 0x4]]),
+    (0x2048, &[[0x26d, 0x20, 0x4], [0x267, 0x20, 0x4]]),
+    (0x2049, &[[0x267, 0x20, 0x4], [0x26d, 0x20, 0x4]]),
+    (0x204a, &[[0x3aa, 0x20, 0x2]]),
+    (0x204b, &[[0x39d, 0x20, 0x2]]),
+    (0x204c, &[[0x3bd, 0x20, 0x2]]),
+    (0x204d, &[[0x3be, 0x20, 0x2]]),
+    (0x204e, &[[0x3a2, 0x20, 0x2]]),
+    (0x204f, &[[0x23c, 0x20, 0x2]]),
+    (0x2050, &[[0x3c8, 0x20, 0x2]]),
+    (0x2051, &[[0x3a3, 0x20, 0x2]]),
+    (0x2052, &[[0x672, 0x20, 0x2]]),
+    (0x2053, &[[0x21a, 0x20, 0x2]]),
+    (0x2054, &[[0x3c6, 0x20, 0x2]]),
+    (0x2055, &[[0x2f9, 0x20, 0x2]]),
+    (0x2056, &[[0x2fa, 0x20, 0x2]]),
+    (0x2057, &[[0x3bf, 0x20, 0x4], [0x3bf, 0x20, 0x4], [0x3bf, 0x20, 0x4], [0x3bf, 0x20, 0x4]]),
+    (0x2058, &[[0x2fb, 0x20, 0x2]]),
+    (0x2059, &[[0x2fc, 0x20, 0x2]]),
+    (0x205a, &[[0x2fd, 0x20, 0x2]]),
+    (0x205b, &[[0x2fe, 0x20, 0x2]]),
+    (0x205c, &[[0x2ff, 0x20, 0x2]]),
+    (0x205d, &[[0x300, 0x20, 0x2]]),
+    (0x205e, &[[0x301, 0x20, 0x2]]),
+    (0x205f, &[[0x209, 0x20, 0x4]]),
+    (0x2060, &[[0x0, 0x0, 0x0]]),
+    (0x2061, &[[0x0, 0x0, 0x0]]),
+    (0x2062, &[[0x0, 0x0, 0x0]]),
+    (0x2063, &[[0x0, 0x0, 0x0]]),
+    (0x2064, &[[0x0, 0x0, 0x0]]),
+    (0x2066, &[[0x0, 0x0, 0x0]]),
+    (0x2067, &[[0x0, 0x0, 0x0]]),
+    (0x2068, &[[0x0, 0x0, 0x0]]),
+    (0x2069, &[[0x0, 0x0, 0x0]]),
+    (0x206a, &[[0x0, 0x0, 0x0]]),
+    (0x206b, &[[0x0, 0x0, 0x0]]),
+    (0x206c, &[[0x0, 0x0, 0x0]]),
+    (0x206d, &[[0x0, 0x0, 0x0]]),
+    (0x206e, &[[0x0, 0x0, 0x0]]),
+    (0x206f, &[[0x0, 0x0, 0x0]]),
+    (0x20a0, &[[0x1f78, 0x20, 0x2]]),
+    (0x20a1, &[[0x1f79, 0x20, 0x2]]),
+    (0x20a2, &[[0x1f7a, 0x20, 0x2]]),
+    (0x20a3, &[[0x1f7b, 0x20, 0x2]]),
+    (0x20a4, &[[0x1f7c, 0x20, 0x2]]),
+    (0x20a5, &[[0x1f7d, 0x20, 0x2]]),
+    (0x20a6, &[[0x1f7e, 0x20, 0x2]]),
+    (0x20a7, &[[0x1f7f, 0x20, 0x2]]),
+    (0x20a8, &[[0x2193, 0x20, 0xa], [0x21d2, 0x20, 0x4]]),
+    (0x20a9, &[[0x1f80, 0x20, 0x2]]),
+    (0x20aa, &[[0x1f81, 0x20, 0x2]]),
+    (0x20ab, &[[0x1f82, 0x20, 0x2]]),
+    (0x20ac, &[[0x1f83, 0x20, 0x2]]),
+    (0x20ad, &[[0x1f84, 0x20, 0x2]]),
+    (0x20ae, &[[0x1f85, 0x20, 0x2]]),
+    (0x20af, &[[0x1f86, 0x20, 0x2]]),
+    (0x20b0, &[[0x1f87, 0x20, 0x2]]),
+    (0x20b1, &[[0x1f88, 0x20, 0x2]]),
+    (0x20b2, &[[0x1f89, 0x20, 0x2]]),
+    (0x20b3, &[[0x1f8a, 0x20, 0x2]]),
+    (0x20b4, &[[0x1f8b, 0x20, 0x2]]),
+    (0x20b5, &[[0x1f8c, 0x20, 0x2]]),
+    (0x20b6, &[[0x1f8d, 0x20, 0x2]]),
+    (0x20b7, &[[0x1f8e, 0x20, 0x2]]),
+    (0x20b8, &[[0x1f8f, 0x20, 0x2]]),
+    (0x20b9, &[[0x1f90, 0x20, 0x2]]),
+    (0x20ba, &[[0x1f92, 0x20, 0x2]]),
+    (0x20bb, &[[0x1f93, 0x20, 0x2]]),
+    (0x20bc, &[[0x1f94, 0x20, 0x2]]),
+    (0x20bd, &[[0x1f95, 0x20, 0x2]]),
+    (0x20be, &[[0x1f96, 0x20, 0x2]]),
+    (0x20bf, &[[0x1f97, 0x20, 0x2]]),
+    (0x2100, &[[0x1fa2, 0x20, 0x4], [0x3a6, 0x20, 0x4], [0x1fd6, 0x20, 0x4]]),
+    (0x2101, &[[0x1fa2, 0x20, 0x4], [0x3a6, 0x20, 0x4], [0x21d2, 0x20, 0x4]]),
+    (0x2102, &[[0x1fd6, 0x20, 0xb]]),
+    (0x2103, &[[0x52a, 0x20, 0x4], [0x1fd6, 0x20, 0xa]]),
+    (0x2104, &[[0x5d6, 0x20, 0x2]]),
+    (0x2105, &[[0x1fd6, 0x20, 0x4], [0x3a6, 0x20, 0x4], [0x213c, 0x20, 0x4]]),
+    (0x2106, &[[0x1fd6, 0x20, 0x4], [0x3a6, 0x20, 0x4], [0x2217, 0x20, 0x4]]),
+    (0x2107, &[[0x201f, 0x20, 0xa]]),
+    (0x2108, &[[0x5d7, 0x20, 0x2]]),
+    (0x2109, &[[0x52a, 0x20, 0x4], [0x2042, 0x20, 0xa]]),
+    (0x210a, &[[0x2051, 0x20, 0x5]]),
+    (0x210b, &[[0x2075, 0x20, 0xb]]),
+    (0x210c, &[[0x2075, 0x20, 0xb]]),
+    (0x210d, &[[0x2075, 0x20, 0xb]]),
+    (0x210e, &[[0x2075, 0x20, 0x5]]),
+    (0x210f, &[[0x2075, 0x20, 0x2], [0x0, 0x39, 0x2]]),
+    (0x2110, &[[0x2090, 0x20, 0xb]]),
+    (0x2111, &[[0x2090, 0x20, 0xb]]),
+    (0x2112, &[[0x20d6, 0x20, 0xb]]),
+    (0x2113, &[[0x20d6, 0x20, 0x5]]),
+    (0x2114, &[[0x5d8, 0x20, 0x2]]),
+    (0x2115, &[[0x2118, 0x20, 0xb]]),
+    (0x2116, &[[0x2118, 0x20, 0xa], [0x213c, 0x20, 0x4]]),
+    (0x2117, &[[0x5d9, 0x20, 0x2]]),
+    (0x2118, &[[0x5da, 0x20, 0x2]]),
+    (0x2119, &[[0x216b, 0x20, 0xb]]),
+    (0x211a, &[[0x2180, 0x20, 0xb]]),
+    (0x211b, &[[0x2193, 0x20, 0xb]]),
+    (0x211c, &[[0x2193, 0x20, 0xb]]),
+    (0x211d, &[[0x2193, 0x20, 0xb]]),
+    (0x211e, &[[0x5db, 0x20, 0x2]]),
+    (0x211f, &[[0x5dc, 0x20, 0x2]]),
+    (0x2120, &[[0x21d2, 0x20, 0x14], [0x2109, 0x20, 0x14]]),
+    (0x2121, &[[0x21f7, 0x20, 0xa], [0x2007, 0x20, 0xa], [0x20d6, 0x20, 0xa]]),
+    (0x2122, &[[0x21f7, 0x20, 0x14], [0x2109, 0x20, 0x14]]),
+    (0x2123, &[[0x5dd, 0x20, 0x2]]),
+    (0x2124, &[[0x2286, 0x20, 0xb]]),
+    (0x2125, &[[0x5de, 0x20, 0x2]]),
+    (0x2126, &[[0x2346, 0x20, 0x8]]),
+    (0x2127, &[[0x5df, 0x20, 0x2]]),
+    (0x2128, &[[0x2286, 0x20, 0xb]]),
+    (0x2129, &[[0x5e0, 0x20, 0x2]]),
+    (0x212a, &[[0x20c4, 0x20, 0x8]]),
+    (0x212b, &[[0x1fa2, 0x20, 0x8], [0x0, 0x29, 0x2]]),
+    (0x212c, &[[0x1fbc, 0x20, 0xb]]),
+    (0x212d, &[[0x1fd6, 0x20, 0xb]]),
+    (0x212e, &[[0x5e1, 0x20, 0x2]]),
+    (0x212f, &[[0x2007, 0x20, 0x5]]),
+    (0x2130, &[[0x2007, 0x20, 0xb]]),
+    (0x2131, &[[0x2042, 0x20, 0xb]]),
+    (0x2132, &[[0x204f, 0x20, 0x8]]),
+    (0x2133, &[[0x2109, 0x20, 0xb]]),
+    (0x2134, &[[0x213c, 0x20, 0x5]]),
+    (0x2135, &[[0x261e, 0x20, 0x4]]),
+    (0x2136, &[[0x261f, 0x20, 0x4]]),
+    (0x2137, &[[0x2620, 0x20, 0x4]]),
+    (0x2138, &[[0x2621, 0x20, 0x4]]),
+    (0x2139, &[[0x2090, 0x20, 0x5]]),
+    (0x213a, &[[0x5e2, 0x20, 0x2]]),
+    (0x213b, &[[0x2042, 0x20, 0xa], [0x1fa2, 0x20, 0xa], [0x2264, 0x20, 0xa]]),
+    (0x213c, &[[0x2334, 0x20, 0x5]]),
+    (0x213d, &[[0x2320, 0x20, 0x5]]),
+    (0x213e, &[[0x2320, 0x20, 0xb]]),
+    (0x213f, &[[0x2334, 0x20, 0xb]]),
+    (0x2140, &[[0x665, 0x20, 0x5]]),
+    (0x2141, &[[0x5e3, 0x20, 0x2]]),
+    (0x2142, &[[0x5e4, 0x20, 0x2]]),
+    (0x2143, &[[0x5e5, 0x20, 0x2]]),
+    (0x2144, &[[0x5e6, 0x20, 0x2]]),
+    (0x2145, &[[0x1feb, 0x20, 0xb]]),
+    (0x2146, &[[0x1feb, 0x20, 0x5]]),
+    (0x2147, &[[0x2007, 0x20, 0x5]]),
+    (0x2148, &[[0x2090, 0x20, 0x5]]),
+    (0x2149, &[[0x20ab, 0x20, 0x5]]),
+    (0x214a, &[[0x5e7, 0x20, 0x2]]),
+    (0x214b, &[[0x701, 0x20, 0x2]]),
+    (0x214c, &[[0x5e8, 0x20, 0x2]]),
+    (0x214d, &[[0x1fa2, 0x20, 0xa], [0x3a6, 0x20, 0x4], [0x21d2, 0x20, 0xa]]),
+    (0x214e, &[[0x204f, 0x20, 0x2]]),
+    (0x214f, &[[0x5e9, 0x20, 0x2]]),
+];
@@ -0,0 +1,194 @@
+//! The data of each locale category, and the built-in C locale
+
+use platform::types::*;
+
+/// A string of locale data. It ends with a NUL, so that it can be handed to C as is
+#[derive(Clone, Copy)]
+pub struct LocaleStr(pub &'static str);
+
+impl LocaleStr {
+    pub fn as_str(self) -> &'static str {
+        &self.0[..self.0.len() - 1]
+    }
+
+    pub fn as_ptr(self) -> *const c_char {
+        self.0.as_ptr() as *const c_char
+    }
+}
+
+/// The LC_CTYPE category. Multibyte strings are always UTF-8, only the name of the codeset differs
+#[derive(Clone, Copy)]
+pub struct LcCtype {
+    pub codeset: LocaleStr,
+}
+
+/// The LC_COLLATE category. The C locale compares bytes, other locales use the Unicode Collation
+/// Algorithm
+#[derive(Clone, Copy)]
+pub struct LcCollate {
+    pub uca: bool,
+    // Characters sorted after another one rather than by the table, as (character, anchor, rank).
+    // Characters of the same rank only differ in their secondary and tertiary weights
+    pub tailoring: &'static [(u32, u32, u8)],
+}
+
+/// The LC_NUMERIC category, used by localeconv and printf
+#[derive(Clone, Copy)]
+pub struct LcNumeric {
+    pub decimal_point: LocaleStr,
+    pub thousands_sep: LocaleStr,
+    pub grouping: LocaleStr,
+}
+
+/// The LC_MONETARY category, reported by localeconv
+#[derive(Clone, Copy)]
+pub struct LcMonetary {
+    pub int_curr_symbol: LocaleStr,
+    pub currency_symbol: LocaleStr,
+    // The currency symbol for nl_langinfo, after - if it goes before the value, + if it goes after
+    // it and . if it replaces the radix character
+    pub crncystr: LocaleStr,
+    pub mon_decimal_point: LocaleStr,
+    pub mon_thousands_sep: LocaleStr,
+    pub mon_grouping: LocaleStr,
+    pub positive_sign: LocaleStr,
+    pub negative_sign: LocaleStr,
+    pub int_frac_digits: c_char,
+    pub frac_digits: c_char,
+    pub p_cs_precedes: c_char,
+    pub p_sep_by_space: c_char,
+    pub n_cs_precedes: c_char,
+    pub n_sep_by_space: c_char,
+    pub p_sign_posn: c_char,
+    pub n_sign_posn: c_char,
+}
+
+/// The LC_TIME category: names and formats used by strftime and strptime
+#[derive(Clone, Copy)]
+pub struct LcTime {
+    pub abday: [LocaleStr; 7],
+    pub day: [LocaleStr; 7],
+    pub abmon: [LocaleStr; 12],
+    pub mon: [LocaleStr; 12],
+    pub am_pm: [LocaleStr; 2],
+    pub d_t_fmt: LocaleStr,
+    pub d_fmt: LocaleStr,
+    pub t_fmt: LocaleStr,
+    pub t_fmt_ampm: LocaleStr,
+}
+
+/// The LC_MESSAGES category: how to recognize answers to yes/no questions
+#[derive(Clone, Copy)]
+pub struct LcMessages {
+    pub yesexpr: LocaleStr,
+    pub noexpr: LocaleStr,
+    pub yesstr: LocaleStr,
+    pub nostr: LocaleStr,
+}
+
+pub const C_NAME: LocaleStr = LocaleStr("C\0");
+pub const C_UTF8_NAME: LocaleStr = LocaleStr("C.UTF-8\0");
+
+pub const C_CTYPE: LcCtype = LcCtype {
+    codeset: LocaleStr("ANSI_X3.4-1968\0"),
+};
+
+pub const C_UTF8_CTYPE: LcCtype = LcCtype {
+    codeset: LocaleStr("UTF-8\0"),
+};
+
+pub const C_COLLATE: LcCollate = LcCollate {
+    uca: false,
+    tailoring: &[],
+};
+
+pub const C_UTF8_COLLATE: LcCollate = LcCollate {
+    uca: true,
+    tailoring: &[],
+};
+
+pub const C_NUMERIC: LcNumeric = LcNumeric {
+    decimal_point: LocaleStr(".\0"),
+    thousands_sep: LocaleStr("\0"),
+    grouping: LocaleStr("\0"),
+};
+
+pub const C_MONETARY: LcMonetary = LcMonetary {
+    int_curr_symbol: LocaleStr("\0"),
+    currency_symbol: LocaleStr("\0"),
+    crncystr: LocaleStr("-\0"),
+    mon_decimal_point: LocaleStr("\0"),
+    mon_thousands_sep: LocaleStr("\0"),
+    mon_grouping: LocaleStr("\0"),
+    positive_sign: LocaleStr("\0"),
+    negative_sign: LocaleStr("\0"),
+    int_frac_digits: c_char::max_value(),
+    frac_digits: c_char::max_value(),
+    p_cs_precedes: c_char::max_value(),
+    p_sep_by_space: c_char::max_value(),
+    n_cs_precedes: c_char::max_value(),
+    n_sep_by_space: c_char::max_value(),
+    p_sign_posn: c_char::max_value(),
+    n_sign_posn: c_char::max_value(),
+};
+
+pub const C_TIME: LcTime = LcTime {
+    abday: [
+        LocaleStr("Sun\0"),
+        LocaleStr("Mon\0"),
+        LocaleStr("Tue\0"),
+        LocaleStr("Wed\0"),
+        LocaleStr("Thu\0"),
+        LocaleStr("Fri\0"),
+        LocaleStr("Sat\0"),
+    ],
+    day: [
+        LocaleStr("Sunday\0"),
+        LocaleStr("Monday\0"),
+        LocaleStr("Tuesday\0"),
+        LocaleStr("Wednesday\0"),
+        LocaleStr("Thursday\0"),
+        LocaleStr("Friday\0"),
+        LocaleStr("Saturday\0"),
+    ],
+    abmon: [
+        LocaleStr("Jan\0"),
+        LocaleStr("Feb\0"),
+        LocaleStr("Mar\0"),
+        LocaleStr("Apr\0"),
+        LocaleStr("May\0"),
+        LocaleStr("Jun\0"),
+        LocaleStr("Jul\0"),
+        LocaleStr("Aug\0"),
+        LocaleStr("Sep\0"),
+        LocaleStr("Oct\0"),
+        LocaleStr("Nov\0"),
+        LocaleStr("Dec\0"),
+    ],
+    mon: [
+        LocaleStr("January\0"),
+        LocaleStr("February\0"),
+        LocaleStr("March\0"),
+        LocaleStr("April\0"),
+        LocaleStr("May\0"),
+        LocaleStr("June\0"),
+        LocaleStr("July\0"),
+        LocaleStr("August\0"),
+        LocaleStr("September\0"),
+        LocaleStr("October\0"),
+        LocaleStr("November\0"),
+        LocaleStr("December\0"),
+    ],
+    am_pm: [LocaleStr("AM\0"), LocaleStr("PM\0")],
+    d_t_fmt: LocaleStr("%a %b %e %H:%M:%S %Y\0"),
+    d_fmt: LocaleStr("%m/%d/%y\0"),
+    t_fmt: LocaleStr("%H:%M:%S\0"),
+    t_fmt_ampm: LocaleStr("%I:%M:%S %p\0"),
+};
+
+pub const C_MESSAGES: LcMessages = LcMessages {
+    yesexpr: LocaleStr("^[yY]\0"),
+    noexpr: LocaleStr("^[nN]\0"),
+    yesstr: LocaleStr("yes\0"),
+    nostr: LocaleStr("no\0"),
+};
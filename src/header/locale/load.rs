@@ -0,0 +1,495 @@
+//! Locales loaded from localedef source files, following
+//! http://pubs.opengroup.org/onlinepubs/9699919799/basedefs/V1_chap07.html#tag_07_03
+//!
+//! A locale called `name` is read from the file `name` in the directories listed in LOCPATH,
+//! separated by colons, or else in /usr/share/i18n/locales. If the name has a codeset, which must
+//! be UTF-8, the file may also be named without it, so `de_DE.UTF-8` is found in `de_DE`. The
+//! files use the syntax of localedef, so glibc's locale sources can be used as they are:
+//!
+//! ```text
+//! comment_char %
+//! escape_char /
+//!
+//! LC_NUMERIC
+//! decimal_point ","
+//! thousands_sep "<U002E>"
+//! grouping 3;3
+//! END LC_NUMERIC
+//!
+//! LC_TIME
+//! copy "en_US"
+//! END LC_TIME
+//! ```
+//!
+//! Only LC_COLLATE, LC_NUMERIC, LC_MONETARY, LC_TIME and LC_MESSAGES are read, and the other
+//! categories are skipped. Keywords of these categories that are missing or unknown keep the value
+//! of the C locale. Strings are UTF-8, and may contain `<Uxxxx>` code points and characters escaped
+//! with the escape character. `-1` stands for CHAR_MAX in numbers and grouping lists.
+//!
+//! Loaded locales collate with the Unicode Collation Algorithm, which glibc's locales get with
+//! `copy "iso14651_t1"`. The only rules of LC_COLLATE that are read reorder characters: the first
+//! `<Uxxxx>` symbol of each line after `reorder-after <Uxxxx>` sorts after that character, and
+//! before the next one in the table. Lines that start with another symbol are skipped.
+
+use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use c_str::{CStr, CString};
+use fs::File;
+use header::fcntl::{O_CLOEXEC, O_RDONLY};
+use header::stdlib::getenv;
+use io::Read;
+use mutex::Mutex;
+use platform::types::*;
+
+use super::collate;
+use super::data::*;
+
+const LOCALE_DIR: &[u8] = b"/usr/share/i18n/locales";
+
+// How deep copy directives may nest, so that a locale copying itself doesn't recurse forever
+const MAX_DEPTH: usize = 8;
+
+/// The data of a locale loaded from a file
+pub struct Definition {
+    pub name: LocaleStr,
+    pub collate: LcCollate,
+    pub numeric: LcNumeric,
+    pub monetary: LcMonetary,
+    pub time: LcTime,
+    pub messages: LcMessages,
+}
+
+lazy_static! {
+    // Locales are never unloaded, as their data may be in use by any locale_t
+    static ref LOADED: Mutex<Vec<&'static Definition>> = Mutex::new(Vec::new());
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Category {
+    Outside,
+    Collate,
+    Numeric,
+    Monetary,
+    Time,
+    Messages,
+    Other,
+}
+
+struct Syntax {
+    comment: u8,
+    escape: u8,
+}
+
+/// Leak a string for use as locale data
+fn leak(bytes: Vec<u8>) -> Option<LocaleStr> {
+    if bytes.contains(&0) {
+        return None;
+    }
+    let mut s = String::from_utf8(bytes).ok()?;
+    s.push('\0');
+    Some(LocaleStr(Box::leak(s.into_boxed_str())))
+}
+
+/// The code point of a `<Uxxxx>` symbol, given what is between the angle brackets
+fn code_point(name: &[u8]) -> Option<char> {
+    if name.len() < 2 || name[0] != b'U' {
+        return None;
+    }
+    let mut code = 0u32;
+    for &c in &name[1..] {
+        let digit = (c as char).to_digit(16)?;
+        code = code.checked_mul(16)?.checked_add(digit)?;
+    }
+    core::char::from_u32(code)
+}
+
+/// Decode a `<Uxxxx>` symbol, given what is between the angle brackets
+fn symbol(name: &[u8], out: &mut Vec<u8>) -> Option<()> {
+    let c = code_point(name)?;
+    let mut buf = [0; 4];
+    out.extend_from_slice(c.encode_utf8(&mut buf).as_bytes());
+    Some(())
+}
+
+/// Split the operands of a keyword at semicolons, decoding strings and symbols. Numbers are
+/// returned as they are
+fn operands(line: &[u8], syntax: &Syntax) -> Option<Vec<Vec<u8>>> {
+    let mut operands = Vec::new();
+    let mut current = Vec::new();
+    let mut quoted = false;
+    let mut i = 0;
+    while i < line.len() {
+        let c = line[i];
+        i += 1;
+        if c == syntax.escape && i < line.len() {
+            current.push(line[i]);
+            i += 1;
+        } else if c == b'"' {
+            quoted = !quoted;
+        } else if c == b'<' {
+            let len = line[i..].iter().position(|&c| c == b'>')?;
+            symbol(&line[i..i + len], &mut current)?;
+            i += len + 1;
+        } else if quoted {
+            current.push(c);
+        } else if c == b';' {
+            operands.push(current);
+            current = Vec::new();
+        } else if !c.is_ascii_whitespace() {
+            current.push(c);
+        }
+    }
+    operands.push(current);
+    Some(operands)
+}
+
+fn number(operand: &[u8]) -> Option<c_char> {
+    let s = core::str::from_utf8(operand).ok()?;
+    match s.parse::<i32>().ok()? {
+        -1 => Some(c_char::max_value()),
+        n if n >= 0 && n <= c_char::max_value() as i32 => Some(n as c_char),
+        _ => None,
+    }
+}
+
+/// A grouping list such as `3;2`, as the string of group sizes localeconv returns
+fn grouping(operands: Vec<Vec<u8>>) -> Option<LocaleStr> {
+    if operands.len() == 1 && &operands[0][..] == b"-1" {
+        return leak(Vec::new());
+    }
+    let mut groups = Vec::new();
+    for operand in &operands {
+        match number(operand)? {
+            0 => return None,
+            n => groups.push(n as u8),
+        }
+    }
+    leak(groups)
+}
+
+fn string(operands: Vec<Vec<u8>>) -> Option<LocaleStr> {
+    let mut operands = operands.into_iter();
+    match (operands.next(), operands.next()) {
+        (Some(operand), None) => leak(operand),
+        _ => None,
+    }
+}
+
+fn strings(operands: Vec<Vec<u8>>, out: &mut [LocaleStr]) -> Option<()> {
+    if operands.len() != out.len() {
+        return None;
+    }
+    let mut strings = Vec::new();
+    for operand in operands {
+        strings.push(leak(operand)?);
+    }
+    out.copy_from_slice(&strings);
+    Some(())
+}
+
+/// Set a keyword of a category. Unknown keywords and invalid operands are ignored
+fn keyword(def: &mut Definition, category: Category, keyword: &[u8], operands: Vec<Vec<u8>>) {
+    macro_rules! set {
+        ($field:expr, $value:expr) => {
+            if let Some(value) = $value {
+                $field = value;
+            }
+        };
+    }
+
+    let numeric = &mut def.numeric;
+    let monetary = &mut def.monetary;
+    let time = &mut def.time;
+    let messages = &mut def.messages;
+    let first_number = operands.first().and_then(|operand| number(operand));
+    match (category, keyword) {
+        (Category::Numeric, b"decimal_point") => set!(numeric.decimal_point, string(operands)),
+        (Category::Numeric, b"thousands_sep") => set!(numeric.thousands_sep, string(operands)),
+        (Category::Numeric, b"grouping") => set!(numeric.grouping, grouping(operands)),
+
+        (Category::Monetary, b"int_curr_symbol") => {
+            set!(monetary.int_curr_symbol, string(operands))
+        }
+        (Category::Monetary, b"currency_symbol") => {
+            set!(monetary.currency_symbol, string(operands))
+        }
+        (Category::Monetary, b"mon_decimal_point") => {
+            set!(monetary.mon_decimal_point, string(operands))
+        }
+        (Category::Monetary, b"mon_thousands_sep") => {
+            set!(monetary.mon_thousands_sep, string(operands))
+        }
+        (Category::Monetary, b"mon_grouping") => set!(monetary.mon_grouping, grouping(operands)),
+        (Category::Monetary, b"positive_sign") => set!(monetary.positive_sign, string(operands)),
+        (Category::Monetary, b"negative_sign") => set!(monetary.negative_sign, string(operands)),
+        (Category::Monetary, b"int_frac_digits") => set!(monetary.int_frac_digits, first_number),
+        (Category::Monetary, b"frac_digits") => set!(monetary.frac_digits, first_number),
+        (Category::Monetary, b"p_cs_precedes") => set!(monetary.p_cs_precedes, first_number),
+        (Category::Monetary, b"p_sep_by_space") => set!(monetary.p_sep_by_space, first_number),
+        (Category::Monetary, b"n_cs_precedes") => set!(monetary.n_cs_precedes, first_number),
+        (Category::Monetary, b"n_sep_by_space") => set!(monetary.n_sep_by_space, first_number),
+        (Category::Monetary, b"p_sign_posn") => set!(monetary.p_sign_posn, first_number),
+        (Category::Monetary, b"n_sign_posn") => set!(monetary.n_sign_posn, first_number),
+
+        (Category::Time, b"abday") => {
+            strings(operands, &mut time.abday);
+        }
+        (Category::Time, b"day") => {
+            strings(operands, &mut time.day);
+        }
+        (Category::Time, b"abmon") => {
+            strings(operands, &mut time.abmon);
+        }
+        (Category::Time, b"mon") => {
+            strings(operands, &mut time.mon);
+        }
+        (Category::Time, b"am_pm") => {
+            strings(operands, &mut time.am_pm);
+        }
+        (Category::Time, b"d_t_fmt") => set!(time.d_t_fmt, string(operands)),
+        (Category::Time, b"d_fmt") => set!(time.d_fmt, string(operands)),
+        (Category::Time, b"t_fmt") => set!(time.t_fmt, string(operands)),
+        (Category::Time, b"t_fmt_ampm") => set!(time.t_fmt_ampm, string(operands)),
+
+        (Category::Messages, b"yesexpr") => set!(messages.yesexpr, string(operands)),
+        (Category::Messages, b"noexpr") => set!(messages.noexpr, string(operands)),
+        (Category::Messages, b"yesstr") => set!(messages.yesstr, string(operands)),
+        (Category::Messages, b"nostr") => set!(messages.nostr, string(operands)),
+        _ => (),
+    }
+}
+
+/// Copy a category from another locale, for the copy directive
+fn copy(def: &mut Definition, category: Category, name: &[u8], depth: usize) -> Option<()> {
+    let other = load_nested(name, depth + 1)?;
+    match category {
+        Category::Collate => def.collate = other.collate,
+        Category::Numeric => def.numeric = other.numeric,
+        Category::Monetary => def.monetary = other.monetary,
+        Category::Time => def.time = other.time,
+        Category::Messages => def.messages = other.messages,
+        _ => (),
+    }
+    Some(())
+}
+
+/// The characters reordered by LC_COLLATE so far
+struct Reorder {
+    tailoring: Vec<(u32, u32, u8)>,
+    // The character the next ones sort after, and the rank and table primary weight of the last
+    anchor: Option<u32>,
+    rank: u8,
+    primary: Option<u16>,
+}
+
+/// Read a line of LC_COLLATE
+fn collate_line(
+    def: &mut Definition,
+    reorder: &mut Reorder,
+    word: &[u8],
+    rest: &[u8],
+    depth: usize,
+) -> Option<()> {
+    // The code point of a <Uxxxx> word
+    let symbol = |word: &[u8]| {
+        if word.len() > 2 && word[0] == b'<' && word[word.len() - 1] == b'>' {
+            code_point(&word[1..word.len() - 1])
+        } else {
+            None
+        }
+    };
+
+    match word {
+        b"copy" => {
+            let name = rest.split(|&c| c == b'"').nth(1)?;
+            // The table of the Unicode Collation Algorithm, which is built in
+            if !name.starts_with(b"iso14651_t1") {
+                copy(def, Category::Collate, name, depth)?;
+                reorder.tailoring = def.collate.tailoring.to_vec();
+            }
+        }
+        b"reorder-after" => {
+            let first = rest.split(|c| c.is_ascii_whitespace()).next()?;
+            reorder.anchor = symbol(first).map(|c| c as u32);
+            reorder.rank = 0;
+            reorder.primary = None;
+        }
+        b"reorder-end" => reorder.anchor = None,
+        _ => {
+            if let (Some(anchor), Some(c)) = (reorder.anchor, symbol(word)) {
+                // Characters with the same primary weight in the table keep it the same
+                let primary = collate::primary(c as u32);
+                if reorder.rank == 0 || primary.is_none() || primary != reorder.primary {
+                    reorder.rank = reorder.rank.checked_add(1)?;
+                }
+                reorder.primary = primary;
+                reorder.tailoring.push((c as u32, anchor, reorder.rank));
+            }
+        }
+    }
+    Some(())
+}
+
+fn parse(def: &mut Definition, data: &[u8], depth: usize) -> Option<()> {
+    let mut syntax = Syntax {
+        comment: b'#',
+        escape: b'\\',
+    };
+    let mut category = Category::Outside;
+    let mut reorder = Reorder {
+        tailoring: Vec::new(),
+        anchor: None,
+        rank: 0,
+        primary: None,
+    };
+
+    let mut line = Vec::new();
+    for physical in data.split(|&c| c == b'\n') {
+        // A line ending with the escape character continues on the next one
+        if physical.last() == Some(&syntax.escape) {
+            line.extend_from_slice(&physical[..physical.len() - 1]);
+            continue;
+        }
+        line.extend_from_slice(physical);
+        let start = line
+            .iter()
+            .position(|c| !c.is_ascii_whitespace())
+            .unwrap_or(line.len());
+        let mut words = line[start..].splitn(2, |c| c.is_ascii_whitespace());
+        let word = words.next().unwrap_or(&[]);
+        let rest = words.next().unwrap_or(&[]);
+        let rest = match rest.iter().position(|&c| !c.is_ascii_whitespace()) {
+            Some(start) => &rest[start..],
+            None => &[],
+        };
+
+        if word.is_empty() || word[0] == syntax.comment {
+            // Blank line or comment
+        } else if category == Category::Outside {
+            match word {
+                b"comment_char" if !rest.is_empty() => syntax.comment = rest[0],
+                b"escape_char" if !rest.is_empty() => syntax.escape = rest[0],
+                b"LC_COLLATE" => category = Category::Collate,
+                b"LC_NUMERIC" => category = Category::Numeric,
+                b"LC_MONETARY" => category = Category::Monetary,
+                b"LC_TIME" => category = Category::Time,
+                b"LC_MESSAGES" => category = Category::Messages,
+                _ if word.starts_with(b"LC_") => category = Category::Other,
+                _ => (),
+            }
+        } else if word == b"END" {
+            category = Category::Outside;
+        } else if category == Category::Collate {
+            // Unsupported rules are skipped, rather than failing the whole locale
+            collate_line(def, &mut reorder, word, rest, depth);
+        } else if category != Category::Other {
+            // Operands are only read for the categories that are kept
+            let operands = operands(rest, &syntax);
+            match (word, operands) {
+                (b"copy", Some(operands)) => copy(def, category, &operands[0], depth)?,
+                (_, Some(operands)) => keyword(def, category, word, operands),
+                (_, None) => (),
+            }
+        }
+        line.clear();
+    }
+
+    if !reorder.tailoring.is_empty() {
+        def.collate.tailoring = Box::leak(reorder.tailoring.into_boxed_slice());
+    }
+    Some(())
+}
+
+/// Read the file of a locale
+fn read_file(name: &[u8]) -> Option<Vec<u8>> {
+    let locpath = unsafe { getenv(b"LOCPATH\0".as_ptr() as *const c_char) };
+    let dirs = if locpath.is_null() {
+        LOCALE_DIR
+    } else {
+        unsafe { CStr::from_ptr(locpath) }.to_bytes()
+    };
+
+    for dir in dirs.split(|&c| c == b':').filter(|dir| !dir.is_empty()) {
+        let mut path = dir.to_vec();
+        path.push(b'/');
+        path.extend_from_slice(name);
+        let path = match CString::new(path) {
+            Ok(path) => path,
+            Err(_) => return None,
+        };
+        if let Ok(mut file) = File::open(&path, O_RDONLY | O_CLOEXEC) {
+            let mut data = Vec::new();
+            file.read_to_end(&mut data).ok()?;
+            return Some(data);
+        }
+    }
+    None
+}
+
+/// Read a locale, trying the name without its codeset too
+fn read(name: &[u8], depth: usize) -> Option<Definition> {
+    if depth > MAX_DEPTH || name.is_empty() || name.contains(&b'/') || name == b"." || name == b".."
+    {
+        return None;
+    }
+
+    // language[_territory][.codeset][@modifier]
+    let at = name.iter().position(|&c| c == b'@').unwrap_or(name.len());
+    let data = match name[..at].iter().position(|&c| c == b'.') {
+        Some(dot) => {
+            let codeset = &name[dot + 1..at];
+            if !codeset.eq_ignore_ascii_case(b"UTF-8") && !codeset.eq_ignore_ascii_case(b"utf8") {
+                return None;
+            }
+            read_file(name).or_else(|| {
+                let mut short = name[..dot].to_vec();
+                short.extend_from_slice(&name[at..]);
+                read_file(&short)
+            })?
+        }
+        None => read_file(name)?,
+    };
+
+    let mut def = Definition {
+        name: C_NAME,
+        collate: C_UTF8_COLLATE,
+        numeric: C_NUMERIC,
+        monetary: C_MONETARY,
+        time: C_TIME,
+        messages: C_MESSAGES,
+    };
+    parse(&mut def, &data, depth)?;
+
+    let monetary = &mut def.monetary;
+    let mut crncystr = match monetary.p_cs_precedes {
+        0 => vec![b'+'],
+        _ => vec![b'-'],
+    };
+    crncystr.extend_from_slice(monetary.currency_symbol.as_str().as_bytes());
+    monetary.crncystr = leak(crncystr)?;
+    def.name = leak(name.to_vec())?;
+    Some(def)
+}
+
+fn load_nested(name: &[u8], depth: usize) -> Option<&'static Definition> {
+    let found = LOADED
+        .lock()
+        .iter()
+        .find(|def| def.name.as_str().as_bytes() == name)
+        .cloned();
+    if found.is_some() {
+        return found;
+    }
+
+    // The lock isn't held while reading, as copy directives load other locales
+    let def: &'static Definition = Box::leak(Box::new(read(name, depth)?));
+    LOADED.lock().push(def);
+    Some(def)
+}
+
+/// The locale called `name`, loaded from its file the first time it is used
+pub fn load(name: &[u8]) -> Option<&'static Definition> {
+    load_nested(name, 0)
+}
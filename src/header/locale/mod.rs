@@ -1,12 +1,47 @@
-//! locale implementation for Redox, following http://pubs.opengroup.org/onlinepubs/7908799/xsh/locale.h.html
+//! locale implementation for Redox, following http://pubs.opengroup.org/onlinepubs/9699919799/basedefs/locale.h.html
 
+use alloc::boxed::Box;
 use core::ptr;
 
+use c_str::CStr;
+use header::errno::{EINVAL, ENOENT};
+use header::stdlib::getenv;
+use platform;
 use platform::types::*;
 
-const EMPTY_PTR: *const c_char = "\0" as *const _ as *const c_char;
-// Can't use &str because of the mutability
-static mut C_LOCALE: [c_char; 2] = [b'C' as c_char, 0];
+pub use self::data::*;
+
+pub mod collate;
+mod data;
+mod load;
+
+pub const LC_ALL: c_int = 0;
+pub const LC_COLLATE: c_int = 1;
+pub const LC_CTYPE: c_int = 2;
+pub const LC_MESSAGES: c_int = 3;
+pub const LC_MONETARY: c_int = 4;
+pub const LC_NUMERIC: c_int = 5;
+pub const LC_TIME: c_int = 6;
+
+pub const LC_COLLATE_MASK: c_int = 0x02;
+pub const LC_CTYPE_MASK: c_int = 0x04;
+pub const LC_MESSAGES_MASK: c_int = 0x08;
+pub const LC_MONETARY_MASK: c_int = 0x10;
+pub const LC_NUMERIC_MASK: c_int = 0x20;
+pub const LC_TIME_MASK: c_int = 0x40;
+pub const LC_ALL_MASK: c_int = 0x7E;
+
+const CATEGORY_NAMES: [&[u8]; 6] = [
+    b"LC_COLLATE",
+    b"LC_CTYPE",
+    b"LC_MESSAGES",
+    b"LC_MONETARY",
+    b"LC_NUMERIC",
+    b"LC_TIME",
+];
+
+// The longest locale name accepted, so that the name of a mixed locale fits in ALL_NAME
+const NAME_MAX: usize = 64;
 
 #[repr(C)]
 #[no_mangle]
@@ -30,39 +65,297 @@ pub struct lconv {
     p_sign_posn: c_char,
     thousands_sep: *const c_char,
 }
-unsafe impl Sync for lconv {}
-
-static CURRENT_LOCALE: lconv = lconv {
-    currency_symbol: EMPTY_PTR,
-    decimal_point: ".\0" as *const _ as *const c_char,
-    frac_digits: c_char::max_value(),
-    grouping: EMPTY_PTR,
-    int_curr_symbol: EMPTY_PTR,
-    int_frac_digits: c_char::max_value(),
-    mon_decimal_point: EMPTY_PTR,
-    mon_grouping: EMPTY_PTR,
-    mon_thousands_sep: EMPTY_PTR,
-    negative_sign: EMPTY_PTR,
-    n_cs_precedes: c_char::max_value(),
-    n_sep_by_space: c_char::max_value(),
-    n_sign_posn: c_char::max_value(),
-    positive_sign: EMPTY_PTR,
-    p_cs_precedes: c_char::max_value(),
-    p_sep_by_space: c_char::max_value(),
-    p_sign_posn: c_char::max_value(),
-    thousands_sep: EMPTY_PTR,
+
+/// A locale: the name and data of each category. No repr(C) needed, C won't see the content
+#[derive(Clone, Copy)]
+pub struct Locale {
+    // The names of the categories, indexed by category - 1
+    names: [LocaleStr; 6],
+    pub collate: &'static LcCollate,
+    pub ctype: &'static LcCtype,
+    pub numeric: &'static LcNumeric,
+    pub time: &'static LcTime,
+    pub monetary: &'static LcMonetary,
+    pub messages: &'static LcMessages,
+}
+
+pub type locale_t = *mut Locale;
+
+pub const LC_GLOBAL_LOCALE: locale_t = !0 as locale_t;
+
+const C_LOCALE: Locale = Locale {
+    names: [C_NAME; 6],
+    collate: &C_COLLATE,
+    ctype: &C_CTYPE,
+    numeric: &C_NUMERIC,
+    time: &C_TIME,
+    monetary: &C_MONETARY,
+    messages: &C_MESSAGES,
+};
+
+static mut GLOBAL_LOCALE: Locale = C_LOCALE;
+
+// The locale set by uselocale for this thread, or null to use the global locale
+#[thread_local]
+static mut THREAD_LOCALE: locale_t = ptr::null_mut();
+
+static mut LCONV: lconv = lconv {
+    currency_symbol: ptr::null(),
+    decimal_point: ptr::null(),
+    frac_digits: 0,
+    grouping: ptr::null(),
+    int_curr_symbol: ptr::null(),
+    int_frac_digits: 0,
+    mon_decimal_point: ptr::null(),
+    mon_grouping: ptr::null(),
+    mon_thousands_sep: ptr::null(),
+    negative_sign: ptr::null(),
+    n_cs_precedes: 0,
+    n_sep_by_space: 0,
+    n_sign_posn: 0,
+    positive_sign: ptr::null(),
+    p_cs_precedes: 0,
+    p_sep_by_space: 0,
+    p_sign_posn: 0,
+    thousands_sep: ptr::null(),
 };
 
+// "LC_CTYPE=...;LC_NUMERIC=...", the name of LC_ALL when the categories differ
+static mut ALL_NAME: [u8; 6 * (13 + NAME_MAX)] = [0; 6 * (13 + NAME_MAX)];
+
+/// The locale of the calling thread
+pub fn current() -> &'static Locale {
+    unsafe {
+        if THREAD_LOCALE.is_null() {
+            &GLOBAL_LOCALE
+        } else {
+            &*THREAD_LOCALE
+        }
+    }
+}
+
+/// The locale a locale_t refers to, for the *_l functions
+pub unsafe fn get<'a>(locale: locale_t) -> &'a Locale {
+    if locale == LC_GLOBAL_LOCALE {
+        &GLOBAL_LOCALE
+    } else {
+        &*locale
+    }
+}
+
+/// The name a locale is requested with in the environment, for a locale name of ""
+unsafe fn env_name(category: c_int) -> &'static [u8] {
+    let mut var = [0; 16];
+    let category = CATEGORY_NAMES[category as usize - 1];
+    var[..category.len()].copy_from_slice(category);
+
+    for name in &[&b"LC_ALL\0"[..], &var[..], &b"LANG\0"[..]] {
+        let value = getenv(name.as_ptr() as *const c_char);
+        if !value.is_null() && *value != 0 {
+            return CStr::from_ptr(value).to_bytes();
+        }
+    }
+    b"C"
+}
+
+/// Set one category of a locale to the locale called `name`, loading it if it isn't built in
+unsafe fn set_category(locale: &mut Locale, category: c_int, mut name: &[u8]) -> bool {
+    if name.is_empty() {
+        name = env_name(category);
+    }
+
+    let utf8 = match name {
+        b"C" | b"POSIX" => false,
+        b"C.UTF-8" | b"C.utf8" => true,
+        _ => {
+            let def = match load::load(name) {
+                Some(def) => def,
+                None => return false,
+            };
+            locale.names[category as usize - 1] = def.name;
+            match category {
+                LC_COLLATE => locale.collate = &def.collate,
+                LC_CTYPE => locale.ctype = &C_UTF8_CTYPE,
+                LC_NUMERIC => locale.numeric = &def.numeric,
+                LC_TIME => locale.time = &def.time,
+                LC_MONETARY => locale.monetary = &def.monetary,
+                LC_MESSAGES => locale.messages = &def.messages,
+                _ => (),
+            }
+            return true;
+        }
+    };
+
+    locale.names[category as usize - 1] = if utf8 { C_UTF8_NAME } else { C_NAME };
+    match category {
+        LC_COLLATE => locale.collate = if utf8 { &C_UTF8_COLLATE } else { &C_COLLATE },
+        LC_CTYPE => locale.ctype = if utf8 { &C_UTF8_CTYPE } else { &C_CTYPE },
+        LC_NUMERIC => locale.numeric = &C_NUMERIC,
+        LC_TIME => locale.time = &C_TIME,
+        LC_MONETARY => locale.monetary = &C_MONETARY,
+        LC_MESSAGES => locale.messages = &C_MESSAGES,
+        _ => (),
+    }
+    true
+}
+
+/// Set the categories in `mask` to the locale called `name`, which may be the name of a mixed
+/// locale as returned by setlocale(LC_ALL, NULL)
+unsafe fn set_categories(locale: &mut Locale, mask: c_int, name: &[u8]) -> bool {
+    if !name.contains(&b';') {
+        return name.len() <= NAME_MAX
+            && (1..=6)
+                .filter(|category| mask & (1 << category) != 0)
+                .all(|category| set_category(locale, category, name));
+    }
+
+    for part in name.split(|&c| c == b';') {
+        let eq = match part.iter().position(|&c| c == b'=') {
+            Some(eq) => eq,
+            None => return false,
+        };
+        let category = match CATEGORY_NAMES.iter().position(|&n| n == &part[..eq]) {
+            Some(i) => i as c_int + 1,
+            None => return false,
+        };
+        let value = &part[eq + 1..];
+        if value.is_empty() || value.len() > NAME_MAX {
+            return false;
+        }
+        if mask & (1 << category) != 0 && !set_category(locale, category, value) {
+            return false;
+        }
+    }
+    true
+}
+
+/// The name of a category of a locale, or of the whole locale for LC_ALL
+unsafe fn locale_name(locale: &Locale, category: c_int) -> *mut c_char {
+    if category != LC_ALL {
+        return locale.names[category as usize - 1].as_ptr() as *mut c_char;
+    }
+    if locale
+        .names
+        .iter()
+        .all(|n| n.as_str() == locale.names[0].as_str())
+    {
+        return locale.names[0].as_ptr() as *mut c_char;
+    }
+
+    let mut len = 0;
+    for (i, name) in locale.names.iter().enumerate() {
+        if i > 0 {
+            ALL_NAME[len] = b';';
+            len += 1;
+        }
+        for part in &[CATEGORY_NAMES[i], &b"="[..], name.as_str().as_bytes()] {
+            ALL_NAME[len..len + part.len()].copy_from_slice(part);
+            len += part.len();
+        }
+    }
+    ALL_NAME[len] = 0;
+    ALL_NAME.as_mut_ptr() as *mut c_char
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn duplocale(locobj: locale_t) -> locale_t {
+    Box::into_raw(Box::new(*get(locobj)))
+}
+
 #[no_mangle]
-pub extern "C" fn localeconv() -> *const lconv {
-    &CURRENT_LOCALE as *const _
+pub unsafe extern "C" fn freelocale(locobj: locale_t) {
+    if !locobj.is_null() && locobj != LC_GLOBAL_LOCALE {
+        Box::from_raw(locobj);
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn localeconv() -> *mut lconv {
+    let locale = current();
+    let numeric = locale.numeric;
+    let monetary = locale.monetary;
+    LCONV = lconv {
+        currency_symbol: monetary.currency_symbol.as_ptr(),
+        decimal_point: numeric.decimal_point.as_ptr(),
+        frac_digits: monetary.frac_digits,
+        grouping: numeric.grouping.as_ptr(),
+        int_curr_symbol: monetary.int_curr_symbol.as_ptr(),
+        int_frac_digits: monetary.int_frac_digits,
+        mon_decimal_point: monetary.mon_decimal_point.as_ptr(),
+        mon_grouping: monetary.mon_grouping.as_ptr(),
+        mon_thousands_sep: monetary.mon_thousands_sep.as_ptr(),
+        negative_sign: monetary.negative_sign.as_ptr(),
+        n_cs_precedes: monetary.n_cs_precedes,
+        n_sep_by_space: monetary.n_sep_by_space,
+        n_sign_posn: monetary.n_sign_posn,
+        positive_sign: monetary.positive_sign.as_ptr(),
+        p_cs_precedes: monetary.p_cs_precedes,
+        p_sep_by_space: monetary.p_sep_by_space,
+        p_sign_posn: monetary.p_sign_posn,
+        thousands_sep: numeric.thousands_sep.as_ptr(),
+    };
+    &mut LCONV
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn newlocale(
+    category_mask: c_int,
+    locale: *const c_char,
+    base: locale_t,
+) -> locale_t {
+    if category_mask & !LC_ALL_MASK != 0 || locale.is_null() {
+        platform::errno = EINVAL;
+        return ptr::null_mut();
+    }
+
+    let mut new = if base.is_null() { C_LOCALE } else { *base };
+    if !set_categories(&mut new, category_mask, CStr::from_ptr(locale).to_bytes()) {
+        platform::errno = ENOENT;
+        return ptr::null_mut();
+    }
+
+    // The base locale is reused for the new one
+    if base.is_null() {
+        Box::into_raw(Box::new(new))
+    } else {
+        *base = new;
+        base
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn setlocale(category: c_int, locale: *const c_char) -> *mut c_char {
+    if category < LC_ALL || category > LC_TIME {
+        return ptr::null_mut();
+    }
+    if !locale.is_null() {
+        let mask = if category == LC_ALL {
+            LC_ALL_MASK
+        } else {
+            1 << category
+        };
+        let mut new = GLOBAL_LOCALE;
+        if !set_categories(&mut new, mask, CStr::from_ptr(locale).to_bytes()) {
+            return ptr::null_mut();
+        }
+        GLOBAL_LOCALE = new;
+    }
+    locale_name(&GLOBAL_LOCALE, category)
 }
 
 #[no_mangle]
-pub unsafe extern "C" fn setlocale(_option: c_int, val: *const c_char) -> *mut c_char {
-    if val.is_null() {
-        return C_LOCALE.as_mut_ptr() as *mut c_char;
+pub unsafe extern "C" fn uselocale(newloc: locale_t) -> locale_t {
+    let old = if THREAD_LOCALE.is_null() {
+        LC_GLOBAL_LOCALE
+    } else {
+        THREAD_LOCALE
+    };
+    if !newloc.is_null() {
+        THREAD_LOCALE = if newloc == LC_GLOBAL_LOCALE {
+            ptr::null_mut()
+        } else {
+            newloc
+        };
     }
-    // TODO actually implement
-    ptr::null_mut()
+    old
 }
@@ -1,5 +1,5 @@
-pub mod _aio;
 pub mod _fenv;
+pub mod aio;
 pub mod arpa_inet;
 pub mod assert;
 pub mod ctype;
@@ -13,7 +13,9 @@ pub mod float;
 pub mod fnmatch;
 pub mod getopt;
 pub mod grp;
+pub mod iconv;
 pub mod inttypes;
+pub mod langinfo;
 pub mod libgen;
 pub mod limits;
 pub mod locale;
@@ -37,7 +39,7 @@ pub mod sys_epoll;
 pub mod sys_file;
 pub mod sys_ioctl;
 pub mod sys_mman;
-//pub mod sys_resource;
+pub mod sys_resource;
 pub mod sys_select;
 pub mod sys_socket;
 pub mod sys_stat;
@@ -45,13 +47,14 @@ pub mod sys_statvfs;
 pub mod sys_time;
 pub mod sys_timeb;
 //pub mod sys_times;
-pub mod _wctype;
 pub mod sys_uio;
 pub mod sys_un;
 pub mod sys_utsname;
 pub mod sys_wait;
 pub mod termios;
 pub mod time;
+pub mod uchar;
 pub mod unistd;
 pub mod utime;
 pub mod wchar;
+pub mod wctype;
@@ -1,11 +1,13 @@
 //! signal implementation for Redox, following http://pubs.opengroup.org/onlinepubs/7908799/xsh/signal.h.html
 
+use alloc::boxed::Box;
 use core::{mem, ptr};
 
 use cbitset::BitSet;
 
 use header::errno;
 use platform;
+use platform::pte::{pthread_attr_t, pthread_create, pthread_detach};
 use platform::types::*;
 use platform::{PalSignal, Sys};
 
@@ -27,6 +29,10 @@ pub const SIG_BLOCK: c_int = 0;
 pub const SIG_UNBLOCK: c_int = 1;
 pub const SIG_SETMASK: c_int = 2;
 
+pub const SIGEV_SIGNAL: c_int = 0;
+pub const SIGEV_NONE: c_int = 1;
+pub const SIGEV_THREAD: c_int = 2;
+
 #[repr(C)]
 #[derive(Clone)]
 pub struct sigaction {
@@ -38,6 +44,66 @@ pub struct sigaction {
 
 pub type sigset_t = c_ulong;
 
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub union sigval {
+    pub sival_int: c_int,
+    pub sival_ptr: *mut c_void,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct sigevent {
+    pub sigev_value: sigval,
+    pub sigev_signo: c_int,
+    pub sigev_notify: c_int,
+    pub sigev_notify_function: Option<extern "C" fn(sigval)>,
+    // Actually a pthread_attr_t *
+    pub sigev_notify_attributes: *mut c_void,
+}
+
+struct ThreadNotify {
+    function: extern "C" fn(sigval),
+    value: sigval,
+}
+
+extern "C" fn sigevent_thread(arg: *mut c_void) -> *mut c_void {
+    let notify = unsafe { Box::from_raw(arg as *mut ThreadNotify) };
+    (notify.function)(notify.value);
+    ptr::null_mut()
+}
+
+/// Deliver the notification requested by a sigevent, such as on completion of asynchronous I/O.
+/// Signals are sent to pid, the process that made the request
+pub unsafe fn sigevent_notify(event: &sigevent, pid: pid_t) {
+    match event.sigev_notify {
+        SIGEV_SIGNAL => {
+            Sys::sigqueue(pid, event.sigev_signo, event.sigev_value);
+        }
+        SIGEV_THREAD => {
+            if let Some(function) = event.sigev_notify_function {
+                let arg = Box::into_raw(Box::new(ThreadNotify {
+                    function,
+                    value: event.sigev_value,
+                }));
+                let mut thread = mem::zeroed();
+                if pthread_create(
+                    &mut thread,
+                    event.sigev_notify_attributes as *const pthread_attr_t,
+                    sigevent_thread,
+                    arg as *mut c_void,
+                ) == 0
+                {
+                    pthread_detach(thread);
+                } else {
+                    Box::from_raw(arg);
+                }
+            }
+        }
+        _ => (),
+    }
+}
+
 #[no_mangle]
 pub extern "C" fn kill(pid: pid_t, sig: c_int) -> c_int {
     Sys::kill(pid, sig)
@@ -198,6 +264,11 @@ pub extern "C" fn sigprocmask(how: c_int, set: *const sigset_t, oset: *mut sigse
     Sys::sigprocmask(how, set, oset)
 }
 
+#[no_mangle]
+pub extern "C" fn sigqueue(pid: pid_t, sig: c_int, value: sigval) -> c_int {
+    Sys::sigqueue(pid, sig, value)
+}
+
 // #[no_mangle]
 pub extern "C" fn sigrelse(sig: c_int) -> c_int {
     unimplemented!();
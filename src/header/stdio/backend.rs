@@ -0,0 +1,99 @@
+use alloc::boxed::Box;
+use alloc::rc::Rc;
+use core::cell::UnsafeCell;
+
+use fs::File;
+use io::{self, Read, Write};
+use platform;
+use platform::types::*;
+use platform::{Pal, Sys};
+
+/// Where the bytes of a stream are read from and written to. Errors set errno
+pub trait Backend {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize>;
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize>;
+    /// Called when the stream is flushed, after the buffered bytes are written
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+    /// Move the position like lseek, returning the new position
+    fn seek(&mut self, off: off_t, whence: c_int) -> io::Result<off_t>;
+    /// Release the backend. It isn't used anymore afterwards
+    fn close(&mut self) -> io::Result<()>;
+    /// The file descriptor, if there is one
+    fn fd(&self) -> Option<c_int> {
+        None
+    }
+}
+
+pub fn last_os_error() -> io::Error {
+    io::Error::from_raw_os_error(unsafe { platform::errno })
+}
+
+/// Set errno, and return it as an error
+pub fn error<T>(errno: c_int) -> io::Result<T> {
+    unsafe {
+        platform::errno = errno;
+    }
+    Err(io::Error::from_raw_os_error(errno))
+}
+
+/// File descriptors are only closed by `close`, so they must be reference files
+impl Backend for File {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        Read::read(self, buf)
+    }
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        Write::write(self, buf)
+    }
+    fn seek(&mut self, off: off_t, whence: c_int) -> io::Result<off_t> {
+        match Sys::lseek(self.fd, off, whence) {
+            -1 => Err(last_os_error()),
+            ok => Ok(ok),
+        }
+    }
+    fn close(&mut self) -> io::Result<()> {
+        match Sys::close(self.fd) {
+            -1 => Err(last_os_error()),
+            _ok => Ok(()),
+        }
+    }
+    fn fd(&self) -> Option<c_int> {
+        Some(self.fd)
+    }
+}
+
+/// A backend shared by the reading and the writing side of a stream. It's only used while the
+/// stream is locked
+#[derive(Clone)]
+pub struct BackendRef(Rc<UnsafeCell<Box<dyn Backend>>>);
+impl BackendRef {
+    pub fn new<B: Backend + 'static>(backend: B) -> Self {
+        BackendRef(Rc::new(UnsafeCell::new(Box::new(backend))))
+    }
+
+    /// A backend for the file descriptor `fd`
+    pub fn fd(fd: c_int) -> Self {
+        Self::new(File {
+            fd,
+            reference: true,
+        })
+    }
+
+    pub fn get(&self) -> &mut dyn Backend {
+        unsafe { &mut **self.0.get() }
+    }
+}
+impl Read for BackendRef {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.get().read(buf)
+    }
+}
+impl Write for BackendRef {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.get().write(buf)
+    }
+    fn flush(&mut self) -> io::Result<()> {
+        self.get().flush()
+    }
+}
@@ -0,0 +1,178 @@
+//! Streams on callbacks: fopencookie from glibc and funopen from BSD
+
+use core::ptr;
+
+use header::errno::{EBADF, EINVAL, ESPIPE};
+use header::string::strchr;
+use io;
+use platform;
+use platform::types::*;
+
+use super::backend::{error, last_os_error, Backend, BackendRef};
+use super::constants::*;
+use super::{helpers, FILE};
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct cookie_io_functions_t {
+    pub read: Option<unsafe extern "C" fn(*mut c_void, *mut c_char, size_t) -> ssize_t>,
+    pub write: Option<unsafe extern "C" fn(*mut c_void, *const c_char, size_t) -> ssize_t>,
+    pub seek: Option<unsafe extern "C" fn(*mut c_void, *mut off_t, c_int) -> c_int>,
+    pub close: Option<unsafe extern "C" fn(*mut c_void) -> c_int>,
+}
+
+/// The backend of fopencookie. Reading without a callback returns end of file, and writing
+/// without a callback discards the bytes
+struct Cookie {
+    cookie: *mut c_void,
+    io: cookie_io_functions_t,
+}
+impl Backend for Cookie {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let read = match self.io.read {
+            Some(read) => read,
+            None => return Ok(0),
+        };
+        match unsafe { read(self.cookie, buf.as_mut_ptr() as *mut c_char, buf.len()) } {
+            n if n < 0 => Err(last_os_error()),
+            n => Ok(n as usize),
+        }
+    }
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let write = match self.io.write {
+            Some(write) => write,
+            None => return Ok(buf.len()),
+        };
+        match unsafe { write(self.cookie, buf.as_ptr() as *const c_char, buf.len()) } {
+            n if n < 0 => Err(last_os_error()),
+            n => Ok(n as usize),
+        }
+    }
+    fn seek(&mut self, mut off: off_t, whence: c_int) -> io::Result<off_t> {
+        let seek = match self.io.seek {
+            Some(seek) => seek,
+            None => return error(ESPIPE),
+        };
+        if unsafe { seek(self.cookie, &mut off, whence) } < 0 {
+            return Err(last_os_error());
+        }
+        Ok(off)
+    }
+    fn close(&mut self) -> io::Result<()> {
+        match self.io.close {
+            Some(close) if unsafe { close(self.cookie) } != 0 => Err(last_os_error()),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// The backend of funopen. A stream without a read or a write callback can't be read from or
+/// written to
+struct FunCookie {
+    cookie: *mut c_void,
+    readfn: Option<unsafe extern "C" fn(*mut c_void, *mut c_char, c_int) -> c_int>,
+    writefn: Option<unsafe extern "C" fn(*mut c_void, *const c_char, c_int) -> c_int>,
+    seekfn: Option<unsafe extern "C" fn(*mut c_void, fpos_t, c_int) -> fpos_t>,
+    closefn: Option<unsafe extern "C" fn(*mut c_void) -> c_int>,
+}
+impl Backend for FunCookie {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let readfn = match self.readfn {
+            Some(readfn) => readfn,
+            None => return error(EBADF),
+        };
+        let len = buf.len().min(c_int::max_value() as usize) as c_int;
+        match unsafe { readfn(self.cookie, buf.as_mut_ptr() as *mut c_char, len) } {
+            n if n < 0 => Err(last_os_error()),
+            n => Ok(n as usize),
+        }
+    }
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let writefn = match self.writefn {
+            Some(writefn) => writefn,
+            None => return error(EBADF),
+        };
+        let len = buf.len().min(c_int::max_value() as usize) as c_int;
+        match unsafe { writefn(self.cookie, buf.as_ptr() as *const c_char, len) } {
+            n if n < 0 => Err(last_os_error()),
+            n => Ok(n as usize),
+        }
+    }
+    fn seek(&mut self, off: off_t, whence: c_int) -> io::Result<off_t> {
+        let seekfn = match self.seekfn {
+            Some(seekfn) => seekfn,
+            None => return error(ESPIPE),
+        };
+        match unsafe { seekfn(self.cookie, off, whence) } {
+            -1 => Err(last_os_error()),
+            pos => Ok(pos),
+        }
+    }
+    fn close(&mut self) -> io::Result<()> {
+        match self.closefn {
+            Some(closefn) if unsafe { closefn(self.cookie) } != 0 => Err(last_os_error()),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Open a stream in mode `mode` that reads, writes, seeks and closes with the callbacks of
+/// `io_funcs`, which get `cookie` as their first argument
+#[no_mangle]
+pub unsafe extern "C" fn fopencookie(
+    cookie: *mut c_void,
+    mode: *const c_char,
+    io_funcs: cookie_io_functions_t,
+) -> *mut FILE {
+    if *mode != b'r' as c_char && *mode != b'w' as c_char && *mode != b'a' as c_char {
+        platform::errno = EINVAL;
+        return ptr::null_mut();
+    }
+
+    let mut flags = 0;
+    if strchr(mode, b'+' as c_int).is_null() {
+        flags |= if *mode == b'r' as c_char {
+            F_NOWR
+        } else {
+            F_NORD
+        };
+    }
+    if *mode == b'a' as c_char {
+        flags |= F_APP;
+    }
+
+    let backend = BackendRef::new(Cookie {
+        cookie,
+        io: io_funcs,
+    });
+    helpers::_fopen_backend(backend, flags, _IOFBF)
+}
+
+/// Open a stream that uses the callbacks that are not null, with `cookie` as their first argument
+#[no_mangle]
+pub unsafe extern "C" fn funopen(
+    cookie: *const c_void,
+    readfn: Option<unsafe extern "C" fn(*mut c_void, *mut c_char, c_int) -> c_int>,
+    writefn: Option<unsafe extern "C" fn(*mut c_void, *const c_char, c_int) -> c_int>,
+    seekfn: Option<unsafe extern "C" fn(*mut c_void, fpos_t, c_int) -> fpos_t>,
+    closefn: Option<unsafe extern "C" fn(*mut c_void) -> c_int>,
+) -> *mut FILE {
+    let flags = match (readfn.is_some(), writefn.is_some()) {
+        (false, false) => {
+            platform::errno = EINVAL;
+            return ptr::null_mut();
+        }
+        (true, false) => F_NOWR,
+        (false, true) => F_NORD,
+        (true, true) => 0,
+    };
+
+    let backend = BackendRef::new(FunCookie {
+        cookie: cookie as *mut c_void,
+        readfn,
+        writefn,
+        seekfn,
+        closefn,
+    });
+    helpers::_fopen_backend(backend, flags, _IOFBF)
+}
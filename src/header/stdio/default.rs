@@ -1,27 +1,27 @@
-use super::{constants, Buffer, BUFSIZ, FILE};
+use super::{constants, helpers, BackendRef, Buffer, BUFSIZ, FILE};
+use alloc::vec::Vec;
 use core::cell::UnsafeCell;
 use core::ptr;
 
-use fs::File;
-use io::LineWriter;
 use mutex::Mutex;
 use platform::types::*;
 
 pub struct GlobalFile(UnsafeCell<FILE>);
 impl GlobalFile {
-    fn new(file: c_int, flags: c_int) -> Self {
-        let file = File::new(file);
-        let writer = LineWriter::new(unsafe { file.get_ref() });
+    fn new(fd: c_int, flags: c_int, mode: c_int) -> Self {
+        let backend = BackendRef::fd(fd);
+        let writer = helpers::writer(&backend, mode, BUFSIZ as usize);
         GlobalFile(UnsafeCell::new(FILE {
             lock: Mutex::new(()),
 
-            file,
+            backend,
             flags: constants::F_PERM | flags,
             read_buf: Buffer::Owned(vec![0; BUFSIZ as usize]),
             read_pos: 0,
             read_size: 0,
-            unget: None,
+            unget: Vec::new(),
             writer,
+            orientation: 0,
 
             pid: None,
         }))
@@ -35,13 +35,16 @@ unsafe impl Sync for GlobalFile {}
 
 lazy_static! {
     #[allow(non_upper_case_globals)]
-    pub static ref default_stdin: GlobalFile = GlobalFile::new(0, constants::F_NOWR);
+    pub static ref default_stdin: GlobalFile =
+        GlobalFile::new(0, constants::F_NOWR, helpers::default_mode(0));
 
     #[allow(non_upper_case_globals)]
-    pub static ref default_stdout: GlobalFile = GlobalFile::new(1, constants::F_NORD);
+    pub static ref default_stdout: GlobalFile =
+        GlobalFile::new(1, constants::F_NORD, helpers::default_mode(1));
 
     #[allow(non_upper_case_globals)]
-    pub static ref default_stderr: GlobalFile = GlobalFile::new(2, constants::F_NORD);
+    pub static ref default_stderr: GlobalFile =
+        GlobalFile::new(2, constants::F_NORD, constants::_IONBF);
 }
 
 #[no_mangle]
@@ -1,11 +1,11 @@
-use header::stdio::{FILE, F_NORD, F_NOWR};
+use header::stdio::{Pending, FILE, F_NORD, F_NOWR};
 use platform::types::*;
 
 #[no_mangle]
 pub extern "C" fn __fpending(stream: *mut FILE) -> size_t {
     let stream = unsafe { &mut *stream }.lock();
 
-    stream.writer.inner.buf.len() as size_t
+    stream.writer.pending()
 }
 
 #[no_mangle]
@@ -0,0 +1,348 @@
+//! The exact digits of floating-point numbers, for printf. Decimal digits are computed on a big
+//! number in limbs of nine digits, so that every digit and every rounding is exact
+
+use core::ffi::VaList;
+use core::{mem, ptr};
+
+use io::{self, Write};
+use platform::types::*;
+
+/// The bytes of a long double, which Rust has no type for
+#[repr(C, align(16))]
+#[derive(Clone, Copy)]
+pub struct LongDouble(pub u128);
+
+extern "C" {
+    // Defined in src/c/stdio.c, and takes a `va_list *`
+    fn __relibc_va_arg_long_double(ap: *mut c_void, out: *mut LongDouble);
+}
+
+// LongDouble::arg relies on a VaList being a single pointer to the va_list of C. Fail to build if
+// the toolchain changes that
+#[allow(dead_code)]
+const VA_LIST_IS_POINTER: [(); 1] =
+    [(); (mem::size_of::<VaList<'static>>() == mem::size_of::<*mut c_void>()) as usize];
+
+// The bits of the fraction, after the leading bit, which x87 stores explicitly before them
+#[cfg(target_arch = "x86_64")]
+const LONG_DOUBLE_FRACTION: u32 = 63;
+#[cfg(target_arch = "x86_64")]
+const LONG_DOUBLE_LEADING: u128 = 1 << 63;
+#[cfg(target_arch = "x86_64")]
+const LONG_DOUBLE_EXPONENT: u32 = 64;
+#[cfg(target_arch = "aarch64")]
+const LONG_DOUBLE_FRACTION: u32 = 112;
+#[cfg(target_arch = "aarch64")]
+const LONG_DOUBLE_LEADING: u128 = 0;
+#[cfg(target_arch = "aarch64")]
+const LONG_DOUBLE_EXPONENT: u32 = 112;
+
+impl LongDouble {
+    /// Read a long double argument from `ap`
+    pub unsafe fn arg(ap: &mut VaList) -> Self {
+        let mut out = LongDouble(0);
+        // On x86_64 and aarch64 a VaList is a pointer to the va_list of C, so the helper moves
+        // `ap` itself past the argument
+        let ap: *mut c_void = mem::transmute(ptr::read(ap));
+        __relibc_va_arg_long_double(ap, &mut out);
+        out
+    }
+
+    /// A double as a long double, which holds every double exactly
+    pub fn from_double(double: f64) -> Self {
+        let float = Float::from_double(double);
+        let (biased, fraction) = match float.kind {
+            Kind::Infinite => (0x7fff, LONG_DOUBLE_LEADING),
+            // A quiet NaN
+            Kind::Nan => (
+                0x7fff,
+                LONG_DOUBLE_LEADING | 1 << (LONG_DOUBLE_FRACTION - 1),
+            ),
+            Kind::Finite { mantissa: 0, .. } => (0, 0),
+            Kind::Finite { mantissa, exp } => {
+                // Subnormal doubles are normal long doubles
+                let shift = LONG_DOUBLE_FRACTION as i32 - (127 - mantissa.leading_zeros() as i32);
+                let fraction = (mantissa << shift) & (1 << LONG_DOUBLE_FRACTION) - 1;
+                (
+                    exp - shift + LONG_DOUBLE_FRACTION as i32 + 16383,
+                    LONG_DOUBLE_LEADING | fraction,
+                )
+            }
+        };
+        LongDouble(
+            (float.negative as u128) << (LONG_DOUBLE_EXPONENT + 15)
+                | (biased as u128) << LONG_DOUBLE_EXPONENT
+                | fraction,
+        )
+    }
+}
+
+#[derive(Clone, Copy)]
+pub enum Kind {
+    /// mantissa × 2^exp
+    Finite {
+        mantissa: u128,
+        exp: i32,
+    },
+    Infinite,
+    Nan,
+}
+
+/// A floating-point number of `digits` bits of precision
+#[derive(Clone, Copy)]
+pub struct Float {
+    pub negative: bool,
+    pub kind: Kind,
+    pub digits: u32,
+}
+impl Float {
+    fn new(negative: bool, biased: i32, fraction: u128, digits: u32, max: i32, bias: i32) -> Self {
+        let kind = if biased == max {
+            if fraction == 0 {
+                Kind::Infinite
+            } else {
+                Kind::Nan
+            }
+        } else if biased == 0 {
+            // Subnormal, without the implicit leading bit
+            Kind::Finite {
+                mantissa: fraction,
+                exp: 1 - bias - (digits as i32 - 1),
+            }
+        } else {
+            Kind::Finite {
+                mantissa: fraction | 1 << (digits - 1),
+                exp: biased - bias - (digits as i32 - 1),
+            }
+        };
+        Float {
+            negative,
+            kind,
+            digits,
+        }
+    }
+
+    pub fn from_double(float: f64) -> Self {
+        let bits = float.to_bits();
+        Self::new(
+            bits >> 63 != 0,
+            (bits >> 52 & 0x7ff) as i32,
+            (bits & (1 << 52) - 1) as u128,
+            53,
+            0x7ff,
+            1023,
+        )
+    }
+
+    /// The 80-bit extended precision of x87, which has an explicit leading bit
+    #[cfg(target_arch = "x86_64")]
+    pub fn from_long_double(float: LongDouble) -> Self {
+        let bits = float.0;
+        let mantissa = bits as u64 as u128;
+        Self::new(
+            bits >> 79 & 1 != 0,
+            (bits >> 64 & 0x7fff) as i32,
+            mantissa & (1 << 63) - 1,
+            64,
+            0x7fff,
+            16383,
+        )
+    }
+
+    /// IEEE quadruple precision
+    #[cfg(target_arch = "aarch64")]
+    pub fn from_long_double(float: LongDouble) -> Self {
+        let bits = float.0;
+        Self::new(
+            bits >> 127 != 0,
+            (bits >> 112 & 0x7fff) as i32,
+            bits & (1 << 112) - 1,
+            113,
+            0x7fff,
+            16383,
+        )
+    }
+}
+
+/// Bits in the integer part of the largest long double, and in the fraction part of the
+/// smallest. Doubles need less
+const MAX_INT_BITS: usize = 16384;
+const MAX_FRAC_BITS: usize = 16494;
+
+const LIMB: u32 = 1_000_000_000;
+// A limb holds at least 29 bits, and one more is needed for the carry of rounding
+const INT_LIMBS: usize = MAX_INT_BITS / 29 + 2;
+// A fraction of n bits has n decimal digits
+const FRAC_LIMBS: usize = MAX_FRAC_BITS / 9 + 1;
+
+const POW10: [u32; 9] = [
+    1,
+    10,
+    100,
+    1000,
+    10000,
+    100_000,
+    1_000_000,
+    10_000_000,
+    100_000_000,
+];
+
+/// The limb and the power of ten in it of the digit of 10^pos
+fn locate(pos: i32) -> (isize, usize) {
+    let limb = if pos >= 0 { pos / 9 } else { (pos - 8) / 9 };
+    (
+        INT_LIMBS as isize - 1 - limb as isize,
+        (pos - limb * 9) as usize,
+    )
+}
+
+/// The exact decimal digits of a finite number, in limbs of nine digits starting with the most
+/// significant. The limbs before `INT_LIMBS` are the integer part
+pub struct Decimal {
+    limbs: [u32; INT_LIMBS + FRAC_LIMBS],
+    start: usize,
+    end: usize,
+}
+impl Decimal {
+    pub fn new(mantissa: u128, mut exp: i32) -> Self {
+        let mut dec = Decimal {
+            limbs: [0; INT_LIMBS + FRAC_LIMBS],
+            start: INT_LIMBS,
+            end: INT_LIMBS,
+        };
+        let mut m = mantissa;
+        while m > 0 {
+            dec.start -= 1;
+            dec.limbs[dec.start] = (m % LIMB as u128) as u32;
+            m /= LIMB as u128;
+        }
+
+        // Multiply by 2^exp, up to 29 bits at a time, so that every product fits in 64 bits
+        while exp > 0 {
+            let shift = exp.min(29);
+            let mut carry = 0;
+            for limb in dec.limbs[dec.start..dec.end].iter_mut().rev() {
+                let x = ((*limb as u64) << shift) + carry;
+                *limb = (x % LIMB as u64) as u32;
+                carry = x / LIMB as u64;
+            }
+            while carry > 0 {
+                dec.start -= 1;
+                dec.limbs[dec.start] = (carry % LIMB as u64) as u32;
+                carry /= LIMB as u64;
+            }
+            exp -= shift;
+        }
+
+        // Divide by 2^-exp, up to 9 bits at a time, which divide a limb with no remainder
+        while exp < 0 {
+            let shift = (-exp).min(9);
+            let mut carry = 0;
+            for limb in dec.limbs[dec.start..dec.end].iter_mut() {
+                let rem = *limb & (1 << shift) - 1;
+                *limb = (*limb >> shift) + carry;
+                carry = (LIMB >> shift) * rem;
+            }
+            if carry > 0 {
+                dec.limbs[dec.end] = carry;
+                dec.end += 1;
+            }
+            if dec.start < INT_LIMBS && dec.limbs[dec.start] == 0 {
+                dec.start += 1;
+            }
+            exp += shift;
+        }
+        dec
+    }
+
+    /// The digit of 10^pos
+    pub fn digit(&self, pos: i32) -> u8 {
+        let (limb, power) = locate(pos);
+        if limb < self.start as isize || limb >= self.end as isize {
+            return 0;
+        }
+        (self.limbs[limb as usize] / POW10[power] % 10) as u8
+    }
+
+    /// The power of ten of the first digit, or 0 for zero
+    pub fn exponent(&self) -> i32 {
+        let first = match (self.start..self.end).find(|&i| self.limbs[i] != 0) {
+            Some(first) => first,
+            None => return 0,
+        };
+        let digits = POW10.iter().filter(|&&p| self.limbs[first] >= p).count();
+        (INT_LIMBS as i32 - 1 - first as i32) * 9 + digits as i32 - 1
+    }
+
+    /// The power of ten of the last digit that isn't zero
+    pub fn last_nonzero(&self) -> Option<i32> {
+        let last = (self.start..self.end).rev().find(|&i| self.limbs[i] != 0)?;
+        let zeros = POW10
+            .iter()
+            .take_while(|&&p| self.limbs[last] % (p * 10) == 0)
+            .count();
+        Some((INT_LIMBS as i32 - 1 - last as i32) * 9 + zeros as i32)
+    }
+
+    /// Round to a multiple of 10^pos, to nearest with ties to even
+    pub fn round(&mut self, pos: i32) {
+        let (limb, power) = locate(pos);
+        let mut limb = limb as usize;
+        if limb >= self.end {
+            return;
+        }
+        while self.start > limb {
+            self.start -= 1;
+            self.limbs[self.start] = 0;
+        }
+
+        let unit = POW10[power];
+        let (rem, half, rest) = if power > 0 {
+            (self.limbs[limb] % unit, unit / 2, limb + 1)
+        } else if limb + 1 < self.end {
+            (self.limbs[limb + 1], LIMB / 2, limb + 2)
+        } else {
+            (0, LIMB / 2, self.end)
+        };
+        let sticky = self.limbs[rest..self.end].iter().any(|&l| l != 0);
+        let odd = self.limbs[limb] / unit % 2 != 0;
+
+        if power > 0 {
+            self.limbs[limb] -= rem;
+        }
+        self.end = limb + 1;
+        if rem > half || (rem == half && (sticky || odd)) {
+            let mut add = unit;
+            loop {
+                if limb < self.start {
+                    self.start = limb;
+                    self.limbs[limb] = 0;
+                }
+                self.limbs[limb] += add;
+                if self.limbs[limb] < LIMB {
+                    break;
+                }
+                self.limbs[limb] = 0;
+                limb -= 1;
+                add = 1;
+            }
+        }
+    }
+
+    /// Write the digits of 10^from down to 10^to
+    pub fn write_digits<W: Write>(&self, w: &mut W, from: i32, to: i32) -> io::Result<()> {
+        let mut buf = [0; 32];
+        let mut len = 0;
+        let mut pos = from;
+        while pos >= to {
+            buf[len] = b'0' + self.digit(pos);
+            len += 1;
+            if len == buf.len() {
+                w.write_all(&buf)?;
+                len = 0;
+            }
+            pos -= 1;
+        }
+        w.write_all(&buf[..len])
+    }
+}
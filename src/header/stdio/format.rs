@@ -0,0 +1,200 @@
+//! The characters of printf and scanf formats: bytes for the narrow functions, and wide
+//! characters for the wide ones. Both read and write bytes, which are multibyte characters for
+//! the wide functions
+
+use header::errno::EILSEQ;
+use header::stdlib::MB_LEN_MAX;
+use header::wchar::{mbrtowc, mbstate_t, wcrtomb};
+use io::{self, Read, Write};
+use platform;
+use platform::types::*;
+
+pub trait FormatChar: Copy + 'static {
+    /// Whether widths, precisions and counts are in wide characters instead of bytes
+    const WIDE: bool;
+
+    /// The byte or the wide character
+    fn code(self) -> u32;
+
+    /// Write `text` of the format as bytes
+    fn write<W: Write>(w: &mut W, text: &[Self]) -> io::Result<()>;
+}
+
+impl FormatChar for c_char {
+    const WIDE: bool = false;
+
+    fn code(self) -> u32 {
+        self as u8 as u32
+    }
+
+    fn write<W: Write>(w: &mut W, text: &[Self]) -> io::Result<()> {
+        w.write_all(unsafe { &*(text as *const [c_char] as *const [u8]) })
+    }
+}
+
+impl FormatChar for wchar_t {
+    const WIDE: bool = true;
+
+    fn code(self) -> u32 {
+        self as u32
+    }
+
+    fn write<W: Write>(w: &mut W, text: &[Self]) -> io::Result<()> {
+        for &wc in text {
+            write_wide(w, wc)?;
+        }
+        Ok(())
+    }
+}
+
+/// `c` if it's ASCII, like everything in a conversion specification, or a byte that doesn't
+/// match any of them
+pub fn ascii(c: u32) -> u8 {
+    if c < 0x80 {
+        c as u8
+    } else {
+        0x80
+    }
+}
+
+fn eilseq() -> io::Error {
+    unsafe {
+        platform::errno = EILSEQ;
+    }
+    io::Error::from_raw_os_error(EILSEQ)
+}
+
+/// Encode `wc` as a multibyte character in `buf`, returning its length
+fn encode(wc: wchar_t, buf: &mut [c_char; MB_LEN_MAX as usize]) -> io::Result<usize> {
+    let len = unsafe { wcrtomb(buf.as_mut_ptr(), wc, &mut mbstate_t::new()) };
+    if len == usize::max_value() {
+        return Err(eilseq());
+    }
+    Ok(len)
+}
+
+/// The length of `wc` as a multibyte character
+pub fn wide_len(wc: wchar_t) -> io::Result<usize> {
+    encode(wc, &mut [0; MB_LEN_MAX as usize])
+}
+
+/// Write `wc` as a multibyte character
+pub fn write_wide<W: Write>(w: &mut W, wc: wchar_t) -> io::Result<()> {
+    let mut buf = [0; MB_LEN_MAX as usize];
+    let len = encode(wc, &mut buf)?;
+    w.write_all(unsafe { &*(&buf[..len] as *const [c_char] as *const [u8]) })
+}
+
+/// Read a multibyte character, or None at the end of the input. A character that is cut off
+/// by the end is an error
+pub fn read_wide<R: Read>(r: &mut R) -> io::Result<Option<wchar_t>> {
+    let mut state = mbstate_t::new();
+    let mut first = true;
+    loop {
+        let mut byte = [0];
+        if r.read(&mut byte)? == 0 {
+            return if first { Ok(None) } else { Err(eilseq()) };
+        }
+        first = false;
+
+        let mut wc = 0;
+        let ptr = byte.as_ptr() as *const c_char;
+        match unsafe { mbrtowc(&mut wc, ptr, 1, &mut state) } as isize {
+            -2 => (),
+            -1 => return Err(io::Error::from_raw_os_error(EILSEQ)),
+            _ => return Ok(Some(wc)),
+        }
+    }
+}
+
+/// Writes the multibyte output of swprintf as wide characters to a buffer of `len` wide
+/// characters, which is kept terminated. Fails when the buffer is full
+pub struct WideStringWriter {
+    buf: *mut wchar_t,
+    len: usize,
+    state: mbstate_t,
+}
+impl WideStringWriter {
+    pub unsafe fn new(buf: *mut wchar_t, len: usize) -> Self {
+        if len > 0 {
+            *buf = 0;
+        }
+        WideStringWriter {
+            buf,
+            len,
+            state: mbstate_t::new(),
+        }
+    }
+}
+impl Write for WideStringWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let mut i = 0;
+        while i < buf.len() {
+            let mut wc = 0;
+            let ptr = buf[i..].as_ptr() as *const c_char;
+            match unsafe { mbrtowc(&mut wc, ptr, buf.len() - i, &mut self.state) } as isize {
+                // The rest of the character is in the next bytes
+                -2 => break,
+                -1 => return Err(io::Error::from_raw_os_error(EILSEQ)),
+                0 => i += 1,
+                n => i += n as usize,
+            }
+            if self.len <= 1 {
+                return Err(io::Error::new(io::ErrorKind::Other, "wide string is full"));
+            }
+            unsafe {
+                *self.buf = wc;
+                self.buf = self.buf.add(1);
+                *self.buf = 0;
+            }
+            self.len -= 1;
+        }
+        Ok(buf.len())
+    }
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Reads a terminated wide string as multibyte characters, for swscanf
+pub struct WideStringReader {
+    string: *const wchar_t,
+    // The bytes of the current character that are not read yet
+    buf: [c_char; MB_LEN_MAX as usize],
+    pos: usize,
+    len: usize,
+}
+impl WideStringReader {
+    pub fn new(string: *const wchar_t) -> Self {
+        WideStringReader {
+            string,
+            buf: [0; MB_LEN_MAX as usize],
+            pos: 0,
+            len: 0,
+        }
+    }
+}
+impl Read for WideStringReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let mut i = 0;
+        while i < buf.len() {
+            if self.pos == self.len {
+                let wc = unsafe { *self.string };
+                if wc == 0 {
+                    break;
+                }
+                let len = unsafe { wcrtomb(self.buf.as_mut_ptr(), wc, &mut mbstate_t::new()) };
+                if len == usize::max_value() {
+                    return Err(io::Error::from_raw_os_error(EILSEQ));
+                }
+                self.string = unsafe { self.string.add(1) };
+                self.pos = 0;
+                self.len = len;
+            }
+            buf[i] = self.buf[self.pos] as u8;
+            self.pos += 1;
+            i += 1;
+        }
+        Ok(i)
+    }
+}
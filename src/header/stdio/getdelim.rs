@@ -30,6 +30,9 @@ pub unsafe extern "C" fn __getdelim(
     let mut buf = Vec::new();
     let count = {
         let mut stream = (*stream).lock();
+        if stream.try_set_byte_orientation_unlocked().is_err() {
+            return -1;
+        }
         match stream.read_until(delim, &mut buf) {
             Ok(ok) => ok,
             Err(err) => return -1,
@@ -1,16 +1,17 @@
 use alloc::boxed::Box;
+use alloc::vec::Vec;
 
-use fs::File;
 use header::errno;
 use header::fcntl::*;
 use header::string::strchr;
-use io::LineWriter;
+use header::unistd::isatty;
+use io::{BufWriter, LineWriter};
 use mutex::Mutex;
 use platform;
 use platform::types::*;
 
 use super::constants::*;
-use super::{Buffer, FILE};
+use super::{BackendRef, Buffer, Writer, FILE, OPEN_FILES};
 
 /// Parse mode flags as a string and output a mode flags integer
 pub unsafe fn parse_mode_flags(mode_str: *const c_char) -> i32 {
@@ -39,6 +40,47 @@ pub unsafe fn parse_mode_flags(mode_str: *const c_char) -> i32 {
     flags
 }
 
+/// The buffering of a new stream: line buffered if it's a terminal, and fully buffered otherwise
+pub fn default_mode(fd: c_int) -> c_int {
+    let err = unsafe { platform::errno };
+    let mode = if isatty(fd) == 1 { _IOLBF } else { _IOFBF };
+    unsafe {
+        platform::errno = err;
+    }
+    mode
+}
+
+/// A writer to `backend` with the buffering `mode` and a buffer of `size` bytes
+pub fn writer(backend: &BackendRef, mode: c_int, size: usize) -> Box<dyn Writer> {
+    match mode {
+        _IONBF => Box::new(BufWriter::with_capacity(0, backend.clone())),
+        _IOLBF => Box::new(LineWriter::with_capacity(size, backend.clone())),
+        _ => Box::new(BufWriter::with_capacity(size, backend.clone())),
+    }
+}
+
+/// Open a stream on `backend`, with the buffering `mode`
+pub fn _fopen_backend(backend: BackendRef, flags: c_int, mode: c_int) -> *mut FILE {
+    let writer = writer(&backend, mode, BUFSIZ as usize);
+
+    let file = Box::into_raw(Box::new(FILE {
+        lock: Mutex::new(()),
+
+        backend,
+        flags,
+        read_buf: Buffer::Owned(vec![0; BUFSIZ as usize]),
+        read_pos: 0,
+        read_size: 0,
+        unget: Vec::new(),
+        writer,
+        orientation: 0,
+
+        pid: None,
+    }));
+    OPEN_FILES.lock().0.push(file);
+    file
+}
+
 /// Open a file with the file descriptor `fd` in the mode `mode`
 pub unsafe fn _fdopen(fd: c_int, mode: *const c_char) -> Option<*mut FILE> {
     if *mode != b'r' as i8 && *mode != b'w' as i8 && *mode != b'a' as i8 {
@@ -63,20 +105,5 @@ pub unsafe fn _fdopen(fd: c_int, mode: *const c_char) -> Option<*mut FILE> {
         flags |= F_APP;
     }
 
-    let file = File::new(fd);
-    let writer = LineWriter::new(file.get_ref());
-
-    Some(Box::into_raw(Box::new(FILE {
-        lock: Mutex::new(()),
-
-        file,
-        flags,
-        read_buf: Buffer::Owned(vec![0; BUFSIZ as usize]),
-        read_pos: 0,
-        read_size: 0,
-        unget: None,
-        writer,
-
-        pid: None,
-    })))
+    Some(_fopen_backend(BackendRef::fd(fd), flags, default_mode(fd)))
 }
@@ -0,0 +1,301 @@
+//! Streams on memory buffers: fmemopen, open_memstream and open_wmemstream
+
+use alloc::vec::Vec;
+use core::{mem, ptr, slice};
+
+use header::errno::{EBADF, EINVAL, ENOMEM, ENOSPC};
+use header::string::strchr;
+use header::wchar::{mbrtowc, mbstate_t};
+use io;
+use platform;
+use platform::types::*;
+
+use super::backend::{error, last_os_error, Backend, BackendRef};
+use super::constants::*;
+use super::{helpers, Buffer, FILE};
+
+/// A stream on a buffer of fixed size
+struct FixedBuffer {
+    buf: Buffer<'static>,
+    pos: usize,
+    // The size of the contents
+    end: usize,
+    append: bool,
+}
+impl Backend for FixedBuffer {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let len = buf.len().min(self.end.saturating_sub(self.pos));
+        buf[..len].copy_from_slice(&self.buf[self.pos..self.pos + len]);
+        self.pos += len;
+        Ok(len)
+    }
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.append {
+            self.pos = self.end;
+        }
+        let len = buf.len().min(self.buf.len() - self.pos);
+        if len == 0 && !buf.is_empty() {
+            return error(ENOSPC);
+        }
+        self.buf[self.pos..self.pos + len].copy_from_slice(&buf[..len]);
+        self.pos += len;
+
+        // Keep the contents terminated when they grow, if there's room for it
+        if self.pos > self.end {
+            self.end = self.pos;
+            if self.end < self.buf.len() {
+                self.buf[self.end] = 0;
+            }
+        }
+        Ok(len)
+    }
+    fn seek(&mut self, off: off_t, whence: c_int) -> io::Result<off_t> {
+        let base = match whence {
+            SEEK_SET => 0,
+            SEEK_CUR => self.pos,
+            SEEK_END => self.end,
+            _ => return error(EINVAL),
+        };
+        let pos = base as off_t + off;
+        if pos < 0 || pos > self.buf.len() as off_t {
+            return error(EINVAL);
+        }
+        self.pos = pos as usize;
+        Ok(pos)
+    }
+    fn close(&mut self) -> io::Result<()> {
+        // Free the buffer if it was allocated by fmemopen
+        self.buf = Buffer::Owned(Vec::new());
+        Ok(())
+    }
+}
+
+/// The buffer of open_memstream and open_wmemstream, which grows as needed and is always
+/// terminated by a zero. The caller frees it, so it's allocated with malloc
+struct Growable<T> {
+    bufp: *mut *mut T,
+    sizep: *mut size_t,
+    buf: *mut T,
+    // The number of units allocated, including the terminating zero
+    cap: usize,
+    len: usize,
+    pos: usize,
+}
+impl<T: Copy + Default> Growable<T> {
+    unsafe fn new(bufp: *mut *mut T, sizep: *mut size_t) -> Option<Self> {
+        let buf = platform::alloc(mem::size_of::<T>()) as *mut T;
+        if buf.is_null() {
+            platform::errno = ENOMEM;
+            return None;
+        }
+        *buf = T::default();
+
+        let mut growable = Growable {
+            bufp,
+            sizep,
+            buf,
+            cap: 1,
+            len: 0,
+            pos: 0,
+        };
+        growable.sync();
+        Some(growable)
+    }
+
+    fn write(&mut self, units: &[T]) -> io::Result<()> {
+        let end = self.pos + units.len();
+        if end >= self.cap {
+            let cap = (end + 1).max(self.cap * 2);
+            let buf = unsafe {
+                platform::realloc(self.buf as *mut c_void, cap * mem::size_of::<T>()) as *mut T
+            };
+            if buf.is_null() {
+                return error(ENOMEM);
+            }
+            self.buf = buf;
+            self.cap = cap;
+        }
+
+        unsafe {
+            // Fill the gap left by seeking past the end
+            for i in self.len..self.pos {
+                *self.buf.add(i) = T::default();
+            }
+            ptr::copy_nonoverlapping(units.as_ptr(), self.buf.add(self.pos), units.len());
+            self.pos = end;
+            if end > self.len {
+                self.len = end;
+                *self.buf.add(end) = T::default();
+            }
+        }
+        Ok(())
+    }
+
+    fn seek(&mut self, off: off_t, whence: c_int) -> io::Result<off_t> {
+        let base = match whence {
+            SEEK_SET => 0,
+            SEEK_CUR => self.pos,
+            SEEK_END => self.len,
+            _ => return error(EINVAL),
+        };
+        let pos = base as off_t + off;
+        if pos < 0 {
+            return error(EINVAL);
+        }
+        self.pos = pos as usize;
+        Ok(pos)
+    }
+
+    /// Show the buffer and the size of its contents to the caller
+    fn sync(&mut self) {
+        unsafe {
+            *self.bufp = self.buf;
+            *self.sizep = self.pos.min(self.len);
+        }
+    }
+}
+
+struct MemStream(Growable<u8>);
+impl Backend for MemStream {
+    fn read(&mut self, _buf: &mut [u8]) -> io::Result<usize> {
+        error(EBADF)
+    }
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.write(buf)?;
+        Ok(buf.len())
+    }
+    fn flush(&mut self) -> io::Result<()> {
+        self.0.sync();
+        Ok(())
+    }
+    fn seek(&mut self, off: off_t, whence: c_int) -> io::Result<off_t> {
+        self.0.seek(off, whence)
+    }
+    fn close(&mut self) -> io::Result<()> {
+        self.0.sync();
+        Ok(())
+    }
+}
+
+/// Stores the bytes written as wide characters, and counts positions in wide characters
+struct WMemStream {
+    growable: Growable<wchar_t>,
+    state: mbstate_t,
+}
+impl Backend for WMemStream {
+    fn read(&mut self, _buf: &mut [u8]) -> io::Result<usize> {
+        error(EBADF)
+    }
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let mut i = 0;
+        while i < buf.len() {
+            let mut wc = 0;
+            let ptr = buf[i..].as_ptr() as *const c_char;
+            match unsafe { mbrtowc(&mut wc, ptr, buf.len() - i, &mut self.state) } as isize {
+                // The rest of the character is in the next bytes
+                -2 => break,
+                -1 => return Err(last_os_error()),
+                0 => i += 1,
+                n => i += n as usize,
+            }
+            self.growable.write(&[wc])?;
+        }
+        Ok(buf.len())
+    }
+    fn flush(&mut self) -> io::Result<()> {
+        self.growable.sync();
+        Ok(())
+    }
+    fn seek(&mut self, off: off_t, whence: c_int) -> io::Result<off_t> {
+        self.state = mbstate_t::new();
+        self.growable.seek(off, whence)
+    }
+    fn close(&mut self) -> io::Result<()> {
+        self.growable.sync();
+        Ok(())
+    }
+}
+
+/// Open a stream on the `size` bytes of `buf`, or on a buffer that is freed by fclose if `buf` is
+/// null
+#[no_mangle]
+pub unsafe extern "C" fn fmemopen(
+    buf: *mut c_void,
+    size: size_t,
+    mode: *const c_char,
+) -> *mut FILE {
+    if size == 0 || (*mode != b'r' as c_char && *mode != b'w' as c_char && *mode != b'a' as c_char)
+    {
+        platform::errno = EINVAL;
+        return ptr::null_mut();
+    }
+
+    let mut flags = 0;
+    if strchr(mode, b'+' as c_int).is_null() {
+        flags |= if *mode == b'r' as c_char {
+            F_NOWR
+        } else {
+            F_NORD
+        };
+    }
+
+    let mut buf = if buf.is_null() {
+        Buffer::Owned(vec![0; size])
+    } else {
+        Buffer::Borrowed(slice::from_raw_parts_mut(buf as *mut u8, size))
+    };
+    let (pos, end) = match *mode as u8 {
+        b'r' => (0, size),
+        b'w' => {
+            buf[0] = 0;
+            (0, 0)
+        }
+        _ => {
+            flags |= F_APP;
+            let end = buf.iter().position(|&b| b == 0).unwrap_or(size);
+            (end, end)
+        }
+    };
+
+    let backend = BackendRef::new(FixedBuffer {
+        buf,
+        pos,
+        end,
+        append: flags & F_APP != 0,
+    });
+    helpers::_fopen_backend(backend, flags, _IOFBF)
+}
+
+/// Open a stream writing to a buffer that grows as needed. `*bufp` and `*sizep` are updated by
+/// fflush and fclose, and the caller frees the buffer after fclose
+#[no_mangle]
+pub unsafe extern "C" fn open_memstream(bufp: *mut *mut c_char, sizep: *mut size_t) -> *mut FILE {
+    if bufp.is_null() || sizep.is_null() {
+        platform::errno = EINVAL;
+        return ptr::null_mut();
+    }
+    match Growable::new(bufp as *mut *mut u8, sizep) {
+        Some(growable) => {
+            helpers::_fopen_backend(BackendRef::new(MemStream(growable)), F_NORD, _IOFBF)
+        }
+        None => ptr::null_mut(),
+    }
+}
+
+/// Like open_memstream, with a buffer of wide characters
+pub unsafe fn _open_wmemstream(bufp: *mut *mut wchar_t, sizep: *mut size_t) -> *mut FILE {
+    if bufp.is_null() || sizep.is_null() {
+        platform::errno = EINVAL;
+        return ptr::null_mut();
+    }
+    match Growable::new(bufp, sizep) {
+        Some(growable) => {
+            let backend = BackendRef::new(WMemStream {
+                growable,
+                state: mbstate_t::new(),
+            });
+            helpers::_fopen_backend(backend, F_NORD, _IOFBF)
+        }
+        None => ptr::null_mut(),
+    }
+}
@@ -6,36 +6,56 @@ use alloc::vec::Vec;
 use core::ffi::VaList as va_list;
 use core::fmt::Write as WriteFmt;
 use core::ops::{Deref, DerefMut};
+use core::sync::atomic::Ordering;
 use core::{fmt, mem, ptr, slice, str};
 
 use c_str::CStr;
-use fs::File;
 use header::errno::{self, STR_ERROR};
 use header::string::{self, strlen};
 use header::{fcntl, stdlib, unistd};
-use io::{self, BufRead, LineWriter, Read, Write};
+use io::{self, BufRead, BufWriter, LineWriter, Read, Write};
 use mutex::Mutex;
 use platform;
+use platform::pte::THREADS_CREATED;
 use platform::types::*;
 use platform::{errno, WriteByte};
 use platform::{Pal, Sys};
 
+use self::backend::BackendRef;
+mod backend;
+
 pub use self::constants::*;
 mod constants;
 
+pub use self::cookie::*;
+mod cookie;
+
 pub use self::default::*;
 mod default;
 
 pub use self::getdelim::*;
 mod getdelim;
 
+pub use self::memory::*;
+mod memory;
+
 mod ext;
+mod float;
+pub(crate) mod format;
 mod helpers;
-mod printf;
-mod scanf;
+pub(crate) mod printf;
+pub(crate) mod scanf;
 
 static mut TMPNAM_BUF: [c_char; L_tmpnam as usize + 1] = [0; L_tmpnam as usize + 1];
 
+/// The streams opened by fopen, fdopen and popen, which are not freed yet
+pub(crate) struct OpenFiles(Vec<*mut FILE>);
+// The streams are only used through their own locks
+unsafe impl Send for OpenFiles {}
+
+/// Always lock this before any stream in it, so that walking the streams can't deadlock
+pub(crate) static OPEN_FILES: Mutex<OpenFiles> = Mutex::new(OpenFiles(Vec::new()));
+
 enum Buffer<'a> {
     Borrowed(&'a mut [u8]),
     Owned(Vec<u8>),
@@ -59,20 +79,64 @@ impl<'a> DerefMut for Buffer<'a> {
     }
 }
 
+pub trait Pending {
+    /// The number of bytes that are buffered but not written yet
+    fn pending(&self) -> size_t;
+}
+
+impl<W: Write> Pending for BufWriter<W> {
+    fn pending(&self) -> size_t {
+        self.buf.len() as size_t
+    }
+}
+
+impl<W: Write> Pending for LineWriter<W> {
+    fn pending(&self) -> size_t {
+        self.inner.buf.len() as size_t
+    }
+}
+
+/// The write side of a stream, which buffers according to the mode set by setvbuf
+pub trait Writer: Write + Pending {
+    /// Add `c` to the buffer if that doesn't call for a flush. Returns false if it doesn't, and
+    /// then `c` needs to be written
+    fn buffer_byte(&mut self, c: u8) -> bool;
+}
+
+impl<W: Write> Writer for BufWriter<W> {
+    #[inline]
+    fn buffer_byte(&mut self, c: u8) -> bool {
+        if self.buf.len() >= self.buf.capacity() {
+            return false;
+        }
+        self.buf.push(c);
+        true
+    }
+}
+impl<W: Write> Writer for LineWriter<W> {
+    #[inline]
+    fn buffer_byte(&mut self, c: u8) -> bool {
+        c != b'\n' && self.inner.buffer_byte(c)
+    }
+}
+
 /// This struct gets exposed to the C API.
 pub struct FILE {
     // Can't use spin crate because *_unlocked functions are things in C :(
     lock: Mutex<()>,
 
-    file: File,
+    backend: BackendRef,
     // pub for stdio_ext
     pub(crate) flags: c_int,
     read_buf: Buffer<'static>,
     read_pos: usize,
     read_size: usize,
-    unget: Option<u8>,
+    // The bytes of the character pushed back by ungetc or ungetwc, in reverse
+    unget: Vec<u8>,
     // pub for stdio_ext
-    pub(crate) writer: LineWriter<File>,
+    pub(crate) writer: Box<dyn Writer>,
+    // Negative once used for bytes, positive once used for wide characters, and 0 before
+    orientation: c_int,
 
     // Optional pid for use with popen/pclose
     pid: Option<c_int>,
@@ -81,7 +145,7 @@ pub struct FILE {
 impl Read for FILE {
     fn read(&mut self, out: &mut [u8]) -> io::Result<usize> {
         if !out.is_empty() {
-            if let Some(c) = self.unget.take() {
+            if let Some(c) = self.unget.pop() {
                 out[0] = c;
                 return Ok(1);
             }
@@ -101,7 +165,7 @@ impl Read for FILE {
 impl BufRead for FILE {
     fn fill_buf(&mut self) -> io::Result<&[u8]> {
         if self.read_pos == self.read_size {
-            self.read_size = match self.file.read(&mut self.read_buf) {
+            self.read_size = match self.backend.get().read(&mut self.read_buf) {
                 Ok(0) => {
                     self.flags |= F_EOF;
                     0
@@ -153,15 +217,69 @@ impl WriteByte for FILE {
     }
 }
 impl FILE {
+    /// Lock the stream, unless no thread was created yet so nothing else can use it
     pub fn lock(&mut self) -> LockGuard {
-        unsafe {
-            flockfile(self);
+        let locked = THREADS_CREATED.load(Ordering::Relaxed);
+        if locked {
+            unsafe {
+                flockfile(self);
+            }
         }
-        LockGuard(self)
+        LockGuard(self, locked)
+    }
+
+    /// Read a byte straight from the read buffer, if the stream is oriented for bytes and has
+    /// one buffered and none pushed back
+    #[inline]
+    fn getc_buffered(&mut self) -> Option<u8> {
+        if self.orientation < 0 && self.unget.is_empty() && self.read_pos < self.read_size {
+            let c = self.read_buf[self.read_pos];
+            self.read_pos += 1;
+            Some(c)
+        } else {
+            None
+        }
+    }
+
+    /// Add a byte straight to the write buffer, if the stream is oriented for bytes and the
+    /// buffer has space for it without a flush
+    #[inline]
+    fn putc_buffered(&mut self, c: u8) -> bool {
+        self.orientation < 0 && self.writer.buffer_byte(c)
+    }
+
+    /// Orient the stream for bytes if `mode` is negative or for wide characters if it's
+    /// positive, unless it's oriented already. Returns the orientation, like fwide
+    pub fn try_set_orientation_unlocked(&mut self, mode: c_int) -> c_int {
+        if self.orientation == 0 {
+            self.orientation = mode.signum();
+        }
+        self.orientation
+    }
+
+    /// Orient the stream for bytes, failing with EOF if it's oriented for wide characters
+    pub fn try_set_byte_orientation_unlocked(&mut self) -> Result<(), c_int> {
+        if self.try_set_orientation_unlocked(-1) < 0 {
+            Ok(())
+        } else {
+            Err(EOF)
+        }
+    }
+
+    /// Push back the bytes of one character so that they're read next. Fails if a character
+    /// is pushed back already
+    pub fn unget(&mut self, bytes: &[u8]) -> bool {
+        if !self.unget.is_empty() {
+            return false;
+        }
+        self.unget.extend(bytes.iter().rev());
+        self.flags &= !F_EOF;
+        true
     }
 }
 
-pub struct LockGuard<'a>(&'a mut FILE);
+/// A stream that is locked, if it had to be, until this is dropped
+pub struct LockGuard<'a>(&'a mut FILE, bool);
 impl<'a> Deref for LockGuard<'a> {
     type Target = FILE;
 
@@ -176,8 +294,10 @@ impl<'a> DerefMut for LockGuard<'a> {
 }
 impl<'a> Drop for LockGuard<'a> {
     fn drop(&mut self) {
-        unsafe {
-            funlockfile(self.0);
+        if self.1 {
+            unsafe {
+                funlockfile(self.0);
+            }
         }
     }
 }
@@ -199,6 +319,12 @@ pub extern "C" fn cuserid(_s: *mut c_char) -> *mut c_char {
     unimplemented!();
 }
 
+/// Free a stream without closing its backend
+unsafe fn free(stream: *mut FILE) {
+    OPEN_FILES.lock().0.retain(|&file| file != stream);
+    drop(Box::from_raw(stream));
+}
+
 /// Close a file
 /// This function does not guarentee that the file buffer will be flushed or that the file
 /// descriptor will be closed, so if it is important that the file be written to, use `fflush()`
@@ -206,17 +332,19 @@ pub extern "C" fn cuserid(_s: *mut c_char) -> *mut c_char {
 #[no_mangle]
 pub unsafe extern "C" fn fclose(stream: *mut FILE) -> c_int {
     let stream = &mut *stream;
+    if stream.flags & constants::F_PERM == 0 {
+        let ptr = stream as *mut FILE;
+        OPEN_FILES.lock().0.retain(|&file| file != ptr);
+    }
     flockfile(stream);
 
     let mut r = stream.flush().is_err();
-    let close = Sys::close(*stream.file) < 0;
+    let close = stream.backend.get().close().is_err();
     r = r || close;
 
     if stream.flags & constants::F_PERM == 0 {
         // Not one of stdin, stdout or stderr
-        let mut stream = Box::from_raw(stream);
-        // Reference files aren't closed on drop, so pretend to be a reference
-        stream.file.reference = true;
+        drop(Box::from_raw(stream));
     } else {
         funlockfile(stream);
     }
@@ -224,6 +352,25 @@ pub unsafe extern "C" fn fclose(stream: *mut FILE) -> c_int {
     r as c_int
 }
 
+/// Close all the streams that were opened, and flush stdin, stdout and stderr which are kept open
+#[no_mangle]
+pub unsafe extern "C" fn fcloseall() -> c_int {
+    let mut r = fflush(ptr::null_mut());
+    loop {
+        // Don't hold the list while closing, since fclose removes the stream from it
+        let stream = OPEN_FILES.lock().0.pop();
+        match stream {
+            Some(stream) => {
+                if fclose(stream) != 0 {
+                    r = EOF;
+                }
+            }
+            None => break,
+        }
+    }
+    r
+}
+
 /// Open a file from a file descriptor
 #[no_mangle]
 pub unsafe extern "C" fn fdopen(fildes: c_int, mode: *const c_char) -> *mut FILE {
@@ -237,8 +384,14 @@ pub unsafe extern "C" fn fdopen(fildes: c_int, mode: *const c_char) -> *mut FILE
 /// Check for EOF
 #[no_mangle]
 pub unsafe extern "C" fn feof(stream: *mut FILE) -> c_int {
-    let stream = (*stream).lock();
-    stream.flags & F_EOF
+    let mut stream = (*stream).lock();
+    feof_unlocked(&mut *stream)
+}
+
+/// Check for EOF without locking the stream
+#[no_mangle]
+pub unsafe extern "C" fn feof_unlocked(stream: *mut FILE) -> c_int {
+    (*stream).flags & F_EOF
 }
 
 /// Check for ERR
@@ -254,23 +407,29 @@ pub unsafe extern "C" fn ferror(stream: *mut FILE) -> c_int {
 #[no_mangle]
 pub unsafe extern "C" fn fflush(stream: *mut FILE) -> c_int {
     if stream.is_null() {
-        //TODO: flush all files!
-
-        if fflush(stdout) != 0 {
-            return EOF;
+        // Flush everything, even after an error
+        let mut r = 0;
+        for &file in [stdout, stderr].iter() {
+            if !file.is_null() && fflush(file) != 0 {
+                r = EOF;
+            }
         }
 
-        if fflush(stderr) != 0 {
-            return EOF;
+        let files = OPEN_FILES.lock();
+        for &file in files.0.iter() {
+            let mut file = (*file).lock();
+            if file.flush().is_err() {
+                r = EOF;
+            }
         }
+        r
     } else {
         let mut stream = (*stream).lock();
         if stream.flush().is_err() {
             return EOF;
         }
+        0
     }
-
-    0
 }
 
 /// Get a single char from a stream
@@ -299,21 +458,43 @@ pub unsafe extern "C" fn fgets(
     stream: *mut FILE,
 ) -> *mut c_char {
     let mut stream = (*stream).lock();
+    fgets_unlocked(original, max, &mut *stream)
+}
+
+/// Get a string from the stream without locking the stream
+#[no_mangle]
+pub unsafe extern "C" fn fgets_unlocked(
+    original: *mut c_char,
+    max: c_int,
+    stream: *mut FILE,
+) -> *mut c_char {
+    let stream = &mut *stream;
+    if stream.try_set_byte_orientation_unlocked().is_err() {
+        return ptr::null_mut();
+    }
+
     let mut out = original;
     let max = max as usize;
     let mut left = max.saturating_sub(1); // Make space for the terminating NUL-byte
     let mut wrote = false;
-
-    if left >= 1 {
-        if let Some(c) = stream.unget.take() {
-            *out = c as c_char;
-            out = out.offset(1);
-            left -= 1;
+    let mut newline = false;
+
+    // Start with the character that is pushed back
+    while left >= 1 && !newline {
+        match stream.unget.pop() {
+            Some(c) => {
+                *out = c as c_char;
+                out = out.offset(1);
+                left -= 1;
+                wrote = true;
+                newline = c == b'\n';
+            }
+            None => break,
         }
     }
 
     loop {
-        if left == 0 {
+        if left == 0 || newline {
             break;
         }
 
@@ -362,7 +543,13 @@ pub unsafe extern "C" fn fgets(
 #[no_mangle]
 pub unsafe extern "C" fn fileno(stream: *mut FILE) -> c_int {
     let stream = (*stream).lock();
-    *stream.file
+    match stream.backend.get().fd() {
+        Some(fd) => fd,
+        None => {
+            platform::errno = errno::EBADF;
+            -1
+        }
+    }
 }
 
 /// Lock the file
@@ -417,8 +604,15 @@ pub unsafe extern "C" fn fputc(c: c_int, stream: *mut FILE) -> c_int {
 /// Insert a string into a stream
 #[no_mangle]
 pub unsafe extern "C" fn fputs(s: *const c_char, stream: *mut FILE) -> c_int {
+    let mut stream = (*stream).lock();
+    fputs_unlocked(s, &mut *stream)
+}
+
+/// Insert a string into a stream without locking the stream
+#[no_mangle]
+pub unsafe extern "C" fn fputs_unlocked(s: *const c_char, stream: *mut FILE) -> c_int {
     let len = strlen(s);
-    (fwrite(s as *const c_void, 1, len, stream) == len) as c_int - 1
+    (fwrite_unlocked(s as *const c_void, 1, len, stream) == len) as c_int - 1
 }
 
 /// Read `nitems` of size `size` into `ptr` from `stream`
@@ -428,12 +622,28 @@ pub unsafe extern "C" fn fread(
     size: size_t,
     nitems: size_t,
     stream: *mut FILE,
+) -> size_t {
+    let mut stream = (*stream).lock();
+    fread_unlocked(ptr, size, nitems, &mut *stream)
+}
+
+/// Read `nitems` of size `size` into `ptr` from `stream` without locking `stream`
+#[no_mangle]
+pub unsafe extern "C" fn fread_unlocked(
+    ptr: *mut c_void,
+    size: size_t,
+    nitems: size_t,
+    stream: *mut FILE,
 ) -> size_t {
     if size == 0 || nitems == 0 {
         return 0;
     }
 
-    let mut stream = (*stream).lock();
+    let stream = &mut *stream;
+    if stream.try_set_byte_orientation_unlocked().is_err() {
+        return 0;
+    }
+
     let buf = slice::from_raw_parts_mut(ptr as *mut u8, size as usize * nitems as usize);
     let mut read = 0;
     while read < buf.len() {
@@ -452,42 +662,78 @@ pub unsafe extern "C" fn freopen(
     stream: &mut FILE,
 ) -> *mut FILE {
     let mut flags = helpers::parse_mode_flags(mode);
+    // Open the new file first, since the list of streams is locked before any stream
+    let new = if filename.is_null() {
+        ptr::null_mut()
+    } else {
+        fopen(filename, mode)
+    };
     flockfile(stream);
 
     let _ = stream.flush();
+    stream.read_pos = 0;
+    stream.read_size = 0;
+    stream.unget.clear();
+    stream.orientation = 0;
     if filename.is_null() {
         // Reopen stream in new mode
+        let fd = match stream.backend.get().fd() {
+            Some(fd) => fd,
+            None => {
+                errno = errno::EBADF;
+                funlockfile(stream);
+                fclose(stream);
+                return ptr::null_mut();
+            }
+        };
         if flags & fcntl::O_CLOEXEC > 0 {
-            fcntl::sys_fcntl(*stream.file, fcntl::F_SETFD, fcntl::FD_CLOEXEC);
+            fcntl::sys_fcntl(fd, fcntl::F_SETFD, fcntl::FD_CLOEXEC);
         }
         flags &= !(fcntl::O_CREAT | fcntl::O_EXCL | fcntl::O_CLOEXEC);
-        if fcntl::sys_fcntl(*stream.file, fcntl::F_SETFL, flags) < 0 {
+        if fcntl::sys_fcntl(fd, fcntl::F_SETFL, flags) < 0 {
             funlockfile(stream);
             fclose(stream);
             return ptr::null_mut();
         }
+        funlockfile(stream);
     } else {
-        let new = fopen(filename, mode);
         if new.is_null() {
             funlockfile(stream);
             fclose(stream);
             return ptr::null_mut();
         }
         let new = &mut *new; // Should be safe, new is not null
-        if *new.file == *stream.file {
-            new.file.fd = -1;
-        } else if Sys::dup2(*new.file, *stream.file) < 0
-            || fcntl::sys_fcntl(*stream.file, fcntl::F_SETFL, flags & fcntl::O_CLOEXEC) < 0
-        {
-            funlockfile(stream);
+        let new_fd = new.backend.get().fd();
+        let close_new = match stream.backend.get().fd() {
+            // The descriptor was reused, so it must stay open
+            Some(fd) if Some(fd) == new_fd => false,
+            Some(fd) => {
+                if Sys::dup2(new_fd.unwrap(), fd) < 0
+                    || fcntl::sys_fcntl(fd, fcntl::F_SETFL, flags & fcntl::O_CLOEXEC) < 0
+                {
+                    funlockfile(stream);
+                    fclose(new);
+                    fclose(stream);
+                    return ptr::null_mut();
+                }
+                true
+            }
+            None => {
+                // There is no descriptor to replace, so take the backend of the new stream
+                let _ = stream.backend.get().close();
+                mem::swap(&mut stream.backend, &mut new.backend);
+                mem::swap(&mut stream.writer, &mut new.writer);
+                false
+            }
+        };
+        stream.flags = (stream.flags & constants::F_PERM) | new.flags;
+        funlockfile(stream);
+        if close_new {
             fclose(new);
-            fclose(stream);
-            return ptr::null_mut();
+        } else {
+            free(new);
         }
-        stream.flags = (stream.flags & constants::F_PERM) | new.flags;
-        fclose(new);
     }
-    funlockfile(stream);
     stream
 }
 
@@ -505,7 +751,7 @@ pub unsafe extern "C" fn fseeko(stream: *mut FILE, mut off: off_t, whence: c_int
     if whence == SEEK_CUR {
         // Since it's a buffered writer, our actual cursor isn't where the user
         // thinks
-        off -= (stream.read_size - stream.read_pos) as off_t;
+        off -= (stream.read_size - stream.read_pos + stream.unget.len()) as off_t;
     }
 
     // Flush write buffer before seek
@@ -513,15 +759,14 @@ pub unsafe extern "C" fn fseeko(stream: *mut FILE, mut off: off_t, whence: c_int
         return -1;
     }
 
-    let err = Sys::lseek(*stream.file, off, whence);
-    if err < 0 {
-        return err as c_int;
+    if stream.backend.get().seek(off, whence).is_err() {
+        return -1;
     }
 
     stream.flags &= !(F_EOF | F_ERR);
     stream.read_pos = 0;
     stream.read_size = 0;
-    stream.unget = None;
+    stream.unget.clear();
     0
 }
 
@@ -541,12 +786,14 @@ pub unsafe extern "C" fn ftell(stream: *mut FILE) -> c_long {
 #[no_mangle]
 pub unsafe extern "C" fn ftello(stream: *mut FILE) -> off_t {
     let stream = (*stream).lock();
-    let pos = Sys::lseek(*stream.file, 0, SEEK_CUR);
-    if pos < 0 {
-        return -1;
-    }
+    let pos = match stream.backend.get().seek(0, SEEK_CUR) {
+        Ok(pos) => pos,
+        Err(_) => return -1,
+    };
 
-    pos - (stream.read_size - stream.read_pos) as off_t
+    // Count the bytes that are buffered, both for reading and for writing, and those pushed back
+    pos - (stream.read_size - stream.read_pos) as off_t - stream.unget.len() as off_t
+        + stream.writer.pending() as off_t
 }
 
 /// Try to lock the file. Returns 0 for success, 1 for failure
@@ -572,11 +819,27 @@ pub unsafe extern "C" fn fwrite(
     size: size_t,
     nitems: size_t,
     stream: *mut FILE,
+) -> size_t {
+    let mut stream = (*stream).lock();
+    fwrite_unlocked(ptr, size, nitems, &mut *stream)
+}
+
+/// Write `nitems` of size `size` from `ptr` to `stream` without locking `stream`
+#[no_mangle]
+pub unsafe extern "C" fn fwrite_unlocked(
+    ptr: *const c_void,
+    size: size_t,
+    nitems: size_t,
+    stream: *mut FILE,
 ) -> size_t {
     if size == 0 || nitems == 0 {
         return 0;
     }
-    let mut stream = (*stream).lock();
+    let stream = &mut *stream;
+    if stream.try_set_byte_orientation_unlocked().is_err() {
+        return 0;
+    }
+
     let buf = slice::from_raw_parts_mut(ptr as *mut u8, size as usize * nitems as usize);
     let mut written = 0;
     while written < buf.len() {
@@ -603,7 +866,15 @@ pub unsafe extern "C" fn getchar() -> c_int {
 
 /// Get a char from a stream without locking the stream
 #[no_mangle]
+#[inline]
 pub unsafe extern "C" fn getc_unlocked(stream: *mut FILE) -> c_int {
+    if let Some(c) = (*stream).getc_buffered() {
+        return c as c_int;
+    }
+    if let Err(eof) = (*stream).try_set_byte_orientation_unlocked() {
+        return eof;
+    }
+
     let mut buf = [0];
 
     match (*stream).read(&mut buf) {
@@ -744,7 +1015,8 @@ pub unsafe extern "C" fn popen(command: *const c_char, mode: *const c_char) -> *
 
         unistd::execv(shell as *const c_char, args.as_ptr() as *const *mut c_char);
 
-        stdlib::exit(127);
+        // Don't flush the streams copied from the parent
+        unistd::_exit(127);
 
         unreachable!();
     } else if child_pid > 0 {
@@ -782,10 +1054,18 @@ pub unsafe extern "C" fn putchar(c: c_int) -> c_int {
 
 /// Put a character `c` into `stream` without locking `stream`
 #[no_mangle]
+#[inline]
 pub unsafe extern "C" fn putc_unlocked(c: c_int, stream: *mut FILE) -> c_int {
+    if (*stream).putc_buffered(c as u8) {
+        return c as u8 as c_int;
+    }
+    if let Err(eof) = (*stream).try_set_byte_orientation_unlocked() {
+        return eof;
+    }
+
     match (*stream).write(&[c as u8]) {
         Ok(0) | Err(_) => EOF,
-        Ok(_) => c,
+        Ok(_) => c as u8 as c_int,
     }
 }
 
@@ -840,15 +1120,27 @@ pub unsafe extern "C" fn rewind(stream: *mut FILE) {
 /// Reset `stream` to use buffer `buf`. Buffer must be `BUFSIZ` in length
 #[no_mangle]
 pub unsafe extern "C" fn setbuf(stream: *mut FILE, buf: *mut c_char) {
+    setbuffer(stream, buf, BUFSIZ as size_t);
+}
+
+/// Reset `stream` to use buffer `buf` of size `size`, or no buffer if `buf` is null
+#[no_mangle]
+pub unsafe extern "C" fn setbuffer(stream: *mut FILE, buf: *mut c_char, size: size_t) {
     setvbuf(
         stream,
         buf,
         if buf.is_null() { _IONBF } else { _IOFBF },
-        BUFSIZ as usize,
+        size,
     );
 }
 
-/// Reset `stream` to use buffer `buf` of size `size`
+/// Make `stream` line buffered
+#[no_mangle]
+pub unsafe extern "C" fn setlinebuf(stream: *mut FILE) {
+    setvbuf(stream, ptr::null_mut(), _IOLBF, 0);
+}
+
+/// Reset `stream` to use buffer `buf` of size `size`, with the buffering `mode`
 /// If this isn't the meaning of unsafe, idk what is
 #[no_mangle]
 pub unsafe extern "C" fn setvbuf(
@@ -857,20 +1149,27 @@ pub unsafe extern "C" fn setvbuf(
     mode: c_int,
     mut size: size_t,
 ) -> c_int {
+    if mode != _IOFBF && mode != _IOLBF && mode != _IONBF {
+        platform::errno = errno::EINVAL;
+        return -1;
+    }
+
     let mut stream = (*stream).lock();
     // Set a buffer of size `size` if no buffer is given
     stream.read_buf = if buf.is_null() || size == 0 {
         if size == 0 {
             size = BUFSIZ as usize;
         }
-        // TODO: Make it unbuffered if _IONBF
-        // if mode == _IONBF {
-        // } else {
         Buffer::Owned(vec![0; size as usize])
-    // }
     } else {
         Buffer::Borrowed(slice::from_raw_parts_mut(buf as *mut u8, size))
     };
+
+    // The write side keeps its own buffer of the same size, since `buf` is used for reading
+    if stream.flush().is_err() {
+        return -1;
+    }
+    stream.writer = helpers::writer(&stream.backend, mode, size);
     stream.flags |= F_SVB;
     0
 }
@@ -974,17 +1273,27 @@ unsafe extern "C" fn tmpnam_inner(buf: *mut c_char, offset: usize) -> *mut c_cha
 #[no_mangle]
 pub unsafe extern "C" fn ungetc(c: c_int, stream: *mut FILE) -> c_int {
     let mut stream = (*stream).lock();
-    if stream.unget.is_some() {
+    if let Err(eof) = stream.try_set_byte_orientation_unlocked() {
+        return eof;
+    }
+
+    if c == EOF {
+        return EOF;
+    }
+    if !stream.unget(&[c as u8]) {
         platform::errno = errno::EIO;
         return EOF;
     }
-    stream.unget = Some(c as u8);
-    c
+    c as u8 as c_int
 }
 
 #[no_mangle]
 pub unsafe extern "C" fn vfprintf(file: *mut FILE, format: *const c_char, ap: va_list) -> c_int {
     let mut file = (*file).lock();
+    if file.try_set_byte_orientation_unlocked().is_err() {
+        return -1;
+    }
+
     printf::printf(&mut *file, format, ap)
 }
 
@@ -1005,6 +1314,11 @@ pub unsafe extern "C" fn vasprintf(
     ret
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn vdprintf(fd: c_int, format: *const c_char, ap: va_list) -> c_int {
+    printf::dprintf(fd, format, ap)
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn vsnprintf(
     s: *mut c_char,
@@ -1027,7 +1341,11 @@ pub unsafe extern "C" fn vsprintf(s: *mut c_char, format: *const c_char, ap: va_
 #[no_mangle]
 pub unsafe extern "C" fn vfscanf(file: *mut FILE, format: *const c_char, ap: va_list) -> c_int {
     let mut file = (*file).lock();
-    scanf::scanf(&mut *file, format, ap)
+    if let Err(eof) = file.try_set_byte_orientation_unlocked() {
+        return eof;
+    }
+
+    scanf::fscanf(&mut *file, format, ap)
 }
 
 #[no_mangle]
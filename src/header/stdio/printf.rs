@@ -1,15 +1,17 @@
-use alloc::collections::BTreeMap;
-use alloc::string::String;
-use alloc::string::ToString;
-use alloc::vec::Vec;
 use core::ffi::VaList;
 use core::ops::Range;
-use core::{fmt, slice};
+use core::slice;
+use fs::File;
+use header::limits::NL_ARGMAX;
+use header::locale;
+use header::string::strerror;
 use io::{self, Write};
-
 use platform;
 use platform::types::*;
 
+use super::float::{Decimal, Float, Kind, LongDouble};
+use super::format::{ascii, wide_len, write_wide, FormatChar};
+
 //  ____        _ _                 _       _
 // | __ )  ___ (_) | ___ _ __ _ __ | | __ _| |_ ___ _
 // |  _ \ / _ \| | |/ _ \ '__| '_ \| |/ _` | __/ _ (_)
@@ -38,11 +40,14 @@ enum FmtKind {
     Scientific,
     Decimal,
     AnyNotation,
+    HexFloat,
 
     String,
     Char,
     Pointer,
     GetWritten,
+    // %m, the message of errno
+    Error,
 }
 #[derive(Clone, Copy, Debug)]
 enum Number {
@@ -51,6 +56,14 @@ enum Number {
     Next,
 }
 impl Number {
+    /// Positional arguments count from 1, up to NL_ARGMAX
+    fn valid(self) -> bool {
+        match self {
+            Number::Index(i) => i >= 1 && i <= NL_ARGMAX,
+            _ => true,
+        }
+    }
+
     unsafe fn resolve(&self, varargs: &mut VaListCache, ap: &mut VaList) -> usize {
         let arg = match *self {
             Number::Static(num) => return num,
@@ -69,6 +82,7 @@ impl Number {
             VaArg::c_longlong(i) => i as usize,
             VaArg::c_short(i) => i as usize,
             VaArg::intmax_t(i) => i as usize,
+            VaArg::long_double(i) => i.0 as usize,
             VaArg::pointer(i) => i as usize,
             VaArg::ptrdiff_t(i) => i as usize,
             VaArg::ssize_t(i) => i as usize,
@@ -84,12 +98,13 @@ enum VaArg {
     c_longlong(c_longlong),
     c_short(c_short),
     intmax_t(intmax_t),
+    long_double(LongDouble),
     pointer(*const c_void),
     ptrdiff_t(ptrdiff_t),
     ssize_t(ssize_t),
 }
 impl VaArg {
-    unsafe fn arg_from(arg: &PrintfArg, ap: &mut VaList) -> VaArg {
+    unsafe fn arg_from(kind: (FmtKind, IntKind), ap: &mut VaList) -> VaArg {
         // Per the C standard using va_arg with a type with a size
         // less than that of an int for integers and double for floats
         // is invalid. As a result any arguments smaller than an int or
@@ -97,9 +112,10 @@ impl VaArg {
         // possible size. The VaList::arg function will handle this
         // automagically.
 
-        match (arg.fmtkind, arg.intkind) {
-            (FmtKind::Percent, _) => panic!("Can't call arg_from on %"),
+        match kind {
+            (FmtKind::Percent, _) | (FmtKind::Error, _) => panic!("Can't call arg_from on %"),
 
+            (FmtKind::Char, IntKind::Long) => VaArg::c_int(ap.arg::<wint_t>() as c_int),
             (FmtKind::Char, _)
             | (FmtKind::Unsigned, IntKind::Byte)
             | (FmtKind::Signed, IntKind::Byte) => VaArg::c_char(ap.arg::<c_char>()),
@@ -125,16 +141,21 @@ impl VaArg {
                 VaArg::ssize_t(ap.arg::<ssize_t>())
             }
 
-            (FmtKind::AnyNotation, _) | (FmtKind::Decimal, _) | (FmtKind::Scientific, _) => {
-                VaArg::c_double(ap.arg::<c_double>())
-            }
+            (FmtKind::AnyNotation, IntKind::LongLong)
+            | (FmtKind::Decimal, IntKind::LongLong)
+            | (FmtKind::Scientific, IntKind::LongLong)
+            | (FmtKind::HexFloat, IntKind::LongLong) => VaArg::long_double(LongDouble::arg(ap)),
+            (FmtKind::AnyNotation, _)
+            | (FmtKind::Decimal, _)
+            | (FmtKind::Scientific, _)
+            | (FmtKind::HexFloat, _) => VaArg::c_double(ap.arg::<c_double>()),
 
             (FmtKind::GetWritten, _) | (FmtKind::Pointer, _) | (FmtKind::String, _) => {
                 VaArg::pointer(ap.arg::<*const c_void>())
             }
         }
     }
-    unsafe fn transmute(&self, arg: &PrintfArg) -> VaArg {
+    unsafe fn transmute(&self, kind: (FmtKind, IntKind)) -> VaArg {
         // At this point, there are conflicting printf arguments. An
         // example of this is:
         // ```c
@@ -151,6 +172,7 @@ impl VaArg {
             c_longlong: c_longlong,
             c_short: c_short,
             intmax_t: intmax_t,
+            long_double: LongDouble,
             pointer: *const c_void,
             ptrdiff_t: ptrdiff_t,
             ssize_t: ssize_t,
@@ -163,13 +185,15 @@ impl VaArg {
             VaArg::c_longlong(i) => Untyped { c_longlong: i },
             VaArg::c_short(i) => Untyped { c_short: i },
             VaArg::intmax_t(i) => Untyped { intmax_t: i },
+            VaArg::long_double(i) => Untyped { long_double: i },
             VaArg::pointer(i) => Untyped { pointer: i },
             VaArg::ptrdiff_t(i) => Untyped { ptrdiff_t: i },
             VaArg::ssize_t(i) => Untyped { ssize_t: i },
         };
-        match (arg.fmtkind, arg.intkind) {
-            (FmtKind::Percent, _) => panic!("Can't call transmute on %"),
+        match kind {
+            (FmtKind::Percent, _) | (FmtKind::Error, _) => panic!("Can't call transmute on %"),
 
+            (FmtKind::Char, IntKind::Long) => VaArg::c_int(untyped.c_int),
             (FmtKind::Char, _)
             | (FmtKind::Unsigned, IntKind::Byte)
             | (FmtKind::Signed, IntKind::Byte) => VaArg::c_char(untyped.c_char),
@@ -195,41 +219,102 @@ impl VaArg {
                 VaArg::ssize_t(untyped.ssize_t)
             }
 
-            (FmtKind::AnyNotation, _) | (FmtKind::Decimal, _) | (FmtKind::Scientific, _) => {
-                VaArg::c_double(untyped.c_double)
-            }
+            (FmtKind::AnyNotation, IntKind::LongLong)
+            | (FmtKind::Decimal, IntKind::LongLong)
+            | (FmtKind::Scientific, IntKind::LongLong)
+            | (FmtKind::HexFloat, IntKind::LongLong) => VaArg::long_double(untyped.long_double),
+            (FmtKind::AnyNotation, _)
+            | (FmtKind::Decimal, _)
+            | (FmtKind::Scientific, _)
+            | (FmtKind::HexFloat, _) => VaArg::c_double(untyped.c_double),
 
             (FmtKind::GetWritten, _) | (FmtKind::Pointer, _) | (FmtKind::String, _) => {
                 VaArg::pointer(untyped.pointer)
             }
         }
     }
+
+    /// The value of an integer argument of %d or %i
+    fn signed(self) -> i64 {
+        match self {
+            VaArg::c_char(i) => i as i8 as i64,
+            VaArg::c_int(i) => i as i64,
+            VaArg::c_long(i) => i as i64,
+            VaArg::c_longlong(i) => i as i64,
+            VaArg::c_short(i) => i as i64,
+            VaArg::intmax_t(i) => i as i64,
+            VaArg::pointer(i) => i as isize as i64,
+            VaArg::ptrdiff_t(i) => i as i64,
+            VaArg::ssize_t(i) => i as i64,
+            VaArg::c_double(_) | VaArg::long_double(_) => panic!("this should not be possible"),
+        }
+    }
+
+    /// The value of an integer argument of %o, %u, %x or %X
+    fn unsigned(self) -> u64 {
+        match self {
+            VaArg::c_char(i) => i as c_uchar as u64,
+            VaArg::c_int(i) => i as c_uint as u64,
+            VaArg::c_long(i) => i as c_ulong as u64,
+            VaArg::c_longlong(i) => i as c_ulonglong as u64,
+            VaArg::c_short(i) => i as c_ushort as u64,
+            VaArg::intmax_t(i) => i as uintmax_t as u64,
+            VaArg::pointer(i) => i as usize as u64,
+            VaArg::ptrdiff_t(i) => i as size_t as u64,
+            VaArg::ssize_t(i) => i as size_t as u64,
+            VaArg::c_double(_) | VaArg::long_double(_) => panic!("this should not be possible"),
+        }
+    }
 }
-#[derive(Default)]
+/// The arguments of a format with positional arguments, which may use them in any order and more
+/// than once. Other formats use each argument once and in order, so their arguments are read
+/// from the VaList as they come, and none are kept
 struct VaListCache {
-    args: Vec<VaArg>,
+    args: [VaArg; NL_ARGMAX],
+    len: usize,
     i: usize,
+    positional: bool,
 }
 impl VaListCache {
-    unsafe fn get(&mut self, i: usize, ap: &mut VaList, default: Option<&PrintfArg>) -> VaArg {
-        if let Some(&arg) = self.args.get(i) {
-            let mut arg = arg;
+    fn new(positional: bool) -> Self {
+        VaListCache {
+            args: [VaArg::c_int(0); NL_ARGMAX],
+            len: 0,
+            i: 0,
+            positional,
+        }
+    }
+
+    unsafe fn get(
+        &mut self,
+        i: usize,
+        ap: &mut VaList,
+        default: Option<(FmtKind, IntKind)>,
+    ) -> VaArg {
+        let read = |ap: &mut VaList| match default {
+            Some(default) => VaArg::arg_from(default, ap),
+            None => VaArg::c_int(ap.arg::<c_int>()),
+        };
+        if !self.positional || i >= NL_ARGMAX {
+            return read(ap);
+        }
+        if i < self.len {
+            let mut arg = self.args[i];
             if let Some(default) = default {
                 arg = arg.transmute(default);
             }
             return arg;
         }
-        while self.args.len() < i {
+        while self.len < i {
             // We can't POSSIBLY know the type if we reach this
             // point. Reaching here means there are unused gaps in the
             // arguments. Ultimately we'll have to settle down with
             // defaulting to c_int.
-            self.args.push(VaArg::c_int(ap.arg::<c_int>()))
+            self.args[self.len] = VaArg::c_int(ap.arg::<c_int>());
+            self.len += 1;
         }
-        self.args.push(match default {
-            Some(default) => VaArg::arg_from(default, ap),
-            None => VaArg::c_int(ap.arg::<c_int>()),
-        });
+        self.args[i] = read(ap);
+        self.len += 1;
         self.args[i]
     }
 }
@@ -241,9 +326,9 @@ impl VaListCache {
 // |___|_| |_| |_| .__/|_|\___|_| |_| |_|\___|_| |_|\__\__,_|\__|_|\___/|_| |_(_)
 //               |_|
 
-unsafe fn pop_int_raw(format: &mut *const u8) -> Option<usize> {
+unsafe fn pop_int_raw<F: FormatChar>(format: &mut *const F) -> Option<usize> {
     let mut int = None;
-    while let Some(digit) = (**format as char).to_digit(10) {
+    while let Some(digit) = (ascii((**format).code()) as char).to_digit(10) {
         *format = format.add(1);
         if int.is_none() {
             int = Some(0);
@@ -253,19 +338,19 @@ unsafe fn pop_int_raw(format: &mut *const u8) -> Option<usize> {
     }
     int
 }
-unsafe fn pop_index(format: &mut *const u8) -> Option<usize> {
+unsafe fn pop_index<F: FormatChar>(format: &mut *const F) -> Option<usize> {
     // Peek ahead for a positional argument:
     let mut format2 = *format;
     if let Some(i) = pop_int_raw(&mut format2) {
-        if *format2 == b'$' {
+        if (*format2).code() == b'$' as u32 {
             *format = format2.add(1);
             return Some(i);
         }
     }
     None
 }
-unsafe fn pop_int(format: &mut *const u8) -> Option<Number> {
-    if **format == b'*' {
+unsafe fn pop_int<F: FormatChar>(format: &mut *const F) -> Option<Number> {
+    if (**format).code() == b'*' as u32 {
         *format = format.add(1);
         Some(pop_index(format).map(Number::Index).unwrap_or(Number::Next))
     } else {
@@ -273,20 +358,25 @@ unsafe fn pop_int(format: &mut *const u8) -> Option<Number> {
     }
 }
 
-unsafe fn fmt_int<I>(fmt: u8, i: I) -> String
-where
-    I: fmt::Display + fmt::Octal + fmt::LowerHex + fmt::UpperHex,
-{
-    match fmt {
-        b'o' => format!("{:o}", i),
-        b'u' => i.to_string(),
-        b'x' => format!("{:x}", i),
-        b'X' => format!("{:X}", i),
-        _ => panic!(
-            "fmt_int should never be called with the fmt {:?}",
-            fmt as char
-        ),
+/// Write the digits of `i` in the base of `fmt` to the end of `buf`, which fits the octal digits
+/// of the largest integer
+fn fmt_int(buf: &mut [u8; 22], fmt: u8, mut i: u64) -> &[u8] {
+    let (base, digits): (u64, &[u8; 16]) = match fmt {
+        b'o' => (8, b"0123456789abcdef"),
+        b'x' | b'p' => (16, b"0123456789abcdef"),
+        b'X' => (16, b"0123456789ABCDEF"),
+        _ => (10, b"0123456789abcdef"),
+    };
+    let mut start = buf.len();
+    loop {
+        start -= 1;
+        buf[start] = digits[(i % base) as usize];
+        i /= base;
+        if i == 0 {
+            break;
+        }
     }
+    &buf[start..]
 }
 
 fn pad<W: Write>(
@@ -296,110 +386,444 @@ fn pad<W: Write>(
     range: Range<usize>,
 ) -> io::Result<()> {
     if current_side {
-        for _ in range {
-            w.write_all(&[pad_char])?;
+        let chunk = [pad_char; 32];
+        let mut len = range.end.saturating_sub(range.start);
+        while len > 0 {
+            let n = len.min(chunk.len());
+            w.write_all(&chunk[..n])?;
+            len -= n;
         }
     }
     Ok(())
 }
 
-fn float_string(float: c_double, precision: usize, trim: bool) -> String {
-    let mut string = format!("{:.p$}", float, p = precision);
-    if trim {
-        let truncate = {
-            let slice = string.trim_end_matches('0');
-            if slice.ends_with('.') {
-                slice.len() - 1
-            } else {
-                slice.len()
+/// The thousands separator and the sizes of the groups of digits it separates, for the ' flag
+#[derive(Clone, Copy)]
+struct Grouping {
+    sep: &'static str,
+    sizes: &'static [u8],
+}
+impl Grouping {
+    /// The grouping of `LC_NUMERIC`, or no grouping without the ' flag
+    fn new(group: bool) -> Self {
+        let numeric = &locale::current().numeric;
+        if group {
+            Grouping {
+                sep: numeric.thousands_sep.as_str(),
+                sizes: numeric.grouping.as_str().as_bytes(),
             }
-        };
-        string.truncate(truncate);
+        } else {
+            Grouping {
+                sep: "",
+                sizes: &[],
+            }
+        }
+    }
+
+    /// Whether a separator goes after the digit of 10^pos. The sizes go from the right, the last
+    /// one repeats and CHAR_MAX ends the grouping
+    fn after(self, pos: usize) -> bool {
+        let mut boundary = 0;
+        let mut last = 0;
+        for &size in self.sizes {
+            if size == 0 || size as c_char == c_char::max_value() {
+                return false;
+            }
+            boundary += size as usize;
+            if pos <= boundary {
+                return pos == boundary;
+            }
+            last = size as usize;
+        }
+        last > 0 && (pos - boundary) % last == 0
+    }
+
+    /// The length of the separators of `digits` digits
+    fn len(self, digits: usize) -> usize {
+        if self.sep.is_empty() {
+            return 0;
+        }
+        (1..digits).filter(|&pos| self.after(pos)).count() * self.sep.len()
+    }
+
+    /// Write the digits of 10^from down to 1 with `digits`, which writes the digits of a range of
+    /// powers, and the separators between them
+    fn write<W: Write, D: FnMut(&mut W, usize, usize) -> io::Result<()>>(
+        self,
+        w: &mut W,
+        from: usize,
+        mut digits: D,
+    ) -> io::Result<()> {
+        let mut start = from;
+        if !self.sep.is_empty() {
+            for pos in (1..=from).rev() {
+                if self.after(pos) {
+                    digits(w, start, pos)?;
+                    w.write_all(self.sep.as_bytes())?;
+                    start = pos - 1;
+                }
+            }
+        }
+        digits(w, start, 0)
     }
-    string
 }
 
-fn fmt_float_exp<W: Write>(
+/// Write a number with its sign and prefix, padded to `width` by spaces or, after the prefix, by
+/// zeros. `len` is the length of the rest, which `digits` writes
+fn pad_number<W: Write, D: FnOnce(&mut W) -> io::Result<()>>(
     w: &mut W,
-    exp_fmt: u8,
-    range: Option<(isize, isize)>,
-    trim: bool,
-    precision: usize,
-    mut float: c_double,
+    sign: &[u8],
+    prefix: &[u8],
+    len: usize,
+    width: usize,
     left: bool,
-    pad_space: usize,
-    pad_zero: usize,
-) -> io::Result<bool> {
-    let mut exp: isize = 0;
-    while float >= 10.0 || float <= -10.0 {
-        float /= 10.0;
-        exp += 1;
+    zero: bool,
+    digits: D,
+) -> io::Result<()> {
+    let len = sign.len() + prefix.len() + len;
+    pad(w, !left && !zero, b' ', len..width)?;
+    w.write_all(sign)?;
+    w.write_all(prefix)?;
+    pad(w, !left && zero, b'0', len..width)?;
+    digits(w)?;
+    pad(w, left, b' ', len..width)
+}
+
+/// Write an integer of %d, %i, %o, %u, %x or %X, with its sign, its prefix and the zeros of its
+/// precision
+fn fmt_integer<W: Write>(
+    w: &mut W,
+    fmt: u8,
+    negative: bool,
+    i: u64,
+    precision: Option<usize>,
+    alternate: bool,
+    left: bool,
+    sign_reserve: bool,
+    sign_always: bool,
+    grouping: Grouping,
+    width: usize,
+    zero: bool,
+) -> io::Result<()> {
+    let mut buf = [0; 22];
+    let digits = if precision == Some(0) && i == 0 {
+        // A precision of zero writes no digits for zero
+        &[][..]
+    } else {
+        fmt_int(&mut buf, fmt, i)
+    };
+
+    let sign: &[u8] = if negative {
+        b"-"
+    } else if sign_always {
+        b"+"
+    } else if sign_reserve {
+        b" "
+    } else {
+        b""
+    };
+    let prefix: &[u8] = match fmt {
+        b'x' if alternate && i != 0 => b"0x",
+        b'X' if alternate && i != 0 => b"0X",
+        _ => b"",
+    };
+    let mut zeros = precision.map_or(0, |precision| precision.saturating_sub(digits.len()));
+    // The # of octal makes the first digit a zero
+    if fmt == b'o' && alternate && zeros == 0 && digits.first() != Some(&b'0') {
+        zeros = 1;
     }
-    while (float > 0.0 && float < 1.0) || (float > -1.0 && float < 0.0) {
-        float *= 10.0;
-        exp -= 1;
+
+    let len = zeros + digits.len() + grouping.len(digits.len());
+    // The 0 flag is ignored with a precision
+    pad_number(
+        w,
+        sign,
+        prefix,
+        len,
+        width,
+        left,
+        zero && precision.is_none(),
+        |w| {
+            pad(w, true, b'0', 0..zeros)?;
+            if digits.is_empty() {
+                return Ok(());
+            }
+            grouping.write(w, digits.len() - 1, |w, from, to| {
+                w.write_all(&digits[digits.len() - 1 - from..digits.len() - to])
+            })
+        },
+    )
+}
+
+/// The length of an exponent of at least `min` digits, with its sign
+fn exp_len(exp: i32, min: usize) -> usize {
+    let mut exp = exp.abs();
+    let mut len = 1;
+    while exp >= 10 {
+        exp /= 10;
+        len += 1;
     }
+    1 + len.max(min)
+}
 
-    if range
-        .map(|(start, end)| exp >= start && exp < end)
-        .unwrap_or(false)
-    {
-        return Ok(false);
+/// Write the hexadecimal digits of %a, rounding to the nearest with ties to even when there is a
+/// precision and exact otherwise
+fn fmt_float_hex<W: Write>(
+    w: &mut W,
+    fmt: u8,
+    float: Float,
+    mantissa: u128,
+    exp: i32,
+    precision: Option<usize>,
+    alternate: bool,
+    left: bool,
+    sign: &[u8],
+    width: usize,
+    zero: bool,
+) -> io::Result<()> {
+    let hex: &[u8; 16] = if fmt == b'A' {
+        b"0123456789ABCDEF"
+    } else {
+        b"0123456789abcdef"
+    };
+
+    // The leading bit is the digit before the point, and the others are padded to whole digits
+    let bits = float.digits as usize - 1;
+    let mut lead = (mantissa >> bits) as u8;
+    let mut nibbles = (bits + 3) / 4;
+    let mut frac = (mantissa & (1 << bits) - 1) << (nibbles * 4 - bits);
+    let exp = if mantissa == 0 { 0 } else { exp + bits as i32 };
+
+    match precision {
+        Some(precision) if precision < nibbles => {
+            let drop = (nibbles - precision) * 4;
+            let rem = frac & (1 << drop) - 1;
+            let half = 1 << (drop - 1);
+            frac >>= drop;
+            let odd = if precision > 0 {
+                frac & 1 != 0
+            } else {
+                lead & 1 != 0
+            };
+            if rem > half || (rem == half && odd) {
+                frac += 1;
+                if frac >> (precision * 4) != 0 {
+                    frac = 0;
+                    lead += 1;
+                }
+            }
+            nibbles = precision;
+        }
+        Some(_) => (),
+        None => {
+            while nibbles > 0 && frac & 0xf == 0 {
+                frac >>= 4;
+                nibbles -= 1;
+            }
+        }
     }
+    let zeros = precision.unwrap_or(0).saturating_sub(nibbles);
+    let point = nibbles + zeros > 0 || alternate;
+
+    let prefix: &[u8] = if fmt == b'A' { b"0X" } else { b"0x" };
+    let len = 1 + point as usize + nibbles + zeros + 1 + exp_len(exp, 1);
+    pad_number(w, sign, prefix, len, width, left, zero, |w| {
+        w.write_all(&[hex[lead as usize]])?;
+        if point {
+            w.write_all(b".")?;
+        }
+        for i in (0..nibbles).rev() {
+            w.write_all(&[hex[(frac >> (i * 4)) as usize & 0xf]])?;
+        }
+        pad(w, true, b'0', 0..zeros)?;
+        write!(w, "{}{:+}", (b'P' | (fmt & 32)) as char, exp)
+    })
+}
 
-    let mut exp2 = exp;
-    let mut exp_len = 1;
-    while exp2 >= 10 {
-        exp2 /= 10;
-        exp_len += 1;
+/// Write a float for %e, %f, %g or %a. Decimal digits are rounded from the exact value, to the
+/// nearest with ties to even
+fn fmt_float<W: Write>(
+    w: &mut W,
+    fmt: u8,
+    float: Float,
+    precision: Option<usize>,
+    alternate: bool,
+    left: bool,
+    sign_reserve: bool,
+    sign_always: bool,
+    grouping: Grouping,
+    width: usize,
+    zero: bool,
+) -> io::Result<()> {
+    let upper = fmt & 32 == 0;
+    let sign: &[u8] = if float.negative {
+        b"-"
+    } else if sign_always {
+        b"+"
+    } else if sign_reserve {
+        b" "
+    } else {
+        b""
+    };
+
+    let (mantissa, exp) = match float.kind {
+        Kind::Finite { mantissa, exp } => (mantissa, exp),
+        Kind::Infinite | Kind::Nan => {
+            let text: &[u8] = match (float.kind, upper) {
+                (Kind::Infinite, false) => b"inf",
+                (Kind::Infinite, true) => b"INF",
+                (_, false) => b"nan",
+                (_, true) => b"NAN",
+            };
+            return pad_number(w, sign, b"", 3, width, left, false, |w| w.write_all(text));
+        }
+    };
+    if fmt | 32 == b'a' {
+        return fmt_float_hex(
+            w, fmt, float, mantissa, exp, precision, alternate, left, sign, width, zero,
+        );
     }
 
-    let string = float_string(float, precision, trim);
-    let len = string.len() + 2 + 2.max(exp_len);
+    let mut dec = Decimal::new(mantissa, exp);
+    let precision = precision.unwrap_or(6);
+    // The number of digits after the point, and the exponent in the e style
+    let (frac, exp) = match fmt | 32 {
+        b'f' => {
+            dec.round(-(precision as i32));
+            (precision, None)
+        }
+        b'e' => {
+            let exp = dec.exponent();
+            dec.round(exp - precision as i32);
+            (precision, Some(dec.exponent()))
+        }
+        _ => {
+            // %g has the style of %e when the exponent is small or at least the precision
+            let precision = precision.max(1);
+            let exp = dec.exponent();
+            dec.round(exp - (precision as i32 - 1));
+            let exp = dec.exponent();
+            let (frac, exp) = if exp >= -4 && exp < precision as i32 {
+                ((precision as i32 - 1 - exp) as usize, None)
+            } else {
+                (precision - 1, Some(exp))
+            };
 
-    pad(w, !left, b' ', len..pad_space)?;
-    let bytes = if string.starts_with('-') {
-        w.write_all(&[b'-'])?;
-        &string.as_bytes()[1..]
+            // Remove the trailing zeros, unless there's a #
+            if alternate {
+                (frac, exp)
+            } else {
+                let units = exp.unwrap_or(0);
+                let last = dec.last_nonzero().unwrap_or(units);
+                (frac.min((units - last).max(0) as usize), exp)
+            }
+        }
+    };
+
+    let decimal_point = locale::current().numeric.decimal_point.as_str().as_bytes();
+    let point: &[u8] = if frac > 0 || alternate {
+        decimal_point
     } else {
-        string.as_bytes()
+        b""
     };
-    pad(w, !left, b'0', len..pad_zero)?;
-    w.write_all(bytes)?;
-    write!(w, "{}{:+03}", exp_fmt as char, exp)?;
-    pad(w, left, b' ', len..pad_space)?;
+    let (first, len) = match exp {
+        Some(exp) => (exp, 1 + point.len() + frac + 1 + exp_len(exp, 2)),
+        None => {
+            let first = dec.exponent().max(0);
+            let digits = first as usize + 1;
+            (first, digits + grouping.len(digits) + point.len() + frac)
+        }
+    };
+    // The power of ten of the units digit
+    let units = exp.unwrap_or(0);
+
+    pad_number(w, sign, b"", len, width, left, zero, |w| {
+        if exp.is_some() {
+            dec.write_digits(w, first, units)?;
+        } else {
+            grouping.write(w, first as usize, |w, from, to| {
+                dec.write_digits(w, from as i32, to as i32)
+            })?;
+        }
+        w.write_all(point)?;
+        dec.write_digits(w, units - 1, units - frac as i32)?;
+        if let Some(exp) = exp {
+            write!(w, "{}{:+03}", (b'E' | (fmt & 32)) as char, exp)?;
+        }
+        Ok(())
+    })
+}
 
-    Ok(true)
+/// Write a string for %s, with a precision and a width in the units of the format
+unsafe fn fmt_string<W: Write, F: FormatChar>(
+    w: &mut W,
+    ptr: *const c_char,
+    precision: Option<usize>,
+    left: bool,
+    pad_space: usize,
+) -> io::Result<()> {
+    if ptr.is_null() {
+        return w.write_all(b"(null)");
+    }
+
+    let max = precision.unwrap_or(::core::usize::MAX);
+    let mut len = 0;
+    let mut chars = 0;
+    while *ptr.add(len) != 0 && chars < max {
+        len += 1;
+        if F::WIDE {
+            // Take whole multibyte characters
+            while *ptr.add(len) as u8 & 0xC0 == 0x80 {
+                len += 1;
+            }
+        }
+        chars += 1;
+    }
+
+    pad(w, !left, b' ', chars..pad_space)?;
+    w.write_all(slice::from_raw_parts(ptr as *const u8, len))?;
+    pad(w, left, b' ', chars..pad_space)
 }
 
-fn fmt_float_normal<W: Write>(
+/// Write a wide string for %ls as multibyte characters. A precision in bytes only takes the
+/// characters that fit whole
+unsafe fn fmt_wide_string<W: Write, F: FormatChar>(
     w: &mut W,
-    trim: bool,
-    precision: usize,
-    float: c_double,
+    ptr: *const wchar_t,
+    precision: Option<usize>,
     left: bool,
     pad_space: usize,
-    pad_zero: usize,
-) -> io::Result<usize> {
-    let string = float_string(float, precision, trim);
-
-    pad(w, !left, b' ', string.len()..pad_space)?;
-    let bytes = if string.starts_with('-') {
-        w.write_all(&[b'-'])?;
-        &string.as_bytes()[1..]
-    } else {
-        string.as_bytes()
-    };
-    pad(w, true, b'0', string.len()..pad_zero)?;
-    w.write_all(bytes)?;
-    pad(w, left, b' ', string.len()..pad_space)?;
+) -> io::Result<()> {
+    if ptr.is_null() {
+        return w.write_all(b"(null)");
+    }
+
+    let max = precision.unwrap_or(::core::usize::MAX);
+    let mut count = 0;
+    let mut len = 0;
+    while *ptr.add(count) != 0 {
+        let char_len = if F::WIDE {
+            1
+        } else {
+            wide_len(*ptr.add(count))?
+        };
+        if len + char_len > max {
+            break;
+        }
+        len += char_len;
+        count += 1;
+    }
 
-    Ok(string.len())
+    pad(w, !left, b' ', len..pad_space)?;
+    for i in 0..count {
+        write_wide(w, *ptr.add(i))?;
+    }
+    pad(w, left, b' ', len..pad_space)
 }
 
 #[derive(Clone, Copy)]
-struct PrintfIter {
-    format: *const u8,
+struct PrintfIter<F> {
+    format: *const F,
 }
 #[derive(Clone, Copy)]
 struct PrintfArg {
@@ -409,34 +833,40 @@ struct PrintfArg {
     left: bool,
     sign_reserve: bool,
     sign_always: bool,
+    group: bool,
     min_width: Number,
     precision: Option<Number>,
-    pad_space: Number,
-    pad_zero: Number,
     intkind: IntKind,
     fmt: u8,
     fmtkind: FmtKind,
 }
-enum PrintfFmt {
-    Plain(&'static [u8]),
+enum PrintfFmt<F: 'static> {
+    Plain(&'static [F]),
     Arg(PrintfArg),
 }
-impl Iterator for PrintfIter {
-    type Item = Result<PrintfFmt, ()>;
+impl<F: FormatChar> PrintfIter<F> {
+    unsafe fn peek(&self) -> u8 {
+        ascii((*self.format).code())
+    }
+}
+impl<F: FormatChar> Iterator for PrintfIter<F> {
+    type Item = Result<PrintfFmt<F>, ()>;
     fn next(&mut self) -> Option<Self::Item> {
         unsafe {
             // Send PrintfFmt::Plain until the next %
             let mut len = 0;
-            while *self.format.add(len) != 0 && *self.format.add(len) != b'%' {
+            while (*self.format.add(len)).code() != 0
+                && (*self.format.add(len)).code() != b'%' as u32
+            {
                 len += 1;
             }
             if len > 0 {
-                let slice = slice::from_raw_parts(self.format as *const u8, len);
+                let slice = slice::from_raw_parts(self.format, len);
                 self.format = self.format.add(len);
                 return Some(Ok(PrintfFmt::Plain(slice)));
             }
             self.format = self.format.add(len);
-            if *self.format == 0 {
+            if (*self.format).code() == 0 {
                 return None;
             }
 
@@ -455,14 +885,16 @@ impl Iterator for PrintfIter {
             let mut left = false;
             let mut sign_reserve = false;
             let mut sign_always = false;
+            let mut group = false;
 
             loop {
-                match *self.format {
+                match self.peek() {
                     b'#' => alternate = true,
                     b'0' => zero = true,
                     b'-' => left = true,
                     b' ' => sign_reserve = true,
                     b'+' => sign_always = true,
+                    b'\'' => group = true,
                     _ => break,
                 }
                 self.format = self.format.add(1);
@@ -470,23 +902,18 @@ impl Iterator for PrintfIter {
 
             // Width and precision:
             let min_width = pop_int(&mut self.format).unwrap_or(Number::Static(0));
-            let precision = if *self.format == b'.' {
+            let precision = if self.peek() == b'.' {
                 self.format = self.format.add(1);
-                match pop_int(&mut self.format) {
-                    int @ Some(_) => int,
-                    None => return Some(Err(())),
-                }
+                // A . alone is a precision of zero
+                Some(pop_int(&mut self.format).unwrap_or(Number::Static(0)))
             } else {
                 None
             };
 
-            let pad_space = if zero { Number::Static(0) } else { min_width };
-            let pad_zero = if zero { min_width } else { Number::Static(0) };
-
             // Integer size:
             let mut intkind = IntKind::Int;
             loop {
-                intkind = match *self.format {
+                intkind = match self.peek() {
                     b'h' => {
                         if intkind == IntKind::Short || intkind == IntKind::Byte {
                             IntKind::Byte
@@ -510,7 +937,7 @@ impl Iterator for PrintfIter {
 
                 self.format = self.format.add(1);
             }
-            let fmt = *self.format;
+            let fmt = self.peek();
             let fmtkind = match fmt {
                 b'%' => FmtKind::Percent,
                 b'd' | b'i' => FmtKind::Signed,
@@ -518,14 +945,23 @@ impl Iterator for PrintfIter {
                 b'e' | b'E' => FmtKind::Scientific,
                 b'f' | b'F' => FmtKind::Decimal,
                 b'g' | b'G' => FmtKind::AnyNotation,
+                b'a' | b'A' => FmtKind::HexFloat,
                 b's' => FmtKind::String,
                 b'c' => FmtKind::Char,
                 b'p' => FmtKind::Pointer,
                 b'n' => FmtKind::GetWritten,
+                b'm' => FmtKind::Error,
                 _ => return Some(Err(())),
             };
             self.format = self.format.add(1);
 
+            if !index.map_or(true, |i| Number::Index(i).valid())
+                || !min_width.valid()
+                || !precision.map_or(true, Number::valid)
+            {
+                return Some(Err(()));
+            }
+
             Some(Ok(PrintfFmt::Arg(PrintfArg {
                 index,
                 alternate,
@@ -533,10 +969,9 @@ impl Iterator for PrintfIter {
                 left,
                 sign_reserve,
                 sign_always,
+                group,
                 min_width,
                 precision,
-                pad_space,
-                pad_zero,
                 intkind,
                 fmt,
                 fmtkind,
@@ -545,43 +980,71 @@ impl Iterator for PrintfIter {
     }
 }
 
-unsafe fn inner_printf<W: Write>(w: W, format: *const c_char, mut ap: VaList) -> io::Result<c_int> {
-    let w = &mut platform::CountingWriter::new(w);
-
-    let iterator = PrintfIter {
-        format: format as *const u8,
+unsafe fn inner_printf<W: Write, F: FormatChar>(
+    w: W,
+    format: *const F,
+    mut ap: VaList,
+) -> io::Result<c_int> {
+    let w = &mut if F::WIDE {
+        platform::CountingWriter::new_wide(w)
+    } else {
+        platform::CountingWriter::new(w)
     };
+    // For %m, before writing changes it
+    let errno = platform::errno;
 
-    // Pre-fetch vararg types
-    let mut varargs = VaListCache::default();
-    let mut positional = BTreeMap::new();
-    // ^ NOTE: This depends on the sorted order, do not change to HashMap or whatever
+    let iterator = PrintfIter { format };
 
+    // Check the format before anything is written, and find whether it has positional
+    // arguments
+    let mut positional = false;
     for section in iterator {
-        let arg = match section {
-            Ok(PrintfFmt::Plain(text)) => continue,
-            Ok(PrintfFmt::Arg(arg)) => arg,
+        match section {
+            Ok(PrintfFmt::Plain(_)) => (),
+            Ok(PrintfFmt::Arg(arg)) => {
+                let index = |n: Option<Number>| match n {
+                    Some(Number::Index(_)) => true,
+                    _ => false,
+                };
+                positional |=
+                    arg.index.is_some() || index(Some(arg.min_width)) || index(arg.precision);
+            }
             Err(()) => return Ok(-1),
-        };
-        if arg.fmtkind == FmtKind::Percent {
-            continue;
-        }
-        if let Some(i) = arg.index {
-            positional.insert(i - 1, arg);
-        } else {
-            varargs.args.push(VaArg::arg_from(&arg, &mut ap));
         }
     }
-    // Make sure, in order, the positional arguments exist with the specified type
-    for (i, arg) in positional {
-        varargs.get(i, &mut ap, Some(&arg));
+
+    let mut varargs = VaListCache::new(positional);
+    if positional {
+        // Make sure, in order, the arguments exist with the type of their last use
+        let mut types = [None; NL_ARGMAX];
+        let mut next = 0;
+        for section in iterator {
+            let arg = match section {
+                Ok(PrintfFmt::Arg(arg)) => arg,
+                _ => continue,
+            };
+            if arg.fmtkind == FmtKind::Percent || arg.fmtkind == FmtKind::Error {
+                continue;
+            }
+            let i = arg.index.map(|i| i - 1).unwrap_or_else(|| {
+                next += 1;
+                next - 1
+            });
+            if i < NL_ARGMAX {
+                types[i] = Some((arg.fmtkind, arg.intkind));
+            }
+        }
+        let count = types.iter().rposition(Option::is_some).map_or(0, |i| i + 1);
+        for (i, &kind) in types[..count].iter().enumerate() {
+            varargs.get(i, &mut ap, kind);
+        }
     }
 
     // Main loop
     for section in iterator {
         let arg = match section {
             Ok(PrintfFmt::Plain(text)) => {
-                w.write_all(text)?;
+                F::write(w, text)?;
                 continue;
             }
             Ok(PrintfFmt::Arg(arg)) => arg,
@@ -589,19 +1052,29 @@ unsafe fn inner_printf<W: Write>(w: W, format: *const c_char, mut ap: VaList) ->
         };
         let alternate = arg.alternate;
         let zero = arg.zero;
-        let left = arg.left;
+        let mut left = arg.left;
         let sign_reserve = arg.sign_reserve;
         let sign_always = arg.sign_always;
-        let min_width = arg.min_width.resolve(&mut varargs, &mut ap);
-        let precision = arg.precision.map(|n| n.resolve(&mut varargs, &mut ap));
-        let pad_space = arg.pad_space.resolve(&mut varargs, &mut ap);
-        let pad_zero = arg.pad_zero.resolve(&mut varargs, &mut ap);
+        let grouping = Grouping::new(arg.group);
+        let mut min_width = arg.min_width.resolve(&mut varargs, &mut ap);
+        // A negative width from an argument is a - flag and a positive width, and a negative
+        // precision is no precision
+        if (min_width as isize) < 0 {
+            left = true;
+            min_width = (min_width as isize).wrapping_neg() as usize;
+        }
+        let precision = arg
+            .precision
+            .map(|n| n.resolve(&mut varargs, &mut ap))
+            .filter(|&precision| precision as isize >= 0);
+        let pad_space = if zero { 0 } else { min_width };
         let intkind = arg.intkind;
         let fmt = arg.fmt;
         let fmtkind = arg.fmtkind;
+        let kind = Some((fmtkind, intkind));
 
         let index = arg.index.map(|i| i - 1).unwrap_or_else(|| {
-            if fmtkind == FmtKind::Percent {
+            if fmtkind == FmtKind::Percent || fmtkind == FmtKind::Error {
                 0
             } else {
                 let i = varargs.i;
@@ -613,210 +1086,131 @@ unsafe fn inner_printf<W: Write>(w: W, format: *const c_char, mut ap: VaList) ->
         match fmtkind {
             FmtKind::Percent => w.write_all(&[b'%'])?,
             FmtKind::Signed => {
-                let string = match varargs.get(index, &mut ap, Some(&arg)) {
-                    VaArg::c_char(i) => i.to_string(),
-                    VaArg::c_double(i) => panic!("this should not be possible"),
-                    VaArg::c_int(i) => i.to_string(),
-                    VaArg::c_long(i) => i.to_string(),
-                    VaArg::c_longlong(i) => i.to_string(),
-                    VaArg::c_short(i) => i.to_string(),
-                    VaArg::intmax_t(i) => i.to_string(),
-                    VaArg::pointer(i) => (i as usize).to_string(),
-                    VaArg::ptrdiff_t(i) => i.to_string(),
-                    VaArg::ssize_t(i) => i.to_string(),
-                };
-                let positive = !string.starts_with('-');
-                let zero = precision == Some(0) && string == "0";
-
-                let mut len = string.len();
-                let mut final_len = string.len().max(precision.unwrap_or(0));
-                if positive && (sign_reserve || sign_always) {
-                    final_len += 1;
-                }
-                if zero {
-                    len = 0;
-                    final_len = 0;
-                }
-
-                pad(w, !left, b' ', final_len..pad_space)?;
-
-                let bytes = if positive {
-                    if sign_reserve {
-                        w.write_all(&[b' '])?;
-                    } else if sign_always {
-                        w.write_all(&[b'+'])?;
-                    }
-                    string.as_bytes()
+                let i = varargs.get(index, &mut ap, kind).signed();
+                let magnitude = if i < 0 {
+                    (i as u64).wrapping_neg()
                 } else {
-                    w.write_all(&[b'-'])?;
-                    &string.as_bytes()[1..]
+                    i as u64
                 };
-                pad(w, true, b'0', len..precision.unwrap_or(pad_zero))?;
-
-                if !zero {
-                    w.write_all(bytes)?;
-                }
-
-                pad(w, left, b' ', final_len..pad_space)?;
+                fmt_integer(
+                    w,
+                    fmt,
+                    i < 0,
+                    magnitude,
+                    precision,
+                    alternate,
+                    left,
+                    sign_reserve,
+                    sign_always,
+                    grouping,
+                    min_width,
+                    zero,
+                )?;
             }
             FmtKind::Unsigned => {
-                let string = match varargs.get(index, &mut ap, Some(&arg)) {
-                    VaArg::c_char(i) => fmt_int(fmt, i as c_uchar),
-                    VaArg::c_double(i) => panic!("this should not be possible"),
-                    VaArg::c_int(i) => fmt_int(fmt, i as c_uint),
-                    VaArg::c_long(i) => fmt_int(fmt, i as c_ulong),
-                    VaArg::c_longlong(i) => fmt_int(fmt, i as c_ulonglong),
-                    VaArg::c_short(i) => fmt_int(fmt, i as c_ushort),
-                    VaArg::intmax_t(i) => fmt_int(fmt, i as uintmax_t),
-                    VaArg::pointer(i) => fmt_int(fmt, i as usize),
-                    VaArg::ptrdiff_t(i) => fmt_int(fmt, i as size_t),
-                    VaArg::ssize_t(i) => fmt_int(fmt, i as size_t),
-                };
-                let zero = precision == Some(0) && string == "0";
-
-                // If this int is padded out to be larger than it is, don't
-                // add an extra zero if octal.
-                let no_precision = precision.map(|pad| pad < string.len()).unwrap_or(true);
-
-                let len;
-                let final_len = if zero {
-                    len = 0;
-                    0
+                let i = varargs.get(index, &mut ap, kind).unsigned();
+                // Only decimal digits are grouped
+                let grouping = if fmt == b'u' {
+                    grouping
                 } else {
-                    len = string.len();
-                    len.max(precision.unwrap_or(0))
-                        + if alternate && string != "0" {
-                            match fmt {
-                                b'o' if no_precision => 1,
-                                b'x' | b'X' => 2,
-                                _ => 0,
-                            }
-                        } else {
-                            0
-                        }
+                    Grouping::new(false)
                 };
-
-                pad(w, !left, b' ', final_len..pad_space)?;
-
-                if alternate && string != "0" {
-                    match fmt {
-                        b'o' if no_precision => w.write_all(&[b'0'])?,
-                        b'x' => w.write_all(&[b'0', b'x'])?,
-                        b'X' => w.write_all(&[b'0', b'X'])?,
-                        _ => (),
-                    }
-                }
-                pad(w, true, b'0', len..precision.unwrap_or(pad_zero))?;
-
-                if !zero {
-                    w.write_all(string.as_bytes())?;
-                }
-
-                pad(w, left, b' ', final_len..pad_space)?;
-            }
-            FmtKind::Scientific => {
-                let float = match varargs.get(index, &mut ap, Some(&arg)) {
-                    VaArg::c_double(i) => i,
-                    _ => panic!("this should not be possible"),
-                };
-                let precision = precision.unwrap_or(6);
-
-                fmt_float_exp(
-                    w, fmt, None, false, precision, float, left, pad_space, pad_zero,
+                fmt_integer(
+                    w, fmt, false, i, precision, alternate, left, false, false, grouping,
+                    min_width, zero,
                 )?;
             }
-            FmtKind::Decimal => {
-                let float = match varargs.get(index, &mut ap, Some(&arg)) {
-                    VaArg::c_double(i) => i,
-                    _ => panic!("this should not be possible"),
-                };
-                let precision = precision.unwrap_or(6);
-
-                fmt_float_normal(w, false, precision, float, left, pad_space, pad_zero)?;
-            }
-            FmtKind::AnyNotation => {
-                let float = match varargs.get(index, &mut ap, Some(&arg)) {
-                    VaArg::c_double(i) => i,
+            FmtKind::Scientific | FmtKind::Decimal | FmtKind::AnyNotation | FmtKind::HexFloat => {
+                let float = match varargs.get(index, &mut ap, kind) {
+                    VaArg::c_double(i) => Float::from_double(i),
+                    VaArg::long_double(i) => Float::from_long_double(i),
                     _ => panic!("this should not be possible"),
                 };
-                let exp_fmt = b'E' | (fmt & 32);
-                let precision = precision.unwrap_or(6);
 
-                if !fmt_float_exp(
+                fmt_float(
                     w,
-                    exp_fmt,
-                    Some((-4, precision as isize)),
-                    true,
-                    precision,
+                    fmt,
                     float,
+                    precision,
+                    alternate,
                     left,
-                    pad_space,
-                    pad_zero,
-                )? {
-                    fmt_float_normal(w, true, precision, float, left, pad_space, pad_zero)?;
-                }
+                    sign_reserve,
+                    sign_always,
+                    grouping,
+                    min_width,
+                    zero,
+                )?;
             }
             FmtKind::String => {
-                // if intkind == IntKind::Long || intkind == IntKind::LongLong, handle *const wchar_t
-
-                let ptr = match varargs.get(index, &mut ap, Some(&arg)) {
+                let ptr = match varargs.get(index, &mut ap, kind) {
                     VaArg::pointer(p) => p,
                     _ => panic!("this should not be possible"),
-                } as *const c_char;
+                };
 
-                if ptr.is_null() {
-                    w.write_all(b"(null)")?;
+                if intkind == IntKind::Long {
+                    fmt_wide_string::<_, F>(w, ptr as *const wchar_t, precision, left, pad_space)?;
                 } else {
-                    let max = precision.unwrap_or(::core::usize::MAX);
-                    let mut len = 0;
-                    while *ptr.add(len) != 0 && len < max {
-                        len += 1;
-                    }
-
-                    pad(w, !left, b' ', len..pad_space)?;
-                    w.write_all(slice::from_raw_parts(ptr as *const u8, len))?;
-                    pad(w, left, b' ', len..pad_space)?;
+                    fmt_string::<_, F>(w, ptr as *const c_char, precision, left, pad_space)?;
                 }
             }
             FmtKind::Char => {
-                // if intkind == IntKind::Long || intkind == IntKind::LongLong, handle wint_t
-
-                let c = match varargs.get(index, &mut ap, Some(&arg)) {
-                    VaArg::c_char(c) => c,
+                let c = match varargs.get(index, &mut ap, kind) {
+                    VaArg::c_char(c) => c as c_int,
+                    VaArg::c_int(c) => c,
                     _ => panic!("this should not be possible"),
                 };
 
-                pad(w, !left, b' ', 1..pad_space)?;
-                w.write_all(&[c as u8])?;
-                pad(w, left, b' ', 1..pad_space)?;
+                if intkind == IntKind::Long {
+                    let wc = c as wchar_t;
+                    let len = if F::WIDE { 1 } else { wide_len(wc)? };
+                    pad(w, !left, b' ', len..pad_space)?;
+                    write_wide(w, wc)?;
+                    pad(w, left, b' ', len..pad_space)?;
+                } else {
+                    pad(w, !left, b' ', 1..pad_space)?;
+                    w.write_all(&[c as u8])?;
+                    pad(w, left, b' ', 1..pad_space)?;
+                }
             }
             FmtKind::Pointer => {
-                let ptr = match varargs.get(index, &mut ap, Some(&arg)) {
+                let ptr = match varargs.get(index, &mut ap, kind) {
                     VaArg::pointer(p) => p,
                     _ => panic!("this should not be possible"),
                 };
 
-                let mut len = 1;
                 if ptr.is_null() {
-                    len = "(nil)".len();
+                    pad_number(w, b"", b"", 5, min_width, left, false, |w| {
+                        w.write_all(b"(nil)")
+                    })?;
                 } else {
-                    let mut ptr = ptr as usize;
-                    while ptr >= 10 {
-                        ptr /= 10;
-                        len += 1;
-                    }
+                    // As %#lx
+                    let sign: &[u8] = if sign_always {
+                        b"+"
+                    } else if sign_reserve {
+                        b" "
+                    } else {
+                        b""
+                    };
+                    let mut buf = [0; 22];
+                    let digits = fmt_int(&mut buf, b'p', ptr as usize as u64);
+                    let zeros = precision.map_or(0, |p| p.saturating_sub(digits.len()));
+                    pad_number(
+                        w,
+                        sign,
+                        b"0x",
+                        zeros + digits.len(),
+                        min_width,
+                        left,
+                        zero && precision.is_none(),
+                        |w| {
+                            pad(w, true, b'0', 0..zeros)?;
+                            w.write_all(digits)
+                        },
+                    )?;
                 }
-
-                pad(w, !left, b' ', len..pad_space)?;
-                if ptr.is_null() {
-                    write!(w, "(nil)")?;
-                } else {
-                    write!(w, "0x{:x}", ptr as usize)?;
-                }
-                pad(w, left, b' ', len..pad_space)?;
             }
             FmtKind::GetWritten => {
-                let ptr = match varargs.get(index, &mut ap, Some(&arg)) {
+                let ptr = match varargs.get(index, &mut ap, kind) {
                     VaArg::pointer(p) => p,
                     _ => panic!("this should not be possible"),
                 };
@@ -832,11 +1226,63 @@ unsafe fn inner_printf<W: Write>(w: W, format: *const c_char, mut ap: VaList) ->
                     IntKind::Size => *(ptr as *mut size_t) = w.written as size_t,
                 }
             }
+            FmtKind::Error => {
+                fmt_string::<_, F>(w, strerror(errno), precision, left, pad_space)?;
+            }
         }
     }
     Ok(w.written as c_int)
 }
 
-pub unsafe fn printf<W: Write>(w: W, format: *const c_char, ap: VaList) -> c_int {
+/// Write the arguments of `ap` to `w` as `format` says. A wide format gives multibyte output,
+/// with widths, precisions and counts in wide characters
+pub unsafe fn printf<W: Write, F: FormatChar>(w: W, format: *const F, ap: VaList) -> c_int {
     inner_printf(w, format, ap).unwrap_or(-1)
 }
+
+/// Writes to a file descriptor through a buffer on the stack
+struct FdWriter {
+    file: File,
+    buf: [u8; 256],
+    len: usize,
+}
+impl Write for FdWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.len + buf.len() > self.buf.len() {
+            self.flush()?;
+        }
+        if buf.len() >= self.buf.len() {
+            return self.file.write(buf);
+        }
+        self.buf[self.len..self.len + buf.len()].copy_from_slice(buf);
+        self.len += buf.len();
+        Ok(buf.len())
+    }
+    fn flush(&mut self) -> io::Result<()> {
+        let len = self.len;
+        self.len = 0;
+        self.file.write_all(&self.buf[..len])
+    }
+}
+
+/// Write the arguments of `ap` to the file descriptor `fd` as `format` says.
+///
+/// This is async-signal-safe: it allocates nothing, takes no locks and calls nothing but write,
+/// so relibc can use it for its own diagnostics, in a signal handler, after a fork, or when the
+/// allocator itself is broken. Formatting a floating-point number takes about 10 KiB of stack
+pub unsafe fn dprintf(fd: c_int, format: *const c_char, ap: VaList) -> c_int {
+    let mut w = FdWriter {
+        file: File {
+            fd,
+            reference: true,
+        },
+        buf: [0; 256],
+        len: 0,
+    };
+    let ret = inner_printf::<_, c_char>(&mut w, format, ap);
+    // What was formatted before an error is still written
+    match (ret, w.flush()) {
+        (Ok(written), Ok(())) => written,
+        _ => -1,
+    }
+}
@@ -1,10 +1,20 @@
-use alloc::string::String;
 use alloc::vec::Vec;
 use core::ffi::VaList as va_list;
-use io::Read;
+use core::marker::PhantomData;
+use core::{char, mem, ptr, slice, str};
+use header::locale;
+use header::stdlib::MB_LEN_MAX;
+use header::wchar::{mbrtowc, mbstate_t, wcrtomb};
+use io::{self, Read};
+use platform;
 use platform::types::*;
 
-#[derive(PartialEq, Eq)]
+use super::constants::EOF;
+use super::float::LongDouble;
+use super::format::{ascii, read_wide, FormatChar};
+use super::FILE;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
 enum IntKind {
     Byte,
     Short,
@@ -16,439 +26,842 @@ enum IntKind {
     Size,
 }
 
+/// Why scanf stopped before the end of the format: the input ended or couldn't be read, or it
+/// didn't match
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Failure {
+    Input,
+    Matching,
+}
+
 /// Helper function for progressing a C string
-unsafe fn next_byte(string: &mut *const c_char) -> Result<u8, c_int> {
-    let c = **string as u8;
+unsafe fn next_char<F: FormatChar>(string: &mut *const F) -> Result<u32, Failure> {
+    let c = (**string).code();
     *string = string.offset(1);
     if c == 0 {
-        Err(-1)
+        Err(Failure::Matching)
     } else {
         Ok(c)
     }
 }
 
-unsafe fn inner_scanf<R: Read>(
-    mut r: R,
-    mut format: *const c_char,
-    mut ap: va_list,
-) -> Result<c_int, c_int> {
-    let mut matched = 0;
-    let mut byte = 0;
-    let mut skip_read = false;
-    let mut count = 0;
+/// Read a byte, or a multibyte character for a wide format
+fn read_char<R: Read, F: FormatChar>(r: &mut R) -> io::Result<Option<u32>> {
+    if F::WIDE {
+        return read_wide(r).map(|wc| wc.map(|wc| wc as u32));
+    }
+    let buf = &mut [0];
+    match r.read(buf)? {
+        0 => Ok(None),
+        _ => Ok(Some(buf[0] as u32)),
+    }
+}
 
-    macro_rules! read {
-        () => {{
-            let buf = &mut [byte];
-            match r.read(buf) {
-                Ok(0) => false,
-                Ok(_) => {
-                    byte = buf[0];
-                    count += 1;
-                    true
-                }
-                Err(_) => return Err(-1),
+fn is_space<F: FormatChar>(c: u32) -> bool {
+    if F::WIDE {
+        char::from_u32(c).map_or(false, char::is_whitespace)
+    } else {
+        c == b' ' as u32 || (c >= b'\t' as u32 && c <= b'\r' as u32)
+    }
+}
+
+/// Whether `c` is the letter `lower` in either case
+fn is_letter(c: u32, lower: u8) -> bool {
+    c == lower as u32 || c == lower.to_ascii_uppercase() as u32
+}
+
+/// The input, read a character at a time with one character of lookahead. That is all that can
+/// be pushed back into a stream, so a conversion ends on the first character it doesn't use,
+/// even if what it read before doesn't make up a number
+struct Input<R, F> {
+    r: R,
+    next: Option<Option<u32>>,
+    count: usize,
+    unit: PhantomData<F>,
+}
+impl<R: Read, F: FormatChar> Input<R, F> {
+    fn new(r: R) -> Self {
+        Input {
+            r,
+            next: None,
+            count: 0,
+            unit: PhantomData,
+        }
+    }
+
+    /// The next character, or None at the end of the input
+    fn peek(&mut self) -> Result<Option<u32>, Failure> {
+        if let Some(next) = self.next {
+            return Ok(next);
+        }
+        let next = read_char::<R, F>(&mut self.r).map_err(|_| Failure::Input)?;
+        self.next = Some(next);
+        Ok(next)
+    }
+
+    /// Use the character `peek` returned
+    fn advance(&mut self) {
+        self.next = None;
+        self.count += 1;
+    }
+
+    /// Use the next character if there is `width` left and `accept` accepts it
+    fn accept<A: Fn(u32) -> bool>(
+        &mut self,
+        width: &mut usize,
+        accept: A,
+    ) -> Result<Option<u32>, Failure> {
+        if *width == 0 {
+            return Ok(None);
+        }
+        match self.peek()? {
+            Some(c) if accept(c) => {
+                self.advance();
+                *width -= 1;
+                Ok(Some(c))
             }
-        }};
+            _ => Ok(None),
+        }
     }
 
-    macro_rules! maybe_read {
-        () => {
-            maybe_read!(inner false);
-        };
-        (noreset) => {
-            maybe_read!(inner);
+    /// Skip white space before a conversion, which fails at the end of the input
+    fn skip_space(&mut self) -> Result<u32, Failure> {
+        loop {
+            match self.peek()? {
+                Some(c) if is_space::<F>(c) => self.advance(),
+                Some(c) => return Ok(c),
+                None => return Err(Failure::Input),
+            }
+        }
+    }
+}
+
+/// Read an integer as strtoll does for signed conversions and strtoull for unsigned ones: the
+/// value saturates at 64 bits, and a base of 0 takes the base of the prefix
+fn scan_int<R: Read, F: FormatChar>(
+    input: &mut Input<R, F>,
+    width: &mut usize,
+    mut base: u32,
+    signed: bool,
+) -> Result<u64, Failure> {
+    let negative =
+        input.accept(width, |c| c == b'-' as u32 || c == b'+' as u32)? == Some(b'-' as u32);
+
+    let mut digits = false;
+    if (base == 0 || base == 16) && input.accept(width, |c| c == b'0' as u32)?.is_some() {
+        // A 0x without digits after it is a zero
+        digits = true;
+        if input.accept(width, |c| is_letter(c, b'x'))?.is_some() {
+            base = 16;
+        } else if base == 0 {
+            base = 8;
+        }
+    }
+    if base == 0 {
+        base = 10;
+    }
+
+    let digit = |c: u32| char::from_u32(c).and_then(|c| c.to_digit(base));
+    let mut value: u64 = 0;
+    let mut overflow = false;
+    while let Some(c) = input.accept(width, |c| digit(c).is_some())? {
+        let d = digit(c).unwrap_or(0) as u64;
+        match value
+            .checked_mul(base as u64)
+            .and_then(|value| value.checked_add(d))
+        {
+            Some(next) => value = next,
+            None => overflow = true,
+        }
+        digits = true;
+    }
+    if !digits {
+        return Err(Failure::Matching);
+    }
+
+    Ok(if signed {
+        let max = if negative {
+            1 << 63
+        } else {
+            i64::max_value() as u64
         };
-        (inner $($placeholder:expr)*) => {
-            if !skip_read && !read!() {
-                return Ok(matched);
+        if overflow || value > max {
+            if negative {
+                i64::min_value() as u64
+            } else {
+                max
             }
-            $(else {
-                // Hacky way of having this optional
-                skip_read = $placeholder;
-            })*
+        } else if negative {
+            value.wrapping_neg()
+        } else {
+            value
+        }
+    } else if overflow {
+        u64::max_value()
+    } else if negative {
+        value.wrapping_neg()
+    } else {
+        value
+    })
+}
+
+/// The significant digits that are kept of a decimal number, which are all Rust parses. The
+/// digits after them only matter as a nonzero last digit, to round correctly
+const MAX_DIGITS: usize = 374;
+
+/// A floating-point number as it was read, without its sign
+enum Scanned {
+    Infinite,
+    Nan,
+    /// digits × 10^exp
+    Decimal {
+        digits: Vec<u8>,
+        exp: i64,
+    },
+    /// mantissa × 2^exp, and whether bits that didn't fit in the mantissa were cut off
+    Hex {
+        mantissa: u64,
+        sticky: bool,
+        exp: i64,
+    },
+}
+impl Scanned {
+    fn parse<T: str::FromStr + Default>(digits: &[u8], exp: i64) -> T {
+        if digits.is_empty() {
+            return T::default();
+        }
+        // Rust rejects a number whose digits and negative exponent add up to more than 358, so
+        // the digits past that are cut off, with a nonzero last one if any of them wasn't zero
+        let lead = digits.len() as i64 + exp;
+        let keep = ((358 + lead) / 2).max(1) as usize;
+        let mut text = Vec::with_capacity(digits.len() + 24);
+        let mut exp = exp;
+        if digits.len() > keep {
+            text.extend_from_slice(&digits[..keep - 1]);
+            text.push(if digits[keep - 1..].iter().any(|&d| d != b'0') {
+                b'1'
+            } else {
+                b'0'
+            });
+            exp += (digits.len() - keep) as i64;
+        } else {
+            text.extend_from_slice(digits);
         }
+        text.push(b'e');
+        if exp < 0 {
+            text.push(b'-');
+        }
+        let mut buf = [0; 20];
+        let mut start = buf.len();
+        exp = exp.abs();
+        loop {
+            start -= 1;
+            buf[start] = b'0' + (exp % 10) as u8;
+            exp /= 10;
+            if exp == 0 {
+                break;
+            }
+        }
+        text.extend_from_slice(&buf[start..]);
+        unsafe { str::from_utf8_unchecked(&text) }
+            .parse()
+            .unwrap_or_default()
     }
 
-    while *format != 0 {
-        let mut c = next_byte(&mut format)?;
+    fn double(&self) -> f64 {
+        match *self {
+            Scanned::Infinite => core::f64::INFINITY,
+            Scanned::Nan => core::f64::NAN,
+            Scanned::Decimal { ref digits, exp } => Self::parse(digits, exp),
+            Scanned::Hex {
+                mantissa,
+                sticky,
+                exp,
+            } => hex_float(mantissa, sticky, exp, 53, -1022),
+        }
+    }
+
+    fn float(&self) -> f32 {
+        match *self {
+            Scanned::Infinite => core::f32::INFINITY,
+            Scanned::Nan => core::f32::NAN,
+            Scanned::Decimal { ref digits, exp } => Self::parse(digits, exp),
+            // Exact in a double, which rounds no further
+            Scanned::Hex {
+                mantissa,
+                sticky,
+                exp,
+            } => hex_float(mantissa, sticky, exp, 24, -126) as f32,
+        }
+    }
+}
+
+/// mantissa × 2^exp, rounded to nearest with ties to even to `bits` bits of precision, or fewer
+/// for subnormal numbers under 2^min_exp
+fn hex_float(mantissa: u64, sticky: bool, exp: i64, bits: i64, min_exp: i64) -> f64 {
+    if mantissa == 0 {
+        return 0.0;
+    }
+    let shift = mantissa.leading_zeros() as i64;
+    let mantissa = mantissa << shift;
+    let exp = exp - shift;
+
+    // The exponent of the last bit that is kept, and how many are dropped
+    let last = (exp + 63 - (bits - 1)).max(min_exp - (bits - 1));
+    let dropped = last - exp;
+    let (mut kept, half, rest) = if dropped > 64 {
+        (0, false, true)
+    } else if dropped == 64 {
+        (0, mantissa >> 63 != 0, mantissa << 1 != 0 || sticky)
+    } else {
+        (
+            mantissa >> dropped,
+            mantissa >> (dropped - 1) & 1 != 0,
+            mantissa & (1 << (dropped - 1)) - 1 != 0 || sticky,
+        )
+    };
+    if half && (rest || kept & 1 != 0) {
+        kept += 1;
+    }
 
-        if c == b' ' {
-            maybe_read!(noreset);
+    // Exact in two steps, unless it overflows
+    let pow2 = |exp: i64| f64::from_bits(((exp + 1023) as u64) << 52);
+    let last = last.min(2046);
+    kept as f64 * pow2(last / 2) * pow2(last - last / 2)
+}
 
-            while (byte as char).is_whitespace() {
-                if !read!() {
-                    return Ok(matched);
+/// Read a floating-point number as strtod does: inf, infinity or nan in any case, or a decimal
+/// number, or a hexadecimal one after 0x. An exponent without digits is left out
+fn scan_float<R: Read, F: FormatChar>(
+    input: &mut Input<R, F>,
+    mut width: usize,
+) -> Result<(bool, Scanned), Failure> {
+    let width = &mut width;
+    let negative =
+        input.accept(width, |c| c == b'-' as u32 || c == b'+' as u32)? == Some(b'-' as u32);
+
+    if let Some(c) = input.accept(width, |c| is_letter(c, b'i') || is_letter(c, b'n'))? {
+        let (rest, scanned): (&[u8], _) = if is_letter(c, b'i') {
+            (b"nf", Scanned::Infinite)
+        } else {
+            (b"an", Scanned::Nan)
+        };
+        for &letter in rest {
+            if input.accept(width, |c| is_letter(c, letter))?.is_none() {
+                return Err(Failure::Matching);
+            }
+        }
+        if let Scanned::Infinite = scanned {
+            if input.accept(width, |c| is_letter(c, b'i'))?.is_some() {
+                for &letter in b"nity" {
+                    if input.accept(width, |c| is_letter(c, letter))?.is_none() {
+                        return Err(Failure::Matching);
+                    }
                 }
             }
+        }
+        return Ok((negative, scanned));
+    }
 
-            skip_read = true;
-        } else if c != b'%' {
-            maybe_read!();
-            if c != byte {
-                return Ok(matched);
+    let mut digits = false;
+    let mut hex = false;
+    if input.accept(width, |c| c == b'0' as u32)?.is_some() {
+        if input.accept(width, |c| is_letter(c, b'x'))?.is_some() {
+            hex = true;
+        } else {
+            digits = true;
+        }
+    }
+    let radix = if hex { 16 } else { 10 };
+    let point = locale::current()
+        .numeric
+        .decimal_point
+        .as_str()
+        .chars()
+        .next()
+        .map_or(b'.' as u32, |c| c as u32);
+    let digit = |c: u32| char::from_u32(c).and_then(|c| c.to_digit(radix));
+
+    let mut decimal = Vec::new();
+    let mut mantissa = 0u64;
+    let mut sticky = false;
+    let mut exp = 0i64;
+    let mut fraction = false;
+    while let Some(c) = input.accept(width, |c| digit(c).is_some() || (c == point && !fraction))? {
+        let d = match digit(c) {
+            Some(d) => d,
+            None => {
+                fraction = true;
+                continue;
+            }
+        };
+        digits = true;
+        if hex {
+            if mantissa >> 60 == 0 {
+                mantissa = mantissa << 4 | d as u64;
+                if fraction {
+                    exp -= 4;
+                }
+            } else {
+                sticky |= d != 0;
+                if !fraction {
+                    exp += 4;
+                }
+            }
+        } else if decimal.is_empty() && d == 0 {
+            // Leading zeros
+            if fraction {
+                exp -= 1;
+            }
+        } else if decimal.len() < MAX_DIGITS {
+            decimal.push(b'0' + d as u8);
+            if fraction {
+                exp -= 1;
             }
         } else {
-            c = next_byte(&mut format)?;
-
-            let mut ignore = false;
-            if c == b'*' {
-                ignore = true;
-                c = next_byte(&mut format)?;
+            sticky |= d != 0;
+            if !fraction {
+                exp += 1;
             }
+        }
+    }
+    if !digits {
+        return Err(Failure::Matching);
+    }
+
+    let marker = if hex { b'p' } else { b'e' };
+    if input.accept(width, |c| is_letter(c, marker))?.is_some() {
+        let negative =
+            input.accept(width, |c| c == b'-' as u32 || c == b'+' as u32)? == Some(b'-' as u32);
+        let mut value = 0i64;
+        while let Some(c) = input.accept(width, |c| c >= b'0' as u32 && c <= b'9' as u32)? {
+            // Far beyond any exponent that isn't zero or infinity
+            value = (value * 10 + (c - b'0' as u32) as i64).min(1_000_000_000);
+        }
+        exp += if negative { -value } else { value };
+    }
 
-            let mut width = String::new();
-            while c >= b'0' && c <= b'9' {
-                width.push(c as char);
-                c = next_byte(&mut format)?;
+    Ok((
+        negative,
+        if hex {
+            Scanned::Hex {
+                mantissa,
+                sticky,
+                exp,
             }
-            let mut width = if width.is_empty() {
-                None
-            } else {
-                match width.parse::<usize>() {
-                    Ok(n) => Some(n),
-                    Err(_) => return Err(-1),
+        } else {
+            if sticky {
+                decimal.push(b'1');
+                exp -= 1;
+            }
+            Scanned::Decimal {
+                digits: decimal,
+                exp,
+            }
+        },
+    ))
+}
+
+/// Whether `c` is in the scanset that starts at `set`, after the [ and the ^. A ] first is part
+/// of the set, and a - between two characters in order is a range
+unsafe fn in_set<F: FormatChar>(set: *const F, c: u32) -> bool {
+    let mut prev = None;
+    let mut i = 0;
+    loop {
+        let sc = (*set.add(i)).code();
+        if sc == 0 || (sc == b']' as u32 && i > 0) {
+            return false;
+        }
+        let next = (*set.add(i + 1)).code();
+        if let Some(prev) = prev {
+            if sc == b'-' as u32 && next != b']' as u32 && next != 0 && prev <= next {
+                if prev <= c && c <= next {
+                    return true;
                 }
-            };
+                i += 2;
+                continue;
+            }
+        }
+        if sc == c {
+            return true;
+        }
+        prev = Some(sc);
+        i += 1;
+    }
+}
 
-            // When an EOF occurs, eof is set, stuff is marked matched
-            // as usual, and finally it is returned
-            let mut eof = false;
-
-            let mut kind = IntKind::Int;
-            loop {
-                kind = match c {
-                    b'h' => {
-                        if kind == IntKind::Short || kind == IntKind::Byte {
-                            IntKind::Byte
-                        } else {
-                            IntKind::Short
-                        }
-                    }
-                    b'j' => IntKind::IntMax,
-                    b'l' => {
-                        if kind == IntKind::Long || kind == IntKind::LongLong {
-                            IntKind::LongLong
-                        } else {
-                            IntKind::Long
-                        }
-                    }
-                    b'q' | b'L' => IntKind::LongLong,
-                    b't' => IntKind::PtrDiff,
-                    b'z' => IntKind::Size,
-                    _ => break,
-                };
+/// Where %c, %s or %[ stores its characters: nowhere when it's suppressed, the buffer of the
+/// caller, or one that the m modifier allocates and grows
+struct Sink {
+    ptr: *mut u8,
+    len: usize,
+    cap: usize,
+    // The pointer to give the allocation to, for m
+    target: *mut *mut u8,
+    // Store wchar_t, for l
+    wide: bool,
+}
+impl Sink {
+    unsafe fn new(ap: &mut va_list, ignore: bool, alloc: bool, wide: bool) -> Self {
+        let mut sink = Sink {
+            ptr: ptr::null_mut(),
+            len: 0,
+            cap: 0,
+            target: ptr::null_mut(),
+            wide,
+        };
+        if alloc && !ignore {
+            sink.target = ap.arg::<*mut *mut u8>();
+        } else if !ignore {
+            sink.ptr = ap.arg::<*mut u8>();
+        }
+        sink
+    }
 
-                c = next_byte(&mut format)?;
+    /// Store `c`. Wide characters are stored as multibyte characters without l
+    unsafe fn push<F: FormatChar>(&mut self, c: u32) -> Result<(), Failure> {
+        if !self.target.is_null() && self.len + MB_LEN_MAX as usize > self.cap {
+            let cap = (self.cap * 2).max(32);
+            let ptr = platform::realloc(self.ptr as *mut c_void, cap) as *mut u8;
+            if ptr.is_null() {
+                return Err(Failure::Input);
             }
+            self.ptr = ptr;
+            self.cap = cap;
+        }
+        if self.ptr.is_null() {
+            return Ok(());
+        }
+
+        if self.wide {
+            ptr::write_unaligned(self.ptr.add(self.len) as *mut wchar_t, c as wchar_t);
+            self.len += mem::size_of::<wchar_t>();
+        } else if F::WIDE {
+            // It was decoded from a multibyte character, so it can be encoded again
+            let ptr = self.ptr.add(self.len) as *mut c_char;
+            self.len += wcrtomb(ptr, c as wchar_t, &mut mbstate_t::new());
+        } else {
+            *self.ptr.add(self.len) = c as u8;
+            self.len += 1;
+        }
+        Ok(())
+    }
 
-            if c != b'n' {
-                maybe_read!(noreset);
+    /// Give an allocation to its pointer, or free it and give NULL if the conversion failed
+    unsafe fn finish(self, ok: bool) {
+        if self.target.is_null() {
+            return;
+        }
+        if ok {
+            *self.target = self.ptr;
+        } else {
+            platform::free(self.ptr as *mut c_void);
+            *self.target = ptr::null_mut();
+        }
+    }
+}
+
+/// Read and store the characters of %c, %s or %[ while `accept` accepts them, up to `width`
+/// characters. With `multibyte`, the bytes of the input are stored as the wide characters they
+/// make up. Returns how many characters were read
+unsafe fn scan_chars<R: Read, F: FormatChar, A: Fn(u32) -> bool>(
+    input: &mut Input<R, F>,
+    width: usize,
+    sink: &mut Sink,
+    multibyte: bool,
+    accept: A,
+) -> Result<usize, Failure> {
+    let mut state = mbstate_t::new();
+    let mut partial = false;
+    let mut count = 0;
+    while count < width {
+        let c = match input.peek()? {
+            Some(c) if accept(c) => c,
+            _ => break,
+        };
+        input.advance();
+
+        if multibyte {
+            let mut wc = 0;
+            let byte = c as c_char;
+            match mbrtowc(&mut wc, &byte, 1, &mut state) as isize {
+                -2 => {
+                    partial = true;
+                    continue;
+                }
+                -1 => return Err(Failure::Matching),
+                _ => {
+                    partial = false;
+                    sink.push::<F>(wc as u32)?;
+                }
             }
-            match c {
-                b'%' => {
-                    while (byte as char).is_whitespace() {
-                        if !read!() {
-                            return Ok(matched);
-                        }
-                    }
+        } else {
+            sink.push::<F>(c)?;
+        }
+        count += 1;
+    }
+    if partial {
+        // Cut off in the middle of a character
+        return Err(Failure::Matching);
+    }
+    Ok(count)
+}
 
-                    if byte != b'%' {
-                        return Err(matched);
-                    } else if !read!() {
-                        return Ok(matched);
-                    }
+/// Store an integer in the type of `kind`
+unsafe fn store_int(ap: &mut va_list, kind: IntKind, i: u64) {
+    match kind {
+        IntKind::Byte => *ap.arg::<*mut c_char>() = i as c_char,
+        IntKind::Short => *ap.arg::<*mut c_short>() = i as c_short,
+        IntKind::Int => *ap.arg::<*mut c_int>() = i as c_int,
+        IntKind::Long => *ap.arg::<*mut c_long>() = i as c_long,
+        IntKind::LongLong => *ap.arg::<*mut c_longlong>() = i as c_longlong,
+        IntKind::IntMax => *ap.arg::<*mut intmax_t>() = i as intmax_t,
+        IntKind::PtrDiff => *ap.arg::<*mut ptrdiff_t>() = i as ptrdiff_t,
+        IntKind::Size => *ap.arg::<*mut size_t>() = i as size_t,
+    }
+}
+
+unsafe fn inner_scanf<R: Read, F: FormatChar>(
+    input: &mut Input<R, F>,
+    mut format: *const F,
+    mut ap: va_list,
+    matched: &mut c_int,
+) -> Result<(), Failure> {
+    while (*format).code() != 0 {
+        let c = next_char(&mut format)?;
+
+        if is_space::<F>(c) {
+            // White space matches any amount of white space, even none
+            while let Some(c) = input.peek()? {
+                if !is_space::<F>(c) {
+                    break;
                 }
-                b'd' | b'i' | b'o' | b'u' | b'x' | b'X' | b'f' | b'e' | b'g' | b'E' | b'a'
-                | b'p' => {
-                    while (byte as char).is_whitespace() {
-                        if !read!() {
-                            return Ok(matched);
-                        }
-                    }
+                input.advance();
+            }
+            continue;
+        } else if c != b'%' as u32 {
+            match input.peek()? {
+                Some(b) if b == c => input.advance(),
+                Some(_) => return Err(Failure::Matching),
+                None => return Err(Failure::Input),
+            }
+            continue;
+        }
 
-                    let pointer = c == b'p';
-                    // Pointers aren't automatic, but we do want to parse "0x"
-                    let auto = c == b'i' || pointer;
-                    let float = c == b'f' || c == b'e' || c == b'g' || c == b'E' || c == b'a';
+        let mut c = ascii(next_char(&mut format)?);
 
-                    let mut radix = match c {
-                        b'o' => 8,
-                        b'x' | b'X' | b'p' => 16,
-                        _ => 10,
-                    };
+        let ignore = c == b'*';
+        if ignore {
+            c = ascii(next_char(&mut format)?);
+        }
 
-                    let mut n = String::new();
-                    let mut dot = false;
-
-                    while width.map(|w| w > 0).unwrap_or(true)
-                        && ((byte >= b'0' && byte <= b'7')
-                            || (radix >= 10 && (byte >= b'8' && byte <= b'9'))
-                            || (float && !dot && byte == b'.')
-                            || (radix == 16
-                                && ((byte >= b'a' && byte <= b'f')
-                                    || (byte >= b'A' && byte <= b'F'))))
-                    {
-                        if auto
-                            && n.is_empty()
-                            && byte == b'0'
-                            && width.map(|w| w > 0).unwrap_or(true)
-                        {
-                            if !pointer {
-                                radix = 8;
-                            }
-                            width = width.map(|w| w - 1);
-                            if !read!() {
-                                return Ok(matched);
-                            }
-                            if width.map(|w| w > 0).unwrap_or(true)
-                                && (byte == b'x' || byte == b'X')
-                            {
-                                radix = 16;
-                                width = width.map(|w| w - 1);
-                                if width.map(|w| w > 0).unwrap_or(true) && !read!() {
-                                    return Ok(matched);
-                                }
-                            }
-                            continue;
-                        }
-                        if byte == b'.' {
-                            // Don't allow another dot
-                            dot = true;
-                        }
-                        n.push(byte as char);
-                        width = width.map(|w| w - 1);
-                        if width.map(|w| w > 0).unwrap_or(true) && !read!() {
-                            return Ok(matched);
-                        }
-                    }
+        let mut width = None;
+        while c >= b'0' && c <= b'9' {
+            let digit = (c - b'0') as usize;
+            width = Some(
+                width
+                    .unwrap_or(0usize)
+                    .saturating_mul(10)
+                    .saturating_add(digit),
+            );
+            c = ascii(next_char(&mut format)?);
+        }
 
-                    macro_rules! parse_type {
-                        (noformat $type:ident) => {{
-                            let n = if n.is_empty() {
-                                0 as $type
-                            } else {
-                                n.parse::<$type>().map_err(|_| 0)?
-                            };
-                            if !ignore {
-                                *ap.arg::<*mut $type>() = n;
-                                matched += 1;
-                            }
-                        }};
-                        (c_double) => {
-                            parse_type!(noformat c_double);
-                        };
-                        (c_float) => {
-                            parse_type!(noformat c_float);
-                        };
-                        ($type:ident) => {
-                            parse_type!($type, $type);
-                        };
-                        ($type:ident, $final:ty) => {{
-                            let n = if n.is_empty() {
-                                0 as $type
-                            } else {
-                                $type::from_str_radix(&n, radix).map_err(|_| 0)?
-                            };
-                            if !ignore {
-                                *ap.arg::<*mut $final>() = n as $final;
-                                matched += 1;
-                            }
-                        }};
-                    }
+        // Allocate the string of %c, %s or %[
+        let alloc = c == b'm';
+        if alloc {
+            c = ascii(next_char(&mut format)?);
+        }
 
-                    if float {
-                        if kind == IntKind::Long || kind == IntKind::LongLong {
-                            parse_type!(c_double);
-                        } else {
-                            parse_type!(c_float);
-                        }
-                    } else if c == b'p' {
-                        parse_type!(size_t, *mut c_void);
+        let mut kind = IntKind::Int;
+        loop {
+            kind = match c {
+                b'h' => {
+                    if kind == IntKind::Short || kind == IntKind::Byte {
+                        IntKind::Byte
                     } else {
-                        let unsigned = c == b'o' || c == b'u' || c == b'x' || c == b'X';
-
-                        match kind {
-                            IntKind::Byte => {
-                                if unsigned {
-                                    parse_type!(c_uchar);
-                                } else {
-                                    parse_type!(c_char);
-                                }
-                            }
-                            IntKind::Short => {
-                                if unsigned {
-                                    parse_type!(c_ushort)
-                                } else {
-                                    parse_type!(c_short)
-                                }
-                            }
-                            IntKind::Int => {
-                                if unsigned {
-                                    parse_type!(c_uint)
-                                } else {
-                                    parse_type!(c_int)
-                                }
-                            }
-                            IntKind::Long => {
-                                if unsigned {
-                                    parse_type!(c_ulong)
-                                } else {
-                                    parse_type!(c_long)
-                                }
-                            }
-                            IntKind::LongLong => {
-                                if unsigned {
-                                    parse_type!(c_ulonglong)
-                                } else {
-                                    parse_type!(c_longlong)
-                                }
-                            }
-                            IntKind::IntMax => {
-                                if unsigned {
-                                    parse_type!(uintmax_t)
-                                } else {
-                                    parse_type!(intmax_t)
-                                }
-                            }
-                            IntKind::PtrDiff => parse_type!(ptrdiff_t),
-                            IntKind::Size => {
-                                if unsigned {
-                                    parse_type!(size_t)
-                                } else {
-                                    parse_type!(ssize_t)
-                                }
-                            }
-                        }
+                        IntKind::Short
                     }
                 }
-                b's' => {
-                    while (byte as char).is_whitespace() {
-                        if !read!() {
-                            return Ok(matched);
-                        }
+                b'j' => IntKind::IntMax,
+                b'l' => {
+                    if kind == IntKind::Long || kind == IntKind::LongLong {
+                        IntKind::LongLong
+                    } else {
+                        IntKind::Long
                     }
+                }
+                b'q' | b'L' => IntKind::LongLong,
+                b't' => IntKind::PtrDiff,
+                b'z' => IntKind::Size,
+                _ => break,
+            };
 
-                    let mut ptr: Option<*mut c_char> = if ignore { None } else { Some(ap.arg()) };
-
-                    while width.map(|w| w > 0).unwrap_or(true) && !(byte as char).is_whitespace() {
-                        if let Some(ref mut ptr) = ptr {
-                            **ptr = byte as c_char;
-                            *ptr = ptr.offset(1);
-                        }
-                        width = width.map(|w| w - 1);
-                        if width.map(|w| w > 0).unwrap_or(true) && !read!() {
-                            eof = true;
-                            break;
-                        }
-                    }
+            c = ascii(next_char(&mut format)?);
+        }
 
-                    if let Some(ptr) = ptr {
-                        *ptr = 0;
-                        matched += 1;
-                    }
+        match c {
+            b'%' => {
+                if input.skip_space()? != b'%' as u32 {
+                    return Err(Failure::Matching);
                 }
-                b'c' => {
-                    let ptr: Option<*mut c_char> = if ignore { None } else { Some(ap.arg()) };
-
-                    for i in 0..width.unwrap_or(1) {
-                        if let Some(ptr) = ptr {
-                            *ptr.add(i) = byte as c_char;
-                        }
-                        width = width.map(|w| w - 1);
-                        if width.map(|w| w > 0).unwrap_or(true) && !read!() {
-                            eof = true;
-                            break;
+                input.advance();
+            }
+            b'n' => {
+                if !ignore {
+                    store_int(&mut ap, kind, input.count as u64);
+                }
+            }
+            b'd' | b'i' | b'o' | b'u' | b'x' | b'X' | b'p' => {
+                input.skip_space()?;
+                let mut width = width.unwrap_or(usize::max_value());
+
+                let i = if c == b'p' && input.peek()? == Some(b'(' as u32) {
+                    // The null pointer, as printf writes it
+                    for &expected in b"(nil)" {
+                        if input
+                            .accept(&mut width, |c| c == expected as u32)?
+                            .is_none()
+                        {
+                            return Err(Failure::Matching);
                         }
                     }
+                    0
+                } else {
+                    let base = match c {
+                        b'd' | b'u' => 10,
+                        b'i' => 0,
+                        b'o' => 8,
+                        _ => 16,
+                    };
+                    scan_int(input, &mut width, base, c == b'd' || c == b'i')?
+                };
 
-                    if ptr.is_some() {
-                        matched += 1;
+                if !ignore {
+                    if c == b'p' {
+                        *ap.arg::<*mut *mut c_void>() = i as usize as *mut c_void;
+                    } else {
+                        store_int(&mut ap, kind, i);
                     }
+                    *matched += 1;
                 }
-                b'[' => {
-                    c = next_byte(&mut format)?;
-
-                    let mut matches = Vec::new();
-                    let invert = if c == b'^' {
-                        c = next_byte(&mut format)?;
-                        true
-                    } else {
-                        false
-                    };
-
-                    let mut prev;
-                    loop {
-                        matches.push(c);
-                        prev = c;
-                        c = next_byte(&mut format)?;
-                        if c == b'-' {
-                            if prev == b']' {
-                                continue;
-                            }
-                            c = next_byte(&mut format)?;
-                            if c == b']' {
-                                matches.push(b'-');
-                                break;
-                            }
-                            prev += 1;
-                            while prev < c {
-                                matches.push(prev);
-                                prev += 1;
-                            }
-                        } else if c == b']' {
-                            break;
+            }
+            b'a' | b'A' | b'e' | b'E' | b'f' | b'F' | b'g' | b'G' => {
+                input.skip_space()?;
+                let (negative, scanned) = scan_float(input, width.unwrap_or(usize::max_value()))?;
+
+                if !ignore {
+                    match kind {
+                        IntKind::Long => {
+                            let f = scanned.double();
+                            *ap.arg::<*mut c_double>() = if negative { -f } else { f };
                         }
-                    }
-
-                    let mut ptr: Option<*mut c_char> = if ignore { None } else { Some(ap.arg()) };
-
-                    // While we haven't used up all the width, and it matches
-                    while width.map(|w| w > 0).unwrap_or(true) && !invert == matches.contains(&byte)
-                    {
-                        if let Some(ref mut ptr) = ptr {
-                            **ptr = byte as c_char;
-                            *ptr = ptr.offset(1);
+                        // Read as precisely as a double
+                        IntKind::LongLong => {
+                            let f = scanned.double();
+                            *ap.arg::<*mut LongDouble>() =
+                                LongDouble::from_double(if negative { -f } else { f });
                         }
-                        // Decrease the width, and read a new character unless the width is 0
-                        width = width.map(|w| w - 1);
-                        if width.map(|w| w > 0).unwrap_or(true) && !read!() {
-                            // Reading a new character has failed, return after
-                            // actually marking this as matched
-                            eof = true;
-                            break;
+                        _ => {
+                            let f = scanned.float();
+                            *ap.arg::<*mut c_float>() = if negative { -f } else { f };
                         }
                     }
-
-                    if let Some(ptr) = ptr {
-                        *ptr = 0;
-                        matched += 1;
-                    }
+                    *matched += 1;
                 }
-                b'n' => {
-                    if !ignore {
-                        *ap.arg::<*mut c_int>() = count as c_int;
+            }
+            b's' | b'c' | b'[' => {
+                // The scanset is checked where it is in the format
+                let mut set = format;
+                let mut invert = false;
+                if c == b'[' {
+                    invert = (*set).code() == b'^' as u32;
+                    if invert {
+                        set = set.add(1);
+                    }
+                    format = set;
+                    if (*format).code() == b']' as u32 {
+                        format = format.add(1);
                     }
+                    while (*format).code() != b']' as u32 {
+                        next_char(&mut format)?;
+                    }
+                    format = format.add(1);
                 }
-                _ => return Err(-1),
-            }
 
-            if eof {
-                return Ok(matched);
-            }
+                let width = width.unwrap_or(if c == b'c' { 1 } else { usize::max_value() });
+                let wide = kind == IntKind::Long;
+                let multibyte = wide && !F::WIDE;
+                let mut sink = Sink::new(&mut ap, ignore, alloc, wide);
+                let start = match c {
+                    b's' => input.skip_space().map(|_| ()),
+                    _ => match input.peek() {
+                        Ok(None) => Err(Failure::Input),
+                        other => other.map(|_| ()),
+                    },
+                };
+                let result = start.and_then(|()| match c {
+                    b's' => scan_chars(input, width, &mut sink, multibyte, |c| !is_space::<F>(c)),
+                    b'c' => scan_chars(input, width, &mut sink, multibyte, |_| true),
+                    _ => scan_chars(input, width, &mut sink, multibyte, |c| {
+                        in_set(set, c) != invert
+                    }),
+                });
+                // %[ fails if it matches nothing, and %c isn't terminated
+                let result = match result {
+                    Ok(0) => Err(Failure::Matching),
+                    Ok(_) if c != b'c' => sink.push::<F>(0),
+                    Ok(_) => Ok(()),
+                    Err(failure) => Err(failure),
+                };
+                sink.finish(result.is_ok());
+                result?;
 
-            if width != Some(0) && c != b'n' {
-                // It didn't hit the width, so an extra character was read and matched.
-                // But this character did not match so let's reuse it.
-                skip_read = true;
+                if !ignore {
+                    *matched += 1;
+                }
             }
+            _ => return Err(Failure::Matching),
         }
     }
-    Ok(matched)
+    Ok(())
+}
+
+/// The number of assigned arguments, or EOF if the input failed before any was
+unsafe fn scan<R: Read, F: FormatChar>(
+    input: &mut Input<R, F>,
+    format: *const F,
+    ap: va_list,
+) -> c_int {
+    let mut matched = 0;
+    match inner_scanf(input, format, ap, &mut matched) {
+        Err(Failure::Input) if matched == 0 => EOF,
+        _ => matched,
+    }
+}
+
+/// Read the arguments of `ap` from `r` as `format` says. A wide format reads multibyte input,
+/// with widths and counts in wide characters
+pub unsafe fn scanf<R: Read, F: FormatChar>(r: R, format: *const F, ap: va_list) -> c_int {
+    scan(&mut Input::<R, F>::new(r), format, ap)
 }
-pub unsafe fn scanf<R: Read>(r: R, format: *const c_char, ap: va_list) -> c_int {
-    match inner_scanf(r, format, ap) {
-        Ok(n) => n,
-        Err(n) => n,
+
+/// Like `scanf`, and push the character that ended the input back into `stream`, so that it is
+/// read next
+pub unsafe fn fscanf<F: FormatChar>(stream: &mut FILE, format: *const F, ap: va_list) -> c_int {
+    let (ret, next) = {
+        let mut input = Input::<_, F>::new(&mut *stream);
+        let ret = scan(&mut input, format, ap);
+        (ret, input.next)
+    };
+    if let Some(Some(c)) = next {
+        if F::WIDE {
+            let mut buf = [0; MB_LEN_MAX as usize];
+            let len = wcrtomb(buf.as_mut_ptr(), c as wchar_t, &mut mbstate_t::new());
+            stream.unget(slice::from_raw_parts(buf.as_ptr() as *const u8, len));
+        } else {
+            stream.unget(&[c as u8]);
+        }
     }
+    ret
 }
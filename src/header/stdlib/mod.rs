@@ -1,7 +1,7 @@
 //! stdlib implementation for Redox, following http://pubs.opengroup.org/onlinepubs/7908799/xsh/stdlib.h.html
 
-use core::{intrinsics, iter, mem, ptr, slice};
 use core::convert::TryFrom;
+use core::{intrinsics, iter, mem, ptr, slice};
 use rand::distributions::{Alphanumeric, Distribution, Uniform};
 use rand::prng::XorShiftRng;
 use rand::rngs::JitterRng;
@@ -17,7 +17,7 @@ use header::time::constants::CLOCK_MONOTONIC;
 use header::time::timespec;
 use header::unistd::{sysconf, _SC_PAGESIZE};
 use header::wchar::*;
-use header::{ctype, errno, unistd};
+use header::{ctype, errno, stdio, unistd};
 use platform;
 use platform::types::*;
 use platform::{Pal, Sys};
@@ -277,6 +277,8 @@ pub unsafe extern "C" fn exit(status: c_int) {
 
     _fini();
 
+    stdio::fflush(ptr::null_mut());
+
     pthread_terminate();
 
     Sys::exit(status);
@@ -449,7 +451,7 @@ pub unsafe extern "C" fn memalign(alignment: size_t, size: size_t) -> *mut c_voi
 #[no_mangle]
 pub unsafe extern "C" fn mblen(s: *const c_char, n: size_t) -> c_int {
     let mut wc: wchar_t = 0;
-    let mut state: mbstate_t = mbstate_t {};
+    let mut state = mbstate_t::new();
     let result: usize = mbrtowc(&mut wc, s, n, &mut state);
 
     if result == -1isize as usize {
@@ -464,13 +466,13 @@ pub unsafe extern "C" fn mblen(s: *const c_char, n: size_t) -> c_int {
 
 #[no_mangle]
 pub unsafe extern "C" fn mbstowcs(pwcs: *mut wchar_t, mut s: *const c_char, n: size_t) -> size_t {
-    let mut state: mbstate_t = mbstate_t {};
+    let mut state = mbstate_t::new();
     mbsrtowcs(pwcs, &mut s, n, &mut state)
 }
 
 #[no_mangle]
 pub unsafe extern "C" fn mbtowc(pwc: *mut wchar_t, s: *const c_char, n: size_t) -> c_int {
-    let mut state: mbstate_t = mbstate_t {};
+    let mut state = mbstate_t::new();
     mbrtowc(pwc, s, n, &mut state) as c_int
 }
 
@@ -1039,13 +1041,13 @@ pub unsafe extern "C" fn valloc(size: size_t) -> *mut c_void {
 
 #[no_mangle]
 pub extern "C" fn wcstombs(s: *mut c_char, pwcs: *mut *const wchar_t, n: size_t) -> size_t {
-    let mut state: mbstate_t = mbstate_t {};
+    let mut state = mbstate_t::new();
     wcsrtombs(s, pwcs, n, &mut state)
 }
 
 #[no_mangle]
 pub unsafe extern "C" fn wctomb(s: *mut c_char, wc: wchar_t) -> c_int {
-    let mut state: mbstate_t = mbstate_t {};
+    let mut state = mbstate_t::new();
     let result: usize = wcrtomb(s, wc, &mut state);
 
     if result == -1isize as usize {
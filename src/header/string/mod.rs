@@ -5,6 +5,7 @@ use core::{mem, ptr, slice, usize};
 use cbitset::BitSet256;
 
 use header::errno::*;
+use header::locale::{self, collate, locale_t, LcCollate};
 use header::signal;
 use platform;
 use platform::types::*;
@@ -148,8 +149,21 @@ pub unsafe extern "C" fn strcmp(s1: *const c_char, s2: *const c_char) -> c_int {
 
 #[no_mangle]
 pub unsafe extern "C" fn strcoll(s1: *const c_char, s2: *const c_char) -> c_int {
-    // relibc has no locale stuff (yet)
-    strcmp(s1, s2)
+    inner_strcoll(s1, s2, locale::current().collate)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn strcoll_l(s1: *const c_char, s2: *const c_char, loc: locale_t) -> c_int {
+    inner_strcoll(s1, s2, locale::get(loc).collate)
+}
+
+unsafe fn inner_strcoll(s1: *const c_char, s2: *const c_char, lc_collate: &LcCollate) -> c_int {
+    if !lc_collate.uca {
+        return strcmp(s1, s2);
+    }
+    let s1 = slice::from_raw_parts(s1 as *const u8, strlen(s1));
+    let s2 = slice::from_raw_parts(s2 as *const u8, strlen(s2));
+    collate::compare(collate::chars(s1), collate::chars(s2), lc_collate)
 }
 
 #[no_mangle]
@@ -243,6 +257,12 @@ pub unsafe extern "C" fn strerror(errnum: c_int) -> *mut c_char {
     strerror_buf.as_mut_ptr() as *mut c_char
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn strerror_l(errnum: c_int, _loc: locale_t) -> *mut c_char {
+    // Messages are only available in English
+    strerror(errnum)
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn strerror_r(errnum: c_int, buf: *mut c_char, buflen: size_t) -> c_int {
     let msg = strerror(errnum);
@@ -366,9 +386,12 @@ pub unsafe extern "C" fn strrchr(s: *const c_char, c: c_int) -> *mut c_char {
 
 #[no_mangle]
 pub unsafe extern "C" fn strsignal(sig: c_int) -> *const c_char {
-    signal::_signal_strings.get(sig as usize).unwrap_or(
-        &signal::_signal_strings[0] // Unknown signal message
-    ).as_ptr() as *const c_char
+    signal::_signal_strings
+        .get(sig as usize)
+        .unwrap_or(
+            &signal::_signal_strings[0], // Unknown signal message
+        )
+        .as_ptr() as *const c_char
 }
 
 #[no_mangle]
@@ -453,10 +476,41 @@ pub unsafe extern "C" fn strtok_r(
 
 #[no_mangle]
 pub unsafe extern "C" fn strxfrm(s1: *mut c_char, s2: *const c_char, n: size_t) -> size_t {
-    // relibc has no locale stuff (yet)
+    inner_strxfrm(s1, s2, n, locale::current().collate)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn strxfrm_l(
+    s1: *mut c_char,
+    s2: *const c_char,
+    n: size_t,
+    loc: locale_t,
+) -> size_t {
+    inner_strxfrm(s1, s2, n, locale::get(loc).collate)
+}
+
+unsafe fn inner_strxfrm(
+    s1: *mut c_char,
+    s2: *const c_char,
+    n: size_t,
+    lc_collate: &LcCollate,
+) -> size_t {
     let len = strlen(s2);
-    if len < n {
-        strcpy(s1, s2);
+    if !lc_collate.uca {
+        if len < n {
+            strcpy(s1, s2);
+        }
+        return len;
+    }
+
+    // The sort key compares with strcmp like the string does with strcoll
+    let key = collate::sort_key(
+        collate::chars(slice::from_raw_parts(s2 as *const u8, len)),
+        lc_collate,
+    );
+    if key.len() < n {
+        ptr::copy_nonoverlapping(key.as_ptr() as *const c_char, s1, key.len());
+        *s1.add(key.len()) = 0;
     }
-    len
+    key.len()
 }
@@ -2,6 +2,7 @@
 
 use core::ptr;
 
+use header::locale::locale_t;
 use header::{ctype, string};
 use platform::types::*;
 
@@ -43,6 +44,15 @@ pub unsafe extern "C" fn strcasecmp(first: *const c_char, second: *const c_char)
     strncasecmp(first, second, size_t::max_value())
 }
 
+#[no_mangle]
+pub unsafe extern "C" fn strcasecmp_l(
+    first: *const c_char,
+    second: *const c_char,
+    _loc: locale_t,
+) -> c_int {
+    strcasecmp(first, second)
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn strncasecmp(
     mut first: *const c_char,
@@ -61,3 +71,13 @@ pub unsafe extern "C" fn strncasecmp(
     }
     0
 }
+
+#[no_mangle]
+pub unsafe extern "C" fn strncasecmp_l(
+    first: *const c_char,
+    second: *const c_char,
+    n: size_t,
+    _loc: locale_t,
+) -> c_int {
+    strncasecmp(first, second, n)
+}
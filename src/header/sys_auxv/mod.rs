@@ -1,10 +1,21 @@
 //! sys/auxv.h implementation
 
+use header::errno::ENOENT;
+use platform;
 use platform::types::*;
 
+pub const AT_NULL: usize = 0;
+pub const AT_PAGESZ: usize = 6;
 pub const AT_HWCAP: usize = 16;
+pub const AT_SYSINFO_EHDR: usize = 33;
 
 #[no_mangle]
-pub extern "C" fn getauxval(_t: c_ulong) -> c_ulong {
-    0
+pub unsafe extern "C" fn getauxval(t: c_ulong) -> c_ulong {
+    match platform::getauxval(t as usize) {
+        Some(value) => value as c_ulong,
+        None => {
+            platform::errno = ENOENT;
+            0
+        }
+    }
 }
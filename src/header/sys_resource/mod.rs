@@ -12,15 +12,38 @@ use platform::{Pal, Sys};
 // const RUSAGE_BOTH: c_int = -2;
 // const RUSAGE_THREAD: c_int = 1;
 
-type rlim_t = u64;
+pub const RLIM_INFINITY: rlim_t = !0;
+pub const RLIM_SAVED_CUR: rlim_t = RLIM_INFINITY;
+pub const RLIM_SAVED_MAX: rlim_t = RLIM_INFINITY;
+
+pub const RLIMIT_CPU: c_int = 0;
+pub const RLIMIT_FSIZE: c_int = 1;
+pub const RLIMIT_DATA: c_int = 2;
+pub const RLIMIT_STACK: c_int = 3;
+pub const RLIMIT_CORE: c_int = 4;
+pub const RLIMIT_RSS: c_int = 5;
+pub const RLIMIT_NPROC: c_int = 6;
+pub const RLIMIT_NOFILE: c_int = 7;
+pub const RLIMIT_MEMLOCK: c_int = 8;
+pub const RLIMIT_AS: c_int = 9;
+pub const RLIMIT_LOCKS: c_int = 10;
+pub const RLIMIT_SIGPENDING: c_int = 11;
+pub const RLIMIT_MSGQUEUE: c_int = 12;
+pub const RLIMIT_NICE: c_int = 13;
+pub const RLIMIT_RTPRIO: c_int = 14;
+pub const RLIMIT_NLIMITS: c_int = 15;
+
+pub type rlim_t = u64;
 
 #[repr(C)]
+#[derive(Default)]
 pub struct rlimit {
     pub rlim_cur: rlim_t,
     pub rlim_max: rlim_t,
 }
 
 #[repr(C)]
+#[derive(Default)]
 pub struct rusage {
     pub ru_utime: timeval,
     pub ru_stime: timeval,
@@ -45,9 +68,9 @@ pub unsafe extern "C" fn getpriority(which: c_int, who: id_t) -> c_int {
     unimplemented!();
 }
 
-// #[no_mangle]
+#[no_mangle]
 pub unsafe extern "C" fn getrlimit(resource: c_int, rlp: *mut rlimit) -> c_int {
-    unimplemented!();
+    Sys::getrlimit(resource, rlp)
 }
 
 #[no_mangle]
@@ -60,7 +83,7 @@ pub unsafe extern "C" fn setpriority(which: c_int, who: id_t, nice: c_int) -> c_
     unimplemented!();
 }
 
-// #[no_mangle]
+#[no_mangle]
 pub unsafe extern "C" fn setrlimit(resource: c_int, rlp: *const rlimit) -> c_int {
-    unimplemented!();
+    Sys::setrlimit(resource, rlp)
 }
@@ -1,16 +1,24 @@
 //! time implementation for Redox, following http://pubs.opengroup.org/onlinepubs/7908799/xsh/time.h.html
 
+use alloc::boxed::Box;
 use core::mem::transmute;
+use core::ptr;
 
 use header::errno::EIO;
+use header::locale::{self, locale_t};
+use header::signal::sigevent;
 use platform;
 use platform::types::*;
 use platform::{Pal, Sys};
 
 use self::constants::*;
+use self::timer::Timer;
 
 pub mod constants;
 mod strftime;
+mod strptime;
+mod timer;
+mod tz;
 
 #[repr(C)]
 #[derive(Default)]
@@ -61,6 +69,34 @@ static mut TM: tm = tm {
     tm_zone: UTC,
 };
 
+// getdate has its own result, separate from the one shared by localtime and gmtime
+static mut GETDATE: tm = tm {
+    tm_sec: 0,
+    tm_min: 0,
+    tm_hour: 0,
+    tm_mday: 0,
+    tm_mon: 0,
+    tm_year: 0,
+    tm_wday: 0,
+    tm_yday: 0,
+    tm_isdst: 0,
+    tm_gmtoff: 0,
+    tm_zone: UTC,
+};
+
+/// Why the last getdate call failed: 1 if DATEMSK is not set, 2 if the template file cannot be
+/// opened, 3 if its status cannot be read, 4 if it is not a regular file, 5 on a read error, 6 if
+/// memory runs out, 7 if no template matches and 8 if the date is invalid
+#[no_mangle]
+pub static mut getdate_err: c_int = 0;
+
+#[no_mangle]
+pub static mut tzname: [*mut c_char; 2] = [UTC as *mut c_char, UTC as *mut c_char];
+#[no_mangle]
+pub static mut timezone: c_long = 0;
+#[no_mangle]
+pub static mut daylight: c_int = 0;
+
 // The C Standard says that ctime and asctime return the same pointer.
 static mut ASCTIME: [c_char; 26] = [0; 26];
 
@@ -70,8 +106,6 @@ pub struct itimerspec {
     pub it_value: timespec,
 }
 
-pub struct sigevent;
-
 #[no_mangle]
 pub unsafe extern "C" fn asctime(timeptr: *const tm) -> *mut c_char {
     asctime_r(timeptr, transmute::<&mut _, *mut c_char>(&mut ASCTIME))
@@ -120,9 +154,14 @@ pub extern "C" fn clock() -> clock_t {
     ts.tv_sec * CLOCKS_PER_SEC + ts.tv_nsec / (1_000_000_000 / CLOCKS_PER_SEC)
 }
 
-// #[no_mangle]
+#[no_mangle]
+pub extern "C" fn clock_getcpuclockid(pid: pid_t, clock_id: *mut clockid_t) -> c_int {
+    Sys::clock_getcpuclockid(pid, clock_id)
+}
+
+#[no_mangle]
 pub extern "C" fn clock_getres(clock_id: clockid_t, res: *mut timespec) -> c_int {
-    unimplemented!();
+    Sys::clock_getres(clock_id, res)
 }
 
 #[no_mangle]
@@ -130,9 +169,27 @@ pub extern "C" fn clock_gettime(clock_id: clockid_t, tp: *mut timespec) -> c_int
     Sys::clock_gettime(clock_id, tp)
 }
 
-// #[no_mangle]
+#[no_mangle]
+pub unsafe extern "C" fn clock_nanosleep(
+    clock_id: clockid_t,
+    flags: c_int,
+    rqtp: *const timespec,
+    rmtp: *mut timespec,
+) -> c_int {
+    // Unlike nanosleep, errors are returned instead of being stored in errno
+    let saved_errno = platform::errno;
+    let ret = if Sys::clock_nanosleep(clock_id, flags, rqtp, rmtp) < 0 {
+        platform::errno
+    } else {
+        0
+    };
+    platform::errno = saved_errno;
+    ret
+}
+
+#[no_mangle]
 pub extern "C" fn clock_settime(clock_id: clockid_t, tp: *const timespec) -> c_int {
-    unimplemented!();
+    Sys::clock_settime(clock_id, tp)
 }
 
 #[no_mangle]
@@ -150,9 +207,20 @@ pub extern "C" fn difftime(time1: time_t, time0: time_t) -> c_double {
     (time1 - time0) as c_double
 }
 
-// #[no_mangle]
-pub extern "C" fn getdate(string: *const c_char) -> tm {
-    unimplemented!();
+#[no_mangle]
+pub unsafe extern "C" fn getdate(string: *const c_char) -> *mut tm {
+    match strptime::getdate(string, &mut GETDATE) {
+        0 => &mut GETDATE,
+        err => {
+            getdate_err = err;
+            ptr::null_mut()
+        }
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn getdate_r(string: *const c_char, result: *mut tm) -> c_int {
+    strptime::getdate(string, &mut *result)
 }
 
 #[no_mangle]
@@ -258,49 +326,28 @@ pub unsafe extern "C" fn localtime(clock: *const time_t) -> *mut tm {
 
 #[no_mangle]
 pub unsafe extern "C" fn localtime_r(clock: *const time_t, t: *mut tm) -> *mut tm {
-    // TODO: Change tm_isdst, tm_gmtoff, tm_zone
-    gmtime_r(clock, t)
+    let local = tz::local_type(*clock);
+    gmtime_r(&(*clock + local.offset), t);
+    (*t).tm_isdst = local.isdst as c_int;
+    (*t).tm_gmtoff = local.offset as c_long;
+    (*t).tm_zone = local.name;
+    t
 }
 
 #[no_mangle]
 pub unsafe extern "C" fn mktime(t: *mut tm) -> time_t {
-    let mut year = (*t).tm_year + 1900;
-    let mut month = (*t).tm_mon;
-    let mut day = (*t).tm_mday as i64 - 1;
-
-    let leap = if leap_year(year) { 1 } else { 0 };
-
-    if year < 1970 {
-        day = MONTH_DAYS[if leap_year(year) { 1 } else { 0 }][(*t).tm_mon as usize] as i64 - day;
-
-        while year < 1969 {
-            year += 1;
-            day += if leap_year(year) { 366 } else { 365 };
-        }
-
-        while month < 11 {
-            month += 1;
-            day += MONTH_DAYS[leap][month as usize] as i64;
-        }
-
-        -(day * (60 * 60 * 24)
-            - (((*t).tm_hour as i64) * (60 * 60) + ((*t).tm_min as i64) * 60 + (*t).tm_sec as i64))
+    // Bring the month into range, timegm handles the other fields being out of range
+    let years = if (*t).tm_mon < 0 {
+        ((*t).tm_mon - 11) / 12
     } else {
-        while year > 1970 {
-            year -= 1;
-            day += if leap_year(year) { 366 } else { 365 };
-        }
-
-        while month > 0 {
-            month -= 1;
-            day += MONTH_DAYS[leap][month as usize] as i64;
-        }
+        (*t).tm_mon / 12
+    };
+    (*t).tm_mon -= years * 12;
+    (*t).tm_year += years;
 
-        (day * (60 * 60 * 24)
-            + ((*t).tm_hour as i64) * (60 * 60)
-            + ((*t).tm_min as i64) * 60
-            + (*t).tm_sec as i64)
-    }
+    let clock = tz::local_to_utc(timegm(t), (*t).tm_isdst);
+    localtime_r(&clock, t);
+    clock
 }
 
 #[no_mangle]
@@ -315,37 +362,37 @@ pub unsafe extern "C" fn strftime(
     format: *const c_char,
     timeptr: *const tm,
 ) -> size_t {
-    let ret = strftime::strftime(
-        &mut platform::StringWriter(s as *mut u8, maxsize),
-        format,
-        timeptr,
-    );
-    if ret < maxsize {
-        ret
-    } else {
-        0
-    }
+    strftime::strftime_buf(s, maxsize, format, timeptr, locale::current())
 }
 
-// #[no_mangle]
-pub extern "C" fn strptime(buf: *const c_char, format: *const c_char, tm: *mut tm) -> *mut c_char {
-    unimplemented!();
+#[no_mangle]
+pub unsafe extern "C" fn strftime_l(
+    s: *mut c_char,
+    maxsize: size_t,
+    format: *const c_char,
+    timeptr: *const tm,
+    loc: locale_t,
+) -> size_t {
+    strftime::strftime_buf(s, maxsize, format, timeptr, locale::get(loc))
 }
 
 #[no_mangle]
-pub unsafe extern "C" fn time(tloc: *mut time_t) -> time_t {
-    let mut ts = timespec::default();
-    Sys::clock_gettime(CLOCK_REALTIME, &mut ts);
-    if !tloc.is_null() {
-        *tloc = ts.tv_sec
-    };
-    ts.tv_sec
+pub unsafe extern "C" fn strptime(
+    buf: *const c_char,
+    format: *const c_char,
+    tm: *mut tm,
+) -> *mut c_char {
+    strptime::strptime(buf, format, &mut *tm)
+}
+
+#[no_mangle]
+pub extern "C" fn time(tloc: *mut time_t) -> time_t {
+    Sys::time(tloc)
 }
 
 #[no_mangle]
 pub unsafe extern "C" fn timelocal(tm: *mut tm) -> time_t {
-    //TODO: timezone
-    timegm(tm)
+    mktime(tm)
 }
 
 #[no_mangle]
@@ -366,43 +413,49 @@ pub unsafe extern "C" fn timegm(tm: *mut tm) -> time_t {
         + s
 }
 
-// #[no_mangle]
-pub extern "C" fn timer_create(
+#[no_mangle]
+pub unsafe extern "C" fn timer_create(
     clock_id: clockid_t,
     evp: *mut sigevent,
     timerid: *mut timer_t,
 ) -> c_int {
-    unimplemented!();
+    match Timer::create(clock_id, evp) {
+        Some(timer) => {
+            *timerid = Box::into_raw(timer) as timer_t;
+            0
+        }
+        None => -1,
+    }
 }
 
-// #[no_mangle]
-pub extern "C" fn timer_delete(timerid: timer_t) -> c_int {
-    unimplemented!();
+#[no_mangle]
+pub unsafe extern "C" fn timer_delete(timerid: timer_t) -> c_int {
+    Box::from_raw(timerid as *mut Timer).delete()
 }
 
-// #[no_mangle]
-pub extern "C" fn tzset() {
-    unimplemented!();
+#[no_mangle]
+pub unsafe extern "C" fn tzset() {
+    tz::tzset();
 }
 
-// #[no_mangle]
-pub extern "C" fn timer_settime(
+#[no_mangle]
+pub unsafe extern "C" fn timer_settime(
     timerid: timer_t,
     flags: c_int,
     value: *const itimerspec,
     ovalue: *mut itimerspec,
 ) -> c_int {
-    unimplemented!();
+    (*(timerid as *mut Timer)).settime(flags, value, ovalue)
 }
 
-// #[no_mangle]
-pub extern "C" fn timer_gettime(timerid: timer_t, value: *mut itimerspec) -> c_int {
-    unimplemented!();
+#[no_mangle]
+pub unsafe extern "C" fn timer_gettime(timerid: timer_t, value: *mut itimerspec) -> c_int {
+    (*(timerid as *mut Timer)).gettime(value)
 }
 
-// #[no_mangle]
-pub extern "C" fn timer_getoverrun(timerid: timer_t) -> c_int {
-    unimplemented!();
+#[no_mangle]
+pub unsafe extern "C" fn timer_getoverrun(timerid: timer_t) -> c_int {
+    (*(timerid as *mut Timer)).getoverrun()
 }
 
 /*
@@ -1,142 +1,332 @@
-use alloc::string::String;
+use alloc::vec::Vec;
+use core::{cmp, ptr};
 
+use c_str::CStr;
+use header::locale::{LcTime, Locale};
+use platform;
 use platform::types::*;
-use platform::{self, WriteByte};
-
-use super::tm;
-
-pub unsafe fn strftime<W: WriteByte>(w: &mut W, format: *const c_char, t: *const tm) -> size_t {
-    pub unsafe fn inner_strftime<W: WriteByte>(
-        w: &mut W,
-        mut format: *const c_char,
-        t: *const tm,
-    ) -> bool {
-        macro_rules! w {
-            (byte $b:expr) => {{
-                if w.write_u8($b).is_err() {
-                    return false;
-                }
-            }};
-            (char $chr:expr) => {{
-                if w.write_char($chr).is_err() {
-                    return false;
-                }
-            }};
-            (recurse $fmt:expr) => {{
-                let mut fmt = String::with_capacity($fmt.len() + 1);
-                fmt.push_str($fmt);
-                fmt.push('\0');
+use platform::WriteByte;
+
+use super::{mktime, tm, tzname};
+
+#[derive(Clone, Copy)]
+enum Case {
+    Keep,
+    Lower,
+    Upper,
+}
+
+/// Append `s`, padded on the left to `width` with zeros if the pad flag is 0 and spaces otherwise
+fn add(out: &mut Vec<u8>, s: &[u8], width: usize, pad: u8, case: Case) {
+    if width > s.len() {
+        let fill = if pad == b'0' { b'0' } else { b' ' };
+        out.resize(out.len() + width - s.len(), fill);
+    }
+    out.extend(s.iter().map(|&c| match case {
+        Case::Keep => c,
+        Case::Lower => c.to_ascii_lowercase(),
+        Case::Upper => c.to_ascii_uppercase(),
+    }));
+}
+
+/// Append a number of at least `digits` digits. The padding works like glibc's: the minimum
+/// number of digits is padded with zeros after the sign, or with spaces before it for the _ flag,
+/// and whatever is left of the field width is padded as in `add`
+fn number(out: &mut Vec<u8>, value: i64, digits: usize, mut width: usize, pad: u8) {
+    let mut buf = [0; 21];
+    let mut start = buf.len();
+    let negative = value < 0;
+    let mut n = if negative {
+        (value as u64).wrapping_neg()
+    } else {
+        value as u64
+    };
+    loop {
+        start -= 1;
+        buf[start] = b'0' + (n % 10) as u8;
+        n /= 10;
+        if n == 0 {
+            break;
+        }
+    }
+    if negative {
+        start -= 1;
+        buf[start] = b'-';
+    }
+
+    let len = buf.len() - start;
+    if pad != b'-' && digits > len {
+        let padding = digits - len;
+        if pad == b'_' {
+            out.resize(out.len() + padding, b' ');
+            width = width.saturating_sub(padding);
+        } else {
+            if negative {
+                out.push(b'-');
+                start += 1;
+            }
+            out.resize(out.len() + padding, b'0');
+            width = 0;
+        }
+    }
+    add(out, &buf[start..], width, pad, Case::Keep);
+}
+
+/// The number of days from the first day of the first ISO 8601 week of the year to the given day
+fn iso_week_days(yday: c_int, wday: c_int) -> c_int {
+    // Week 1 is the one containing the year's first Thursday. 378 is a multiple of 7 large enough
+    // to keep the remainder positive
+    yday - (yday - wday + 4 + 378) % 7 + 3
+}
+
+/// The ISO 8601 week-based year, and the days since the start of its first week
+fn iso_week(t: &tm) -> (i64, c_int) {
+    let mut year = t.tm_year as i64 + 1900;
+    let year_days = |year: i64| {
+        if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) {
+            366
+        } else {
+            365
+        }
+    };
+    let mut days = iso_week_days(t.tm_yday, t.tm_wday);
+    if days < 0 {
+        // The day belongs to the last week of the previous year
+        year -= 1;
+        days = iso_week_days(t.tm_yday + year_days(year), t.tm_wday);
+    } else {
+        let next = iso_week_days(t.tm_yday - year_days(year), t.tm_wday);
+        if next >= 0 {
+            // The day belongs to the first week of the next year
+            year += 1;
+            days = next;
+        }
+    }
+    (year, days)
+}
+
+unsafe fn inner_strftime(out: &mut Vec<u8>, format: &[u8], t: &tm, names: &LcTime) {
+    let mut i = 0;
+    while i < format.len() {
+        if format[i] != b'%' {
+            out.push(format[i]);
+            i += 1;
+            continue;
+        }
+        let start = i;
+        i += 1;
+
+        // GNU flags: _ pads numbers with spaces, - leaves them unpadded and 0 pads with zeros, ^
+        // converts to upper case and # swaps the case of names
+        let mut pad = 0;
+        let mut to_upper = false;
+        let mut to_lower = false;
+        let mut change_case = false;
+        loop {
+            match format.get(i) {
+                Some(&c @ b'_') | Some(&c @ b'-') | Some(&c @ b'0') => pad = c,
+                Some(b'^') => to_upper = true,
+                Some(b'#') => change_case = true,
+                _ => break,
+            }
+            i += 1;
+        }
+
+        let mut width = 0usize;
+        while let Some(&c @ b'0'..=b'9') = format.get(i) {
+            width = width.saturating_mul(10).saturating_add((c - b'0') as usize);
+            i += 1;
+        }
+
+        let modifier = match format.get(i) {
+            Some(&c @ b'E') | Some(&c @ b'O') => {
+                i += 1;
+                c
+            }
+            _ => 0,
+        };
+
+        // A % at the end of the format is written as is
+        let conv = format.get(i).cloned().unwrap_or(0);
+        if conv != 0 {
+            i += 1;
+        }
 
-                if !inner_strftime(w, fmt.as_ptr() as *mut c_char, t) {
-                    return false;
+        // The conversions without an alternative era (%E) or digits (%O) form
+        let allowed = match modifier {
+            b'E' => !b"aAbBdDeFgGhHIjklmMSUVwW".contains(&conv),
+            b'O' => !b"aAcDFxXY".contains(&conv),
+            _ => true,
+        };
+
+        macro_rules! case {
+            () => {
+                if to_lower {
+                    Case::Lower
+                } else if to_upper {
+                    Case::Upper
+                } else {
+                    Case::Keep
                 }
-            }};
-            ($str:expr) => {{
-                if w.write_str($str).is_err() {
-                    return false;
+            };
+        }
+        macro_rules! cpy {
+            ($s:expr) => {
+                add(out, $s.as_bytes(), width, pad, case!())
+            };
+        }
+        macro_rules! name {
+            ($s:expr) => {{
+                if change_case {
+                    to_upper = true;
                 }
+                cpy!($s)
             }};
-            ($fmt:expr, $($args:expr),+) => {{
-                // Would use write!() if I could get the length written
-                if write!(w, $fmt, $($args),+).is_err() {
-                    return false;
+        }
+        macro_rules! num {
+            ($digits:expr, $value:expr) => {
+                number(out, $value as i64, cmp::max($digits, width), width, pad)
+            };
+        }
+        macro_rules! num_spaces {
+            ($digits:expr, $value:expr) => {{
+                if pad == 0 {
+                    pad = b'_';
                 }
+                num!($digits, $value)
             }};
         }
-        const WDAYS: [&str; 7] = [
-            "Sunday",
-            "Monday",
-            "Tuesday",
-            "Wednesday",
-            "Thursday",
-            "Friday",
-            "Saturday",
-        ];
-        const MONTHS: [&str; 12] = [
-            "January",
-            "Febuary",
-            "March",
-            "April",
-            "May",
-            "June",
-            "July",
-            "August",
-            "September",
-            "October",
-            "November",
-            "December",
-        ];
-
-        while *format != 0 {
-            if *format as u8 != b'%' {
-                w!(byte * format as u8);
-                format = format.offset(1);
-                continue;
-            }
-
-            format = format.offset(1);
+        macro_rules! subformat {
+            ($fmt:expr) => {{
+                let mut sub = Vec::new();
+                inner_strftime(&mut sub, $fmt.as_bytes(), t, names);
+                let case = if to_upper { Case::Upper } else { Case::Keep };
+                add(out, &sub, width, pad, case);
+            }};
+        }
+        // Like in glibc, %#b is upper case even when the modifier is invalid
+        if change_case && (conv == b'b' || conv == b'h') {
+            to_upper = true;
+        }
 
-            if *format as u8 == b'E' || *format as u8 == b'O' {
-                // Ignore because these do nothing without locale
-                format = format.offset(1);
+        let hour12 = (t.tm_hour + 11) % 12 + 1;
+        match conv {
+            _ if !allowed => add(out, &format[start..i], width, pad, case!()),
+            b'%' => add(out, b"%", width, pad, Case::Keep),
+            b'n' => add(out, b"\n", width, pad, Case::Keep),
+            b't' => add(out, b"\t", width, pad, Case::Keep),
+            b'a' => name!(names.abday[t.tm_wday as usize].as_str()),
+            b'A' => name!(names.day[t.tm_wday as usize].as_str()),
+            b'b' | b'h' => name!(names.abmon[t.tm_mon as usize].as_str()),
+            b'B' => name!(names.mon[t.tm_mon as usize].as_str()),
+            b'c' => subformat!(names.d_t_fmt.as_str()),
+            b'C' => {
+                let year = t.tm_year as i64 + 1900;
+                num!(1, year / 100 - (year % 100 < 0) as i64)
             }
-
-            match *format as u8 {
-                b'%' => w!(byte b'%'),
-                b'n' => w!(byte b'\n'),
-                b't' => w!(byte b'\t'),
-                b'a' => w!(&WDAYS[(*t).tm_wday as usize][..3]),
-                b'A' => w!(WDAYS[(*t).tm_wday as usize]),
-                b'b' | b'h' => w!(&MONTHS[(*t).tm_mon as usize][..3]),
-                b'B' => w!(MONTHS[(*t).tm_mon as usize]),
-                b'C' => {
-                    let mut year = (*t).tm_year / 100;
-                    // Round up
-                    if (*t).tm_year % 100 != 0 {
-                        year += 1;
-                    }
-                    w!("{:02}", year + 19);
+            b'd' => num!(2, t.tm_mday),
+            b'D' => subformat!("%m/%d/%y"),
+            b'e' => num_spaces!(2, t.tm_mday),
+            b'F' => subformat!("%Y-%m-%d"),
+            b'g' => num!(2, (iso_week(t).0 % 100 + 100) % 100),
+            b'G' => num!(1, iso_week(t).0),
+            b'H' => num!(2, t.tm_hour),
+            b'I' => num!(2, hour12),
+            b'j' => num!(3, t.tm_yday + 1),
+            b'k' => num_spaces!(2, t.tm_hour),
+            b'l' => num_spaces!(2, hour12),
+            b'm' => num!(2, t.tm_mon + 1),
+            b'M' => num!(2, t.tm_min),
+            b'p' | b'P' => {
+                // %P is lower case, as is %p with the # flag
+                to_lower = conv == b'P' || change_case;
+                cpy!(names.am_pm[(t.tm_hour >= 12) as usize].as_str())
+            }
+            b'r' => subformat!(names.t_fmt_ampm.as_str()),
+            b'R' => subformat!("%H:%M"),
+            b's' => {
+                // mktime normalizes its argument, so it gets a copy
+                let mut copy = ptr::read(t);
+                number(out, mktime(&mut copy) as i64, 1, width, pad)
+            }
+            b'S' => num!(2, t.tm_sec),
+            b'T' => subformat!("%H:%M:%S"),
+            b'u' => num!(1, (t.tm_wday + 6) % 7 + 1),
+            b'U' => num!(2, (t.tm_yday - t.tm_wday + 7) / 7),
+            b'V' => num!(2, iso_week(t).1 / 7 + 1),
+            b'w' => num!(1, t.tm_wday),
+            b'W' => num!(2, (t.tm_yday - (t.tm_wday + 6) % 7 + 7) / 7),
+            b'x' => subformat!(names.d_fmt.as_str()),
+            b'X' => subformat!(names.t_fmt.as_str()),
+            b'y' => num!(2, (t.tm_year % 100 + 100) % 100),
+            b'Y' => num!(1, t.tm_year as i64 + 1900),
+            b'z' => {
+                // Nothing is known about the time zone if tm_isdst is negative
+                if t.tm_isdst >= 0 {
+                    let sign = if t.tm_gmtoff < 0 { b"-" } else { b"+" };
+                    add(out, sign, width, pad, Case::Keep);
+                    let minutes = (t.tm_gmtoff as i64).abs() / 60;
+                    num!(4, minutes / 60 * 100 + minutes % 60);
                 }
-                b'd' => w!("{:02}", (*t).tm_mday),
-                b'D' => w!(recurse "%m/%d/%y"),
-                b'e' => w!("{:2}", (*t).tm_mday),
-                b'F' => w!(recurse "%Y-%m-%d"),
-                b'H' => w!("{:02}", (*t).tm_hour),
-                b'I' => w!("{:02}", ((*t).tm_hour + 12 - 1) % 12 + 1),
-                b'j' => w!("{:03}", (*t).tm_yday),
-                b'k' => w!("{:2}", (*t).tm_hour),
-                b'l' => w!("{:2}", ((*t).tm_hour + 12 - 1) % 12 + 1),
-                b'm' => w!("{:02}", (*t).tm_mon + 1),
-                b'M' => w!("{:02}", (*t).tm_min),
-                b'p' => w!(if (*t).tm_hour < 12 { "AM" } else { "PM" }),
-                b'P' => w!(if (*t).tm_hour < 12 { "am" } else { "pm" }),
-                b'r' => w!(recurse "%I:%M:%S %p"),
-                b'R' => w!(recurse "%H:%M"),
-                // Nothing is modified in mktime, but the C standard of course requires a mutable pointer ._.
-                b's' => w!("{}", super::mktime(t as *mut tm)),
-                b'S' => w!("{:02}", (*t).tm_sec),
-                b'T' => w!(recurse "%H:%M:%S"),
-                b'u' => w!("{}", ((*t).tm_wday + 7 - 1) % 7 + 1),
-                b'U' => w!("{}", ((*t).tm_yday + 7 - (*t).tm_wday) / 7),
-                b'w' => w!("{}", (*t).tm_wday),
-                b'W' => w!("{}", ((*t).tm_yday + 7 - ((*t).tm_wday + 6) % 7) / 7),
-                b'y' => w!("{:02}", (*t).tm_year % 100),
-                b'Y' => w!("{}", (*t).tm_year + 1900),
-                b'z' => w!("+0000"), // TODO
-                b'Z' => w!("UTC"),   // TODO
-                b'+' => w!(recurse "%a %b %d %T %Z %Y"),
-                _ => return false,
             }
-
-            format = format.offset(1);
+            b'Z' => {
+                to_lower = change_case;
+                let mut zone = t.tm_zone;
+                if (zone.is_null() || *zone == 0) && t.tm_isdst >= 0 {
+                    zone = tzname[(t.tm_isdst != 0) as usize] as *const c_char;
+                }
+                let zone = if zone.is_null() {
+                    &[]
+                } else {
+                    CStr::from_ptr(zone).to_bytes()
+                };
+                add(out, zone, width, pad, case!());
+            }
+            b'+' => subformat!("%a %b %d %T %Z %Y"),
+            // Unknown conversions are copied as is, like in glibc
+            _ => add(out, &format[start..i], width, pad, case!()),
         }
-        true
     }
+}
 
-    let mut w = platform::CountingWriter::new(w);
-    if !inner_strftime(&mut w, format, t) {
-        return 0;
+pub unsafe fn strftime<W: WriteByte>(
+    w: &mut W,
+    format: *const c_char,
+    t: *const tm,
+    locale: &Locale,
+) -> size_t {
+    let mut out = Vec::new();
+    inner_strftime(
+        &mut out,
+        CStr::from_ptr(format).to_bytes(),
+        &*t,
+        locale.time,
+    );
+    for &b in &out {
+        if w.write_u8(b).is_err() {
+            return 0;
+        }
     }
+    out.len()
+}
 
-    w.written
+/// strftime into a buffer of `maxsize` bytes, returning 0 if the result doesn't fit
+pub unsafe fn strftime_buf(
+    s: *mut c_char,
+    maxsize: size_t,
+    format: *const c_char,
+    t: *const tm,
+    locale: &Locale,
+) -> size_t {
+    let ret = strftime(
+        &mut platform::StringWriter(s as *mut u8, maxsize),
+        format,
+        t,
+        locale,
+    );
+    if ret < maxsize {
+        ret
+    } else {
+        0
+    }
 }
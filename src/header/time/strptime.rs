@@ -0,0 +1,498 @@
+//! strptime and getdate, following
+//! http://pubs.opengroup.org/onlinepubs/9699919799/functions/strptime.html and
+//! http://pubs.opengroup.org/onlinepubs/9699919799/functions/getdate.html
+
+use alloc::vec::Vec;
+use core::{mem, ptr};
+
+use c_str::CStr;
+use fs::File;
+use header::fcntl::{O_CLOEXEC, O_RDONLY};
+use header::locale::{self, LocaleStr};
+use header::stdlib::getenv;
+use header::sys_stat::{stat, S_IFMT, S_IFREG};
+use io::Read;
+use platform::types::*;
+use platform::{Pal, Sys};
+
+use super::{leap_year, localtime_r, mktime, time, tm, MONTH_DAYS};
+
+/// The fields that were given in the input, used to work out the ones that were not
+#[derive(Default)]
+struct Fields {
+    century: Option<c_int>,
+    year_of_century: Option<c_int>,
+    year: bool,
+    pm: Option<bool>,
+    hour12: bool,
+    // The week number, and whether weeks start on Monday
+    week: Option<(c_int, bool)>,
+    mon: bool,
+    mday: bool,
+    wday: bool,
+    yday: bool,
+    hour: bool,
+    min: bool,
+    sec: bool,
+    epoch: bool,
+}
+
+struct Parser<'a> {
+    input: &'a [u8],
+    pos: usize,
+}
+
+fn is_space(c: u8) -> bool {
+    c == b' ' || (c >= b'\t' && c <= b'\r')
+}
+
+impl<'a> Parser<'a> {
+    fn new(input: &'a [u8]) -> Self {
+        Self { input, pos: 0 }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.input.get(self.pos).cloned()
+    }
+
+    fn skip_space(&mut self) {
+        while self.peek().map_or(false, is_space) {
+            self.pos += 1;
+        }
+    }
+
+    fn literal(&mut self, c: u8) -> Option<()> {
+        if self.peek() != Some(c) {
+            return None;
+        }
+        self.pos += 1;
+        Some(())
+    }
+
+    /// Parse a number of up to `digits` digits in the range `min..=max`, after optional white
+    /// space
+    fn number(&mut self, min: c_int, max: c_int, digits: usize) -> Option<c_int> {
+        self.skip_space();
+        let start = self.pos;
+        let mut value: c_int = 0;
+        while self.pos - start < digits {
+            match self.peek() {
+                Some(c @ b'0'..=b'9') => value = value * 10 + (c - b'0') as c_int,
+                _ => break,
+            }
+            self.pos += 1;
+        }
+        if self.pos == start || value < min || value > max {
+            return None;
+        }
+        Some(value)
+    }
+
+    /// Parse exactly `digits` digits
+    fn digits(&mut self, digits: usize) -> Option<c_int> {
+        let mut value: c_int = 0;
+        for _ in 0..digits {
+            match self.peek() {
+                Some(c @ b'0'..=b'9') => value = value * 10 + (c - b'0') as c_int,
+                _ => return None,
+            }
+            self.pos += 1;
+        }
+        Some(value)
+    }
+
+    fn seconds(&mut self) -> Option<time_t> {
+        self.skip_space();
+        let negative = self.peek() == Some(b'-');
+        if negative || self.peek() == Some(b'+') {
+            self.pos += 1;
+        }
+        let start = self.pos;
+        let mut value: time_t = 0;
+        while let Some(c @ b'0'..=b'9') = self.peek() {
+            value = value.checked_mul(10)?.checked_add((c - b'0') as time_t)?;
+            self.pos += 1;
+        }
+        if self.pos == start {
+            return None;
+        }
+        Some(if negative { -value } else { value })
+    }
+
+    /// Parse a UTC offset: Z, +hh, +hhmm or +hh:mm
+    fn offset(&mut self) -> Option<c_long> {
+        self.skip_space();
+        let sign = match self.peek()? {
+            b'Z' => {
+                self.pos += 1;
+                return Some(0);
+            }
+            b'+' => 1,
+            b'-' => -1,
+            _ => return None,
+        };
+        self.pos += 1;
+        let hours = self.digits(2)?;
+        let colon = self.peek() == Some(b':');
+        if colon {
+            self.pos += 1;
+        }
+        let minutes = match self.peek() {
+            Some(b'0'..=b'9') => self.digits(2)?,
+            _ if colon => return None,
+            _ => 0,
+        };
+        if hours > 24 || minutes > 59 {
+            return None;
+        }
+        Some(sign * (hours as c_long * 3600 + minutes as c_long * 60))
+    }
+
+    /// Match one of the names, ignoring case. Full names are tried before abbreviations, so that
+    /// "Monday" is not taken as "Mon" followed by "day"
+    fn name(&mut self, full: &[LocaleStr], abbreviated: &[LocaleStr]) -> Option<usize> {
+        self.skip_space();
+        for names in &[full, abbreviated] {
+            for (i, name) in names.iter().enumerate() {
+                let name = name.as_str().as_bytes();
+                let rest = &self.input[self.pos..];
+                if rest.len() >= name.len() && rest[..name.len()].eq_ignore_ascii_case(name) {
+                    self.pos += name.len();
+                    return Some(i);
+                }
+            }
+        }
+        None
+    }
+}
+
+fn parse(p: &mut Parser, format: &[u8], t: &mut tm, f: &mut Fields) -> Option<()> {
+    let names = locale::current().time;
+    let mut i = 0;
+    while i < format.len() {
+        let c = format[i];
+        i += 1;
+        if is_space(c) {
+            p.skip_space();
+            continue;
+        }
+        if c != b'%' {
+            p.literal(c)?;
+            continue;
+        }
+
+        let mut spec = *format.get(i)?;
+        i += 1;
+        // Without era or alternative digit data, the modifiers change nothing
+        if spec == b'E' || spec == b'O' {
+            spec = *format.get(i)?;
+            i += 1;
+        }
+
+        match spec {
+            b'%' => {
+                p.skip_space();
+                p.literal(b'%')?;
+            }
+            b'a' | b'A' => {
+                t.tm_wday = p.name(&names.day, &names.abday)? as c_int;
+                f.wday = true;
+            }
+            b'b' | b'B' | b'h' => {
+                t.tm_mon = p.name(&names.mon, &names.abmon)? as c_int;
+                f.mon = true;
+            }
+            b'c' => parse(p, names.d_t_fmt.as_str().as_bytes(), t, f)?,
+            b'C' => f.century = Some(p.number(0, 99, 2)?),
+            b'd' | b'e' => {
+                t.tm_mday = p.number(1, 31, 2)?;
+                f.mday = true;
+            }
+            b'D' => parse(p, b"%m/%d/%y", t, f)?,
+            b'F' => parse(p, b"%Y-%m-%d", t, f)?,
+            b'g' => {
+                // The ISO 8601 week-based year and week are accepted, but not used
+                p.number(0, 99, 2)?;
+            }
+            b'G' => {
+                p.number(0, 9999, 4)?;
+            }
+            b'H' | b'k' => {
+                t.tm_hour = p.number(0, 23, 2)?;
+                f.hour = true;
+                f.hour12 = false;
+            }
+            b'I' | b'l' => {
+                t.tm_hour = p.number(1, 12, 2)? % 12;
+                f.hour = true;
+                f.hour12 = true;
+            }
+            b'j' => {
+                t.tm_yday = p.number(1, 366, 3)? - 1;
+                f.yday = true;
+            }
+            b'm' => {
+                t.tm_mon = p.number(1, 12, 2)? - 1;
+                f.mon = true;
+            }
+            b'M' => {
+                t.tm_min = p.number(0, 59, 2)?;
+                f.min = true;
+            }
+            b'n' | b't' => p.skip_space(),
+            b'p' => f.pm = Some(p.name(&names.am_pm, &[])? == 1),
+            b'r' => parse(p, names.t_fmt_ampm.as_str().as_bytes(), t, f)?,
+            b'R' => parse(p, b"%H:%M", t, f)?,
+            b's' => {
+                let clock = p.seconds()?;
+                unsafe {
+                    localtime_r(&clock, t);
+                }
+                f.epoch = true;
+            }
+            b'S' => {
+                t.tm_sec = p.number(0, 60, 2)?;
+                f.sec = true;
+            }
+            b'T' => parse(p, b"%H:%M:%S", t, f)?,
+            b'u' => {
+                t.tm_wday = p.number(1, 7, 1)? % 7;
+                f.wday = true;
+            }
+            b'U' => f.week = Some((p.number(0, 53, 2)?, false)),
+            b'V' => {
+                p.number(0, 53, 2)?;
+            }
+            b'w' => {
+                t.tm_wday = p.number(0, 6, 1)?;
+                f.wday = true;
+            }
+            b'W' => f.week = Some((p.number(0, 53, 2)?, true)),
+            b'x' => parse(p, names.d_fmt.as_str().as_bytes(), t, f)?,
+            b'X' => parse(p, names.t_fmt.as_str().as_bytes(), t, f)?,
+            b'y' => f.year_of_century = Some(p.number(0, 99, 2)?),
+            b'Y' => {
+                t.tm_year = p.number(0, 9999, 4)? - 1900;
+                f.year = true;
+                f.century = None;
+                f.year_of_century = None;
+            }
+            b'z' => t.tm_gmtoff = p.offset()?,
+            b'Z' => {
+                // Time zone names are skipped, as they cannot be mapped to an offset
+                p.skip_space();
+                while p.peek().map_or(false, |c| c.is_ascii_alphabetic()) {
+                    p.pos += 1;
+                }
+            }
+            _ => return None,
+        }
+    }
+    Some(())
+}
+
+fn year_days(year: c_int) -> c_int {
+    if leap_year(year + 1900) {
+        366
+    } else {
+        365
+    }
+}
+
+fn yday(year: c_int, mon: c_int, mday: c_int) -> c_int {
+    let leap = leap_year(year + 1900) as usize;
+    MONTH_DAYS[leap][..mon as usize].iter().sum::<c_int>() + mday - 1
+}
+
+/// The day of the week, for a day of a year counted from 1900
+fn weekday(year: c_int, yday: c_int) -> c_int {
+    // 0001-01-01 was a Monday in the proleptic Gregorian calendar
+    let y = year as i64 + 1899;
+    let days = y * 365 + y / 4 - y / 100 + y / 400 + yday as i64 + 1;
+    let wday = (days % 7) as c_int;
+    if wday < 0 {
+        wday + 7
+    } else {
+        wday
+    }
+}
+
+/// Fill in the fields that follow from the ones given, like the day of the week from the date
+fn finish(t: &mut tm, f: &mut Fields) {
+    if f.hour12 && f.pm == Some(true) {
+        t.tm_hour += 12;
+    }
+
+    match (f.century, f.year_of_century) {
+        (Some(century), year) => {
+            t.tm_year = century * 100 + year.unwrap_or(0) - 1900;
+            f.year = true;
+        }
+        (None, Some(year)) => {
+            // POSIX: 69-99 refer to the 20th century, 00-68 to the 21st
+            t.tm_year = if year < 69 { year + 100 } else { year };
+            f.year = true;
+        }
+        (None, None) => (),
+    }
+
+    if f.epoch {
+        return;
+    }
+
+    if !f.yday {
+        if let (Some((week, monday)), true) = (f.week, f.wday) {
+            let jan1 = weekday(t.tm_year, 0);
+            let (start, day) = if monday {
+                (1, (t.tm_wday + 6) % 7)
+            } else {
+                (0, t.tm_wday)
+            };
+            // The first day of week 1
+            let first = (start - jan1 + 7) % 7;
+            let day = first + (week - 1) * 7 + day;
+            if day >= 0 && day < year_days(t.tm_year) {
+                t.tm_yday = day;
+                f.yday = true;
+            }
+        }
+    }
+
+    if f.yday && !(f.mon && f.mday) {
+        let leap = leap_year(t.tm_year + 1900) as usize;
+        let mut day = t.tm_yday;
+        let mut mon = 0;
+        while mon < 11 && day >= MONTH_DAYS[leap][mon] {
+            day -= MONTH_DAYS[leap][mon];
+            mon += 1;
+        }
+        t.tm_mon = mon as c_int;
+        t.tm_mday = day + 1;
+        f.mon = true;
+        f.mday = true;
+    } else if f.mon && f.mday {
+        t.tm_yday = yday(t.tm_year, t.tm_mon, t.tm_mday);
+        f.yday = true;
+    }
+
+    if f.yday && !f.wday {
+        t.tm_wday = weekday(t.tm_year, t.tm_yday);
+    }
+}
+
+pub unsafe fn strptime(buf: *const c_char, format: *const c_char, t: &mut tm) -> *mut c_char {
+    let mut p = Parser::new(CStr::from_ptr(buf).to_bytes());
+    let mut f = Fields::default();
+    if parse(&mut p, CStr::from_ptr(format).to_bytes(), t, &mut f).is_none() {
+        return ptr::null_mut();
+    }
+    finish(t, &mut f);
+    buf.add(p.pos) as *mut c_char
+}
+
+// getdate_err codes
+const DATEMSK_UNSET: c_int = 1;
+const DATEMSK_OPEN: c_int = 2;
+const DATEMSK_STAT: c_int = 3;
+const DATEMSK_NOT_REGULAR: c_int = 4;
+const DATEMSK_READ: c_int = 5;
+const NO_MATCH: c_int = 7;
+const INVALID_DATE: c_int = 8;
+
+/// Convert `string` using the first matching template in the file named by DATEMSK, returning 0
+/// or a getdate_err code
+pub unsafe fn getdate(string: *const c_char, result: &mut tm) -> c_int {
+    let datemsk = getenv(b"DATEMSK\0".as_ptr() as *const c_char);
+    if datemsk.is_null() || *datemsk == 0 {
+        return DATEMSK_UNSET;
+    }
+    let mut file = match File::open(CStr::from_ptr(datemsk), O_RDONLY | O_CLOEXEC) {
+        Ok(file) => file,
+        Err(_) => return DATEMSK_OPEN,
+    };
+    let mut st: stat = mem::zeroed();
+    if Sys::fstat(file.fd, &mut st) < 0 {
+        return DATEMSK_STAT;
+    }
+    if st.st_mode as c_int & S_IFMT != S_IFREG {
+        return DATEMSK_NOT_REGULAR;
+    }
+    let mut data = Vec::new();
+    if file.read_to_end(&mut data).is_err() {
+        return DATEMSK_READ;
+    }
+
+    let mut now: tm = mem::zeroed();
+    localtime_r(&time(ptr::null_mut()), &mut now);
+
+    let input = CStr::from_ptr(string).to_bytes();
+    for template in data.split(|&c| c == b'\n') {
+        let mut t: tm = mem::zeroed();
+        let mut f = Fields::default();
+        let mut p = Parser::new(input);
+        if parse(&mut p, template, &mut t, &mut f).is_none() {
+            continue;
+        }
+        p.skip_space();
+        if p.pos != input.len() {
+            continue;
+        }
+
+        if f.epoch {
+            *result = t;
+            return 0;
+        }
+        if !f.year && f.century.is_none() && f.year_of_century.is_none() {
+            t.tm_year = now.tm_year;
+        }
+        finish(&mut t, &mut f);
+        return complete(t, &f, &now, result);
+    }
+    NO_MATCH
+}
+
+/// Fill in the parts of the date and time that were not given, as POSIX describes
+unsafe fn complete(mut t: tm, f: &Fields, now: &tm, result: &mut tm) -> c_int {
+    if !f.hour && !f.min && !f.sec {
+        t.tm_hour = now.tm_hour;
+        t.tm_min = now.tm_min;
+        t.tm_sec = now.tm_sec;
+    }
+
+    if !f.year && !f.mon && !f.mday && !f.wday {
+        // Only a time: today if it is still to come, otherwise tomorrow
+        t.tm_mon = now.tm_mon;
+        t.tm_mday = now.tm_mday;
+        if (t.tm_hour, t.tm_min, t.tm_sec) < (now.tm_hour, now.tm_min, now.tm_sec) {
+            t.tm_mday += 1;
+        }
+    } else if f.wday && !f.year && !f.mon && !f.mday {
+        // Only a weekday: the next one, which may be today
+        t.tm_mon = now.tm_mon;
+        t.tm_mday = now.tm_mday + (t.tm_wday - now.tm_wday + 7) % 7;
+    } else {
+        if !f.mon {
+            t.tm_mon = if f.year { 0 } else { now.tm_mon };
+        } else if !f.year && t.tm_mon < now.tm_mon {
+            t.tm_year += 1;
+        }
+        if !f.mday {
+            // The first day of the month, or the first given weekday in it
+            t.tm_mday = 1;
+            if f.wday {
+                let first = weekday(t.tm_year, yday(t.tm_year, t.tm_mon, 1));
+                t.tm_mday += (t.tm_wday - first + 7) % 7;
+            }
+        }
+    }
+
+    let (mon, mday) = (t.tm_mon, t.tm_mday);
+    t.tm_isdst = -1;
+    mktime(&mut t);
+    // Reject dates like February 30, which mktime would move into the next month
+    if f.mday && (t.tm_mon != mon || t.tm_mday != mday) {
+        return INVALID_DATE;
+    }
+    *result = t;
+    0
+}
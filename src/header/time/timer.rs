@@ -0,0 +1,177 @@
+//! Per-process timers. SIGEV_SIGNAL and SIGEV_NONE timers are kernel timers. A SIGEV_THREAD timer
+//! is a timerfd, read by a helper thread that runs the notification function on each expiration.
+
+use alloc::boxed::Box;
+use core::{intrinsics, mem, ptr, slice};
+
+use header::errno::{EAGAIN, EINTR, EINVAL};
+use header::signal::{sigevent, sigval, SIGEV_THREAD};
+use platform;
+use platform::pte::{pthread_attr_t, pthread_create, pthread_detach};
+use platform::types::*;
+use platform::{Pal, Sys};
+
+use super::{itimerspec, timespec};
+
+/// Largest overrun count reported by timer_getoverrun
+const DELAYTIMER_MAX: c_int = c_int::max_value();
+
+/// The object a timer_t points to
+pub struct Timer {
+    /// The kernel timer id, or the timerfd of a SIGEV_THREAD timer
+    id: c_int,
+    /// Null unless this is a SIGEV_THREAD timer
+    thread: *mut ThreadTimer,
+}
+
+/// State shared with the helper thread of a SIGEV_THREAD timer
+struct ThreadTimer {
+    fd: c_int,
+    function: extern "C" fn(sigval),
+    value: sigval,
+    overrun: c_int,
+    deleted: c_int,
+    /// The timer and the helper thread each hold a reference, the last one closes the timerfd
+    refs: c_int,
+}
+
+impl ThreadTimer {
+    unsafe fn release(timer: *mut Self) {
+        if intrinsics::atomic_xsub(&mut (*timer).refs, 1) == 1 {
+            Sys::close((*timer).fd);
+            Box::from_raw(timer);
+        }
+    }
+}
+
+extern "C" fn helper(arg: *mut c_void) -> *mut c_void {
+    let timer = arg as *mut ThreadTimer;
+    unsafe {
+        loop {
+            let mut expirations = 0u64;
+            // The raw read leaves errno to the application
+            let count = Sys::read_raw(
+                (*timer).fd,
+                slice::from_raw_parts_mut(
+                    &mut expirations as *mut u64 as *mut u8,
+                    mem::size_of::<u64>(),
+                ),
+            );
+
+            if intrinsics::atomic_load(&(*timer).deleted) != 0
+                || (count < 0 && count != -EINTR as ssize_t)
+            {
+                ThreadTimer::release(timer);
+                return ptr::null_mut();
+            }
+
+            if count == mem::size_of::<u64>() as ssize_t && expirations > 0 {
+                let overrun = (expirations - 1).min(DELAYTIMER_MAX as u64) as c_int;
+                intrinsics::atomic_store(&mut (*timer).overrun, overrun);
+                ((*timer).function)((*timer).value);
+            }
+        }
+    }
+}
+
+impl Timer {
+    /// Create a timer, notifying as described by evp, or with SIGALRM if evp is null
+    pub unsafe fn create(clock_id: clockid_t, evp: *const sigevent) -> Option<Box<Self>> {
+        if evp.is_null() || (*evp).sigev_notify != SIGEV_THREAD {
+            let mut id = 0;
+            if Sys::timer_create(clock_id, evp, &mut id) < 0 {
+                return None;
+            }
+            return Some(Box::new(Self {
+                id,
+                thread: ptr::null_mut(),
+            }));
+        }
+
+        let function = match (*evp).sigev_notify_function {
+            Some(function) => function,
+            None => {
+                platform::errno = EINVAL;
+                return None;
+            }
+        };
+        let fd = Sys::timerfd_create(clock_id);
+        if fd < 0 {
+            return None;
+        }
+
+        let thread = Box::into_raw(Box::new(ThreadTimer {
+            fd,
+            function,
+            value: (*evp).sigev_value,
+            overrun: 0,
+            deleted: 0,
+            refs: 2,
+        }));
+        let mut handle = mem::zeroed();
+        if pthread_create(
+            &mut handle,
+            (*evp).sigev_notify_attributes as *const pthread_attr_t,
+            helper,
+            thread as *mut c_void,
+        ) != 0
+        {
+            Box::from_raw(thread);
+            Sys::close(fd);
+            platform::errno = EAGAIN;
+            return None;
+        }
+        pthread_detach(handle);
+
+        Some(Box::new(Self { id: fd, thread }))
+    }
+
+    pub unsafe fn settime(
+        &mut self,
+        flags: c_int,
+        value: *const itimerspec,
+        ovalue: *mut itimerspec,
+    ) -> c_int {
+        if self.thread.is_null() {
+            Sys::timer_settime(self.id, flags, value, ovalue)
+        } else {
+            // TIMER_ABSTIME has the same value as TFD_TIMER_ABSTIME
+            Sys::timerfd_settime(self.id, flags, value, ovalue)
+        }
+    }
+
+    pub unsafe fn gettime(&mut self, value: *mut itimerspec) -> c_int {
+        if self.thread.is_null() {
+            Sys::timer_gettime(self.id, value)
+        } else {
+            Sys::timerfd_gettime(self.id, value)
+        }
+    }
+
+    pub unsafe fn getoverrun(&mut self) -> c_int {
+        if self.thread.is_null() {
+            Sys::timer_getoverrun(self.id)
+        } else {
+            intrinsics::atomic_load(&(*self.thread).overrun)
+        }
+    }
+
+    pub unsafe fn delete(self: Box<Self>) -> c_int {
+        if self.thread.is_null() {
+            return Sys::timer_delete(self.id);
+        }
+
+        // Expire the timer right away, so the helper thread sees that it was deleted and exits
+        intrinsics::atomic_store(&mut (*self.thread).deleted, 1);
+        let wake = itimerspec {
+            it_interval: timespec::default(),
+            it_value: timespec {
+                tv_sec: 0,
+                tv_nsec: 1,
+            },
+        };
+        let ret = Sys::timerfd_settime(self.id, 0, &wake, ptr::null_mut());
+        ThreadTimer::release(self.thread);
+        ret
+    }
+}
@@ -0,0 +1,676 @@
+//! Time zones, loaded from TZif files (RFC 8536) or POSIX TZ strings, following
+//! http://pubs.opengroup.org/onlinepubs/9699919799/basedefs/V1_chap08.html
+
+use alloc::vec::Vec;
+
+use c_str::{CStr, CString};
+use fs::File;
+use header::fcntl::{O_CLOEXEC, O_RDONLY};
+use header::stdlib::getenv;
+use io::Read;
+use mutex::Mutex;
+use platform::types::*;
+
+use super::constants::UTC;
+
+const ZONEINFO: &[u8] = b"/usr/share/zoneinfo/";
+const LOCALTIME: &[u8] = b"/etc/localtime";
+
+/// Offsets and times in POSIX TZ strings may be up to 167 hours
+const MAX_HOURS: i64 = 167;
+
+/// A local time type
+#[derive(Clone, Copy)]
+pub struct LocalType {
+    /// Seconds east of UTC
+    pub offset: i64,
+    pub isdst: bool,
+    /// The abbreviation, which stays valid once the zone is unloaded
+    pub name: *const c_char,
+}
+
+const UTC_TYPE: LocalType = LocalType {
+    offset: 0,
+    isdst: false,
+    name: UTC,
+};
+
+/// The day on which a POSIX TZ rule switches between standard and daylight saving time
+#[derive(Clone, Copy)]
+enum RuleDate {
+    /// Jn: day 1 to 365, February 29 is never counted
+    Julian(i64),
+    /// n: day 0 to 365, counting February 29 in leap years
+    Zero(i64),
+    /// Mm.w.d: weekday d of week w (5 meaning the last) of month m
+    MonthWeekDay(i64, i64, i64),
+}
+
+struct Dst {
+    ty: LocalType,
+    start: RuleDate,
+    /// Seconds after midnight in standard time
+    start_time: i64,
+    end: RuleDate,
+    /// Seconds after midnight in daylight saving time
+    end_time: i64,
+}
+
+struct Rule {
+    std: LocalType,
+    dst: Option<Dst>,
+}
+
+struct Zone {
+    /// Times at which the local time type changes, in ascending order
+    transitions: Vec<i64>,
+    /// The index into types of the type starting at each transition
+    indices: Vec<u8>,
+    /// Never empty
+    types: Vec<LocalType>,
+    /// Applies after the last transition
+    rule: Option<Rule>,
+}
+
+struct State {
+    /// The value of TZ the zone was loaded for, None if it was unset
+    tz: Option<Vec<u8>>,
+    loaded: bool,
+    zone: Zone,
+    /// Every abbreviation seen so far, kept so that tm_zone and tzname stay valid
+    names: Vec<CString>,
+}
+unsafe impl Send for State {}
+
+lazy_static! {
+    static ref STATE: Mutex<State> = Mutex::new(State {
+        tz: None,
+        loaded: false,
+        zone: Zone::utc(),
+        names: Vec::new(),
+    });
+}
+
+fn floor_div(a: i64, b: i64) -> i64 {
+    let q = a / b;
+    if a % b < 0 {
+        q - 1
+    } else {
+        q
+    }
+}
+
+fn floor_mod(a: i64, b: i64) -> i64 {
+    a - floor_div(a, b) * b
+}
+
+fn is_leap(year: i64) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+/// Days from 1970-01-01 to January 1 of year
+fn year_start(year: i64) -> i64 {
+    let leaps = |y: i64| floor_div(y, 4) - floor_div(y, 100) + floor_div(y, 400);
+    365 * (year - 1970) + leaps(year - 1) - leaps(1969)
+}
+
+/// The year containing a day counted from 1970-01-01
+fn year_of(day: i64) -> i64 {
+    let mut year = 1970 + floor_div(day, 366);
+    while year_start(year + 1) <= day {
+        year += 1;
+    }
+    year
+}
+
+fn month_days(year: i64, month: usize) -> i64 {
+    const DAYS: [i64; 12] = [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+    if month == 1 && is_leap(year) {
+        29
+    } else {
+        DAYS[month]
+    }
+}
+
+impl RuleDate {
+    /// The zero-based day of the year this date falls on
+    fn day_of_year(self, year: i64) -> i64 {
+        match self {
+            RuleDate::Julian(n) => {
+                if is_leap(year) && n >= 60 {
+                    n
+                } else {
+                    n - 1
+                }
+            }
+            RuleDate::Zero(n) => n,
+            RuleDate::MonthWeekDay(m, w, d) => {
+                let month = (m - 1) as usize;
+                let first = (0..month).map(|i| month_days(year, i)).sum::<i64>();
+                // 1970-01-01 was a Thursday
+                let first_wday = floor_mod(year_start(year) + first + 4, 7);
+                let mut day = floor_mod(d - first_wday, 7) + (w - 1) * 7;
+                while day >= month_days(year, month) {
+                    day -= 7;
+                }
+                first + day
+            }
+        }
+    }
+}
+
+impl Rule {
+    fn lookup(&self, t: i64) -> LocalType {
+        let dst = match self.dst {
+            Some(ref dst) => dst,
+            None => return self.std,
+        };
+
+        let year = year_of(floor_div(t + self.std.offset, 86400));
+        let start = (year_start(year) + dst.start.day_of_year(year)) * 86400 + dst.start_time
+            - self.std.offset;
+        let end =
+            (year_start(year) + dst.end.day_of_year(year)) * 86400 + dst.end_time - dst.ty.offset;
+        let in_dst = if start < end {
+            t >= start && t < end
+        } else {
+            // Southern hemisphere, daylight saving time spans the new year
+            t < end || t >= start
+        };
+        if in_dst {
+            dst.ty
+        } else {
+            self.std
+        }
+    }
+}
+
+impl Zone {
+    fn utc() -> Self {
+        Self {
+            transitions: Vec::new(),
+            indices: Vec::new(),
+            types: vec![UTC_TYPE],
+            rule: None,
+        }
+    }
+
+    fn from_rule(rule: Rule) -> Self {
+        Self {
+            transitions: Vec::new(),
+            indices: Vec::new(),
+            types: vec![rule.std],
+            rule: Some(rule),
+        }
+    }
+
+    fn lookup(&self, t: i64) -> LocalType {
+        if let Some(&last) = self.transitions.last() {
+            if t < self.transitions[0] {
+                return self.types[0];
+            }
+            if t < last || self.rule.is_none() {
+                let i = match self.transitions.binary_search(&t) {
+                    Ok(i) => i,
+                    Err(i) => i - 1,
+                };
+                return self.types[self.indices[i] as usize];
+            }
+        }
+        match self.rule {
+            Some(ref rule) => rule.lookup(t),
+            None => self.types[0],
+        }
+    }
+
+    /// The most recent standard and daylight saving time types, for tzname
+    fn current_types(&self) -> (LocalType, Option<LocalType>) {
+        if let Some(ref rule) = self.rule {
+            return (rule.std, rule.dst.as_ref().map(|dst| dst.ty));
+        }
+        let mut std = None;
+        let mut dst = None;
+        for &i in self.indices.iter().rev() {
+            let ty = self.types[i as usize];
+            if ty.isdst {
+                dst = dst.or(Some(ty));
+            } else {
+                std = std.or(Some(ty));
+            }
+        }
+        let std = std
+            .or_else(|| self.types.iter().cloned().find(|ty| !ty.isdst))
+            .unwrap_or(self.types[0]);
+        (std, dst)
+    }
+}
+
+fn intern(names: &mut Vec<CString>, name: &[u8]) -> *const c_char {
+    if let Some(existing) = names.iter().find(|existing| existing.as_bytes() == name) {
+        return existing.as_ptr();
+    }
+    match CString::new(name) {
+        Ok(name) => {
+            let ptr = name.as_ptr();
+            names.push(name);
+            ptr
+        }
+        Err(_) => UTC,
+    }
+}
+
+struct Parser<'a> {
+    s: &'a [u8],
+    i: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<u8> {
+        self.s.get(self.i).cloned()
+    }
+
+    fn eat(&mut self, c: u8) -> bool {
+        if self.peek() == Some(c) {
+            self.i += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn done(&self) -> bool {
+        self.i == self.s.len()
+    }
+
+    /// An abbreviation of at least three letters, or of letters, digits and signs within <>
+    fn name(&mut self) -> Option<&'a [u8]> {
+        let quoted = self.eat(b'<');
+        let start = self.i;
+        while let Some(c) = self.peek() {
+            let valid = if quoted {
+                c.is_ascii_alphanumeric() || c == b'+' || c == b'-'
+            } else {
+                c.is_ascii_alphabetic()
+            };
+            if !valid {
+                break;
+            }
+            self.i += 1;
+        }
+        let name = &self.s[start..self.i];
+        if name.len() < 3 || (quoted && !self.eat(b'>')) {
+            return None;
+        }
+        Some(name)
+    }
+
+    fn number(&mut self, max: i64) -> Option<i64> {
+        let start = self.i;
+        let mut n = 0;
+        while let Some(c @ b'0'..=b'9') = self.peek() {
+            n = n * 10 + (c - b'0') as i64;
+            if n > max {
+                return None;
+            }
+            self.i += 1;
+        }
+        if self.i == start {
+            None
+        } else {
+            Some(n)
+        }
+    }
+
+    /// [+-]hh[:mm[:ss]] in seconds
+    fn time(&mut self) -> Option<i64> {
+        let sign = if self.eat(b'-') {
+            -1
+        } else {
+            self.eat(b'+');
+            1
+        };
+        let mut secs = self.number(MAX_HOURS)? * 3600;
+        if self.eat(b':') {
+            secs += self.number(59)? * 60;
+            if self.eat(b':') {
+                secs += self.number(59)?;
+            }
+        }
+        Some(sign * secs)
+    }
+
+    fn date(&mut self) -> Option<RuleDate> {
+        if self.eat(b'J') {
+            let n = self.number(365)?;
+            if n < 1 {
+                return None;
+            }
+            Some(RuleDate::Julian(n))
+        } else if self.eat(b'M') {
+            let m = self.number(12)?;
+            if m < 1 || !self.eat(b'.') {
+                return None;
+            }
+            let w = self.number(5)?;
+            if w < 1 || !self.eat(b'.') {
+                return None;
+            }
+            let d = self.number(6)?;
+            Some(RuleDate::MonthWeekDay(m, w, d))
+        } else {
+            Some(RuleDate::Zero(self.number(365)?))
+        }
+    }
+
+    /// A rule date with an optional /time, which defaults to 02:00:00
+    fn date_time(&mut self) -> Option<(RuleDate, i64)> {
+        let date = self.date()?;
+        let time = if self.eat(b'/') { self.time()? } else { 7200 };
+        Some((date, time))
+    }
+}
+
+/// Parse a POSIX TZ string such as EST5EDT,M3.2.0,M11.1.0
+fn parse_rule(s: &[u8], names: &mut Vec<CString>) -> Option<Rule> {
+    let mut p = Parser { s, i: 0 };
+
+    // Offsets in TZ strings are west of UTC
+    let std_name = p.name()?;
+    let std = LocalType {
+        offset: -p.time()?,
+        isdst: false,
+        name: intern(names, std_name),
+    };
+    if p.done() {
+        return Some(Rule { std, dst: None });
+    }
+
+    let dst_name = p.name()?;
+    let dst_offset = match p.peek() {
+        None | Some(b',') => std.offset + 3600,
+        Some(_) => -p.time()?,
+    };
+    let ((start, start_time), (end, end_time)) = if p.eat(b',') {
+        let start = p.date_time()?;
+        if !p.eat(b',') {
+            return None;
+        }
+        (start, p.date_time()?)
+    } else {
+        // The rules used in the United States since 2007
+        (
+            (RuleDate::MonthWeekDay(3, 2, 0), 7200),
+            (RuleDate::MonthWeekDay(11, 1, 0), 7200),
+        )
+    };
+    if !p.done() {
+        return None;
+    }
+
+    Some(Rule {
+        std,
+        dst: Some(Dst {
+            ty: LocalType {
+                offset: dst_offset,
+                isdst: true,
+                name: intern(names, dst_name),
+            },
+            start,
+            start_time,
+            end,
+            end_time,
+        }),
+    })
+}
+
+struct Reader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn bytes(&mut self, n: usize) -> Option<&'a [u8]> {
+        let end = self.pos.checked_add(n)?;
+        let bytes = self.data.get(self.pos..end)?;
+        self.pos = end;
+        Some(bytes)
+    }
+
+    fn u8(&mut self) -> Option<u8> {
+        self.bytes(1).map(|b| b[0])
+    }
+
+    fn i32(&mut self) -> Option<i32> {
+        let b = self.bytes(4)?;
+        Some(i32::from_be_bytes([b[0], b[1], b[2], b[3]]))
+    }
+
+    fn i64(&mut self) -> Option<i64> {
+        let b = self.bytes(8)?;
+        let mut array = [0; 8];
+        array.copy_from_slice(b);
+        Some(i64::from_be_bytes(array))
+    }
+
+    fn count(&mut self) -> Option<usize> {
+        Some(self.i32()? as u32 as usize)
+    }
+}
+
+struct Header {
+    version: u8,
+    isutcnt: usize,
+    isstdcnt: usize,
+    leapcnt: usize,
+    timecnt: usize,
+    typecnt: usize,
+    charcnt: usize,
+}
+
+impl Header {
+    fn read(r: &mut Reader) -> Option<Self> {
+        if r.bytes(4)? != b"TZif" {
+            return None;
+        }
+        let version = r.u8()?;
+        r.bytes(15)?;
+        Some(Self {
+            version,
+            isutcnt: r.count()?,
+            isstdcnt: r.count()?,
+            leapcnt: r.count()?,
+            timecnt: r.count()?,
+            typecnt: r.count()?,
+            charcnt: r.count()?,
+        })
+    }
+
+    /// The size of the data block, with times of time_size bytes
+    fn data_len(&self, time_size: usize) -> usize {
+        self.timecnt * (time_size + 1)
+            + self.typecnt * 6
+            + self.charcnt
+            + self.leapcnt * (time_size + 4)
+            + self.isstdcnt
+            + self.isutcnt
+    }
+}
+
+/// Parse a TZif file, using the 64-bit data and the footer of version 2 and later files
+fn parse_tzif(data: &[u8], names: &mut Vec<CString>) -> Option<Zone> {
+    let mut r = Reader { data, pos: 0 };
+    let mut header = Header::read(&mut r)?;
+    let mut time_size = 4;
+    if header.version >= b'2' {
+        r.bytes(header.data_len(4))?;
+        header = Header::read(&mut r)?;
+        time_size = 8;
+    }
+    if header.typecnt == 0 {
+        return None;
+    }
+
+    let mut transitions = Vec::with_capacity(header.timecnt);
+    for _ in 0..header.timecnt {
+        transitions.push(if time_size == 8 {
+            r.i64()?
+        } else {
+            r.i32()? as i64
+        });
+    }
+    let indices = r.bytes(header.timecnt)?.to_vec();
+    if indices.iter().any(|&i| i as usize >= header.typecnt) {
+        return None;
+    }
+
+    let mut raw_types = Vec::with_capacity(header.typecnt);
+    for _ in 0..header.typecnt {
+        raw_types.push((r.i32()?, r.u8()?, r.u8()?));
+    }
+    let chars = r.bytes(header.charcnt)?;
+    let mut types = Vec::with_capacity(header.typecnt);
+    for (offset, isdst, index) in raw_types {
+        let name = chars.get(index as usize..).unwrap_or(&[]);
+        let len = name.iter().position(|&c| c == 0).unwrap_or(name.len());
+        types.push(LocalType {
+            offset: offset as i64,
+            isdst: isdst != 0,
+            name: intern(names, &name[..len]),
+        });
+    }
+
+    // Leap seconds and the standard/wall and UT/local indicators are not needed
+    r.bytes(header.leapcnt * (time_size + 4) + header.isstdcnt + header.isutcnt)?;
+
+    let mut rule = None;
+    if time_size == 8 && r.u8() == Some(b'\n') {
+        let rest = &data[r.pos..];
+        if let Some(len) = rest.iter().position(|&c| c == b'\n') {
+            if len > 0 {
+                rule = parse_rule(&rest[..len], names);
+            }
+        }
+    }
+
+    Some(Zone {
+        transitions,
+        indices,
+        types,
+        rule,
+    })
+}
+
+fn load_file(path: &[u8], names: &mut Vec<CString>) -> Option<Zone> {
+    let path = CString::new(path).ok()?;
+    let mut file = File::open(&path, O_RDONLY | O_CLOEXEC).ok()?;
+    let mut data = Vec::new();
+    file.read_to_end(&mut data).ok()?;
+    parse_tzif(&data, names)
+}
+
+/// Load the zone described by TZ: a POSIX TZ string, or a TZif file given by an absolute path or a
+/// name in the zoneinfo directory, optionally starting with a colon. The zoneinfo directory is
+/// tzdir if it is set and not empty
+fn load(tz: Option<&[u8]>, tzdir: Option<&[u8]>, names: &mut Vec<CString>) -> Zone {
+    let tz = match tz {
+        Some(tz) => tz,
+        None => return load_file(LOCALTIME, names).unwrap_or_else(Zone::utc),
+    };
+    if tz.is_empty() {
+        return Zone::utc();
+    }
+
+    let (tz, file_only) = if tz[0] == b':' {
+        (&tz[1..], true)
+    } else {
+        (tz, false)
+    };
+    if !file_only {
+        if let Some(rule) = parse_rule(tz, names) {
+            return Zone::from_rule(rule);
+        }
+    }
+
+    let zone = if tz.first() == Some(&b'/') {
+        load_file(tz, names)
+    } else if tz.split(|&c| c == b'/').any(|part| part == b"..") {
+        None
+    } else {
+        let mut path = match tzdir {
+            Some(dir) if !dir.is_empty() => {
+                let mut path = dir.to_vec();
+                path.push(b'/');
+                path
+            }
+            _ => ZONEINFO.to_vec(),
+        };
+        path.extend_from_slice(tz);
+        load_file(&path, names)
+    };
+    zone.unwrap_or_else(Zone::utc)
+}
+
+unsafe fn env(name: &[u8]) -> Option<&'static [u8]> {
+    let value = getenv(name.as_ptr() as *const c_char);
+    if value.is_null() {
+        None
+    } else {
+        Some(CStr::from_ptr(value).to_bytes())
+    }
+}
+
+/// Reload the zone if TZ has changed since it was loaded, returning true if it was reloaded
+unsafe fn update(state: &mut State) -> bool {
+    let tz = env(b"TZ\0");
+    if state.loaded && state.tz.as_ref().map(|tz| &tz[..]) == tz {
+        return false;
+    }
+
+    state.zone = load(tz, env(b"TZDIR\0"), &mut state.names);
+    state.tz = tz.map(|tz| tz.to_vec());
+    state.loaded = true;
+    true
+}
+
+/// Load the zone from TZ and set tzname, timezone and daylight
+pub unsafe fn tzset() {
+    let mut state = STATE.lock();
+    if update(&mut state) {
+        let (std, dst) = state.zone.current_types();
+        super::tzname[0] = std.name as *mut c_char;
+        super::tzname[1] = dst.unwrap_or(std).name as *mut c_char;
+        super::timezone = -std.offset as c_long;
+        super::daylight = dst.is_some() as c_int;
+    }
+}
+
+/// The local time type in effect at t
+pub unsafe fn local_type(t: time_t) -> LocalType {
+    tzset();
+    STATE.lock().zone.lookup(t)
+}
+
+/// Convert a local time, given in seconds as if it were UTC, to a time. If isdst is not negative
+/// and the local time is ambiguous or does not exist, it selects the type to use
+pub unsafe fn local_to_utc(local: time_t, isdst: c_int) -> time_t {
+    tzset();
+    let state = STATE.lock();
+    let zone = &state.zone;
+
+    let guess = local - zone.lookup(local).offset;
+    let ty = zone.lookup(guess);
+    let t = local - ty.offset;
+    if isdst < 0 || ty.isdst == (isdst > 0) {
+        return t;
+    }
+
+    // Look for the other kind of time about half a year away
+    const HALF_YEAR: i64 = 183 * 86400;
+    for &other_t in &[t - HALF_YEAR, t + HALF_YEAR] {
+        let other = zone.lookup(other_t);
+        if other.isdst == (isdst > 0) {
+            return local - other.offset;
+        }
+    }
+    t
+}
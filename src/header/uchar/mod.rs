@@ -0,0 +1,230 @@
+//! uchar implementation for Redox, following https://en.cppreference.com/w/c/string/multibyte
+//!
+//! The conversions share mbstate_t with mbrtowc. When a character takes several code units, the
+//! units that are left are kept in the state, and returned by the next calls with (size_t)-3.
+
+use core::{char, ptr};
+
+use header::errno::EILSEQ;
+use header::stdlib::MB_LEN_MAX;
+use header::wchar::{mbrtowc, mbstate_t, utf8, wcrtomb};
+use platform;
+use platform::types::*;
+
+pub type char8_t = c_uchar;
+pub type char16_t = u16;
+pub type char32_t = u32;
+
+// Returned for a code unit that comes from the last character, without reading any input
+const PENDING: size_t = -3isize as size_t;
+
+/// Convert the next character to a wide character, returning it and the value for mbrtoc32
+unsafe fn next_char(s: *const c_char, n: size_t, ps: *mut mbstate_t) -> (u32, size_t) {
+    let mut wc: wchar_t = 0;
+    let result = mbrtowc(&mut wc, s, n, ps);
+    (wc as u32, result)
+}
+
+fn is_error(result: size_t) -> bool {
+    result == -1isize as size_t || result == -2isize as size_t
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn c8rtomb(s: *mut c_char, c8: char8_t, ps: *mut mbstate_t) -> size_t {
+    static mut INTERNAL: mbstate_t = mbstate_t::new();
+    let ps = if ps.is_null() {
+        &mut INTERNAL
+    } else {
+        &mut *ps
+    };
+
+    if s.is_null() {
+        let mut buf = [0; MB_LEN_MAX as usize];
+        return c8rtomb(buf.as_mut_ptr(), 0, ps);
+    }
+
+    // Collect the code units of a character until it's complete
+    let len = ps.partial_len as usize + 1;
+    ps.partial[len - 1] = c8;
+    match utf8::decode(&ps.partial[..len]) {
+        Ok(_) => {
+            ptr::copy_nonoverlapping(ps.partial.as_ptr() as *const c_char, s, len);
+            ps.partial_len = 0;
+            len
+        }
+        Err(utf8::DecodeError::Incomplete) => {
+            ps.partial_len = len as u8;
+            0
+        }
+        Err(utf8::DecodeError::Invalid) => {
+            ps.partial_len = 0;
+            platform::errno = EILSEQ;
+            -1isize as size_t
+        }
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn c16rtomb(s: *mut c_char, c16: char16_t, ps: *mut mbstate_t) -> size_t {
+    static mut INTERNAL: mbstate_t = mbstate_t::new();
+    let ps = if ps.is_null() {
+        &mut INTERNAL
+    } else {
+        &mut *ps
+    };
+
+    if s.is_null() {
+        let mut buf = [0; MB_LEN_MAX as usize];
+        return c16rtomb(buf.as_mut_ptr(), 0, ps);
+    }
+
+    let high = if ps.pending_len != 0 {
+        ps.pending_len = 0;
+        Some(ps.pending)
+    } else {
+        None
+    };
+    match (high, c16 as u32) {
+        // Wait for the low surrogate
+        (None, c @ 0xD800..=0xDBFF) => {
+            ps.pending = c;
+            ps.pending_len = 1;
+            0
+        }
+        (Some(high), low @ 0xDC00..=0xDFFF) => {
+            let c = 0x10000 + ((high - 0xD800) << 10 | (low - 0xDC00));
+            wcrtomb(s, c as wchar_t, ps)
+        }
+        (None, c) if c < 0xDC00 || c > 0xDFFF => wcrtomb(s, c as wchar_t, ps),
+        _ => {
+            platform::errno = EILSEQ;
+            -1isize as size_t
+        }
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn c32rtomb(s: *mut c_char, c32: char32_t, ps: *mut mbstate_t) -> size_t {
+    static mut INTERNAL: mbstate_t = mbstate_t::new();
+    let ps = if ps.is_null() {
+        &mut INTERNAL
+    } else {
+        &mut *ps
+    };
+    wcrtomb(s, c32 as wchar_t, ps)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn mbrtoc8(
+    pc8: *mut char8_t,
+    s: *const c_char,
+    n: size_t,
+    ps: *mut mbstate_t,
+) -> size_t {
+    static mut INTERNAL: mbstate_t = mbstate_t::new();
+    let ps = if ps.is_null() {
+        &mut INTERNAL
+    } else {
+        &mut *ps
+    };
+
+    // The code units left are in the pending bytes, starting with the lowest
+    if ps.pending_len != 0 {
+        if !pc8.is_null() {
+            *pc8 = ps.pending as char8_t;
+        }
+        ps.pending >>= 8;
+        ps.pending_len -= 1;
+        return PENDING;
+    }
+    if s.is_null() {
+        return mbrtoc8(ptr::null_mut(), b"\0".as_ptr() as *const c_char, 1, ps);
+    }
+
+    let (c, result) = next_char(s, n, ps);
+    if is_error(result) {
+        return result;
+    }
+
+    // Characters are valid once mbrtowc has returned them
+    let mut buf = [0; MB_LEN_MAX as usize];
+    let units = char::from_u32(c).unwrap().encode_utf8(&mut buf).as_bytes();
+    ps.pending = 0;
+    for (i, &unit) in units[1..].iter().enumerate() {
+        ps.pending |= (unit as u32) << (8 * i);
+    }
+    ps.pending_len = units.len() as u8 - 1;
+    if !pc8.is_null() {
+        *pc8 = units[0];
+    }
+    result
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn mbrtoc16(
+    pc16: *mut char16_t,
+    s: *const c_char,
+    n: size_t,
+    ps: *mut mbstate_t,
+) -> size_t {
+    static mut INTERNAL: mbstate_t = mbstate_t::new();
+    let ps = if ps.is_null() {
+        &mut INTERNAL
+    } else {
+        &mut *ps
+    };
+
+    // The low surrogate of the last character
+    if ps.pending_len != 0 {
+        if !pc16.is_null() {
+            *pc16 = ps.pending as char16_t;
+        }
+        ps.pending_len = 0;
+        return PENDING;
+    }
+    if s.is_null() {
+        return mbrtoc16(ptr::null_mut(), b"\0".as_ptr() as *const c_char, 1, ps);
+    }
+
+    let (c, result) = next_char(s, n, ps);
+    if is_error(result) {
+        return result;
+    }
+
+    let unit = if c >= 0x10000 {
+        ps.pending = 0xDC00 | ((c - 0x10000) & 0x3FF);
+        ps.pending_len = 1;
+        0xD800 | ((c - 0x10000) >> 10)
+    } else {
+        c
+    };
+    if !pc16.is_null() {
+        *pc16 = unit as char16_t;
+    }
+    result
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn mbrtoc32(
+    pc32: *mut char32_t,
+    s: *const c_char,
+    n: size_t,
+    ps: *mut mbstate_t,
+) -> size_t {
+    static mut INTERNAL: mbstate_t = mbstate_t::new();
+    let ps = if ps.is_null() {
+        &mut INTERNAL
+    } else {
+        &mut *ps
+    };
+
+    if s.is_null() {
+        return mbrtoc32(ptr::null_mut(), b"\0".as_ptr() as *const c_char, 1, ps);
+    }
+
+    let (c, result) = next_char(s, n, ps);
+    if !is_error(result) && !pc32.is_null() {
+        *pc32 = c;
+    }
+    result
+}
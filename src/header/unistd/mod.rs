@@ -207,9 +207,9 @@ pub extern "C" fn fchdir(fildes: c_int) -> c_int {
     Sys::fchdir(fildes)
 }
 
-// #[no_mangle]
+#[no_mangle]
 pub extern "C" fn fdatasync(fildes: c_int) -> c_int {
-    unimplemented!();
+    Sys::fdatasync(fildes)
 }
 
 #[no_mangle]
@@ -430,24 +430,17 @@ pub unsafe extern "C" fn pipe2(fildes: *mut c_int, flags: c_int) -> c_int {
 }
 
 #[no_mangle]
-pub extern "C" fn pread(fildes: c_int, buf: *mut c_void, nbyte: size_t, offset: off_t) -> ssize_t {
-    //TODO: better pread using system calls
-
-    let previous = lseek(fildes, offset, SEEK_SET);
-    if previous == -1 {
-        return -1;
-    }
-
-    let res = read(fildes, buf, nbyte);
-    if res < 0 {
-        return res;
-    }
-
-    if lseek(fildes, previous, SEEK_SET) == -1 {
-        return -1;
-    }
-
-    res
+pub unsafe extern "C" fn pread(
+    fildes: c_int,
+    buf: *mut c_void,
+    nbyte: size_t,
+    offset: off_t,
+) -> ssize_t {
+    Sys::pread(
+        fildes,
+        slice::from_raw_parts_mut(buf as *mut u8, nbyte as usize),
+        offset,
+    )
 }
 
 // #[no_mangle]
@@ -460,29 +453,17 @@ pub extern "C" fn pthread_atfork(
 }
 
 #[no_mangle]
-pub extern "C" fn pwrite(
+pub unsafe extern "C" fn pwrite(
     fildes: c_int,
     buf: *const c_void,
     nbyte: size_t,
     offset: off_t,
 ) -> ssize_t {
-    //TODO: better pwrite using system calls
-
-    let previous = lseek(fildes, offset, SEEK_SET);
-    if previous == -1 {
-        return -1;
-    }
-
-    let res = write(fildes, buf, nbyte);
-    if res < 0 {
-        return res;
-    }
-
-    if lseek(fildes, previous, SEEK_SET) == -1 {
-        return -1;
-    }
-
-    res
+    Sys::pwrite(
+        fildes,
+        slice::from_raw_parts(buf as *const u8, nbyte as usize),
+        offset,
+    )
 }
 
 #[no_mangle]
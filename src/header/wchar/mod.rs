@@ -1,22 +1,47 @@
 //! wchar implementation for Redox, following http://pubs.opengroup.org/onlinepubs/7908799/xsh/wchar.h.html
 
 use core::ffi::VaList as va_list;
-use core::{mem, ptr, usize};
+use core::{mem, ptr, slice, usize};
 
+use header::errno::EIO;
+use header::locale::{self, collate, locale_t, LcCollate};
+use header::stdio::format::{self, WideStringReader, WideStringWriter};
 use header::stdio::*;
+use header::stdio::{printf, scanf};
 use header::stdlib::MB_CUR_MAX;
 use header::string;
 use header::time::*;
+use header::wctype::{in_table, iswprint, tables};
 use platform;
 use platform::types::*;
 
-mod utf8;
+pub mod utf8;
 
 const WEOF: wint_t = 0xFFFF_FFFFu32;
 
 #[repr(C)]
 #[derive(Clone, Copy)]
-pub struct mbstate_t;
+pub struct mbstate_t {
+    // The first bytes of a UTF-8 character that has only been partly converted
+    pub partial: [u8; 4],
+    pub partial_len: u8,
+    // Code units of the last character that mbrtoc16 or mbrtoc8 have yet to return, or the high
+    // surrogate given to c16rtomb
+    pub pending_len: u8,
+    pub pending: u32,
+}
+
+impl mbstate_t {
+    /// The initial conversion state
+    pub const fn new() -> Self {
+        mbstate_t {
+            partial: [0; 4],
+            partial_len: 0,
+            pending_len: 0,
+            pending: 0,
+        }
+    }
+}
 
 #[no_mangle]
 pub unsafe extern "C" fn btowc(c: c_int) -> wint_t {
@@ -27,7 +52,7 @@ pub unsafe extern "C" fn btowc(c: c_int) -> wint_t {
 
     let uc = c as u8;
     let c = uc as c_char;
-    let mut ps: mbstate_t = mbstate_t;
+    let mut ps = mbstate_t::new();
     let mut wc: wchar_t = 0;
     let saved_errno = platform::errno;
     let status = mbrtowc(&mut wc, &c as (*const c_char), 1, &mut ps);
@@ -40,62 +65,99 @@ pub unsafe extern "C" fn btowc(c: c_int) -> wint_t {
 
 #[no_mangle]
 pub unsafe extern "C" fn fgetwc(stream: *mut FILE) -> wint_t {
-    //TODO: Real multibyte
-    btowc(fgetc(stream))
+    let mut stream = (*stream).lock();
+    if stream.try_set_orientation_unlocked(1) < 0 {
+        return WEOF;
+    }
+
+    match format::read_wide(&mut *stream) {
+        Ok(Some(wc)) => wc as wint_t,
+        Ok(None) => WEOF,
+        Err(_) => {
+            stream.flags |= F_ERR;
+            WEOF
+        }
+    }
 }
 
 #[no_mangle]
 pub unsafe extern "C" fn fgetws(ws: *mut wchar_t, n: c_int, stream: *mut FILE) -> *mut wchar_t {
-    //TODO: lock
-    let mut i = 0;
-    while ((i + 1) as c_int) < n {
-        let wc = fgetwc(stream);
-        if wc == WEOF {
-            return ptr::null_mut();
-        }
-        *ws.add(i) = wc as wchar_t;
-        i += 1;
+    let mut stream = (*stream).lock();
+    if n <= 0 || stream.try_set_orientation_unlocked(1) < 0 {
+        return ptr::null_mut();
     }
-    while (i as c_int) < n {
-        *ws.add(i) = 0;
+
+    // Read up to a newline, leaving space for the terminating zero
+    let mut i = 0;
+    while i + 1 < n as usize {
+        let wc = match format::read_wide(&mut *stream) {
+            Ok(Some(wc)) => wc,
+            Ok(None) if i > 0 => break,
+            Ok(None) => return ptr::null_mut(),
+            Err(_) => {
+                stream.flags |= F_ERR;
+                return ptr::null_mut();
+            }
+        };
+        *ws.add(i) = wc;
         i += 1;
+        if wc == '\n' as wchar_t {
+            break;
+        }
     }
+    *ws.add(i) = 0;
     ws
 }
 
+/// Write `wc` as a multibyte character to `stream`, which is locked and oriented for wide
+/// characters
+unsafe fn putwc_unlocked(wc: wchar_t, stream: &mut FILE) -> wint_t {
+    match format::write_wide(stream, wc) {
+        Ok(()) => wc as wint_t,
+        Err(_) => {
+            stream.flags |= F_ERR;
+            WEOF
+        }
+    }
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn fputwc(wc: wchar_t, stream: *mut FILE) -> wint_t {
-    //Convert wchar_t to multibytes first
-    static mut INTERNAL: mbstate_t = mbstate_t;
-    let mut bytes: [c_char; MB_CUR_MAX as usize] = [0; MB_CUR_MAX as usize];
-
-    let amount = wcrtomb(bytes.as_mut_ptr(), wc, &mut INTERNAL);
-
-    for i in 0..amount {
-        fputc(bytes[i] as c_int, &mut *stream);
+    let mut stream = (*stream).lock();
+    if stream.try_set_orientation_unlocked(1) < 0 {
+        return WEOF;
     }
 
-    wc as wint_t
+    putwc_unlocked(wc, &mut *stream)
 }
 
 #[no_mangle]
 pub unsafe extern "C" fn fputws(ws: *const wchar_t, stream: *mut FILE) -> c_int {
+    let mut stream = (*stream).lock();
+    if stream.try_set_orientation_unlocked(1) < 0 {
+        return -1;
+    }
+
     let mut i = 0;
     loop {
         let wc = *ws.add(i);
         if wc == 0 {
             return 0;
         }
-        if fputwc(wc, stream) == WEOF {
+        if putwc_unlocked(wc, &mut *stream) == WEOF {
             return -1;
         }
         i += 1;
     }
 }
 
-// #[no_mangle]
-pub extern "C" fn fwide(stream: *mut FILE, mode: c_int) -> c_int {
-    unimplemented!();
+/// Orient `stream` for wide characters if `mode` is positive, or for bytes if it's negative,
+/// unless it's oriented already. Returns the orientation: positive for wide characters,
+/// negative for bytes and 0 for none
+#[no_mangle]
+pub unsafe extern "C" fn fwide(stream: *mut FILE, mode: c_int) -> c_int {
+    let mut stream = (*stream).lock();
+    stream.try_set_orientation_unlocked(mode)
 }
 
 #[no_mangle]
@@ -110,8 +172,7 @@ pub unsafe extern "C" fn getwchar() -> wint_t {
 
 #[no_mangle]
 pub unsafe extern "C" fn mbsinit(ps: *const mbstate_t) -> c_int {
-    //Add a check for the state maybe
-    if ps.is_null() {
+    if ps.is_null() || ((*ps).partial_len == 0 && (*ps).pending_len == 0) {
         1
     } else {
         0
@@ -120,8 +181,13 @@ pub unsafe extern "C" fn mbsinit(ps: *const mbstate_t) -> c_int {
 
 #[no_mangle]
 pub unsafe extern "C" fn mbrlen(s: *const c_char, n: size_t, ps: *mut mbstate_t) -> size_t {
-    static mut INTERNAL: mbstate_t = mbstate_t;
-    mbrtowc(ptr::null_mut(), s, n, &mut INTERNAL)
+    static mut INTERNAL: mbstate_t = mbstate_t::new();
+    let ps = if ps.is_null() {
+        &mut INTERNAL as *mut _
+    } else {
+        ps
+    };
+    mbrtowc(ptr::null_mut(), s, n, ps)
 }
 
 //Only works for UTF8 at the moment
@@ -132,11 +198,13 @@ pub unsafe extern "C" fn mbrtowc(
     n: size_t,
     ps: *mut mbstate_t,
 ) -> size_t {
-    static mut INTERNAL: mbstate_t = mbstate_t;
+    static mut INTERNAL: mbstate_t = mbstate_t::new();
 
-    if ps.is_null() {
-        let ps = &mut INTERNAL;
-    }
+    let ps = if ps.is_null() {
+        &mut INTERNAL as *mut _
+    } else {
+        ps
+    };
     if s.is_null() {
         let xs: [c_char; 1] = [0];
         utf8::mbrtowc(pwc, &xs[0] as *const c_char, 1, ps)
@@ -155,11 +223,13 @@ pub unsafe extern "C" fn mbsnrtowcs(
     dst_len: size_t,
     ps: *mut mbstate_t,
 ) -> size_t {
-    static mut INTERNAL: mbstate_t = mbstate_t;
+    static mut INTERNAL: mbstate_t = mbstate_t::new();
 
-    if ps.is_null() {
-        let ps = &mut INTERNAL;
-    }
+    let ps = if ps.is_null() {
+        &mut INTERNAL as *mut _
+    } else {
+        ps
+    };
 
     let mut src = *src_ptr;
 
@@ -216,6 +286,12 @@ pub unsafe extern "C" fn mbsrtowcs(
     mbsnrtowcs(dst, src, size_t::max_value(), len, ps)
 }
 
+/// Open a stream writing wide characters to a buffer that grows as needed
+#[no_mangle]
+pub unsafe extern "C" fn open_wmemstream(bufp: *mut *mut wchar_t, sizep: *mut size_t) -> *mut FILE {
+    _open_wmemstream(bufp, sizep)
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn putwc(wc: wchar_t, stream: *mut FILE) -> wint_t {
     fputwc(wc, &mut *stream)
@@ -226,54 +302,85 @@ pub unsafe extern "C" fn putwchar(wc: wchar_t) -> wint_t {
     fputwc(wc, &mut *stdout)
 }
 
-// #[no_mangle]
-pub extern "C" fn swprintf(
-    s: *mut wchar_t,
-    n: size_t,
-    format: *const wchar_t,
-    ap: va_list,
-) -> c_int {
-    unimplemented!();
-}
-
-// #[no_mangle]
-pub extern "C" fn swscanf(s: *const wchar_t, format: *const wchar_t, ap: va_list) -> c_int {
-    unimplemented!();
-}
+/// Push back `wc` onto `stream` so it'll be read next, as a multibyte character
+#[no_mangle]
+pub unsafe extern "C" fn ungetwc(wc: wint_t, stream: *mut FILE) -> wint_t {
+    let mut stream = (*stream).lock();
+    if stream.try_set_orientation_unlocked(1) < 0 || wc == WEOF {
+        return WEOF;
+    }
 
-// #[no_mangle]
-pub extern "C" fn towlower(wc: wint_t) -> wint_t {
-    unimplemented!();
+    let mut bytes = [0; MB_CUR_MAX as usize];
+    let len = wcrtomb(
+        bytes.as_mut_ptr() as *mut c_char,
+        wc as wchar_t,
+        &mut mbstate_t::new(),
+    );
+    if len == usize::max_value() {
+        return WEOF;
+    }
+    if !stream.unget(&bytes[..len]) {
+        platform::errno = EIO;
+        return WEOF;
+    }
+    wc
 }
 
-// #[no_mangle]
-pub extern "C" fn towupper(wc: wint_t) -> wint_t {
-    unimplemented!();
-}
+#[no_mangle]
+pub unsafe extern "C" fn vfwprintf(
+    stream: *mut FILE,
+    format: *const wchar_t,
+    arg: va_list,
+) -> c_int {
+    let mut stream = (*stream).lock();
+    if stream.try_set_orientation_unlocked(1) < 0 {
+        return -1;
+    }
 
-// #[no_mangle]
-pub extern "C" fn ungetwc(wc: wint_t, stream: *mut FILE) -> wint_t {
-    unimplemented!();
+    printf::printf(&mut *stream, format, arg)
 }
 
-// #[no_mangle]
-pub extern "C" fn vfwprintf(stream: *mut FILE, format: *const wchar_t, arg: va_list) -> c_int {
-    unimplemented!();
-}
+#[no_mangle]
+pub unsafe extern "C" fn vfwscanf(
+    stream: *mut FILE,
+    format: *const wchar_t,
+    arg: va_list,
+) -> c_int {
+    let mut stream = (*stream).lock();
+    if stream.try_set_orientation_unlocked(1) < 0 {
+        return -1;
+    }
 
-// #[no_mangle]
-pub extern "C" fn vwprintf(format: *const wchar_t, arg: va_list) -> c_int {
-    unimplemented!();
+    scanf::fscanf(&mut *stream, format, arg)
 }
 
-// #[no_mangle]
-pub extern "C" fn vswprintf(
+#[no_mangle]
+pub unsafe extern "C" fn vswprintf(
     s: *mut wchar_t,
     n: size_t,
     format: *const wchar_t,
     arg: va_list,
 ) -> c_int {
-    unimplemented!();
+    printf::printf(WideStringWriter::new(s, n), format, arg)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn vswscanf(
+    s: *const wchar_t,
+    format: *const wchar_t,
+    arg: va_list,
+) -> c_int {
+    scanf::scanf(WideStringReader::new(s), format, arg)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn vwprintf(format: *const wchar_t, arg: va_list) -> c_int {
+    vfwprintf(stdout, format, arg)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn vwscanf(format: *const wchar_t, arg: va_list) -> c_int {
+    vfwscanf(stdin, format, arg)
 }
 
 //widechar to multibyte
@@ -314,8 +421,29 @@ pub unsafe extern "C" fn wcscmp(ws1: *const wchar_t, ws2: *const wchar_t) -> c_i
 
 #[no_mangle]
 pub unsafe extern "C" fn wcscoll(ws1: *const wchar_t, ws2: *const wchar_t) -> c_int {
-    //TODO: locale comparison
-    wcscmp(ws1, ws2)
+    inner_wcscoll(ws1, ws2, locale::current().collate)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn wcscoll_l(
+    ws1: *const wchar_t,
+    ws2: *const wchar_t,
+    loc: locale_t,
+) -> c_int {
+    inner_wcscoll(ws1, ws2, locale::get(loc).collate)
+}
+
+unsafe fn inner_wcscoll(ws1: *const wchar_t, ws2: *const wchar_t, lc_collate: &LcCollate) -> c_int {
+    if !lc_collate.uca {
+        return wcscmp(ws1, ws2);
+    }
+    let ws1 = slice::from_raw_parts(ws1, wcslen(ws1) as usize);
+    let ws2 = slice::from_raw_parts(ws2, wcslen(ws2) as usize);
+    collate::compare(
+        collate::wide_chars(ws1),
+        collate::wide_chars(ws2),
+        lc_collate,
+    )
 }
 
 #[no_mangle]
@@ -492,14 +620,64 @@ pub extern "C" fn wcswcs(ws1: *const wchar_t, ws2: *const wchar_t) -> *mut wchar
     unimplemented!();
 }
 
-// #[no_mangle]
-pub extern "C" fn wcswidth(pwcs: *const wchar_t, n: size_t) -> c_int {
-    unimplemented!();
+#[no_mangle]
+pub unsafe extern "C" fn wcswidth(pwcs: *const wchar_t, n: size_t) -> c_int {
+    let mut total = 0;
+    for i in 0..n {
+        let wc = *pwcs.add(i);
+        if wc == 0 {
+            break;
+        }
+        let width = wcwidth(wc);
+        if width < 0 {
+            return -1;
+        }
+        total += width;
+    }
+    total
 }
 
-// #[no_mangle]
-pub extern "C" fn wcsxfrm(ws1: *mut wchar_t, ws2: *const wchar_t, n: size_t) -> size_t {
-    unimplemented!();
+#[no_mangle]
+pub unsafe extern "C" fn wcsxfrm(ws1: *mut wchar_t, ws2: *const wchar_t, n: size_t) -> size_t {
+    inner_wcsxfrm(ws1, ws2, n, locale::current().collate)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn wcsxfrm_l(
+    ws1: *mut wchar_t,
+    ws2: *const wchar_t,
+    n: size_t,
+    loc: locale_t,
+) -> size_t {
+    inner_wcsxfrm(ws1, ws2, n, locale::get(loc).collate)
+}
+
+unsafe fn inner_wcsxfrm(
+    ws1: *mut wchar_t,
+    ws2: *const wchar_t,
+    n: size_t,
+    lc_collate: &LcCollate,
+) -> size_t {
+    let len = wcslen(ws2) as usize;
+    if !lc_collate.uca {
+        if len < n {
+            wcscpy(ws1, ws2);
+        }
+        return len;
+    }
+
+    // Each byte of the sort key becomes a wide character, so that it compares with wcscmp
+    let key = collate::sort_key(
+        collate::wide_chars(slice::from_raw_parts(ws2, len)),
+        lc_collate,
+    );
+    if key.len() < n {
+        for (i, &byte) in key.iter().enumerate() {
+            *ws1.add(i) = byte as wchar_t;
+        }
+        *ws1.add(key.len()) = 0;
+    }
+    key.len()
 }
 
 #[no_mangle]
@@ -511,9 +689,22 @@ pub extern "C" fn wctob(c: wint_t) -> c_int {
     }
 }
 
-// #[no_mangle]
+#[no_mangle]
 pub extern "C" fn wcwidth(wc: wchar_t) -> c_int {
-    unimplemented!();
+    let wc = wc as wint_t;
+    if wc == 0 {
+        return 0;
+    }
+    if iswprint(wc) == 0 {
+        return -1;
+    }
+    if in_table(tables::ZERO_WIDTH, wc) {
+        0
+    } else if in_table(tables::WIDE, wc) {
+        2
+    } else {
+        1
+    }
 }
 
 #[no_mangle]
@@ -571,13 +762,3 @@ pub unsafe extern "C" fn wmemset(ws: *mut wchar_t, wc: wchar_t, n: size_t) -> *m
     }
     ws
 }
-
-// #[no_mangle]
-pub extern "C" fn wprintf(format: *const wchar_t, ap: va_list) -> c_int {
-    unimplemented!();
-}
-
-// #[no_mangle]
-pub extern "C" fn wscanf(format: *const wchar_t, ap: va_list) -> c_int {
-    unimplemented!();
-}
@@ -9,35 +9,79 @@ use platform::types::*;
 
 use super::mbstate_t;
 
+pub enum DecodeError {
+    /// The input ends in the middle of a character
+    Incomplete,
+    /// The input doesn't start with a valid character
+    Invalid,
+}
+
+/// Decode the character at the start of `input`, returning it and its length in bytes. Only the
+/// bytes of that character are read. Shared with iconv
+pub fn decode(input: &[u8]) -> Result<(u32, usize), DecodeError> {
+    let size = str::utf8_char_width(input[0]);
+    if size == 0 {
+        return Err(DecodeError::Invalid);
+    }
+
+    let slice = &input[..size.min(input.len())];
+    match str::from_utf8(slice) {
+        Ok(decoded) if slice.len() == size => Ok((decoded.chars().next().unwrap() as u32, size)),
+        Ok(_) => Err(DecodeError::Incomplete),
+        Err(err) => match err.error_len() {
+            None => Err(DecodeError::Incomplete),
+            Some(_) => Err(DecodeError::Invalid),
+        },
+    }
+}
+
 //It's guaranteed that we don't have any nullpointers here
 pub unsafe fn mbrtowc(pwc: *mut wchar_t, s: *const c_char, n: usize, ps: *mut mbstate_t) -> usize {
-    let size = str::utf8_char_width(*s as u8);
-    if size > n {
-        platform::errno = errno::EILSEQ;
+    let ps = &mut *ps;
+    if n == 0 {
         return -2isize as usize;
     }
-    if size == 0 {
-        platform::errno = errno::EILSEQ;
-        return -1isize as usize;
-    }
 
-    let slice = slice::from_raw_parts(s as *const u8, size);
-    let decoded = str::from_utf8(slice);
-    if decoded.is_err() {
-        platform::errno = errno::EILSEQ;
-        return -1isize as usize;
+    // Continue the character the state holds the first bytes of, taking no more bytes than it needs
+    let mut buf = ps.partial;
+    let mut len = ps.partial_len as usize;
+    if len == 0 {
+        buf[0] = *s as u8;
+    }
+    let size = str::utf8_char_width(buf[0]).max(1);
+    let mut taken = 0;
+    while len < size && taken < n {
+        let byte = *s.add(taken) as u8;
+        buf[len] = byte;
+        len += 1;
+        taken += 1;
+        // Stop at a byte that can't continue the character, which may be the terminating NUL
+        if len > 1 && byte & 0xC0 != 0x80 {
+            break;
+        }
     }
 
-    let wc = decoded.unwrap();
-
-    let result: wchar_t = wc.chars().next().unwrap() as wchar_t;
+    let result = match decode(&buf[..len]) {
+        Ok((c, _)) => c as wchar_t,
+        Err(DecodeError::Incomplete) => {
+            ps.partial = buf;
+            ps.partial_len = len as u8;
+            return -2isize as usize;
+        }
+        Err(DecodeError::Invalid) => {
+            ps.partial_len = 0;
+            platform::errno = errno::EILSEQ;
+            return -1isize as usize;
+        }
+    };
+    ps.partial_len = 0;
 
     if !pwc.is_null() {
         *pwc = result;
     }
 
     if result != 0 {
-        size
+        taken
     } else {
         0
     }
@@ -0,0 +1,142 @@
+#!/usr/bin/env python3
+"""Generate tables.rs from the Unicode Character Database.
+
+Usage: gen_tables.py UCD_DIR > tables.rs
+
+UCD_DIR must contain UnicodeData.txt, DerivedCoreProperties.txt and EastAsianWidth.txt, from
+https://www.unicode.org/Public/UCD/latest/ucd/
+"""
+
+import os
+import sys
+
+
+def parse_ranges(path):
+    """Yield (first, last, fields) for each data line of a UCD file with code point ranges."""
+    with open(path) as f:
+        for line in f:
+            line = line.split("#", 1)[0].strip()
+            if not line:
+                continue
+            fields = [field.strip() for field in line.split(";")]
+            first, _, last = fields[0].partition("..")
+            yield int(first, 16), int(last or first, 16), fields[1:]
+
+
+def unicode_data(path):
+    """Map each code point to (general category, simple upper case, simple lower case)."""
+    data = {}
+    first = None
+    with open(path) as f:
+        for line in f:
+            fields = line.rstrip("\n").split(";")
+            cp = int(fields[0], 16)
+            upper = int(fields[12], 16) if fields[12] else None
+            lower = int(fields[13], 16) if fields[13] else None
+            value = (fields[2], upper, lower)
+            if fields[1].endswith(", First>"):
+                first = cp
+                continue
+            if fields[1].endswith(", Last>"):
+                for c in range(first, cp + 1):
+                    data[c] = value
+                continue
+            data[cp] = value
+    return data
+
+
+def to_ranges(cps):
+    ranges = []
+    for cp in sorted(cps):
+        if ranges and ranges[-1][1] + 1 == cp:
+            ranges[-1][1] = cp
+        else:
+            ranges.append([cp, cp])
+    return ranges
+
+
+def to_case_ranges(mapping):
+    """Compress a case mapping into runs of code points, every code point or every other one,
+    that map with the same delta."""
+    runs = []
+    for cp, mapped in sorted(mapping.items()):
+        delta = mapped - cp
+        if runs and runs[-1][2] == delta:
+            first, last, _, stride = runs[-1]
+            if first == last and cp - last in (1, 2):
+                runs[-1] = [first, cp, delta, cp - last]
+                continue
+            if first != last and cp - last == stride:
+                runs[-1][1] = cp
+                continue
+        runs.append([cp, cp, delta, 1])
+    return runs
+
+
+def emit_ranges(name, doc, ranges):
+    print("/// {}".format(doc))
+    print("#[rustfmt::skip]")
+    print("pub static {}: &[(u32, u32)] = &[".format(name))
+    for i in range(0, len(ranges), 5):
+        row = ranges[i:i + 5]
+        print("    " + " ".join("(0x{:x}, 0x{:x}),".format(a, b) for a, b in row))
+    print("];")
+    print()
+
+
+def emit_case(name, doc, runs):
+    print("/// {}".format(doc))
+    print("#[rustfmt::skip]")
+    print("pub static {}: &[(u32, u32, i32, u32)] = &[".format(name))
+    for i in range(0, len(runs), 3):
+        row = runs[i:i + 3]
+        print("    " + " ".join("(0x{:x}, 0x{:x}, {}, {}),".format(*run) for run in row))
+    print("];")
+
+
+def main():
+    ucd = sys.argv[1]
+    data = unicode_data(os.path.join(ucd, "UnicodeData.txt"))
+
+    derived = {}
+    for first, last, fields in parse_ranges(os.path.join(ucd, "DerivedCoreProperties.txt")):
+        derived.setdefault(fields[0], set()).update(range(first, last + 1))
+
+    # Digits other than 0-9 are letters, as iswdigit is only true for 0-9
+    alpha = derived["Alphabetic"] | {
+        cp for cp, (gc, _, _) in data.items() if gc == "Nd" and cp > 0x7f
+    }
+
+    zero_width = {cp for cp, (gc, _, _) in data.items() if gc in ("Mn", "Me", "Cf")}
+    # The soft hyphen is shown when a line is broken there
+    zero_width.discard(0xad)
+    # Hangul medial vowels and final consonants combine with the preceding syllable
+    zero_width.update(range(0x1160, 0x1200))
+    zero_width.update(range(0xd7b0, 0xd800))
+
+    wide = set()
+    for first, last, fields in parse_ranges(os.path.join(ucd, "EastAsianWidth.txt")):
+        if fields[0] in ("W", "F"):
+            wide.update(range(first, last + 1))
+    wide -= zero_width
+
+    to_upper = {cp: upper for cp, (_, upper, _) in data.items() if upper is not None}
+    to_lower = {cp: lower for cp, (_, _, lower) in data.items() if lower is not None}
+
+    print("//! Unicode character property tables, generated by gen_tables.py. Do not edit.")
+    print()
+    emit_ranges("ALPHA", "Alphabetic characters", to_ranges(alpha))
+    emit_ranges("LOWER", "Lowercase characters", to_ranges(derived["Lowercase"]))
+    emit_ranges("UPPER", "Uppercase characters", to_ranges(derived["Uppercase"]))
+    emit_ranges("ZERO_WIDTH", "Combining and format characters, which take up no column", to_ranges(zero_width))
+    emit_ranges("WIDE", "East Asian wide and fullwidth characters, which take up two columns", to_ranges(wide))
+    print("// Case mappings, as (first, last, delta, stride): every stride-th code point from first to last")
+    print("// maps to itself plus delta")
+    print()
+    emit_case("TO_LOWER", "Simple lowercase mappings", to_case_ranges(to_lower))
+    print()
+    emit_case("TO_UPPER", "Simple uppercase mappings", to_case_ranges(to_upper))
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,293 @@
+//! wctype implementation for Redox, following http://pubs.opengroup.org/onlinepubs/7908799/xsh/wctype.h.html
+
+use core::cmp::Ordering;
+use core::ptr;
+
+use c_str::CStr;
+use header::locale::locale_t;
+use platform::types::*;
+
+pub mod tables;
+
+// Character classes returned by wctype
+const WCTYPE_ALNUM: wctype_t = 1;
+const WCTYPE_ALPHA: wctype_t = 2;
+const WCTYPE_BLANK: wctype_t = 3;
+const WCTYPE_CNTRL: wctype_t = 4;
+const WCTYPE_DIGIT: wctype_t = 5;
+const WCTYPE_GRAPH: wctype_t = 6;
+const WCTYPE_LOWER: wctype_t = 7;
+const WCTYPE_PRINT: wctype_t = 8;
+const WCTYPE_PUNCT: wctype_t = 9;
+const WCTYPE_SPACE: wctype_t = 10;
+const WCTYPE_UPPER: wctype_t = 11;
+const WCTYPE_XDIGIT: wctype_t = 12;
+
+const WCTYPE_NAMES: [&[u8]; 12] = [
+    b"alnum", b"alpha", b"blank", b"cntrl", b"digit", b"graph", b"lower", b"print", b"punct",
+    b"space", b"upper", b"xdigit",
+];
+
+// Mappings returned by wctrans. A wctrans_t is a pointer, so these point into a static array
+static WCTRANS: [i32; 2] = [0, 1];
+
+pub(crate) fn in_table(table: &[(u32, u32)], wc: wint_t) -> bool {
+    table
+        .binary_search_by(|&(first, last)| {
+            if last < wc {
+                Ordering::Less
+            } else if first > wc {
+                Ordering::Greater
+            } else {
+                Ordering::Equal
+            }
+        })
+        .is_ok()
+}
+
+fn map_case(table: &[(u32, u32, i32, u32)], wc: wint_t) -> wint_t {
+    let i = match table.binary_search_by_key(&wc, |&(first, _, _, _)| first) {
+        Ok(i) => i,
+        Err(0) => return wc,
+        Err(i) => i - 1,
+    };
+    let (first, last, delta, stride) = table[i];
+    if wc <= last && (wc - first) % stride == 0 {
+        (wc as i32 + delta) as wint_t
+    } else {
+        wc
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn iswalnum(wc: wint_t) -> c_int {
+    (iswdigit(wc) != 0 || iswalpha(wc) != 0) as c_int
+}
+
+// Characters are classified by Unicode in every locale, so the *_l functions ignore the locale
+#[no_mangle]
+pub extern "C" fn iswalnum_l(wc: wint_t, _loc: locale_t) -> c_int {
+    iswalnum(wc)
+}
+
+#[no_mangle]
+pub extern "C" fn iswalpha(wc: wint_t) -> c_int {
+    in_table(tables::ALPHA, wc) as c_int
+}
+
+#[no_mangle]
+pub extern "C" fn iswalpha_l(wc: wint_t, _loc: locale_t) -> c_int {
+    iswalpha(wc)
+}
+
+#[no_mangle]
+pub extern "C" fn iswblank(wc: wint_t) -> c_int {
+    match wc {
+        0x09 | 0x20 | 0x1680 | 0x2000..=0x2006 | 0x2008..=0x200A | 0x205F | 0x3000 => 1,
+        _ => 0,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn iswblank_l(wc: wint_t, _loc: locale_t) -> c_int {
+    iswblank(wc)
+}
+
+#[no_mangle]
+pub extern "C" fn iswcntrl(wc: wint_t) -> c_int {
+    (wc < 0x20 || (wc >= 0x7F && wc < 0xA0) || wc == 0x2028 || wc == 0x2029) as c_int
+}
+
+#[no_mangle]
+pub extern "C" fn iswcntrl_l(wc: wint_t, _loc: locale_t) -> c_int {
+    iswcntrl(wc)
+}
+
+#[no_mangle]
+pub extern "C" fn iswctype(wc: wint_t, charclass: wctype_t) -> c_int {
+    match charclass {
+        WCTYPE_ALNUM => iswalnum(wc),
+        WCTYPE_ALPHA => iswalpha(wc),
+        WCTYPE_BLANK => iswblank(wc),
+        WCTYPE_CNTRL => iswcntrl(wc),
+        WCTYPE_DIGIT => iswdigit(wc),
+        WCTYPE_GRAPH => iswgraph(wc),
+        WCTYPE_LOWER => iswlower(wc),
+        WCTYPE_PRINT => iswprint(wc),
+        WCTYPE_PUNCT => iswpunct(wc),
+        WCTYPE_SPACE => iswspace(wc),
+        WCTYPE_UPPER => iswupper(wc),
+        WCTYPE_XDIGIT => iswxdigit(wc),
+        _ => 0,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn iswctype_l(wc: wint_t, charclass: wctype_t, _loc: locale_t) -> c_int {
+    iswctype(wc, charclass)
+}
+
+#[no_mangle]
+pub extern "C" fn iswdigit(wc: wint_t) -> c_int {
+    (wc >= b'0' as wint_t && wc <= b'9' as wint_t) as c_int
+}
+
+#[no_mangle]
+pub extern "C" fn iswdigit_l(wc: wint_t, _loc: locale_t) -> c_int {
+    iswdigit(wc)
+}
+
+#[no_mangle]
+pub extern "C" fn iswgraph(wc: wint_t) -> c_int {
+    (iswprint(wc) != 0 && iswspace(wc) == 0) as c_int
+}
+
+#[no_mangle]
+pub extern "C" fn iswgraph_l(wc: wint_t, _loc: locale_t) -> c_int {
+    iswgraph(wc)
+}
+
+#[no_mangle]
+pub extern "C" fn iswlower(wc: wint_t) -> c_int {
+    in_table(tables::LOWER, wc) as c_int
+}
+
+#[no_mangle]
+pub extern "C" fn iswlower_l(wc: wint_t, _loc: locale_t) -> c_int {
+    iswlower(wc)
+}
+
+#[no_mangle]
+pub extern "C" fn iswprint(wc: wint_t) -> c_int {
+    // Unassigned code points are printable, so that text from newer versions of Unicode is shown
+    let unprintable = iswcntrl(wc) != 0
+        || (wc >= 0xD800 && wc <= 0xDFFF)
+        || (wc >= 0xFFF9 && wc <= 0xFFFB)
+        || wc & 0xFFFE == 0xFFFE
+        || wc > 0x10FFFF;
+    (!unprintable) as c_int
+}
+
+#[no_mangle]
+pub extern "C" fn iswprint_l(wc: wint_t, _loc: locale_t) -> c_int {
+    iswprint(wc)
+}
+
+#[no_mangle]
+pub extern "C" fn iswpunct(wc: wint_t) -> c_int {
+    (iswgraph(wc) != 0 && iswalnum(wc) == 0) as c_int
+}
+
+#[no_mangle]
+pub extern "C" fn iswpunct_l(wc: wint_t, _loc: locale_t) -> c_int {
+    iswpunct(wc)
+}
+
+#[no_mangle]
+pub extern "C" fn iswspace(wc: wint_t) -> c_int {
+    // White space, except for the no-break spaces
+    match wc {
+        0x09..=0x0D
+        | 0x20
+        | 0x1680
+        | 0x2000..=0x2006
+        | 0x2008..=0x200A
+        | 0x2028
+        | 0x2029
+        | 0x205F
+        | 0x3000 => 1,
+        _ => 0,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn iswspace_l(wc: wint_t, _loc: locale_t) -> c_int {
+    iswspace(wc)
+}
+
+#[no_mangle]
+pub extern "C" fn iswupper(wc: wint_t) -> c_int {
+    in_table(tables::UPPER, wc) as c_int
+}
+
+#[no_mangle]
+pub extern "C" fn iswupper_l(wc: wint_t, _loc: locale_t) -> c_int {
+    iswupper(wc)
+}
+
+#[no_mangle]
+pub extern "C" fn iswxdigit(wc: wint_t) -> c_int {
+    match wc {
+        0x30..=0x39 | 0x41..=0x46 | 0x61..=0x66 => 1,
+        _ => 0,
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn iswxdigit_l(wc: wint_t, _loc: locale_t) -> c_int {
+    iswxdigit(wc)
+}
+
+#[no_mangle]
+pub extern "C" fn towctrans(wc: wint_t, desc: wctrans_t) -> wint_t {
+    if desc == &WCTRANS[0] as wctrans_t {
+        towlower(wc)
+    } else if desc == &WCTRANS[1] as wctrans_t {
+        towupper(wc)
+    } else {
+        wc
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn towctrans_l(wc: wint_t, desc: wctrans_t, _loc: locale_t) -> wint_t {
+    towctrans(wc, desc)
+}
+
+#[no_mangle]
+pub extern "C" fn towlower(wc: wint_t) -> wint_t {
+    map_case(tables::TO_LOWER, wc)
+}
+
+#[no_mangle]
+pub extern "C" fn towlower_l(wc: wint_t, _loc: locale_t) -> wint_t {
+    towlower(wc)
+}
+
+#[no_mangle]
+pub extern "C" fn towupper(wc: wint_t) -> wint_t {
+    map_case(tables::TO_UPPER, wc)
+}
+
+#[no_mangle]
+pub extern "C" fn towupper_l(wc: wint_t, _loc: locale_t) -> wint_t {
+    towupper(wc)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn wctrans(property: *const c_char) -> wctrans_t {
+    match CStr::from_ptr(property).to_bytes() {
+        b"tolower" => &WCTRANS[0],
+        b"toupper" => &WCTRANS[1],
+        _ => ptr::null(),
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn wctrans_l(property: *const c_char, _loc: locale_t) -> wctrans_t {
+    wctrans(property)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn wctype(property: *const c_char) -> wctype_t {
+    let property = CStr::from_ptr(property).to_bytes();
+    WCTYPE_NAMES
+        .iter()
+        .position(|&name| name == property)
+        .map_or(0, |i| i as wctype_t + 1)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn wctype_l(property: *const c_char, _loc: locale_t) -> wctype_t {
+    wctype(property)
+}
@@ -16,7 +16,7 @@ unsafe impl<T: Send> Send for Mutex<T> {}
 unsafe impl<T: Send> Sync for Mutex<T> {}
 impl<T> Mutex<T> {
     /// Create a new mutex
-    pub fn new(content: T) -> Self {
+    pub const fn new(content: T) -> Self {
         Self {
             lock: UnsafeCell::new(0),
             content: UnsafeCell::new(content),
@@ -57,7 +57,7 @@ impl<T> Mutex<T> {
             // - Skip the atomic operation if the last value was 2, since it most likely hasn't changed.
             // - Skip the futex wait if the atomic operation says the mutex is unlocked.
             if last == 2 || intrinsics::atomic_cxchg(self.lock.get(), 1, 2).0 != 0 {
-                Sys::futex(self.lock.get(), FUTEX_WAIT, 2);
+                Sys::futex(self.lock.get(), FUTEX_WAIT, 2, 0);
             }
 
             last = match self.manual_try_lock() {
@@ -70,7 +70,7 @@ impl<T> Mutex<T> {
     pub unsafe fn manual_unlock(&self) {
         if intrinsics::atomic_xchg(self.lock.get(), 0) == 2 {
             // At least one futex is up, so let's notify it
-            Sys::futex(self.lock.get(), FUTEX_WAKE, 1);
+            Sys::futex(self.lock.get(), FUTEX_WAKE, 1, 0);
         }
     }
 
@@ -5,18 +5,21 @@ use super::types::*;
 use super::{errno, Pal};
 use c_str::CStr;
 use header::dirent::dirent;
-use header::signal::SIGCHLD;
-// use header::sys_resource::rusage;
+use header::errno::{EINVAL, ESRCH};
+use header::signal::{sigevent, sigval, SIGCHLD};
+use header::sys_resource::{rlimit, rusage};
 use header::sys_stat::stat;
 use header::sys_statvfs::statvfs;
 use header::sys_time::{timeval, timezone};
 // use header::sys_times::tms;
 use header::sys_utsname::utsname;
-use header::time::timespec;
+use header::time::constants::CLOCK_REALTIME;
+use header::time::{itimerspec, timespec};
 
 mod epoll;
 mod signal;
 mod socket;
+pub mod vdso;
 
 const AT_FDCWD: c_int = -100;
 const AT_EMPTY_PATH: c_int = 0x1000;
@@ -27,6 +30,22 @@ const CLONE_VM: usize = 0x0100;
 const CLONE_FS: usize = 0x0200;
 const CLONE_FILES: usize = 0x0400;
 const CLONE_SIGHAND: usize = 0x0800;
+const CLONE_CHILD_CLEARTID: usize = 0x0020_0000;
+
+const TFD_CLOEXEC: c_int = 0o2_000_000;
+
+// Encoding of the clock ids of CPU-time clocks for other processes and threads
+const CPUCLOCK_PERTHREAD: clockid_t = 4;
+const CPUCLOCK_SCHED: clockid_t = 2;
+
+/// The kernel's struct sigevent, only used for SIGEV_SIGNAL and SIGEV_NONE
+#[repr(C)]
+struct linux_sigevent {
+    sigev_value: sigval,
+    sigev_signo: c_int,
+    sigev_notify: c_int,
+    _pad: [c_int; 12],
+}
 
 #[repr(C)]
 #[derive(Default)]
@@ -56,13 +75,25 @@ pub fn e(sys: usize) -> usize {
     }
 }
 
+/// Return a CPU-time clock id if the kernel accepts it. The kernel rejects the clocks of processes
+/// that do not exist with EINVAL
+fn cpu_clock(id: clockid_t, clock_id: *mut clockid_t) -> c_int {
+    let mut res = timespec::default();
+    match unsafe { syscall!(CLOCK_GETRES, id, &mut res as *mut timespec) } as isize {
+        0 => {
+            unsafe {
+                *clock_id = id;
+            }
+            0
+        }
+        err if err == -(EINVAL as isize) => ESRCH,
+        err => -err as c_int,
+    }
+}
+
 pub struct Sys;
 
 impl Sys {
-    // fn getrusage(who: c_int, r_usage: *mut rusage) -> c_int {
-    //     e(unsafe { syscall!(GETRUSAGE, who, r_usage) }) as c_int
-    // }
-
     pub unsafe fn ioctl(fd: c_int, request: c_ulong, out: *mut c_void) -> c_int {
         // TODO: Somehow support varargs to syscall??
         e(syscall!(IOCTL, fd, request, out)) as c_int
@@ -102,10 +133,34 @@ impl Pal for Sys {
         }) as c_int
     }
 
+    fn clock_getcpuclockid(pid: pid_t, clock_id: *mut clockid_t) -> c_int {
+        cpu_clock(!pid << 3 | CPUCLOCK_SCHED, clock_id)
+    }
+
+    fn clock_getres(clk_id: clockid_t, res: *mut timespec) -> c_int {
+        e(unsafe { syscall!(CLOCK_GETRES, clk_id, res) }) as c_int
+    }
+
     fn clock_gettime(clk_id: clockid_t, tp: *mut timespec) -> c_int {
+        if let Some(clock_gettime) = unsafe { vdso::CLOCK_GETTIME } {
+            return e(clock_gettime(clk_id, tp) as usize) as c_int;
+        }
         e(unsafe { syscall!(CLOCK_GETTIME, clk_id, tp) }) as c_int
     }
 
+    fn clock_nanosleep(
+        clk_id: clockid_t,
+        flags: c_int,
+        rqtp: *const timespec,
+        rmtp: *mut timespec,
+    ) -> c_int {
+        e(unsafe { syscall!(CLOCK_NANOSLEEP, clk_id, flags, rqtp, rmtp) }) as c_int
+    }
+
+    fn clock_settime(clk_id: clockid_t, tp: *const timespec) -> c_int {
+        e(unsafe { syscall!(CLOCK_SETTIME, clk_id, tp) }) as c_int
+    }
+
     fn close(fildes: c_int) -> c_int {
         e(unsafe { syscall!(CLOSE, fildes) }) as c_int
     }
@@ -141,6 +196,10 @@ impl Pal for Sys {
         e(unsafe { syscall!(FCHOWN, fildes, owner, group) }) as c_int
     }
 
+    fn fdatasync_raw(fildes: c_int) -> c_int {
+        unsafe { syscall!(FDATASYNC, fildes) as c_int }
+    }
+
     fn flock(fd: c_int, operation: c_int) -> c_int {
         e(unsafe { syscall!(FLOCK, fd, operation) }) as c_int
     }
@@ -195,16 +254,16 @@ impl Pal for Sys {
         Self::readlink(CStr::from_bytes_with_nul(&proc_path).unwrap(), out)
     }
 
-    fn fsync(fildes: c_int) -> c_int {
-        e(unsafe { syscall!(FSYNC, fildes) }) as c_int
+    fn fsync_raw(fildes: c_int) -> c_int {
+        unsafe { syscall!(FSYNC, fildes) as c_int }
     }
 
     fn ftruncate(fildes: c_int, length: off_t) -> c_int {
         e(unsafe { syscall!(FTRUNCATE, fildes, length) }) as c_int
     }
 
-    fn futex(addr: *mut c_int, op: c_int, val: c_int) -> c_int {
-        unsafe { syscall!(FUTEX, addr, op, val, 0, 0, 0) as c_int }
+    fn futex(addr: *mut c_int, op: c_int, val: c_int, val2: usize) -> c_int {
+        unsafe { syscall!(FUTEX, addr, op, val, val2, 0, 0) as c_int }
     }
 
     fn futimens(fd: c_int, times: *const timespec) -> c_int {
@@ -251,11 +310,22 @@ impl Pal for Sys {
         e(unsafe { syscall!(GETPPID) }) as pid_t
     }
 
+    fn getrlimit(resource: c_int, rlim: *mut rlimit) -> c_int {
+        e(unsafe { syscall!(GETRLIMIT, resource, rlim) }) as c_int
+    }
+
+    fn getrusage(who: c_int, r_usage: *mut rusage) -> c_int {
+        e(unsafe { syscall!(GETRUSAGE, who, r_usage) }) as c_int
+    }
+
     fn gettid() -> pid_t {
         e(unsafe { syscall!(GETTID) }) as pid_t
     }
 
     fn gettimeofday(tp: *mut timeval, tzp: *mut timezone) -> c_int {
+        if let Some(gettimeofday) = unsafe { vdso::GETTIMEOFDAY } {
+            return e(gettimeofday(tp, tzp) as usize) as c_int;
+        }
         e(unsafe { syscall!(GETTIMEOFDAY, tp, tzp) }) as c_int
     }
 
@@ -319,9 +389,18 @@ impl Pal for Sys {
         e(unsafe { syscall!(PIPE2, fildes.as_mut_ptr(), flags) }) as c_int
     }
 
+    fn pread_raw(fildes: c_int, buf: &mut [u8], offset: off_t) -> ssize_t {
+        unsafe { syscall!(PREAD64, fildes, buf.as_mut_ptr(), buf.len(), offset) as ssize_t }
+    }
+
+    fn pwrite_raw(fildes: c_int, buf: &[u8], offset: off_t) -> ssize_t {
+        unsafe { syscall!(PWRITE64, fildes, buf.as_ptr(), buf.len(), offset) as ssize_t }
+    }
+
     #[cfg(target_arch = "x86_64")]
-    unsafe fn pte_clone(stack: *mut usize) -> pid_t {
-        let flags = CLONE_VM | CLONE_FS | CLONE_FILES | CLONE_SIGHAND;
+    unsafe fn pte_clone(stack: *mut usize, ctid: *mut pid_t) -> pid_t {
+        // The kernel clears ctid and wakes futex waiters on it once the thread has exited
+        let flags = CLONE_VM | CLONE_FS | CLONE_FILES | CLONE_SIGHAND | CLONE_CHILD_CLEARTID;
         let pid;
         asm!("
             # Call clone syscall
@@ -355,7 +434,7 @@ impl Pal for Sys {
             .parent:
             "
             : "={rax}"(pid)
-            : "{rax}"(SYS_CLONE), "{rdi}"(flags), "{rsi}"(stack), "{rdx}"(0), "{rcx}"(0), "{r8}"(0)
+            : "{rax}"(SYS_CLONE), "{rdi}"(flags), "{rsi}"(stack), "{rdx}"(0), "{r10}"(ctid), "{r8}"(0)
             : "memory", "rbx", "rcx", "rdx", "rsi", "rdi", "r8",
               "r9", "r10", "r11", "r12", "r13", "r14", "r15"
             : "intel", "volatile"
@@ -363,8 +442,12 @@ impl Pal for Sys {
         e(pid) as pid_t
     }
 
-    fn read(fildes: c_int, buf: &mut [u8]) -> ssize_t {
-        e(unsafe { syscall!(READ, fildes, buf.as_mut_ptr(), buf.len()) }) as ssize_t
+    fn pthread_getcpuclockid(tid: pid_t, clock_id: *mut clockid_t) -> c_int {
+        cpu_clock(!tid << 3 | CPUCLOCK_PERTHREAD | CPUCLOCK_SCHED, clock_id)
+    }
+
+    fn read_raw(fildes: c_int, buf: &mut [u8]) -> ssize_t {
+        unsafe { syscall!(READ, fildes, buf.as_mut_ptr(), buf.len()) as ssize_t }
     }
 
     fn readlink(pathname: &CStr, out: &mut [u8]) -> ssize_t {
@@ -387,6 +470,18 @@ impl Pal for Sys {
         e(unsafe { syscall!(UNLINKAT, AT_FDCWD, path.as_ptr(), AT_REMOVEDIR) }) as c_int
     }
 
+    fn sched_getcpu() -> c_int {
+        let mut cpu: c_uint = 0;
+        let ret = match unsafe { vdso::GETCPU } {
+            Some(getcpu) => getcpu(&mut cpu, ptr::null_mut(), ptr::null_mut()) as usize,
+            None => unsafe { syscall!(GETCPU, &mut cpu as *mut c_uint, 0, 0) },
+        };
+        if e(ret) == !0 {
+            return -1;
+        }
+        cpu as c_int
+    }
+
     fn sched_yield() -> c_int {
         e(unsafe { syscall!(SCHED_YIELD) }) as c_int
     }
@@ -403,10 +498,82 @@ impl Pal for Sys {
         e(unsafe { syscall!(SETREUID, ruid, euid) }) as c_int
     }
 
+    fn setrlimit(resource: c_int, rlim: *const rlimit) -> c_int {
+        e(unsafe { syscall!(SETRLIMIT, resource, rlim) }) as c_int
+    }
+
     fn symlink(path1: &CStr, path2: &CStr) -> c_int {
         e(unsafe { syscall!(SYMLINKAT, path1.as_ptr(), AT_FDCWD, path2.as_ptr()) }) as c_int
     }
 
+    fn time(tloc: *mut time_t) -> time_t {
+        if let Some(time) = unsafe { vdso::TIME } {
+            return time(tloc);
+        }
+        let mut ts = timespec::default();
+        Self::clock_gettime(CLOCK_REALTIME, &mut ts);
+        if !tloc.is_null() {
+            unsafe { *tloc = ts.tv_sec };
+        }
+        ts.tv_sec
+    }
+
+    fn timer_create(clock_id: clockid_t, evp: *const sigevent, timerid: *mut c_int) -> c_int {
+        let event;
+        let kevp = if evp.is_null() {
+            ptr::null()
+        } else {
+            event = unsafe {
+                linux_sigevent {
+                    sigev_value: (*evp).sigev_value,
+                    sigev_signo: (*evp).sigev_signo,
+                    sigev_notify: (*evp).sigev_notify,
+                    _pad: [0; 12],
+                }
+            };
+            &event as *const linux_sigevent
+        };
+        e(unsafe { syscall!(TIMER_CREATE, clock_id, kevp, timerid) }) as c_int
+    }
+
+    fn timer_delete(timerid: c_int) -> c_int {
+        e(unsafe { syscall!(TIMER_DELETE, timerid) }) as c_int
+    }
+
+    fn timer_getoverrun(timerid: c_int) -> c_int {
+        e(unsafe { syscall!(TIMER_GETOVERRUN, timerid) }) as c_int
+    }
+
+    fn timer_gettime(timerid: c_int, value: *mut itimerspec) -> c_int {
+        e(unsafe { syscall!(TIMER_GETTIME, timerid, value) }) as c_int
+    }
+
+    fn timer_settime(
+        timerid: c_int,
+        flags: c_int,
+        value: *const itimerspec,
+        ovalue: *mut itimerspec,
+    ) -> c_int {
+        e(unsafe { syscall!(TIMER_SETTIME, timerid, flags, value, ovalue) }) as c_int
+    }
+
+    fn timerfd_create(clock_id: clockid_t) -> c_int {
+        e(unsafe { syscall!(TIMERFD_CREATE, clock_id, TFD_CLOEXEC) }) as c_int
+    }
+
+    fn timerfd_gettime(fd: c_int, value: *mut itimerspec) -> c_int {
+        e(unsafe { syscall!(TIMERFD_GETTIME, fd, value) }) as c_int
+    }
+
+    fn timerfd_settime(
+        fd: c_int,
+        flags: c_int,
+        value: *const itimerspec,
+        ovalue: *mut itimerspec,
+    ) -> c_int {
+        e(unsafe { syscall!(TIMERFD_SETTIME, fd, flags, value, ovalue) }) as c_int
+    }
+
     fn umask(mask: mode_t) -> mode_t {
         unsafe { syscall!(UMASK, mask) as mode_t }
     }
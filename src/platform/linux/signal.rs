@@ -1,11 +1,26 @@
 use core::mem;
 
 use super::super::types::*;
-use super::super::PalSignal;
+use super::super::{Pal, PalSignal};
 use super::{e, Sys};
-use header::signal::{sigaction, sigset_t};
+use header::signal::{sigaction, sigset_t, sigval};
 use header::sys_time::itimerval;
 
+const SI_QUEUE: c_int = -1;
+
+/// The kernel's siginfo_t, as filled in for queued signals
+#[repr(C)]
+struct siginfo_rt {
+    si_signo: c_int,
+    si_errno: c_int,
+    si_code: c_int,
+    _pad: c_int,
+    si_pid: pid_t,
+    si_uid: uid_t,
+    si_value: sigval,
+    _rest: [c_int; 24],
+}
+
 impl PalSignal for Sys {
     fn getitimer(which: c_int, out: *mut itimerval) -> c_int {
         e(unsafe { syscall!(GETITIMER, which, out) }) as c_int
@@ -45,4 +60,18 @@ impl PalSignal for Sys {
     fn sigprocmask(how: c_int, set: *const sigset_t, oset: *mut sigset_t) -> c_int {
         e(unsafe { syscall!(RT_SIGPROCMASK, how, set, oset, mem::size_of::<sigset_t>()) }) as c_int
     }
+
+    fn sigqueue(pid: pid_t, sig: c_int, value: sigval) -> c_int {
+        let info = siginfo_rt {
+            si_signo: sig,
+            si_errno: 0,
+            si_code: SI_QUEUE,
+            _pad: 0,
+            si_pid: Sys::getpid(),
+            si_uid: Sys::getuid(),
+            si_value: value,
+            _rest: [0; 24],
+        };
+        e(unsafe { syscall!(RT_SIGQUEUEINFO, pid, sig, &info as *const siginfo_rt) }) as c_int
+    }
 }
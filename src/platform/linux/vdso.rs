@@ -0,0 +1,66 @@
+//! Functions exported by the vDSO, which the kernel maps into every process. They read the time
+//! without entering the kernel, and fall back to a system call when they cannot.
+
+use core::{mem, slice};
+
+use goblin::elf::{program_header, sym, Elf};
+use goblin::elf64::header::Header;
+
+use super::super::types::*;
+use header::sys_time::{timeval, timezone};
+use header::time::timespec;
+
+// The vDSO functions return a negative error number on failure, like a system call
+pub static mut CLOCK_GETTIME: Option<extern "C" fn(clockid_t, *mut timespec) -> c_int> = None;
+pub static mut GETCPU: Option<extern "C" fn(*mut c_uint, *mut c_uint, *mut c_void) -> c_int> = None;
+pub static mut GETTIMEOFDAY: Option<extern "C" fn(*mut timeval, *mut timezone) -> c_int> = None;
+pub static mut TIME: Option<extern "C" fn(*mut time_t) -> time_t> = None;
+
+/// Look up the vDSO functions, given the address of the vDSO from AT_SYSINFO_EHDR. Must be called
+/// before any other thread is started
+pub unsafe fn init(base: usize) {
+    if base == 0 {
+        return;
+    }
+
+    // The vDSO is mapped as a whole, ending with its section headers
+    let header = &*(base as *const Header);
+    let size = header.e_shoff as usize + header.e_shnum as usize * header.e_shentsize as usize;
+    let elf = match Elf::parse(slice::from_raw_parts(base as *const u8, size)) {
+        Ok(elf) => elf,
+        Err(_) => return,
+    };
+
+    // Symbol values are relative to the address the vDSO was linked at
+    let link_base = match elf
+        .program_headers
+        .iter()
+        .find(|ph| ph.p_type == program_header::PT_LOAD)
+    {
+        Some(ph) => ph.p_vaddr.wrapping_sub(ph.p_offset) as usize,
+        None => return,
+    };
+
+    for symbol in elf.dynsyms.iter() {
+        let bind = symbol.st_bind();
+        if (bind != sym::STB_GLOBAL && bind != sym::STB_WEAK)
+            || symbol.st_type() != sym::STT_FUNC
+            || symbol.st_value == 0
+        {
+            continue;
+        }
+        let name = match elf.dynstrtab.get(symbol.st_name) {
+            Some(Ok(name)) => name,
+            _ => continue,
+        };
+        let addr = base.wrapping_add((symbol.st_value as usize).wrapping_sub(link_base));
+
+        match name {
+            "__vdso_clock_gettime" => CLOCK_GETTIME = Some(mem::transmute(addr)),
+            "__vdso_getcpu" => GETCPU = Some(mem::transmute(addr)),
+            "__vdso_gettimeofday" => GETTIMEOFDAY = Some(mem::transmute(addr)),
+            "__vdso_time" => TIME = Some(mem::transmute(addr)),
+            _ => (),
+        }
+    }
+}
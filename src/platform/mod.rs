@@ -1,5 +1,6 @@
 use alloc::vec::Vec;
 use core::{fmt, ptr};
+use header::sys_auxv::{AT_NULL, AT_SYSINFO_EHDR};
 use io::{self, Read, Write};
 
 pub use self::allocator::*;
@@ -26,7 +27,7 @@ mod sys;
 #[path = "redox/mod.rs"]
 mod sys;
 
-mod pte;
+pub mod pte;
 
 pub use self::rlb::{Line, RawLineBuffer};
 pub mod rlb;
@@ -39,6 +40,19 @@ pub mod types;
 #[no_mangle]
 pub static mut errno: c_int = 0;
 
+/// Set errno from the negative error number returned by a `*_raw` function of Pal, returning -1
+/// for it
+pub fn set_errno(ret: isize) -> isize {
+    if ret < 0 {
+        unsafe {
+            errno = -ret as c_int;
+        }
+        -1
+    } else {
+        ret
+    }
+}
+
 #[allow(non_upper_case_globals)]
 pub static mut argv: *mut *mut c_char = ptr::null_mut();
 #[allow(non_upper_case_globals)]
@@ -50,6 +64,37 @@ pub static mut environ: *mut *mut c_char = ptr::null_mut();
 #[allow(non_upper_case_globals)]
 pub static mut inner_environ: Vec<*mut c_char> = Vec::new();
 
+/// The auxiliary vector, a list of key and value pairs terminated by AT_NULL
+#[allow(non_upper_case_globals)]
+pub static mut auxv: *const [usize; 2] = ptr::null();
+
+/// Look up a value from the auxiliary vector
+pub unsafe fn getauxval(key: usize) -> Option<usize> {
+    if auxv.is_null() {
+        return None;
+    }
+    let mut entry = auxv;
+    while (*entry)[0] != AT_NULL {
+        if (*entry)[0] == key {
+            return Some((*entry)[1]);
+        }
+        entry = entry.add(1);
+    }
+    None
+}
+
+/// Set up the platform once the arguments and environment are known. On Linux, the auxiliary
+/// vector is found on the initial stack after the environment
+pub unsafe fn init(initial_auxv: *const [usize; 2]) {
+    #[cfg(target_os = "linux")]
+    {
+        auxv = initial_auxv;
+        sys::vdso::init(getauxval(AT_SYSINFO_EHDR).unwrap_or(0));
+    }
+    #[cfg(target_os = "redox")]
+    let _ = initial_auxv;
+}
+
 pub trait WriteByte: fmt::Write {
     fn write_u8(&mut self, byte: u8) -> fmt::Result;
 }
@@ -226,42 +271,59 @@ impl Read for UnsafeStringReader {
 pub struct CountingWriter<T> {
     pub inner: T,
     pub written: usize,
+    /// Count the characters of UTF-8 output instead of its bytes
+    wide: bool,
 }
 impl<T> CountingWriter<T> {
     pub fn new(writer: T) -> Self {
         Self {
             inner: writer,
             written: 0,
+            wide: false,
+        }
+    }
+    /// A writer counting the wide characters of the UTF-8 it is given, for wide output
+    pub fn new_wide(writer: T) -> Self {
+        Self {
+            wide: true,
+            ..Self::new(writer)
+        }
+    }
+    fn count(&self, buf: &[u8]) -> usize {
+        if self.wide {
+            // Count the bytes that start a character
+            buf.iter().filter(|&&b| b & 0xC0 != 0x80).count()
+        } else {
+            buf.len()
         }
     }
 }
 impl<T: fmt::Write> fmt::Write for CountingWriter<T> {
     fn write_str(&mut self, s: &str) -> fmt::Result {
-        self.written += s.len();
+        self.written += self.count(s.as_bytes());
         self.inner.write_str(s)
     }
 }
 impl<T: WriteByte> WriteByte for CountingWriter<T> {
     fn write_u8(&mut self, byte: u8) -> fmt::Result {
-        self.written += 1;
+        self.written += self.count(&[byte]);
         self.inner.write_u8(byte)
     }
 }
 impl<T: Write> Write for CountingWriter<T> {
     fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
-        let res = self.inner.write(buf);
-        if let Ok(written) = res {
-            self.written += written;
-        }
-        res
+        let written = self.inner.write(buf)?;
+        self.written += self.count(&buf[..written]);
+        Ok(written)
     }
     fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+        // Output that doesn't fit in the buffer of snprintf is still counted
         match self.inner.write_all(&buf) {
             Ok(()) => (),
             Err(ref err) if err.kind() == io::ErrorKind::WriteZero => (),
             Err(err) => return Err(err),
         }
-        self.written += buf.len();
+        self.written += self.count(buf);
         Ok(())
     }
     fn flush(&mut self) -> io::Result<()> {
@@ -1,11 +1,13 @@
 use super::types::*;
 use c_str::CStr;
 use header::dirent::dirent;
+use header::signal::sigevent;
+use header::sys_resource::{rlimit, rusage};
 use header::sys_stat::stat;
 use header::sys_statvfs::statvfs;
 use header::sys_time::{timeval, timezone};
 use header::sys_utsname::utsname;
-use header::time::timespec;
+use header::time::{itimerspec, timespec};
 
 pub use self::epoll::PalEpoll;
 mod epoll;
@@ -27,8 +29,22 @@ pub trait Pal {
 
     fn chown(path: &CStr, owner: uid_t, group: gid_t) -> c_int;
 
+    /// Get the CPU-time clock of a process. Returns an error number instead of setting errno
+    fn clock_getcpuclockid(pid: pid_t, clock_id: *mut clockid_t) -> c_int;
+
+    fn clock_getres(clk_id: clockid_t, res: *mut timespec) -> c_int;
+
     fn clock_gettime(clk_id: clockid_t, tp: *mut timespec) -> c_int;
 
+    fn clock_nanosleep(
+        clk_id: clockid_t,
+        flags: c_int,
+        rqtp: *const timespec,
+        rmtp: *mut timespec,
+    ) -> c_int;
+
+    fn clock_settime(clk_id: clockid_t, tp: *const timespec) -> c_int;
+
     fn close(fildes: c_int) -> c_int;
 
     fn dup(fildes: c_int) -> c_int;
@@ -45,6 +61,13 @@ pub trait Pal {
 
     fn fchown(fildes: c_int, owner: uid_t, group: gid_t) -> c_int;
 
+    fn fdatasync(fildes: c_int) -> c_int {
+        super::set_errno(Self::fdatasync_raw(fildes) as isize) as c_int
+    }
+
+    /// Like fdatasync, but returns a negative error number instead of setting errno
+    fn fdatasync_raw(fildes: c_int) -> c_int;
+
     fn flock(fd: c_int, operation: c_int) -> c_int;
 
     fn fstat(fildes: c_int, buf: *mut stat) -> c_int;
@@ -57,11 +80,17 @@ pub trait Pal {
 
     fn fpath(fildes: c_int, out: &mut [u8]) -> ssize_t;
 
-    fn fsync(fildes: c_int) -> c_int;
+    fn fsync(fildes: c_int) -> c_int {
+        super::set_errno(Self::fsync_raw(fildes) as isize) as c_int
+    }
+
+    /// Like fsync, but returns a negative error number instead of setting errno
+    fn fsync_raw(fildes: c_int) -> c_int;
 
     fn ftruncate(fildes: c_int, length: off_t) -> c_int;
 
-    fn futex(addr: *mut c_int, op: c_int, val: c_int) -> c_int;
+    /// For FUTEX_WAIT, val2 is a pointer to a relative timeout, or 0 to wait forever
+    fn futex(addr: *mut c_int, op: c_int, val: c_int, val2: usize) -> c_int;
 
     fn futimens(fd: c_int, times: *const timespec) -> c_int;
 
@@ -83,6 +112,10 @@ pub trait Pal {
 
     fn getppid() -> pid_t;
 
+    fn getrlimit(resource: c_int, rlim: *mut rlimit) -> c_int;
+
+    fn getrusage(who: c_int, r_usage: *mut rusage) -> c_int;
+
     fn gettid() -> pid_t;
 
     fn gettimeofday(tp: *mut timeval, tzp: *mut timezone) -> c_int;
@@ -116,9 +149,33 @@ pub trait Pal {
 
     fn pipe2(fildes: &mut [c_int], flags: c_int) -> c_int;
 
-    unsafe fn pte_clone(stack: *mut usize) -> pid_t;
+    fn pread(fildes: c_int, buf: &mut [u8], offset: off_t) -> ssize_t {
+        super::set_errno(Self::pread_raw(fildes, buf, offset) as isize) as ssize_t
+    }
+
+    /// Like pread, but returns a negative error number instead of setting errno, for threads
+    /// that mustn't touch the errno of the application
+    fn pread_raw(fildes: c_int, buf: &mut [u8], offset: off_t) -> ssize_t;
+
+    unsafe fn pte_clone(stack: *mut usize, ctid: *mut pid_t) -> pid_t;
+
+    /// Get the CPU-time clock of the thread with the given id. Returns an error number instead of
+    /// setting errno
+    fn pthread_getcpuclockid(tid: pid_t, clock_id: *mut clockid_t) -> c_int;
+
+    fn pwrite(fildes: c_int, buf: &[u8], offset: off_t) -> ssize_t {
+        super::set_errno(Self::pwrite_raw(fildes, buf, offset) as isize) as ssize_t
+    }
+
+    /// Like pwrite, but returns a negative error number instead of setting errno
+    fn pwrite_raw(fildes: c_int, buf: &[u8], offset: off_t) -> ssize_t;
 
-    fn read(fildes: c_int, buf: &mut [u8]) -> ssize_t;
+    fn read(fildes: c_int, buf: &mut [u8]) -> ssize_t {
+        super::set_errno(Self::read_raw(fildes, buf) as isize) as ssize_t
+    }
+
+    /// Like read, but returns a negative error number instead of setting errno
+    fn read_raw(fildes: c_int, buf: &mut [u8]) -> ssize_t;
 
     fn readlink(pathname: &CStr, out: &mut [u8]) -> ssize_t;
 
@@ -126,6 +183,8 @@ pub trait Pal {
 
     fn rmdir(path: &CStr) -> c_int;
 
+    fn sched_getcpu() -> c_int;
+
     fn sched_yield() -> c_int;
 
     fn setpgid(pid: pid_t, pgid: pid_t) -> c_int;
@@ -134,8 +193,39 @@ pub trait Pal {
 
     fn setreuid(ruid: uid_t, euid: uid_t) -> c_int;
 
+    fn setrlimit(resource: c_int, rlim: *const rlimit) -> c_int;
+
     fn symlink(path1: &CStr, path2: &CStr) -> c_int;
 
+    fn time(tloc: *mut time_t) -> time_t;
+
+    fn timer_create(clock_id: clockid_t, evp: *const sigevent, timerid: *mut c_int) -> c_int;
+
+    fn timer_delete(timerid: c_int) -> c_int;
+
+    fn timer_getoverrun(timerid: c_int) -> c_int;
+
+    fn timer_gettime(timerid: c_int, value: *mut itimerspec) -> c_int;
+
+    fn timer_settime(
+        timerid: c_int,
+        flags: c_int,
+        value: *const itimerspec,
+        ovalue: *mut itimerspec,
+    ) -> c_int;
+
+    /// Create a close-on-exec file descriptor that becomes readable when the timer expires
+    fn timerfd_create(clock_id: clockid_t) -> c_int;
+
+    fn timerfd_gettime(fd: c_int, value: *mut itimerspec) -> c_int;
+
+    fn timerfd_settime(
+        fd: c_int,
+        flags: c_int,
+        value: *const itimerspec,
+        ovalue: *mut itimerspec,
+    ) -> c_int;
+
     fn umask(mask: mode_t) -> mode_t;
 
     fn uname(utsname: *mut utsname) -> c_int;
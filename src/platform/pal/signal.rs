@@ -1,6 +1,6 @@
 use super::super::types::*;
 use super::super::Pal;
-use header::signal::{sigaction, sigset_t};
+use header::signal::{sigaction, sigset_t, sigval};
 use header::sys_time::itimerval;
 
 pub trait PalSignal: Pal {
@@ -17,4 +17,6 @@ pub trait PalSignal: Pal {
     unsafe fn sigaction(sig: c_int, act: *const sigaction, oact: *mut sigaction) -> c_int;
 
     fn sigprocmask(how: c_int, set: *const sigset_t, oset: *mut sigset_t) -> c_int;
+
+    fn sigqueue(pid: pid_t, sig: c_int, value: sigval) -> c_int;
 }
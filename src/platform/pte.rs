@@ -2,14 +2,20 @@
 
 use alloc::boxed::Box;
 use alloc::collections::BTreeMap;
-use core::sync::atomic::{AtomicUsize, Ordering};
+use alloc::vec::Vec;
+use core::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use core::{intrinsics, ptr};
 
+use header::errno::{EINVAL, ESRCH};
 use header::sys_mman;
+use header::sys_resource::{rlimit, RLIMIT_STACK, RLIM_INFINITY};
+#[cfg(target_os = "redox")]
+use header::sys_wait::WNOHANG;
 use header::time::timespec;
 use ld_so::tcb::{Master, Tcb};
+use ld_so::PAGE_SIZE;
 use mutex::{FUTEX_WAIT, FUTEX_WAKE};
-use platform::types::{c_int, c_uint, c_void, pid_t, size_t};
+use platform::types::{c_int, c_uint, c_ulong, c_void, clockid_t, pid_t, size_t};
 use platform::{Pal, Sys};
 
 pub struct Semaphore {
@@ -39,9 +45,155 @@ use self::pte_osResult::*;
 static mut pid_mutexes: Option<BTreeMap<pte_osThreadHandle, pte_osMutexHandle>> = None;
 static mut pid_mutexes_lock: i32 = 0;
 
-static mut pid_stacks: Option<BTreeMap<pte_osThreadHandle, (*mut c_void, size_t)>> = None;
+static mut pid_stacks: Option<BTreeMap<pte_osThreadHandle, Thread>> = None;
 static mut pid_stacks_lock: i32 = 0;
 
+/// Deleted threads that may still be running on their stacks, protected by pid_stacks_lock
+static mut dying_threads: Vec<Thread> = Vec::new();
+/// Stacks of exited threads kept for reuse, protected by pid_stacks_lock
+static mut stack_cache: Vec<Stack> = Vec::new();
+const STACK_CACHE_MAX: usize = 16;
+
+const DEFAULT_STACK_SIZE: usize = 1024 * 1024;
+const DEFAULT_GUARD_SIZE: usize = PAGE_SIZE;
+/// Stack size reported for the main thread when RLIMIT_STACK is unlimited
+const MAIN_STACK_SIZE: usize = 8 * 1024 * 1024;
+
+/// Set before the first thread starts. Until then there is only one thread, so stdio doesn't lock
+/// its streams
+pub static THREADS_CREATED: AtomicBool = AtomicBool::new(false);
+
+static mut main_thread: pte_osThreadHandle = 0;
+static mut main_stack_top: usize = 0;
+
+fn page_round(size: usize) -> usize {
+    (size + PAGE_SIZE - 1) & !(PAGE_SIZE - 1)
+}
+
+/// Memory used as a thread stack, with an inaccessible guard region below it
+#[derive(Clone, Copy)]
+struct Stack {
+    /// Start of the mapping, including the guard region
+    map: *mut c_void,
+    /// Size of the mapping in bytes, including the guard region
+    map_len: usize,
+    /// Size of the guard region in bytes
+    guard: usize,
+    /// False if the stack was supplied by the application
+    owned: bool,
+}
+
+impl Stack {
+    /// The lowest usable address of the stack
+    fn base(&self) -> *mut c_void {
+        (self.map as usize + self.guard) as *mut c_void
+    }
+
+    /// The usable size of the stack in bytes
+    fn size(&self) -> usize {
+        self.map_len - self.guard
+    }
+
+    /// Take a stack from the cache, or map a new one with a PROT_NONE guard region
+    unsafe fn alloc(size: usize, guard: usize) -> Option<Self> {
+        let size = page_round(size);
+        let guard = page_round(guard);
+
+        pte_osMutexLock(&mut pid_stacks_lock);
+        reap_threads();
+        let cached = stack_cache
+            .iter()
+            .position(|stack| stack.size() == size && stack.guard == guard)
+            .map(|i| stack_cache.swap_remove(i));
+        pte_osMutexUnlock(&mut pid_stacks_lock);
+        if cached.is_some() {
+            return cached;
+        }
+
+        let map = sys_mman::mmap(
+            ptr::null_mut(),
+            size + guard,
+            sys_mman::PROT_READ | sys_mman::PROT_WRITE,
+            sys_mman::MAP_PRIVATE | sys_mman::MAP_ANONYMOUS,
+            -1,
+            0,
+        );
+        if map as isize == -1 {
+            return None;
+        }
+        if guard > 0 && sys_mman::mprotect(map, guard, sys_mman::PROT_NONE) < 0 {
+            sys_mman::munmap(map, size + guard);
+            return None;
+        }
+
+        Some(Self {
+            map,
+            map_len: size + guard,
+            guard,
+            owned: true,
+        })
+    }
+
+    /// Put the stack in the cache, or unmap it if the cache is full. Must be called with
+    /// pid_stacks_lock held
+    unsafe fn release(self) {
+        if !self.owned {
+            return;
+        }
+        if stack_cache.len() < STACK_CACHE_MAX {
+            stack_cache.push(self);
+        } else {
+            sys_mman::munmap(self.map, self.map_len);
+        }
+    }
+}
+
+struct Thread {
+    id: pte_osThreadHandle,
+    stack: Stack,
+    /// Nonzero until the thread has exited, cleared by the kernel or by pte_osThreadWaitForEnd
+    exit_tid: Box<pid_t>,
+}
+
+impl Thread {
+    fn exited(&self) -> bool {
+        unsafe { intrinsics::atomic_load(&*self.exit_tid) == 0 }
+    }
+
+    /// Redox can't clear exit_tid when a thread exits, so check with waitpid, which also reaps the
+    /// thread. Nobody else waits for a deleted thread
+    #[cfg(target_os = "redox")]
+    unsafe fn poll_exit(&mut self) {
+        let mut status = 0;
+        if Sys::waitpid(self.id, &mut status, WNOHANG) == self.id {
+            intrinsics::atomic_store(&mut *self.exit_tid, 0);
+        }
+    }
+
+    #[cfg(not(target_os = "redox"))]
+    unsafe fn poll_exit(&mut self) {}
+}
+
+/// Release the stacks of deleted threads that have finished exiting. Must be called with
+/// pid_stacks_lock held
+unsafe fn reap_threads() {
+    let mut i = 0;
+    while i < dying_threads.len() {
+        dying_threads[i].poll_exit();
+        if dying_threads[i].exited() {
+            dying_threads.swap_remove(i).stack.release();
+        } else {
+            i += 1;
+        }
+    }
+}
+
+/// Record the top of the initial thread's stack, called once at startup
+pub unsafe fn init_main_stack(top: usize) {
+    main_thread = Sys::gettid();
+    main_stack_top = top;
+}
+
 #[thread_local]
 static mut LOCALS: *mut BTreeMap<c_uint, *mut c_void> = ptr::null_mut();
 
@@ -89,35 +241,64 @@ unsafe extern "C" fn pte_osThreadShim(
 pub unsafe extern "C" fn pte_osThreadCreate(
     entryPoint: pte_osThreadEntryPoint,
     stackSize: c_int,
+    initialPriority: c_int,
+    argv: *mut c_void,
+    ppte_osThreadHandle: *mut pte_osThreadHandle,
+) -> pte_osResult {
+    pte_osThreadCreateStack(
+        entryPoint,
+        ptr::null_mut(),
+        stackSize as size_t,
+        DEFAULT_GUARD_SIZE,
+        initialPriority,
+        argv,
+        ppte_osThreadHandle,
+    )
+}
+
+/// Like pte_osThreadCreate, with the stack taken from the thread attributes, which is how
+/// pthread_create starts threads (see patches/pthreads-emb.patch). If stackAddr is not null, the
+/// thread runs on the stackSize bytes starting at it and guardSize is ignored
+#[no_mangle]
+pub unsafe extern "C" fn pte_osThreadCreateStack(
+    entryPoint: pte_osThreadEntryPoint,
+    stackAddr: *mut c_void,
+    stackSize: size_t,
+    guardSize: size_t,
     _initialPriority: c_int,
     argv: *mut c_void,
     ppte_osThreadHandle: *mut pte_osThreadHandle,
 ) -> pte_osResult {
+    let stack = if !stackAddr.is_null() {
+        Stack {
+            map: stackAddr,
+            map_len: stackSize,
+            guard: 0,
+            owned: false,
+        }
+    } else {
+        let stack_size = if stackSize == 0 {
+            DEFAULT_STACK_SIZE
+        } else {
+            stackSize
+        };
+        match Stack::alloc(stack_size, guardSize) {
+            Some(stack) => stack,
+            None => return PTE_OS_NO_RESOURCES,
+        }
+    };
+
     // Create a locked mutex, unlocked by pte_osThreadStart
     let mutex: pte_osMutexHandle = Box::into_raw(Box::new(2));
 
-    let stack_size = if stackSize == 0 {
-        1024 * 1024
-    } else {
-        stackSize as usize
-    };
-    let stack_base = sys_mman::mmap(
-        ptr::null_mut(),
-        stack_size,
-        sys_mman::PROT_READ | sys_mman::PROT_WRITE,
-        sys_mman::MAP_SHARED | sys_mman::MAP_ANONYMOUS,
-        -1,
-        0,
-    );
-    if stack_base as isize == -1 {
-        return PTE_OS_GENERAL_FAILURE;
-    }
-    let stack_end = stack_base.add(stack_size);
-    let mut stack = stack_end as *mut usize;
+    let mut exit_tid = Box::new(-1);
+
+    let stack_end = stack.base() as usize + stack.size();
+    let mut stack_ptr = (stack_end & !0xF) as *mut usize;
     {
         let mut push = |value: usize| {
-            stack = stack.offset(-1);
-            *stack = value;
+            stack_ptr = stack_ptr.offset(-1);
+            *stack_ptr = value;
         };
 
         if let Some(tcb) = Tcb::current() {
@@ -138,8 +319,13 @@ pub unsafe extern "C" fn pte_osThreadCreate(
         push(pte_osThreadShim as usize);
     }
 
-    let id = Sys::pte_clone(stack);
+    THREADS_CREATED.store(true, Ordering::SeqCst);
+    let id = Sys::pte_clone(stack_ptr, &mut *exit_tid);
     if id < 0 {
+        Box::from_raw(mutex);
+        pte_osMutexLock(&mut pid_stacks_lock);
+        stack.release();
+        pte_osMutexUnlock(&mut pid_stacks_lock);
         return PTE_OS_GENERAL_FAILURE;
     }
 
@@ -154,10 +340,14 @@ pub unsafe extern "C" fn pte_osThreadCreate(
     if pid_stacks.is_none() {
         pid_stacks = Some(BTreeMap::new());
     }
-    pid_stacks
-        .as_mut()
-        .unwrap()
-        .insert(id, (stack_base, stack_size));
+    pid_stacks.as_mut().unwrap().insert(
+        id,
+        Thread {
+            id,
+            stack,
+            exit_tid,
+        },
+    );
     pte_osMutexUnlock(&mut pid_stacks_lock);
 
     *ppte_osThreadHandle = id;
@@ -165,6 +355,45 @@ pub unsafe extern "C" fn pte_osThreadCreate(
     PTE_OS_OK
 }
 
+/// Get the usable stack and the guard size of a thread. The main thread's stack is taken to
+/// extend RLIMIT_STACK bytes below the top of the initial stack
+#[no_mangle]
+pub unsafe extern "C" fn pte_osThreadGetStack(
+    handle: pte_osThreadHandle,
+    pStackAddr: *mut *mut c_void,
+    pStackSize: *mut size_t,
+    pGuardSize: *mut size_t,
+) -> pte_osResult {
+    let mut ret = PTE_OS_INVALID_PARAM;
+    pte_osMutexLock(&mut pid_stacks_lock);
+    if let Some(ref stacks) = pid_stacks {
+        if let Some(thread) = stacks.get(&handle) {
+            *pStackAddr = thread.stack.base();
+            *pStackSize = thread.stack.size();
+            *pGuardSize = thread.stack.guard;
+            ret = PTE_OS_OK;
+        }
+    }
+    pte_osMutexUnlock(&mut pid_stacks_lock);
+
+    if ret != PTE_OS_OK && handle == main_thread && main_stack_top != 0 {
+        let mut limit = rlimit::default();
+        let size =
+            if Sys::getrlimit(RLIMIT_STACK, &mut limit) == 0 && limit.rlim_cur != RLIM_INFINITY {
+                page_round(limit.rlim_cur as usize)
+            } else {
+                MAIN_STACK_SIZE
+            };
+        let size = size.min(main_stack_top);
+        *pStackAddr = (main_stack_top - size) as *mut c_void;
+        *pStackSize = size;
+        *pGuardSize = 0;
+        ret = PTE_OS_OK;
+    }
+
+    ret
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn pte_osThreadStart(handle: pte_osThreadHandle) -> pte_osResult {
     let mut ret = PTE_OS_GENERAL_FAILURE;
@@ -206,10 +435,16 @@ pub unsafe extern "C" fn pte_osThreadDelete(handle: pte_osThreadHandle) -> pte_o
 
     pte_osMutexLock(&mut pid_stacks_lock);
     if let Some(ref mut stacks) = pid_stacks {
-        if let Some((stack_base, stack_size)) = stacks.remove(&handle) {
-            sys_mman::munmap(stack_base, stack_size);
+        if let Some(thread) = stacks.remove(&handle) {
+            // Threads can delete themselves, so the stack may still be in use
+            if thread.exited() {
+                thread.stack.release();
+            } else {
+                dying_threads.push(thread);
+            }
         }
     }
+    reap_threads();
     pte_osMutexUnlock(&mut pid_stacks_lock);
 
     PTE_OS_OK
@@ -217,8 +452,29 @@ pub unsafe extern "C" fn pte_osThreadDelete(handle: pte_osThreadHandle) -> pte_o
 
 #[no_mangle]
 pub unsafe extern "C" fn pte_osThreadWaitForEnd(handle: pte_osThreadHandle) -> pte_osResult {
+    let mut exit_tid: *mut pid_t = ptr::null_mut();
+    pte_osMutexLock(&mut pid_stacks_lock);
+    if let Some(ref mut stacks) = pid_stacks {
+        if let Some(thread) = stacks.get_mut(&handle) {
+            exit_tid = &mut *thread.exit_tid;
+        }
+    }
+    pte_osMutexUnlock(&mut pid_stacks_lock);
+
     let mut status = 0;
-    Sys::waitpid(handle, &mut status, 0);
+    if Sys::waitpid(handle, &mut status, 0) == handle {
+        if !exit_tid.is_null() {
+            intrinsics::atomic_store(exit_tid, 0);
+        }
+    } else if !exit_tid.is_null() {
+        loop {
+            let tid = intrinsics::atomic_load(exit_tid);
+            if tid == 0 {
+                break;
+            }
+            Sys::futex(exit_tid, FUTEX_WAIT, tid, 0);
+        }
+    }
     PTE_OS_OK
 }
 
@@ -276,6 +532,11 @@ pub unsafe extern "C" fn pte_osThreadGetDefaultPriority() -> c_int {
     1
 }
 
+#[no_mangle]
+pub extern "C" fn pte_osThreadGetDefaultGuardSize() -> size_t {
+    DEFAULT_GUARD_SIZE
+}
+
 #[no_mangle]
 pub unsafe extern "C" fn pte_osMutexCreate(pHandle: *mut pte_osMutexHandle) -> pte_osResult {
     *pHandle = Box::into_raw(Box::new(0));
@@ -301,7 +562,7 @@ pub unsafe extern "C" fn pte_osMutexLock(handle: pte_osMutexHandle) -> pte_osRes
         c = intrinsics::atomic_xchg(handle, 2);
     }
     while c != 0 {
-        Sys::futex(handle, FUTEX_WAIT, 2);
+        Sys::futex(handle, FUTEX_WAIT, 2, 0);
         c = intrinsics::atomic_xchg(handle, 2);
     }
 
@@ -320,7 +581,7 @@ pub unsafe extern "C" fn pte_osMutexUnlock(handle: pte_osMutexHandle) -> pte_osR
             return PTE_OS_OK;
         }
     }
-    Sys::futex(handle, FUTEX_WAKE, 1);
+    Sys::futex(handle, FUTEX_WAKE, 1, 0);
 
     PTE_OS_OK
 }
@@ -435,3 +696,140 @@ pub unsafe extern "C" fn pte_osTlsFree(index: c_uint) -> pte_osResult {
     // XXX free keys
     PTE_OS_OK
 }
+
+// Stack attributes and CPU-time clocks of pthreads-emb threads, which pthreads-emb does not
+// implement itself
+
+/// pthreads-emb's pthread_t
+#[repr(C)]
+pub struct pthread_t {
+    p: *mut pte_thread_t,
+    x: c_uint,
+}
+
+/// Must match the leading fields of pthreads-emb's struct pte_thread_t_
+#[repr(C)]
+pub struct pte_thread_t {
+    threadId: pte_osThreadHandle,
+}
+
+/// Must match the leading fields of pthreads-emb's struct pthread_attr_t_, with guardsize added by
+/// patches/pthreads-emb.patch
+#[repr(C)]
+pub struct pthread_attr {
+    valid: c_ulong,
+    stackaddr: *mut c_void,
+    stacksize: size_t,
+    guardsize: size_t,
+}
+
+pub type pthread_attr_t = *mut pthread_attr;
+
+extern "C" {
+    fn pthread_attr_init(attr: *mut pthread_attr_t) -> c_int;
+    pub fn pthread_create(
+        thread: *mut pthread_t,
+        attr: *const pthread_attr_t,
+        start_routine: extern "C" fn(arg: *mut c_void) -> *mut c_void,
+        arg: *mut c_void,
+    ) -> c_int;
+    pub fn pthread_detach(thread: pthread_t) -> c_int;
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_attr_getguardsize(
+    attr: *const pthread_attr_t,
+    guardsize: *mut size_t,
+) -> c_int {
+    if attr.is_null() || (*attr).is_null() {
+        return EINVAL;
+    }
+    *guardsize = (**attr).guardsize;
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_attr_setguardsize(
+    attr: *mut pthread_attr_t,
+    guardsize: size_t,
+) -> c_int {
+    if attr.is_null() || (*attr).is_null() {
+        return EINVAL;
+    }
+    (**attr).guardsize = guardsize;
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_attr_getstack(
+    attr: *const pthread_attr_t,
+    stackaddr: *mut *mut c_void,
+    stacksize: *mut size_t,
+) -> c_int {
+    if attr.is_null() || (*attr).is_null() {
+        return EINVAL;
+    }
+    *stackaddr = (**attr).stackaddr;
+    *stacksize = (**attr).stacksize;
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_attr_setstack(
+    attr: *mut pthread_attr_t,
+    stackaddr: *mut c_void,
+    stacksize: size_t,
+) -> c_int {
+    if attr.is_null() || (*attr).is_null() || stacksize < PAGE_SIZE {
+        return EINVAL;
+    }
+    (**attr).stackaddr = stackaddr;
+    (**attr).stacksize = stacksize;
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_getattr_np(thread: pthread_t, attr: *mut pthread_attr_t) -> c_int {
+    if thread.p.is_null() {
+        return ESRCH;
+    }
+
+    let mut stack_addr = ptr::null_mut();
+    let mut stack_size = 0;
+    let mut guard_size = 0;
+    if pte_osThreadGetStack(
+        (*thread.p).threadId,
+        &mut stack_addr,
+        &mut stack_size,
+        &mut guard_size,
+    ) != PTE_OS_OK
+    {
+        return ESRCH;
+    }
+
+    let res = pthread_attr_init(attr);
+    if res != 0 {
+        return res;
+    }
+    (**attr).stackaddr = stack_addr;
+    (**attr).stacksize = stack_size;
+    (**attr).guardsize = guard_size;
+    0
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn pthread_getcpuclockid(
+    thread: pthread_t,
+    clock_id: *mut clockid_t,
+) -> c_int {
+    if thread.p.is_null() {
+        return ESRCH;
+    }
+    Sys::pthread_getcpuclockid((*thread.p).threadId, clock_id)
+}
+
+/// sched.h comes from pthreads-emb, which has no way to find the current CPU
+#[no_mangle]
+pub extern "C" fn sched_getcpu() -> c_int {
+    Sys::sched_getcpu()
+}
@@ -11,17 +11,21 @@ use syscall::{self, Result};
 use c_str::{CStr, CString};
 use fs::File;
 use header::dirent::dirent;
-use header::errno::{EINVAL, EIO, EPERM};
+use header::errno::{EINVAL, EIO, ENOSYS, EPERM};
 use header::fcntl;
+use header::signal::sigevent;
 use header::sys_mman::MAP_ANON;
+use header::sys_resource::{rlimit, rusage, RLIM_INFINITY};
 use header::sys_stat::stat;
 use header::sys_statvfs::statvfs;
 use header::sys_time::{timeval, timezone};
 use header::sys_utsname::{utsname, UTSLENGTH};
-use header::time::timespec;
+use header::time::constants::TIMER_ABSTIME;
+use header::time::{itimerspec, timespec};
 use header::unistd::{F_OK, R_OK, W_OK, X_OK};
 use io::prelude::*;
 use io::{self, BufReader, SeekFrom};
+use mutex::Mutex;
 
 use super::types::*;
 use super::{errno, Pal, Read};
@@ -43,6 +47,39 @@ pub fn e(sys: Result<usize>) -> usize {
     }
 }
 
+/// The result of a system call, or a negative error number, for the functions that don't set
+/// errno
+fn raw(sys: Result<usize>) -> isize {
+    match sys {
+        Ok(ok) => ok as isize,
+        Err(err) => -(err.errno as isize),
+    }
+}
+
+// pread and pwrite seek to the offset and back, so calls on the same descriptor mustn't overlap.
+// Descriptors share these locks by their number modulo the count
+static OFFSET_LOCKS: [Mutex<()>; 4] = [
+    Mutex::new(()),
+    Mutex::new(()),
+    Mutex::new(()),
+    Mutex::new(()),
+];
+
+//TODO: better pread and pwrite using system calls
+/// Run the transfer `f` at `offset` in the file, leaving the file offset unchanged
+fn at_offset<F: FnOnce(usize) -> Result<usize>>(fd: c_int, offset: off_t, f: F) -> ssize_t {
+    let _guard = OFFSET_LOCKS[fd as usize % OFFSET_LOCKS.len()].lock();
+    let fd = fd as usize;
+    raw(
+        syscall::lseek(fd, 0, syscall::SEEK_CUR).and_then(|previous| {
+            syscall::lseek(fd, offset as isize, syscall::SEEK_SET)?;
+            let res = f(fd);
+            syscall::lseek(fd, previous as isize, syscall::SEEK_SET)?;
+            res
+        }),
+    ) as ssize_t
+}
+
 pub struct Sys;
 
 impl Pal for Sys {
@@ -113,6 +150,27 @@ impl Pal for Sys {
         }
     }
 
+    fn clock_getcpuclockid(_pid: pid_t, _clock_id: *mut clockid_t) -> c_int {
+        //TODO: CPU-time clocks
+        ENOSYS
+    }
+
+    fn clock_getres(clk_id: clockid_t, res: *mut timespec) -> c_int {
+        if clk_id as usize != syscall::CLOCK_REALTIME && clk_id as usize != syscall::CLOCK_MONOTONIC
+        {
+            unsafe { errno = EINVAL };
+            return -1;
+        }
+        //TODO: ask the kernel for the resolution
+        if !res.is_null() {
+            unsafe {
+                (*res).tv_sec = 0;
+                (*res).tv_nsec = 1;
+            }
+        }
+        0
+    }
+
     fn clock_gettime(clk_id: clockid_t, tp: *mut timespec) -> c_int {
         let mut redox_tp = unsafe { redox_timespec::from(&*tp) };
         match e(syscall::clock_gettime(clk_id as usize, &mut redox_tp)) as c_int {
@@ -127,6 +185,44 @@ impl Pal for Sys {
         }
     }
 
+    fn clock_nanosleep(
+        clk_id: clockid_t,
+        flags: c_int,
+        rqtp: *const timespec,
+        rmtp: *mut timespec,
+    ) -> c_int {
+        if flags & TIMER_ABSTIME == 0 {
+            //TODO: sleep on clk_id
+            return Self::nanosleep(rqtp, rmtp);
+        }
+
+        // Sleep until clk_id reaches rqtp, as an interrupted absolute sleep can be restarted
+        let mut now = timespec::default();
+        if Self::clock_gettime(clk_id, &mut now) < 0 {
+            return -1;
+        }
+        let mut left = unsafe {
+            timespec {
+                tv_sec: (*rqtp).tv_sec - now.tv_sec,
+                tv_nsec: (*rqtp).tv_nsec - now.tv_nsec,
+            }
+        };
+        if left.tv_nsec < 0 {
+            left.tv_sec -= 1;
+            left.tv_nsec += 1_000_000_000;
+        }
+        if left.tv_sec < 0 {
+            return 0;
+        }
+        Self::nanosleep(&left, ptr::null_mut())
+    }
+
+    fn clock_settime(_clk_id: clockid_t, _tp: *const timespec) -> c_int {
+        //TODO: setting the time
+        unsafe { errno = EPERM };
+        -1
+    }
+
     fn close(fd: c_int) -> c_int {
         e(syscall::close(fd as usize)) as c_int
     }
@@ -303,6 +399,10 @@ impl Pal for Sys {
         e(syscall::fcntl(fd as usize, cmd as usize, args as usize)) as c_int
     }
 
+    fn fdatasync_raw(fd: c_int) -> c_int {
+        Self::fsync_raw(fd)
+    }
+
     fn flock(_fd: c_int, _operation: c_int) -> c_int {
         // TODO: Redox does not have file locking yet
         0
@@ -374,21 +474,21 @@ impl Pal for Sys {
         }
     }
 
-    fn fsync(fd: c_int) -> c_int {
-        e(syscall::fsync(fd as usize)) as c_int
+    fn fsync_raw(fd: c_int) -> c_int {
+        raw(syscall::fsync(fd as usize)) as c_int
     }
 
     fn ftruncate(fd: c_int, len: off_t) -> c_int {
         e(syscall::ftruncate(fd as usize, len as usize)) as c_int
     }
 
-    fn futex(addr: *mut c_int, op: c_int, val: c_int) -> c_int {
+    fn futex(addr: *mut c_int, op: c_int, val: c_int, val2: usize) -> c_int {
         match unsafe {
             syscall::futex(
                 addr as *mut i32,
                 op as usize,
                 val as i32,
-                0,
+                val2,
                 ptr::null_mut(),
             )
         } {
@@ -541,6 +641,23 @@ impl Pal for Sys {
         e(syscall::getppid()) as pid_t
     }
 
+    fn getrlimit(resource: c_int, rlim: *mut rlimit) -> c_int {
+        //TODO: Redox does not have resource limits yet
+        unsafe {
+            (*rlim).rlim_cur = RLIM_INFINITY;
+            (*rlim).rlim_max = RLIM_INFINITY;
+        }
+        0
+    }
+
+    fn getrusage(who: c_int, r_usage: *mut rusage) -> c_int {
+        //TODO: Redox does not track resource usage yet
+        unsafe {
+            *r_usage = rusage::default();
+        }
+        0
+    }
+
     fn gettid() -> pid_t {
         //TODO
         Self::getpid()
@@ -687,8 +804,18 @@ impl Pal for Sys {
         res as c_int
     }
 
+    fn pread_raw(fd: c_int, buf: &mut [u8], offset: off_t) -> ssize_t {
+        at_offset(fd, offset, |fd| syscall::read(fd, buf))
+    }
+
+    fn pwrite_raw(fd: c_int, buf: &[u8], offset: off_t) -> ssize_t {
+        at_offset(fd, offset, |fd| syscall::write(fd, buf))
+    }
+
     #[cfg(target_arch = "x86_64")]
-    unsafe fn pte_clone(stack: *mut usize) -> pid_t {
+    unsafe fn pte_clone(stack: *mut usize, _ctid: *mut pid_t) -> pid_t {
+        //TODO: Redox cannot clear ctid on exit, pte_osThreadWaitForEnd and reap_threads clear it after
+        // waitpid
         let flags = syscall::CLONE_VM
             | syscall::CLONE_FS
             | syscall::CLONE_FILES
@@ -735,8 +862,13 @@ impl Pal for Sys {
         e(syscall::Error::demux(pid)) as pid_t
     }
 
-    fn read(fd: c_int, buf: &mut [u8]) -> ssize_t {
-        e(syscall::read(fd as usize, buf)) as ssize_t
+    fn pthread_getcpuclockid(_tid: pid_t, _clock_id: *mut clockid_t) -> c_int {
+        //TODO: CPU-time clocks
+        ENOSYS
+    }
+
+    fn read_raw(fd: c_int, buf: &mut [u8]) -> ssize_t {
+        raw(syscall::read(fd as usize, buf)) as ssize_t
     }
 
     fn fpath(fildes: c_int, out: &mut [u8]) -> ssize_t {
@@ -777,6 +909,11 @@ impl Pal for Sys {
         e(syscall::rmdir(path.to_bytes())) as c_int
     }
 
+    fn sched_getcpu() -> c_int {
+        //TODO: get the current CPU
+        0
+    }
+
     fn sched_yield() -> c_int {
         e(syscall::sched_yield()) as c_int
     }
@@ -793,6 +930,14 @@ impl Pal for Sys {
         e(syscall::setreuid(ruid as usize, euid as usize)) as c_int
     }
 
+    fn setrlimit(resource: c_int, rlim: *const rlimit) -> c_int {
+        //TODO: Redox does not have resource limits yet
+        unsafe {
+            errno = EPERM;
+        }
+        -1
+    }
+
     fn symlink(path1: &CStr, path2: &CStr) -> c_int {
         let mut file = match File::create(
             path2,
@@ -810,6 +955,61 @@ impl Pal for Sys {
         0
     }
 
+    fn time(tloc: *mut time_t) -> time_t {
+        let mut ts = timespec::default();
+        if Self::clock_gettime(syscall::CLOCK_REALTIME as clockid_t, &mut ts) < 0 {
+            return -1;
+        }
+        if !tloc.is_null() {
+            unsafe { *tloc = ts.tv_sec };
+        }
+        ts.tv_sec
+    }
+
+    fn timer_create(_clock_id: clockid_t, _evp: *const sigevent, _timerid: *mut c_int) -> c_int {
+        //TODO: timers using the time: scheme
+        e(Err(syscall::Error::new(syscall::ENOSYS))) as c_int
+    }
+
+    fn timer_delete(_timerid: c_int) -> c_int {
+        e(Err(syscall::Error::new(syscall::ENOSYS))) as c_int
+    }
+
+    fn timer_getoverrun(_timerid: c_int) -> c_int {
+        e(Err(syscall::Error::new(syscall::ENOSYS))) as c_int
+    }
+
+    fn timer_gettime(_timerid: c_int, _value: *mut itimerspec) -> c_int {
+        e(Err(syscall::Error::new(syscall::ENOSYS))) as c_int
+    }
+
+    fn timer_settime(
+        _timerid: c_int,
+        _flags: c_int,
+        _value: *const itimerspec,
+        _ovalue: *mut itimerspec,
+    ) -> c_int {
+        e(Err(syscall::Error::new(syscall::ENOSYS))) as c_int
+    }
+
+    fn timerfd_create(_clock_id: clockid_t) -> c_int {
+        //TODO: timerfds using the time: scheme
+        e(Err(syscall::Error::new(syscall::ENOSYS))) as c_int
+    }
+
+    fn timerfd_gettime(_fd: c_int, _value: *mut itimerspec) -> c_int {
+        e(Err(syscall::Error::new(syscall::ENOSYS))) as c_int
+    }
+
+    fn timerfd_settime(
+        _fd: c_int,
+        _flags: c_int,
+        _value: *const itimerspec,
+        _ovalue: *mut itimerspec,
+    ) -> c_int {
+        e(Err(syscall::Error::new(syscall::ENOSYS))) as c_int
+    }
+
     fn umask(mask: mode_t) -> mode_t {
         e(syscall::umask(mask as usize)) as mode_t
     }
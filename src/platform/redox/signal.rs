@@ -5,7 +5,7 @@ use super::super::types::*;
 use super::super::{Pal, PalSignal};
 use super::{e, Sys};
 use header::errno::EINVAL;
-use header::signal::{sigaction, sigset_t};
+use header::signal::{sigaction, sigset_t, sigval};
 use header::sys_time::{itimerval, ITIMER_REAL};
 use platform::errno;
 
@@ -147,4 +147,9 @@ impl PalSignal for Sys {
         }
         ret
     }
+
+    fn sigqueue(pid: pid_t, sig: c_int, _value: sigval) -> c_int {
+        //TODO: Redox cannot queue a value with a signal
+        Self::kill(pid, sig)
+    }
 }
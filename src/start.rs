@@ -2,6 +2,7 @@ use alloc::vec::Vec;
 use core::{intrinsics, ptr};
 
 use header::{stdio, stdlib};
+use ld_so::PAGE_SIZE;
 use platform;
 use platform::types::*;
 use platform::{Pal, Sys};
@@ -45,6 +46,26 @@ unsafe fn copy_string_array(array: *const *const c_char, len: usize) -> Vec<*mut
     vec
 }
 
+/// Find the top of the initial stack, above the argument and environment strings
+unsafe fn stack_top(sp: &'static Stack, envp: *const *const c_char) -> usize {
+    let mut top = sp as *const Stack as usize;
+    let mut check = |array: *const *const c_char| {
+        let mut i = 0;
+        while !(*array.add(i)).is_null() {
+            let item = *array.add(i);
+            let mut len = 0;
+            while *item.add(len) != 0 {
+                len += 1;
+            }
+            top = top.max(item as usize + len + 1);
+            i += 1;
+        }
+    };
+    check(sp.argv());
+    check(envp);
+    (top + PAGE_SIZE - 1) & !(PAGE_SIZE - 1)
+}
+
 // Since Redox and Linux are so similar, it is easy to accidentally run a binary from one on the
 // other. This will test that the current system is compatible with the current binary
 #[no_mangle]
@@ -86,6 +107,12 @@ pub unsafe extern "C" fn relibc_start(sp: &'static Stack) -> ! {
     platform::inner_environ = copy_string_array(envp, len);
     platform::environ = platform::inner_environ.as_mut_ptr();
 
+    // Set up the platform, such as finding the vDSO
+    platform::init(envp.add(len + 1) as *const [usize; 2]);
+
+    // Remember the main thread's stack for pthread_getattr_np
+    platform::pte::init_main_stack(stack_top(sp, envp));
+
     // Initialize stdin/stdout/stderr, see https://github.com/rust-lang/rust/issues/51718
     stdio::stdin = stdio::default_stdin.get();
     stdio::stdout = stdio::default_stdout.get();
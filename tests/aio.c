@@ -0,0 +1,145 @@
+#include <aio.h>
+#include <errno.h>
+#include <fcntl.h>
+#include <signal.h>
+#include <stdio.h>
+#include <string.h>
+#include <time.h>
+#include <unistd.h>
+
+#include "test_helpers.h"
+
+static volatile int signals = 0;
+static volatile int notified = 0;
+static volatile int notified_value = 0;
+
+void handler(int sig) {
+    signals++;
+}
+
+void notify(union sigval value) {
+    notified_value = value.sival_int;
+    notified++;
+}
+
+static void sleep_ms(long ms) {
+    struct timespec ts = { 0, ms * 1000000 };
+    nanosleep(&ts, NULL);
+}
+
+static void wait_for(struct aiocb *cb) {
+    const struct aiocb *list[1] = { cb };
+    while (aio_error(cb) == EINPROGRESS) {
+        int status = aio_suspend(list, 1, NULL);
+        ERROR_IF(aio_suspend, status, == -1);
+        UNEXP_IF(aio_suspend, status, != 0);
+    }
+}
+
+int main(void) {
+    char *path = "aio.out";
+    int fd = open(path, O_RDWR | O_CREAT | O_TRUNC, 0644);
+    ERROR_IF(open, fd, == -1);
+
+    char text[] = "Hello, asynchronous world!";
+    struct aiocb write_cb;
+    memset(&write_cb, 0, sizeof(write_cb));
+    write_cb.aio_fildes = fd;
+    write_cb.aio_buf = text;
+    write_cb.aio_nbytes = strlen(text);
+    write_cb.aio_offset = 0;
+    write_cb.aio_sigevent.sigev_notify = SIGEV_NONE;
+
+    int status = aio_write(&write_cb);
+    ERROR_IF(aio_write, status, == -1);
+    wait_for(&write_cb);
+    printf("aio_write: error %d, return %zd\n", aio_error(&write_cb), aio_return(&write_cb));
+
+    struct aiocb sync_cb;
+    memset(&sync_cb, 0, sizeof(sync_cb));
+    sync_cb.aio_fildes = fd;
+    sync_cb.aio_sigevent.sigev_notify = SIGEV_NONE;
+    status = aio_fsync(O_SYNC, &sync_cb);
+    ERROR_IF(aio_fsync, status, == -1);
+    wait_for(&sync_cb);
+    printf("aio_fsync: error %d, return %zd\n", aio_error(&sync_cb), aio_return(&sync_cb));
+
+    char first[8] = { 0 };
+    char second[8] = { 0 };
+    struct aiocb read_cbs[2];
+    memset(read_cbs, 0, sizeof(read_cbs));
+    read_cbs[0].aio_fildes = fd;
+    read_cbs[0].aio_lio_opcode = LIO_READ;
+    read_cbs[0].aio_buf = first;
+    read_cbs[0].aio_nbytes = 5;
+    read_cbs[0].aio_offset = 0;
+    read_cbs[0].aio_sigevent.sigev_notify = SIGEV_NONE;
+    read_cbs[1].aio_fildes = fd;
+    read_cbs[1].aio_lio_opcode = LIO_READ;
+    read_cbs[1].aio_buf = second;
+    read_cbs[1].aio_nbytes = 5;
+    read_cbs[1].aio_offset = 20;
+    read_cbs[1].aio_sigevent.sigev_notify = SIGEV_NONE;
+    struct aiocb *list[3] = { &read_cbs[0], NULL, &read_cbs[1] };
+
+    status = lio_listio(LIO_WAIT, list, 3, NULL);
+    ERROR_IF(lio_listio, status, == -1);
+    printf("lio_listio: %zd \"%s\", %zd \"%s\"\n",
+        aio_return(&read_cbs[0]), first, aio_return(&read_cbs[1]), second);
+
+    status = aio_cancel(fd, NULL);
+    ERROR_IF(aio_cancel, status, == -1);
+    printf("aio_cancel: %s\n", status == AIO_ALLDONE ? "AIO_ALLDONE" : "unexpected");
+
+    struct aiocb bad_cb;
+    memset(&bad_cb, 0, sizeof(bad_cb));
+    bad_cb.aio_fildes = -1;
+    bad_cb.aio_buf = first;
+    bad_cb.aio_nbytes = 1;
+    bad_cb.aio_sigevent.sigev_notify = SIGEV_NONE;
+    errno = 0;
+    status = aio_read(&bad_cb);
+    ERROR_IF(aio_read, status, == -1);
+    wait_for(&bad_cb);
+    // The error is only reported in the aiocb, errno is left alone
+    printf("aio_read on a bad descriptor: %s, errno %d\n", strerror(aio_error(&bad_cb)), errno);
+
+    void (*signal_status)(int) = signal(SIGUSR1, &handler);
+    ERROR_IF(signal, signal_status, == SIG_ERR);
+
+    struct aiocb signal_cb;
+    memset(&signal_cb, 0, sizeof(signal_cb));
+    memset(first, 0, sizeof(first));
+    signal_cb.aio_fildes = fd;
+    signal_cb.aio_buf = first;
+    signal_cb.aio_nbytes = 5;
+    signal_cb.aio_offset = 7;
+    signal_cb.aio_sigevent.sigev_notify = SIGEV_SIGNAL;
+    signal_cb.aio_sigevent.sigev_signo = SIGUSR1;
+    status = aio_read(&signal_cb);
+    ERROR_IF(aio_read, status, == -1);
+    while (signals < 1) {
+        sleep_ms(1);
+    }
+    printf("SIGEV_SIGNAL: %zd \"%s\"\n", aio_return(&signal_cb), first);
+
+    struct aiocb thread_cb;
+    memset(&thread_cb, 0, sizeof(thread_cb));
+    memset(second, 0, sizeof(second));
+    thread_cb.aio_fildes = fd;
+    thread_cb.aio_buf = second;
+    thread_cb.aio_nbytes = 6;
+    thread_cb.aio_offset = 13;
+    thread_cb.aio_sigevent.sigev_notify = SIGEV_THREAD;
+    thread_cb.aio_sigevent.sigev_notify_function = notify;
+    thread_cb.aio_sigevent.sigev_value.sival_int = 42;
+    status = aio_read(&thread_cb);
+    ERROR_IF(aio_read, status, == -1);
+    while (notified < 1) {
+        sleep_ms(1);
+    }
+    printf("SIGEV_THREAD: value %d, %zd \"%s\"\n", notified_value, aio_return(&thread_cb), second);
+
+    close(fd);
+    unlink(path);
+}
@@ -0,0 +1,93 @@
+#include <errno.h>
+#include <iconv.h>
+#include <locale.h>
+#include <stdio.h>
+#include <string.h>
+
+#include "test_helpers.h"
+
+static void convert(const char *to, const char *from, const char *in, size_t inlen, size_t outlen) {
+    iconv_t cd = iconv_open(to, from);
+    ERROR_IF(iconv_open, cd, == (iconv_t) -1);
+
+    char out[64] = { 0 };
+    char *inptr = (char *) in;
+    char *outptr = out;
+    size_t inleft = inlen;
+    size_t outleft = outlen;
+    errno = 0;
+    size_t ret = iconv(cd, &inptr, &inleft, &outptr, &outleft);
+
+    printf("%s -> %s: ret %ld, errno %s, read %ld, wrote", from, to, (long) ret,
+           ret == (size_t) -1 ? strerror(errno) : "-", (long) (inptr - in));
+    for (char *c = out; c < outptr; c++) {
+        printf(" %02x", (unsigned char) *c);
+    }
+    printf("\n");
+
+    int status = iconv_close(cd);
+    ERROR_IF(iconv_close, status, == -1);
+}
+
+int main(void) {
+    // The locale decides how glibc transliterates, so use one it always has
+    setlocale(LC_ALL, "C.UTF-8");
+
+    // Unicode encodings, with and without byte order marks
+    convert("UTF-16LE", "UTF-8", "a\xc3\xa9\xf0\x9f\x98\x80", 7, 64);
+    convert("UTF-16BE", "UTF-8", "a\xc3\xa9\xf0\x9f\x98\x80", 7, 64);
+    convert("UTF-16", "UTF-8", "ab", 2, 64);
+    convert("UTF-32BE", "UTF-8", "a\xf0\x9f\x98\x80", 5, 64);
+    convert("UTF-32", "UTF-8", "a", 1, 64);
+    convert("UTF-8", "UTF-16", "\xfe\xff\x00\x61\xd8\x3d\xde\x00", 8, 64);
+    convert("UTF-8", "UTF-16", "\xff\xfe\x61\x00", 4, 64);
+    convert("UTF-8", "UTF-16", "\x61\x00\x62\x00", 4, 64);
+    convert("UTF-8", "UTF-32", "\x00\x00\xfe\xff\x00\x00\x00\x61", 8, 64);
+    convert("UTF-8", "UTF-32LE", "\x00\x00\x11\x00", 4, 64);
+
+    // Single-byte character sets
+    convert("UTF-8", "ISO-8859-1", "caf\xe9", 4, 64);
+    convert("UTF-8", "ISO-8859-15", "\xa4", 1, 64);
+    convert("UTF-8", "WINDOWS-1252", "\x80\x93", 2, 64);
+    convert("UTF-8", "WINDOWS-1252", "\x81", 1, 64);
+    convert("ISO-8859-2", "UTF-8", "\xc5\x81\xc3\xb3" "d\xc5\xba", 7, 64);
+    convert("ASCII", "UTF-8", "abc", 3, 64);
+    convert("UTF-8", "ASCII", "a\x80", 2, 64);
+
+    // Errors stop at the character they are about
+    convert("ASCII", "UTF-8", "ab\xc3\xa9", 4, 64);
+    convert("UTF-16LE", "UTF-8", "abc", 3, 4);
+    convert("UTF-16LE", "UTF-8", "ab\xff", 3, 64);
+    convert("UTF-16LE", "UTF-8", "ab\xc3", 3, 64);
+    convert("UTF-8", "UTF-16LE", "a\x00\x3d\xd8", 4, 64);
+    convert("UTF-8", "UTF-16LE", "a\x00\x00\xdc", 4, 64);
+
+    // Transliteration and ignoring
+    convert("ASCII//TRANSLIT", "UTF-8", "na\xc3\xafve \xe2\x80\x9cx\xe2\x80\x9d", 14, 64);
+    convert("ASCII//TRANSLIT", "UTF-8", "\xe2\x82\xac" "5 \xc3\x9f \xc2\xbd", 10, 64);
+    convert("ASCII//TRANSLIT", "UTF-8", "\xe4\xb8\x80", 3, 64);
+    convert("ISO-8859-1//TRANSLIT", "UTF-8", "\xc3\xaf\xe2\x82\xac", 5, 64);
+    convert("ASCII//TRANSLIT", "UTF-8", "\xe2\x82\xac", 3, 2);
+    convert("ASCII//IGNORE", "UTF-8", "a\xc3\xa9" "b", 4, 64);
+    convert("UTF-16LE//IGNORE", "UTF-8", "a\xff" "b", 3, 64);
+
+    // Unknown character sets
+    errno = 0;
+    iconv_t cd = iconv_open("UTF-8", "EBCDIC-FOO");
+    printf("unknown: %d %s\n", cd == (iconv_t) -1, strerror(errno));
+
+    // A null input resets the state, so that a byte order mark is written again
+    cd = iconv_open("UTF-16", "UTF-8");
+    ERROR_IF(iconv_open, cd, == (iconv_t) -1);
+    for (int i = 0; i < 2; i++) {
+        char out[8];
+        char *inptr = "a", *outptr = out;
+        size_t inleft = 1, outleft = sizeof(out);
+        size_t ret = iconv(cd, &inptr, &inleft, &outptr, &outleft);
+        ERROR_IF(iconv, ret, == (size_t) -1);
+        printf("reset %d: wrote %ld\n", i, (long) (outptr - out));
+        ret = iconv(cd, NULL, NULL, NULL, NULL);
+        ERROR_IF(iconv, ret, == (size_t) -1);
+    }
+    iconv_close(cd);
+}
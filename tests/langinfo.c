@@ -0,0 +1,41 @@
+#include <langinfo.h>
+#include <locale.h>
+#include <stdio.h>
+#include <stdlib.h>
+
+#include "test_helpers.h"
+
+static void print_items(locale_t loc) {
+    static const struct {
+        const char *name;
+        nl_item item;
+    } items[] = {
+        {"CODESET", CODESET},   {"D_T_FMT", D_T_FMT},   {"D_FMT", D_FMT},
+        {"T_FMT", T_FMT},       {"T_FMT_AMPM", T_FMT_AMPM}, {"AM_STR", AM_STR},
+        {"PM_STR", PM_STR},     {"DAY_1", DAY_1},       {"DAY_7", DAY_7},
+        {"ABDAY_2", ABDAY_2},   {"MON_1", MON_1},       {"MON_12", MON_12},
+        {"ABMON_3", ABMON_3},   {"ERA", ERA},           {"ALT_DIGITS", ALT_DIGITS},
+        {"RADIXCHAR", RADIXCHAR}, {"THOUSEP", THOUSEP}, {"YESEXPR", YESEXPR},
+        {"NOEXPR", NOEXPR},     {"CRNCYSTR", CRNCYSTR},
+    };
+    for (size_t i = 0; i < sizeof(items) / sizeof(items[0]); i++) {
+        const char *value = loc ? nl_langinfo_l(items[i].item, loc) : nl_langinfo(items[i].item);
+        printf("%s: \"%s\"\n", items[i].name, value);
+    }
+}
+
+int main(void) {
+    puts("C:");
+    print_items(NULL);
+
+    char *name = setlocale(LC_CTYPE, "C.UTF-8");
+    ERROR_IF(setlocale, name, == NULL);
+    printf("C.UTF-8 CODESET: \"%s\"\n", nl_langinfo(CODESET));
+
+    setenv("LOCPATH", "example_locales", 1);
+    locale_t loc = newlocale(LC_ALL_MASK, "de_DE.UTF-8", NULL);
+    ERROR_IF(newlocale, loc, == NULL);
+    puts("de_DE.UTF-8:");
+    print_items(loc);
+    freelocale(loc);
+}
@@ -1,15 +1,101 @@
+#include <ctype.h>
 #include <locale.h>
 #include <stdio.h>
+#include <stdlib.h>
 #include <string.h>
+#include <time.h>
 
 #include "test_helpers.h"
 
 int main(void) {
-    // TODO: Implement locale properly and test it here
-    char* val = setlocale(LC_ALL, NULL);
-    if (strcmp(val, "C") == 0) {
-        puts("success!");
-    } else {
-        printf("setlocale returned the wrong value: %s", val);
-    }
+    printf("initial: %s\n", setlocale(LC_ALL, NULL));
+
+    char *name = setlocale(LC_CTYPE, "C.UTF-8");
+    ERROR_IF(setlocale, name, == NULL);
+    printf("LC_CTYPE: %s\n", name);
+    printf("LC_NUMERIC: %s\n", setlocale(LC_NUMERIC, NULL));
+
+    // A mixed locale has a name that can be used to restore it
+    char all[256];
+    strcpy(all, setlocale(LC_ALL, NULL));
+    printf("LC_ALL: %s\n", all);
+    printf("POSIX: %s\n", setlocale(LC_ALL, "POSIX"));
+    printf("restored: %s\n", setlocale(LC_ALL, all));
+
+    name = setlocale(LC_ALL, "xx_XX.UTF-8");
+    printf("unknown: %s\n", name == NULL ? "(null)" : name);
+    printf("unchanged: %s\n", setlocale(LC_ALL, NULL));
+
+    // The empty name comes from the environment
+    unsetenv("LC_ALL");
+    setenv("LANG", "C.UTF-8", 1);
+    setenv("LC_TIME", "POSIX", 1);
+    printf("environment: %s\n", setlocale(LC_ALL, ""));
+    setenv("LC_ALL", "C", 1);
+    printf("LC_ALL set: %s\n", setlocale(LC_ALL, ""));
+
+    struct lconv *conv = localeconv();
+    printf("decimal_point: \"%s\", thousands_sep: \"%s\", frac_digits: %d\n",
+           conv->decimal_point, conv->thousands_sep, conv->frac_digits);
+
+    errno = 0;
+    locale_t loc = newlocale(~LC_ALL_MASK, "C", NULL);
+    printf("bad mask: %d, errno: %d\n", loc == NULL, errno == EINVAL);
+    errno = 0;
+    loc = newlocale(LC_ALL_MASK, "xx_XX", NULL);
+    printf("unknown: %d, errno: %d\n", loc == NULL, errno == ENOENT);
+
+    loc = newlocale(LC_ALL_MASK, "C", NULL);
+    ERROR_IF(newlocale, loc, == NULL);
+    loc = newlocale(LC_CTYPE_MASK, "C.UTF-8", loc);
+    ERROR_IF(newlocale, loc, == NULL);
+    locale_t copy = duplocale(loc);
+    ERROR_IF(duplocale, copy, == NULL);
+
+    // The locale of the thread doesn't change the global locale
+    locale_t old = uselocale(copy);
+    printf("old is global: %d\n", old == LC_GLOBAL_LOCALE);
+    printf("in use: %d\n", uselocale(NULL) == copy);
+    printf("global: %s\n", setlocale(LC_CTYPE, NULL));
+    uselocale(LC_GLOBAL_LOCALE);
+    printf("back to global: %d\n", uselocale(NULL) == LC_GLOBAL_LOCALE);
+    freelocale(copy);
+
+    printf("isalpha_l: %d %d\n", isalpha_l('a', loc) != 0, isalpha_l('1', loc) != 0);
+    printf("toupper_l: %c\n", toupper_l('q', loc));
+    printf("strcoll_l: %d\n", strcoll_l("abc", "abd", loc) < 0);
+    printf("strcasecmp_l: %d\n", strcasecmp_l("HeLLo", "hello", loc));
+
+    struct tm t = {
+        .tm_sec = 5, .tm_min = 4, .tm_hour = 15, .tm_mday = 9, .tm_mon = 6, .tm_year = 118,
+        .tm_wday = 1, .tm_yday = 189, .tm_isdst = -1,
+    };
+    char buf[64];
+    size_t len = strftime_l(buf, sizeof(buf), "%A %d %B %Y, %r", &t, loc);
+    ERROR_IF(strftime_l, len, == 0);
+    printf("strftime_l: %s\n", buf);
+    freelocale(loc);
+
+    // Locales are loaded from the directories in LOCPATH
+    setenv("LOCPATH", "example_locales", 1);
+    name = setlocale(LC_ALL, "de_DE.UTF-8");
+    ERROR_IF(setlocale, name, == NULL);
+    printf("loaded: %s\n", name);
+    name = setlocale(LC_ALL, "de_DE.ISO-8859-1");
+    printf("other codeset: %s\n", name == NULL ? "(null)" : name);
+
+    conv = localeconv();
+    printf("decimal_point: \"%s\", thousands_sep: \"%s\", grouping: %d;%d\n",
+           conv->decimal_point, conv->thousands_sep, conv->grouping[0], conv->grouping[1]);
+    printf("currency: \"%s\" \"%s\", frac_digits: %d, n_sign_posn: %d\n",
+           conv->int_curr_symbol, conv->currency_symbol, conv->frac_digits, conv->n_sign_posn);
+    printf("printf: %.2f %g\n", 3.14159, 0.5);
+
+    t.tm_mon = 2;
+    len = strftime(buf, sizeof(buf), "%A %d %B %Y, %c", &t);
+    ERROR_IF(strftime, len, == 0);
+    printf("strftime: %s\n", buf);
+
+    printf("C: %s\n", setlocale(LC_ALL, "C"));
+    printf("printf: %.2f\n", 3.14159);
 }
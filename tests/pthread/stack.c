@@ -0,0 +1,88 @@
+#include <pthread.h>
+#include <stdint.h>
+#include <stdio.h>
+#include <stdlib.h>
+#include <sys/mman.h>
+
+#include "test_helpers.h"
+
+#define STACK_SIZE (256 * 1024)
+#define GUARD_SIZE (64 * 1024)
+
+// Not declared by pthreads-emb's pthread.h
+int pthread_attr_getguardsize(const pthread_attr_t *attr, size_t *guardsize);
+int pthread_attr_setguardsize(pthread_attr_t *attr, size_t guardsize);
+int pthread_attr_getstack(const pthread_attr_t *attr, void **stackaddr, size_t *stacksize);
+int pthread_attr_setstack(pthread_attr_t *attr, void *stackaddr, size_t stacksize);
+int pthread_getattr_np(pthread_t thread, pthread_attr_t *attr);
+
+struct stack {
+    void *addr;
+    size_t size;
+    size_t guard;
+    int local_on_stack;
+};
+
+void self_stack(struct stack *s) {
+    int local;
+    pthread_attr_t attr;
+    int status = pthread_getattr_np(pthread_self(), &attr);
+    ERROR_IF(pthread_getattr_np, status, != 0);
+    status = pthread_attr_getstack(&attr, &s->addr, &s->size);
+    ERROR_IF(pthread_attr_getstack, status, != 0);
+    status = pthread_attr_getguardsize(&attr, &s->guard);
+    ERROR_IF(pthread_attr_getguardsize, status, != 0);
+    pthread_attr_destroy(&attr);
+
+    uintptr_t p = (uintptr_t) &local, start = (uintptr_t) s->addr;
+    s->local_on_stack = p >= start && p < start + s->size;
+}
+
+void *thread_main(void *arg) {
+    self_stack(arg);
+    return NULL;
+}
+
+void run(pthread_attr_t *attr, struct stack *s) {
+    pthread_t thread;
+    int status = pthread_create(&thread, attr, thread_main, s);
+    ERROR_IF(pthread_create, status, != 0);
+    status = pthread_join(thread, NULL);
+    ERROR_IF(pthread_join, status, != 0);
+}
+
+int main(void) {
+    struct stack s;
+
+    self_stack(&s);
+    printf("main thread: on stack %d, size > 0 %d\n", s.local_on_stack, s.size > 0);
+
+    run(NULL, &s);
+    printf("default: on stack %d, size >= 16K %d, guard > 0 %d\n",
+        s.local_on_stack, s.size >= 16 * 1024, s.guard > 0);
+
+    pthread_attr_t attr;
+    int status = pthread_attr_init(&attr);
+    ERROR_IF(pthread_attr_init, status, != 0);
+    status = pthread_attr_setguardsize(&attr, GUARD_SIZE);
+    ERROR_IF(pthread_attr_setguardsize, status, != 0);
+    size_t guard = 0;
+    status = pthread_attr_getguardsize(&attr, &guard);
+    ERROR_IF(pthread_attr_getguardsize, status, != 0);
+    printf("guard size attribute: %zu\n", guard);
+    run(&attr, &s);
+    printf("guard: on stack %d, guard %zu\n", s.local_on_stack, s.guard);
+    pthread_attr_destroy(&attr);
+
+    void *stack = mmap(NULL, STACK_SIZE, PROT_READ | PROT_WRITE, MAP_PRIVATE | MAP_ANONYMOUS, -1, 0);
+    ERROR_IF(mmap, stack, == MAP_FAILED);
+    status = pthread_attr_init(&attr);
+    ERROR_IF(pthread_attr_init, status, != 0);
+    status = pthread_attr_setstack(&attr, stack, STACK_SIZE);
+    ERROR_IF(pthread_attr_setstack, status, != 0);
+    run(&attr, &s);
+    printf("user stack: on stack %d, same address %d, size %zu\n",
+        s.local_on_stack, s.addr == stack, s.size);
+    pthread_attr_destroy(&attr);
+    munmap(stack, STACK_SIZE);
+}
@@ -0,0 +1,51 @@
+#define _DEFAULT_SOURCE
+#include <stdio.h>
+#include <stdio_ext.h>
+#include <unistd.h>
+
+#include "test_helpers.h"
+
+int main(void) {
+    FILE *f = tmpfile();
+    ERROR_IF(tmpfile, f, == NULL);
+
+    // Files are fully buffered
+    fputs("full\n", f);
+    printf("fully buffered: %zu\n", __fpending(f));
+
+    int status = setvbuf(f, NULL, _IOLBF, 0);
+    ERROR_IF(setvbuf, status, != 0);
+    fputs("line\n", f);
+    printf("line buffered, newline: %zu\n", __fpending(f));
+    fputs("line", f);
+    printf("line buffered, no newline: %zu\n", __fpending(f));
+
+    status = setvbuf(f, NULL, _IONBF, 0);
+    ERROR_IF(setvbuf, status, != 0);
+    fputs(" none", f);
+    printf("unbuffered: %zu\n", __fpending(f));
+
+    char buf[64];
+    setbuffer(f, buf, sizeof(buf));
+    fputs(" buffer\n", f);
+    printf("setbuffer: %zu\n", __fpending(f));
+
+    setlinebuf(f);
+    fputs("linebuf\n", f);
+    printf("setlinebuf: %zu\n", __fpending(f));
+
+    setbuf(f, NULL);
+    fputs("unbuf\n", f);
+    printf("setbuf: %zu\n", __fpending(f));
+
+    status = setvbuf(f, NULL, 42, 0);
+    printf("invalid mode: %d\n", status != 0);
+
+    char contents[64] = { 0 };
+    ssize_t len = pread(fileno(f), contents, sizeof(contents) - 1, 0);
+    ERROR_IF(pread, len, == -1);
+    printf("%s", contents);
+
+    status = fclose(f);
+    ERROR_IF(fclose, status, == EOF);
+}
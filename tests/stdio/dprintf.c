@@ -0,0 +1,18 @@
+#include <stdio.h>
+#include <unistd.h>
+
+int main(void) {
+    int len = dprintf(STDOUT_FILENO, "%s %d %x %c\n", "dprintf:", -42, 0xbeef, '!');
+    dprintf(STDOUT_FILENO, "returned %d\n", len);
+
+    dprintf(STDOUT_FILENO, "[%*d] [%-*d] [%*d]\n", 5, 1, 5, 2, -5, 3);
+    dprintf(STDOUT_FILENO, "[%.*d] [%.*d] [%.d]\n", 3, 1, -3, 2, 0);
+    dprintf(STDOUT_FILENO, "[%2$*1$d] [%3$.*1$d]\n", 6, 42, 7);
+    dprintf(STDOUT_FILENO, "[%+.3d] [% 08d] [%#o] [%#.0o] [%#08x]\n", 7, -7, 8, 0, 255);
+    dprintf(STDOUT_FILENO, "[%hhd] [%hu] [%lld] [%llu]\n", 200, 70000, -9223372036854775807LL - 1, 18446744073709551615ULL);
+    dprintf(STDOUT_FILENO, "[%20p] [%-8p] [%.3f]\n", (void *) 0xabcdef, (void *) 0, 2.0 / 3);
+
+    // Longer than the buffer of dprintf
+    len = dprintf(STDOUT_FILENO, "%300s|\n", "end");
+    dprintf(STDOUT_FILENO, "returned %d\n", len);
+}
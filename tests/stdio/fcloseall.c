@@ -0,0 +1,38 @@
+#define _GNU_SOURCE
+#include <fcntl.h>
+#include <stdio.h>
+#include <unistd.h>
+
+#include "test_helpers.h"
+
+int main(void) {
+    FILE *f = tmpfile();
+    ERROR_IF(tmpfile, f, == NULL);
+    int fd = fileno(f);
+
+    // Not flushed by a newline
+    fputs("flushed by fflush(NULL)", f);
+
+    int status = fflush(NULL);
+    ERROR_IF(fflush, status, == EOF);
+
+    char buf[64] = { 0 };
+    ssize_t len = pread(fd, buf, sizeof(buf) - 1, 0);
+    ERROR_IF(pread, len, == -1);
+    printf("read: %s\n", buf);
+
+    FILE *g = tmpfile();
+    ERROR_IF(tmpfile, g, == NULL);
+    int gd = fileno(g);
+
+    status = fcloseall();
+    ERROR_IF(fcloseall, status, == EOF);
+    printf("first closed: %d\n", fcntl(fd, F_GETFD) == -1);
+    printf("second closed: %d\n", fcntl(gd, F_GETFD) == -1);
+
+    // Left in the buffer for exit to flush
+    FILE *out = fdopen(dup(STDOUT_FILENO), "w");
+    ERROR_IF(fdopen, out, == NULL);
+    fputs("flushed by exit\n", stdout);
+    fputs("written to a copy of stdout", out);
+}
@@ -0,0 +1,62 @@
+#include <stdio.h>
+#include <string.h>
+
+#include "test_helpers.h"
+
+int main(void) {
+    char buf[16];
+    memset(buf, 'x', sizeof(buf));
+
+    // Writing truncates the contents and keeps them terminated
+    FILE *f = fmemopen(buf, sizeof(buf), "w");
+    ERROR_IF(fmemopen, f, == NULL);
+    fprintf(f, "%d apples", 42);
+    int status = fflush(f);
+    ERROR_IF(fflush, status, == EOF);
+    printf("written: %s\n", buf);
+    printf("position: %ld\n", ftell(f));
+
+    status = fseek(f, 3, SEEK_SET);
+    ERROR_IF(fseek, status, == -1);
+    fputs("pears", f);
+    status = fclose(f);
+    ERROR_IF(fclose, status, == EOF);
+    printf("overwritten: %s\n", buf);
+
+    // Appending starts at the first NUL
+    f = fmemopen(buf, sizeof(buf), "a");
+    ERROR_IF(fmemopen, f, == NULL);
+    printf("append position: %ld\n", ftell(f));
+    fputs("!", f);
+    status = fclose(f);
+    ERROR_IF(fclose, status, == EOF);
+    printf("appended: %s\n", buf);
+
+    // Reading stops at the size of the buffer
+    f = fmemopen(buf, 6, "r");
+    ERROR_IF(fmemopen, f, == NULL);
+    char line[32];
+    char *s = fgets(line, sizeof(line), f);
+    ERROR_IF(fgets, s, == NULL);
+    printf("read: %s\n", line);
+    printf("eof: %d\n", fgetc(f) == EOF);
+    status = fseek(f, -2, SEEK_END);
+    ERROR_IF(fseek, status, == -1);
+    printf("from the end: %c\n", fgetc(f));
+    status = fseek(f, 7, SEEK_SET);
+    printf("seek past the size: %d\n", status);
+    fclose(f);
+
+    // A buffer allocated by fmemopen
+    f = fmemopen(NULL, 32, "w+");
+    ERROR_IF(fmemopen, f, == NULL);
+    fputs("scratch space", f);
+    rewind(f);
+    s = fgets(line, sizeof(line), f);
+    ERROR_IF(fgets, s, == NULL);
+    printf("read back: %s\n", line);
+    fclose(f);
+
+    f = fmemopen(buf, 0, "w");
+    printf("empty buffer: %d\n", f == NULL);
+}
@@ -0,0 +1,128 @@
+#include <errno.h>
+#include <stdio.h>
+#include <string.h>
+#include <sys/types.h>
+
+#include "test_helpers.h"
+
+struct memory {
+    char data[64];
+    size_t len;
+    size_t pos;
+    int closed;
+};
+
+static ssize_t memory_read(void *cookie, char *buf, size_t size) {
+    struct memory *m = cookie;
+    size_t left = m->len - m->pos;
+    if (size > left) {
+        size = left;
+    }
+    memcpy(buf, m->data + m->pos, size);
+    m->pos += size;
+    return size;
+}
+
+static ssize_t memory_write(void *cookie, const char *buf, size_t size) {
+    struct memory *m = cookie;
+    printf("write callback: %zu bytes\n", size);
+    memcpy(m->data + m->pos, buf, size);
+    m->pos += size;
+    if (m->pos > m->len) {
+        m->len = m->pos;
+    }
+    return size;
+}
+
+static int memory_seek(void *cookie, off_t *offset, int whence) {
+    struct memory *m = cookie;
+    if (whence == SEEK_END) {
+        *offset += m->len;
+    } else if (whence == SEEK_CUR) {
+        *offset += m->pos;
+    }
+    m->pos = *offset;
+    return 0;
+}
+
+static int memory_close(void *cookie) {
+    struct memory *m = cookie;
+    m->closed = 1;
+    return 0;
+}
+
+static int fun_read(void *cookie, char *buf, int size) {
+    return memory_read(cookie, buf, size);
+}
+
+static int fun_write(void *cookie, const char *buf, int size) {
+    return memory_write(cookie, buf, size);
+}
+
+static fpos_t fun_seek(void *cookie, fpos_t offset, int whence) {
+    off_t off = offset;
+    memory_seek(cookie, &off, whence);
+    return off;
+}
+
+int main(void) {
+    struct memory m = { .len = 0, .pos = 0, .closed = 0 };
+    cookie_io_functions_t io = {
+        .read = memory_read,
+        .write = memory_write,
+        .seek = memory_seek,
+        .close = memory_close,
+    };
+
+    FILE *f = fopencookie(&m, "w+", io);
+    ERROR_IF(fopencookie, f, == NULL);
+
+    // Buffered until flushed
+    fprintf(f, "%s %d\n", "cookie", 42);
+    puts("before fflush");
+    int status = fflush(f);
+    ERROR_IF(fflush, status, == EOF);
+
+    errno = 0;
+    int fd = fileno(f);
+    printf("fileno: %d, EBADF: %d\n", fd, errno == EBADF);
+
+    status = fseek(f, 0, SEEK_SET);
+    ERROR_IF(fseek, status, == -1);
+    int c = fgetc(f);
+    printf("read: %c\n", c);
+    ungetc('C', f);
+    char line[32];
+    char *s = fgets(line, sizeof(line), f);
+    ERROR_IF(fgets, s, == NULL);
+    printf("line: %s", line);
+
+    status = fclose(f);
+    ERROR_IF(fclose, status, == EOF);
+    printf("closed: %d\n", m.closed);
+
+    // Without callbacks, reads return end of file and writes are discarded
+    cookie_io_functions_t none = { 0 };
+    f = fopencookie(&m, "r+", none);
+    ERROR_IF(fopencookie, f, == NULL);
+    printf("no read: %d\n", fgetc(f) == EOF);
+    fputs("discarded", f);
+    status = fclose(f);
+    ERROR_IF(fclose, status, == EOF);
+
+    m.pos = 0;
+    m.closed = 0;
+    f = funopen(&m, fun_read, fun_write, fun_seek, NULL);
+    ERROR_IF(funopen, f, == NULL);
+    s = fgets(line, sizeof(line), f);
+    ERROR_IF(fgets, s, == NULL);
+    printf("funopen: %s", line);
+    fseek(f, 0, SEEK_END);
+    fputs("more\n", f);
+    status = fclose(f);
+    ERROR_IF(fclose, status, == EOF);
+    printf("contents: %.*s", (int) m.len, m.data);
+
+    f = funopen(&m, NULL, NULL, NULL, NULL);
+    printf("no callbacks: %d\n", f == NULL);
+}
@@ -0,0 +1,61 @@
+#include <stdio.h>
+#include <stdlib.h>
+#include <wchar.h>
+
+#include "test_helpers.h"
+
+int main(void) {
+    char *buf;
+    size_t size;
+    FILE *f = open_memstream(&buf, &size);
+    ERROR_IF(open_memstream, f, == NULL);
+
+    fprintf(f, "hello %s", "world");
+    int status = fflush(f);
+    ERROR_IF(fflush, status, == EOF);
+    printf("%s (%zu)\n", buf, size);
+
+    // The buffer grows as needed
+    for (int i = 0; i < 1000; i++) {
+        fputc('a' + i % 26, f);
+    }
+    fflush(f);
+    printf("grown to %zu: ...%s\n", size, buf + size - 5);
+
+    // The size is the position, when seeking back
+    status = fseek(f, 5, SEEK_SET);
+    ERROR_IF(fseek, status, == -1);
+    status = fclose(f);
+    ERROR_IF(fclose, status, == EOF);
+    printf("after seeking back: %zu, %.5s\n", size, buf);
+    free(buf);
+
+    // Seeking past the end fills the gap with zeros
+    f = open_memstream(&buf, &size);
+    ERROR_IF(open_memstream, f, == NULL);
+    fputs("ab", f);
+    fseek(f, 2, SEEK_CUR);
+    fputc('!', f);
+    status = fclose(f);
+    ERROR_IF(fclose, status, == EOF);
+    printf("size: %zu, bytes:", size);
+    for (size_t i = 0; i <= size; i++) {
+        printf(" %d", buf[i]);
+    }
+    printf("\n");
+    free(buf);
+
+    wchar_t *wbuf;
+    f = open_wmemstream(&wbuf, &size);
+    ERROR_IF(open_wmemstream, f, == NULL);
+    fputws(L"wide", f);
+    fputwc(L'!', f);
+    status = fclose(f);
+    ERROR_IF(fclose, status, == EOF);
+    printf("wide: %zu characters,", size);
+    for (size_t i = 0; i <= size; i++) {
+        printf(" %d", (int) wbuf[i]);
+    }
+    printf("\n");
+    free(wbuf);
+}
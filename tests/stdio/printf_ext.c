@@ -0,0 +1,37 @@
+#include <errno.h>
+#include <locale.h>
+#include <stdio.h>
+#include <stdlib.h>
+#include <wchar.h>
+
+#include "test_helpers.h"
+
+int main(void) {
+    puts("Wide strings and characters:");
+    printf("[%ls] [%.3ls] [%.2ls] [%8ls] [%-8ls] [%ls]\n",
+           L"héllo", L"héllo", L"héllo", L"é→", L"é→", (wchar_t *) NULL);
+    printf("[%lc] [%3lc] [%-3lc] [%lc]\n", L'x', L'é', L'→', (wint_t) L'ö');
+    int n = 0;
+    printf("%ls%n\n", L"ünï", &n);
+    printf("%%n: %d\n", n);
+
+    puts("\nErrors:");
+    errno = ENOENT;
+    printf("[%m] [%.6m] [%30m]\n");
+    errno = EINVAL;
+    printf("%d %m %s\n", 1, "after");
+
+    puts("\nGrouping:");
+    printf("[%'d] [%'.2f]\n", 1234567, 1234567.891);
+
+    setenv("LOCPATH", "example_locales", 1);
+    char *name = setlocale(LC_NUMERIC, "de_DE.UTF-8");
+    ERROR_IF(setlocale, name, == NULL);
+    printf("[%'d] [%'d] [%'u] [%'ld] [%'d]\n", 1234567, -1234567, 4000000000u, 123L, 1000);
+    printf("[%'.2f] [%'f] [%'g] [%'e] [%'.0f]\n", 1234567.891, -1e10, 1234567.0, 1234567.0, 999.5);
+    printf("[%'15.1f] [%'015.1f] [%'12d] [%'-12d] [%'012d]\n",
+           1234567.0, 1234567.0, 1234567, 1234567, 1234567);
+    printf("%'d%n\n", 1234567, &n);
+    printf("%%n: %d\n", n);
+    printf("[%d] [%.2f]\n", 1234567, 1234567.891);
+}
@@ -0,0 +1,53 @@
+#include <math.h>
+#include <stdio.h>
+
+int main(void) {
+    puts("Exact digits:");
+    printf("%.20f\n", 0.1);
+    printf("%f\n", 1e300);
+    printf("%.30e\n", 1.0 / 3);
+    printf("%.1100f\n", 5e-324);
+    printf("%e %g\n", 5e-324, 1.7976931348623157e308);
+    printf("%.17g %.17g\n", 0.1, 2.2250738585072014e-308);
+
+    puts("\nRounding:");
+    printf("%.0f %.0f %.0f %.0f\n", 0.5, 1.5, 2.5, 3.5);
+    printf("%.1f %.1f %.2f %.2f\n", 0.25, 0.35, 1.125, 1.005);
+    printf("%.0e %.1e %.3e\n", 25.0, 0.95, 9.9995);
+    printf("%.2f %.3g %g\n", 99.995, 99.95, 999999.5);
+    printf("%.0f %.0e %.0g\n", 0.3, 0.3, 0.3);
+
+    puts("\nStyles of %g:");
+    printf("%g %g %g %g\n", 0.0001, 0.00001, 123456.0, 1234567.0);
+    printf("%g %g %g\n", 100.0, 0.5, 0.0);
+    printf("%.0g %.1g %.2g %.10g\n", 123.0, 123.0, 123.0, 123.0);
+    printf("%#g %#.3g %#.0f %#.0e\n", 1.0, 0.0001, 2.0, 3.0);
+    printf("%G %G\n", 1e-10, 1e20);
+
+    puts("\nFlags:");
+    printf("[%+f] [% e] [%+g] [% g]\n", 1.5, 1.5, -1.5, 0.0);
+    printf("[%012.4f] [%-12.3e] [%012g]\n", -3.14159, 3.14159, 1e-7);
+    printf("[%+08.2f] [%-+8.2f] [%8.2f]\n", 2.5, 2.5, -2.5);
+    printf("[%f] [%e] [%g]\n", -0.0, -0.0, -0.0);
+
+    puts("\nInfinities and NaNs:");
+    printf("[%f] [%e] [%g] [%a]\n", INFINITY, -INFINITY, NAN, -NAN);
+    printf("[%F] [%E] [%G] [%A]\n", INFINITY, -INFINITY, NAN, -NAN);
+    printf("[%8f] [%-8f] [%08f] [%+f]\n", INFINITY, INFINITY, -INFINITY, INFINITY);
+
+    puts("\nHexadecimal:");
+    printf("%a %a %a %a\n", 1.0, 0.5, 0.1, -255.0);
+    printf("%A %a %a\n", 3.0, 0.0, 5e-324);
+    printf("%.0a %.0a %.1a %.3a\n", 1.5, 2.5, 1.96875, 0.1);
+    printf("%.20a %#a %#.0a\n", 1.0, 1.0, 1.0);
+    printf("[%020a] [%-12a] [%+a] [% .2a]\n", 1.5, 1.5, 1.5, 1.5);
+
+    puts("\nLong double:");
+    printf("%Lf %Le %Lg\n", 0.1L, 0.1L, 0.1L);
+    printf("%.30Le\n", 1.0L / 3);
+    printf("%.25Lf\n", 2.5L);
+    printf("%Le %Lg\n", 1e4000L, 1e-4000L);
+    printf("%.0Lf %.0Lf %.1Lf\n", 0.5L, 1.5L, 0.25L);
+    printf("%Lf %d %Lf\n", 1.5L, 42, -2.25L);
+    printf("%2$.3Lf %1$d\n", 7, 3.5L);
+}
@@ -0,0 +1,88 @@
+#include <stdio.h>
+#include <stdlib.h>
+#include <wchar.h>
+
+#include "test_helpers.h"
+
+int main(void) {
+    puts("Allocation:");
+    char *s = NULL, *t = NULL, *c = NULL;
+    int ret = sscanf("hello world, again", "%ms %m[a-z,]%n %3mc", &s, &t, &(int) {0}, &c);
+    printf("%d [%s] [%s] [%.3s]\n", ret, s, t, c);
+    free(s);
+    free(t);
+    free(c);
+    s = (char *) 1;
+    ret = sscanf("   ", "%ms", &s);
+    printf("%d %p\n", ret, s);
+    wchar_t *w = NULL;
+    ret = sscanf("grüße→x y", "%mls", &w);
+    printf("%d [%ls] %zu\n", ret, w, wcslen(w));
+    free(w);
+
+    puts("\nPointers:");
+    void *p = (void *) 1, *q = (void *) 1;
+    ret = sscanf("0xabc (nil)", "%p %p", &p, &q);
+    printf("%d %p %p\n", ret, p, q);
+
+    puts("\n%n:");
+    int a = 0, b = 0, n = -1;
+    ret = sscanf("12 34", "%d%*n %*d%n", &a, &n);
+    printf("%d %d %d\n", ret, a, n);
+    ret = sscanf("12", "%d%n%n", &a, &b, &n);
+    printf("%d %d %d\n", ret, b, n);
+
+    puts("\nScansets:");
+    char s1[16] = "", s2[16] = "", s3[16] = "";
+    ret = sscanf("]]a-b]c", "%[]a]%[-b]%[^]]", s1, s2, s3);
+    printf("%d [%s] [%s] [%s]\n", ret, s1, s2, s3);
+    ret = sscanf("abcxyz-9", "%[a-c]%[x-z-]%[^0-9]", s1, s2, s3);
+    printf("%d [%s] [%s] [%s]\n", ret, s1, s2, s3);
+    ret = sscanf("za-", "%[z-a]", s1);
+    printf("%d [%s]\n", ret, s1);
+
+    puts("\nWide conversions:");
+    wchar_t ws[16] = L"", wc[4] = L"", wt[16] = L"";
+    ret = sscanf("héllo wörld→!", "%3ls %2lc%l[^!]", ws, wc, wt);
+    printf("%d [%ls] [%lc%lc] [%ls]\n", ret, ws, wc[0], wc[1], wt);
+    char cs[8] = "", cc[4] = "";
+    ret = swscanf(L"wörld ŝ", L"%s %c", cs, cc);
+    printf("%d [%s] [%s]\n", ret, cs, cc);
+
+    puts("\nFloats:");
+    double d[6] = {0};
+    ret = sscanf("0x1.8p3 -0X.4P-2 inf -Infinity nan 1e+x",
+                 "%la %le %lf %lg %la %lf%n", d, d + 1, d + 2, d + 3, d + 4, d + 5, &n);
+    printf("%d %g %g %g %g %g %g %d\n", ret, d[0], d[1], d[2], d[3], d[4], d[5], n);
+    float f = 0;
+    long double l = 0;
+    ret = sscanf("0x1.fffffep127 0x1p-1074", "%f %Lf", &f, &l);
+    printf("%d %a %Lg\n", ret, f, l);
+    ret = sscanf("infinit", "%lf", d);
+    printf("%d\n", ret);
+    ret = sscanf("0x", "%lf", d);
+    printf("%d\n", ret);
+
+    puts("\nFailures:");
+    printf("%d\n", sscanf("", "%d", &a));
+    printf("%d\n", sscanf("   ", " %d", &a));
+    printf("%d\n", sscanf("x", "%d", &a));
+    printf("%d\n", sscanf("1", "%d %d", &a, &b));
+    printf("%d\n", sscanf("1 x", "%d %d", &a, &b));
+    printf("%d\n", sscanf("ab", "abc%d", &a));
+    printf("%d\n", sscanf("ax", "abc%d", &a));
+    printf("%d\n", sscanf("", "%n", &a));
+    printf("%d\n", sscanf("", "%c", s1));
+    printf("%d\n", sscanf("1", "%d%y", &a));
+
+    puts("\nStreams:");
+    FILE *f1 = tmpfile();
+    ERROR_IF(tmpfile, f1, == NULL);
+    fputs("42abc 0x1p4z", f1);
+    rewind(f1);
+    ret = fscanf(f1, "%d%2[a-z]%la", &a, s1, d);
+    printf("%d %d [%s] %g [%c]\n", ret, a, s1, d[0], fgetc(f1));
+    ret = fscanf(f1, "%*c%d", &a);
+    printf("%d\n", ret);
+    fclose(f1);
+}
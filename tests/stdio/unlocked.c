@@ -0,0 +1,67 @@
+#include <pthread.h>
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+
+#include "test_helpers.h"
+
+#define COUNT 100000
+
+void *writer(void *arg) {
+    FILE *f = arg;
+    for (int i = 0; i < COUNT; i++) {
+        putc('b', f);
+    }
+    return NULL;
+}
+
+int main(void) {
+    FILE *f = tmpfile();
+    ERROR_IF(tmpfile, f, == NULL);
+
+    flockfile(f);
+    fputs_unlocked("first line\n", f);
+    fwrite_unlocked("0123456789", 1, 10, f);
+    for (char c = 'a'; c <= 'e'; c++) {
+        putc_unlocked(c, f);
+    }
+    funlockfile(f);
+    rewind(f);
+
+    char buf[32] = {0};
+    int c;
+    flockfile(f);
+    printf("fgets_unlocked: %s", fgets_unlocked(buf, sizeof(buf), f));
+    size_t n = fread_unlocked(buf, 2, 3, f);
+    printf("fread_unlocked: %zu %.6s\n", n, buf);
+    printf("getc_unlocked: %c\n", getc_unlocked(f));
+    funlockfile(f);
+
+    ungetc('X', f);
+    c = getc_unlocked(f);
+    printf("after ungetc: %c %c\n", c, getc_unlocked(f));
+    n = fread_unlocked(buf, 1, sizeof(buf), f);
+    printf("rest: %zu %.*s, feof_unlocked: %d\n", n, (int) n, buf, feof_unlocked(f) != 0);
+    printf("at the end: %d\n", getc_unlocked(f));
+
+    // Once there is a thread, the streams have to be locked again
+    rewind(f);
+    pthread_t thread;
+    int status = pthread_create(&thread, NULL, writer, f);
+    ERROR_IF(pthread_create, status, != 0);
+    for (int i = 0; i < COUNT; i++) {
+        putc('a', f);
+    }
+    status = pthread_join(thread, NULL);
+    ERROR_IF(pthread_join, status, != 0);
+
+    rewind(f);
+    long counts[2] = {0};
+    while ((c = getc(f)) != EOF) {
+        if (c == 'a' || c == 'b') {
+            counts[c - 'a']++;
+        }
+    }
+    printf("a: %ld, b: %ld, feof: %d\n", counts[0], counts[1], feof(f) != 0);
+    fclose(f);
+}
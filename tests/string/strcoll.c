@@ -0,0 +1,76 @@
+#include <locale.h>
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+
+#include "test_helpers.h"
+
+static const char *words[] = {
+    "zebra", "Apple", "apple", "\xc3\x84pfel", "banana", "\xc3\xa9" "clair", "Eclair", "eclair",
+    "10", "9", "Zoo", "\xc3\xa5ngstr\xc3\xb6m", "ox", "\xc3\xb6l", "a b", "ab", "\xce\xb1\xce\xb2",
+};
+#define WORDS (sizeof(words) / sizeof(words[0]))
+
+static int compare(const void *a, const void *b) {
+    return strcoll(*(const char **) a, *(const char **) b);
+}
+
+static int sign(int n) {
+    return (n > 0) - (n < 0);
+}
+
+static void sort_words(const char *name) {
+    const char *sorted[WORDS];
+    memcpy(sorted, words, sizeof(words));
+    qsort(sorted, WORDS, sizeof(sorted[0]), compare);
+
+    printf("%s:", name);
+    for (size_t i = 0; i < WORDS; i++) {
+        printf(" %s", sorted[i]);
+    }
+    printf("\n");
+
+    // Sort keys compare like the strings
+    char keys[WORDS][256];
+    for (size_t i = 0; i < WORDS; i++) {
+        size_t len = strxfrm(keys[i], words[i], sizeof(keys[i]));
+        ERROR_IF(strxfrm, len, >= sizeof(keys[i]));
+        ERROR_IF(strxfrm, strlen(keys[i]), != len);
+    }
+    for (size_t i = 0; i < WORDS; i++) {
+        for (size_t j = 0; j < WORDS; j++) {
+            int coll = sign(strcoll(words[i], words[j]));
+            int cmp = sign(strcmp(keys[i], keys[j]));
+            ERROR_IF(strcmp, cmp, != coll);
+            ERROR_IF(strcoll, coll == 0, != (i == j));
+        }
+    }
+}
+
+int main(void) {
+    // The C locale compares bytes
+    sort_words("C");
+    char key[8] = "xxxxxxx";
+    size_t len = strxfrm(key, "abc", sizeof(key));
+    printf("strxfrm C: %zu %s\n", len, key);
+
+    char *locale = setlocale(LC_ALL, "C.UTF-8");
+    ERROR_IF(setlocale, locale, == NULL);
+    sort_words("C.UTF-8");
+
+    // A key that doesn't fit isn't written, but its length is returned
+    len = strxfrm(key, "abc", 0);
+    printf("strxfrm too small: %d %s\n", len > 3, key);
+
+    // Locales may reorder characters
+    setenv("LOCPATH", "example_locales", 1);
+    locale = setlocale(LC_COLLATE, "sv_SE.UTF-8");
+    ERROR_IF(setlocale, locale, == NULL);
+    sort_words("sv_SE");
+
+    locale_t c = newlocale(LC_ALL_MASK, "C", NULL);
+    ERROR_IF(newlocale, c, == NULL);
+    printf("strcoll_l: %d %d\n", sign(strcoll_l("a", "B", c)), sign(strcoll("a", "B")));
+    printf("strxfrm_l: %zu\n", strxfrm_l(key, "abc", sizeof(key), c));
+    freelocale(c);
+}
@@ -0,0 +1,59 @@
+#include <sched.h>
+#include <stdio.h>
+#include <sys/time.h>
+#include <time.h>
+
+#include "test_helpers.h"
+
+// The sched.h of pthreads-emb doesn't declare it
+int sched_getcpu(void);
+
+static long long ns(struct timespec ts) {
+    return ts.tv_sec * 1000000000LL + ts.tv_nsec;
+}
+
+int main(void) {
+    struct timespec real1, real2, mono1, mono2;
+    struct timeval tv;
+
+    int cgt = clock_gettime(CLOCK_REALTIME, &real1);
+    ERROR_IF(clock_gettime, cgt, == -1);
+    cgt = clock_gettime(CLOCK_MONOTONIC, &mono1);
+    ERROR_IF(clock_gettime, cgt, == -1);
+
+    int gtod = gettimeofday(&tv, NULL);
+    ERROR_IF(gettimeofday, gtod, == -1);
+    time_t t = time(NULL);
+    ERROR_IF(time, t, == (time_t)-1);
+
+    int monotonic = 1;
+    struct timespec prev = mono1;
+    for (int i = 0; i < 1000; i++) {
+        struct timespec now;
+        cgt = clock_gettime(CLOCK_MONOTONIC, &now);
+        ERROR_IF(clock_gettime, cgt, == -1);
+        if (ns(now) < ns(prev)) {
+            monotonic = 0;
+        }
+        prev = now;
+    }
+
+    cgt = clock_gettime(CLOCK_MONOTONIC, &mono2);
+    ERROR_IF(clock_gettime, cgt, == -1);
+    cgt = clock_gettime(CLOCK_REALTIME, &real2);
+    ERROR_IF(clock_gettime, cgt, == -1);
+
+    printf("monotonic: %d\n", monotonic && ns(mono2) >= ns(mono1));
+    printf("realtime forward: %d\n", ns(real2) >= ns(real1));
+
+    // Each reading lies between the two of clock_gettime, at the resolution of its function. time
+    // may read a coarser clock, a tick behind
+    long long usec = tv.tv_sec * 1000000LL + tv.tv_usec;
+    printf("gettimeofday agrees: %d\n",
+        usec >= ns(real1) / 1000 && usec <= ns(real2) / 1000);
+    printf("time agrees: %d\n", t >= real1.tv_sec - 1 && t <= real2.tv_sec);
+
+    int cpu = sched_getcpu();
+    ERROR_IF(sched_getcpu, cpu, == -1);
+    printf("sched_getcpu >= 0: %d\n", cpu >= 0);
+}
@@ -1,9 +1,13 @@
 #include <stdio.h>
+#include <stdlib.h>
 #include <time.h>
 
 #include "test_helpers.h"
 
 int main(void) {
+    // Use UTC regardless of the local time zone
+    setenv("TZ", "UTC0", 1);
+
     int day = 60 * 60 * 24;
     time_t inputs[] = { -(day * 33), -day, -1, -500, 0, 1, 1531454950 };
     for (int i = 0; i < (sizeof(inputs) / sizeof(time_t)); i += 1) {
@@ -26,6 +26,9 @@ int check(time_t input) {
 }
 
 int main(void) {
+    // Use UTC regardless of the local time zone
+    setenv("TZ", "UTC0", 1);
+
     struct tm t = { 0 };
 
     t.tm_year = 71;
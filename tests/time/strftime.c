@@ -12,6 +12,9 @@ void print(time_t timestamp, char* fmt) {
 }
 
 int main(void) {
+    // Use UTC regardless of the local time zone
+    setenv("TZ", "UTC0", 1);
+
     print(1531808742, "%a %A %b %B");
     print(1531808742, "The %Cst century");
     print(1531808742, "%I:%M:%S %p");
@@ -21,4 +24,57 @@ int main(void) {
     print(1531839600, "%j %U");
     print(1531839600, "%+");
     print(1533669431, "%+%+%+%+%+"); // will overflow 50 characters
+
+    // Every conversion
+    print(1531839600, "%a %A %b %B %h");
+    print(1531839600, "%c");
+    print(1531839600, "%C %d %D %e %F");
+    print(1531839600, "%H %I %j %k %l %m %M");
+    print(1531839600, "%n%t%p %P %r %R");
+    print(1531839600, "%S %T %u %w %x %X");
+    print(1531839600, "%y %Y %z %Z %%");
+
+    // ISO 8601 and other week numbers around the turn of the year
+    print(1230595200, "%G %g %V %U %W"); // Tuesday 2008-12-30
+    print(1262304000, "%G %g %V %U %W"); // Friday 2010-01-01
+    print(1293840000, "%G %g %V %U %W"); // Saturday 2011-01-01
+    print(1609459200, "%G %g %V %U %W"); // Friday 2021-01-01
+
+    // Modifiers, and conversions that do not accept them
+    print(1531839600, "%Ec|%EC");
+    print(1531839600, "%Ex|%EX|%Ey|%EY");
+    print(1531839600, "%Od|%Oe|%OH|%OI|%Om|%OM|%OS");
+    print(1531839600, "%Ou|%OU|%OV|%Ow|%OW|%Oy");
+    print(1531839600, "%Ea|%Ed|%OY|%Ox|%OD");
+
+    // GNU flags and field widths
+    print(1531241645, "%-d|%_d|%0e|%-e|%-H|%_H|%-j");
+    print(1531241645, "%10d|%_10d|%-10d|%10e");
+    print(1531241645, "%^a|%^B|%#a|%#B|%#p|%^P|%#Z");
+    print(1531241645, "%10A|%010a|%-10b|%^10h");
+    print(1531241645, "%26c|%^x|%#X");
+    print(1531241645, "%5%|%3n");
+    print(1531241645, "%10s|%_3z|%-z|%7z|%07Y");
+
+    // Unknown conversions and a trailing % are copied
+    print(1531241645, "%q %5q %^q %");
+
+    // Years outside of 1000-9999
+    struct tm t = { 0 };
+    char out[50];
+    int years[] = { -1001, -1, 0, 5, 999, 10000 };
+    for (size_t i = 0; i < sizeof(years) / sizeof(years[0]); i++) {
+        t.tm_year = years[i] - 1900;
+        size_t n = strftime(out, sizeof(out), "%Y|%C|%y|%G|%g|%F|%_5Y|%05C", &t);
+        printf("%zu: %s\n", n, out);
+    }
+
+    // The time zone comes from tm_gmtoff and tm_zone, and is left out if tm_isdst is negative
+    t.tm_gmtoff = -(4 * 3600 + 30 * 60);
+    t.tm_zone = "VET";
+    size_t n = strftime(out, sizeof(out), "%z %Z", &t);
+    printf("%zu: %s\n", n, out);
+    t.tm_isdst = -1;
+    n = strftime(out, sizeof(out), "[%z]", &t);
+    printf("%zu: %s\n", n, out);
 }
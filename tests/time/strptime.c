@@ -0,0 +1,89 @@
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+#include <time.h>
+#include <unistd.h>
+
+#include "test_helpers.h"
+
+static void parse(const char *input, const char *format) {
+    struct tm t = { 0 };
+    char *end = strptime(input, format, &t);
+    if (end == NULL) {
+        printf("\"%s\" \"%s\": no match\n", input, format);
+        return;
+    }
+    printf(
+        "\"%s\" \"%s\": %d-%02d-%02d %02d:%02d:%02d wday %d yday %d gmtoff %ld, rest \"%s\"\n",
+        input, format, t.tm_year + 1900, t.tm_mon + 1, t.tm_mday, t.tm_hour,
+        t.tm_min, t.tm_sec, t.tm_wday, t.tm_yday, t.tm_gmtoff, end
+    );
+}
+
+// Only the date is printed, as the time comes from the clock when it is not given
+static void date(const char *input) {
+    struct tm *t = getdate(input);
+    if (t == NULL) {
+        printf("getdate \"%s\": error %d\n", input, getdate_err);
+        return;
+    }
+    printf(
+        "getdate \"%s\": %d-%02d-%02d wday %d yday %d\n",
+        input, t->tm_year + 1900, t->tm_mon + 1, t->tm_mday, t->tm_wday,
+        t->tm_yday
+    );
+}
+
+int main(void) {
+    setenv("TZ", "UTC0", 1);
+
+    parse("2019-07-04 12:30:45", "%Y-%m-%d %H:%M:%S");
+    parse("2019-07-04T12:30:45 rest", "%FT%T");
+    parse("Thursday, July 4 2019", "%A, %B %e %Y");
+    parse("thu jul  4 12:30:45 2019", "%c");
+    parse("07/04/19", "%D");
+    parse("07/04/70", "%x");
+    parse("  3:05:09 PM", "%r");
+    parse("12 am", "%I %p");
+    parse("20 19 185", "%C %y %j");
+    parse("2019 26 4", "%Y %U %w");
+    parse("2019 27 1", "%Y %W %u");
+    parse("1562243445", "%s");
+    parse("12:00 +0530", "%R %z");
+    parse("12:00 -08:00", "%R %z");
+    parse("12:00 Z", "%R %z");
+    parse("2019%100", "%EY%%%Om");
+    parse("2019\t\n 07", "%Y %m");
+    parse("2019-13-01", "%F");
+    parse("25:00", "%H:%M");
+    parse("Jul", "%A");
+    parse("2019", "%Y-");
+
+    ERROR_IF(getenv, getenv("DATEMSK"), != NULL);
+    date("2019-07-04");
+
+    setenv("DATEMSK", "/nonexistent", 1);
+    date("2019-07-04");
+
+    setenv("DATEMSK", "/", 1);
+    date("2019-07-04");
+
+    char path[] = "/tmp/strptime-XXXXXX";
+    int fd = mkstemp(path);
+    ERROR_IF(mkstemp, fd, == -1);
+    FILE *templates = fdopen(fd, "w");
+    ERROR_IF(fdopen, templates, == NULL);
+    fputs("%Y-%m-%d %H:%M\n%Y-%m-%d\n%d %B %Y\n", templates);
+    fclose(templates);
+    setenv("DATEMSK", path, 1);
+
+    struct tm *t = getdate("2019-07-04 08:15");
+    ERROR_IF(getdate, t, == NULL);
+    printf("getdate \"2019-07-04 08:15\": %02d:%02d:%02d\n", t->tm_hour, t->tm_min, t->tm_sec);
+    date("2019-07-04");
+    date("29 February 2020");
+    date("30 February 2020");
+    date("yesterday");
+
+    unlink(path);
+}
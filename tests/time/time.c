@@ -15,4 +15,31 @@ int main(void) {
 
     clock_t c = clock();
     ERROR_IF(clock, c, == (clock_t)-1);
+
+    struct timespec res = {0, 0};
+    int cgr = clock_getres(CLOCK_MONOTONIC, &res);
+    ERROR_IF(clock_getres, cgr, == -1);
+    UNEXP_IF(clock_getres, res.tv_nsec, <= 0);
+
+    struct timespec delay = {0, 1000000};
+    int cns = clock_nanosleep(CLOCK_MONOTONIC, 0, &delay, NULL);
+    ERROR_IF(clock_nanosleep, cns, != 0);
+
+    struct timespec deadline = {0, 0};
+    cgt = clock_gettime(CLOCK_MONOTONIC, &deadline);
+    ERROR_IF(clock_gettime, cgt, == -1);
+    deadline.tv_nsec += 1000000;
+    if (deadline.tv_nsec >= 1000000000) {
+        deadline.tv_sec += 1;
+        deadline.tv_nsec -= 1000000000;
+    }
+    cns = clock_nanosleep(CLOCK_MONOTONIC, TIMER_ABSTIME, &deadline, NULL);
+    ERROR_IF(clock_nanosleep, cns, != 0);
+
+    clockid_t cpu_clock;
+    int cgc = clock_getcpuclockid(0, &cpu_clock);
+    ERROR_IF(clock_getcpuclockid, cgc, != 0);
+    struct timespec cpu_time = {0, 0};
+    cgt = clock_gettime(cpu_clock, &cpu_time);
+    ERROR_IF(clock_gettime, cgt, == -1);
 }
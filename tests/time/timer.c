@@ -0,0 +1,82 @@
+#include <signal.h>
+#include <stdio.h>
+#include <string.h>
+#include <time.h>
+
+#include "test_helpers.h"
+
+static volatile int signals = 0;
+static volatile int notified = 0;
+static volatile int notified_value = 0;
+
+void handler(int sig) {
+    signals++;
+}
+
+void notify(union sigval value) {
+    notified_value = value.sival_int;
+    notified++;
+}
+
+static void sleep_ms(long ms) {
+    struct timespec ts = { 0, ms * 1000000 };
+    nanosleep(&ts, NULL);
+}
+
+int main(void) {
+    void (*signal_status)(int) = signal(SIGUSR1, &handler);
+    ERROR_IF(signal, signal_status, == SIG_ERR);
+
+    struct sigevent event;
+    memset(&event, 0, sizeof(event));
+    event.sigev_notify = SIGEV_SIGNAL;
+    event.sigev_signo = SIGUSR1;
+
+    timer_t signal_timer;
+    int status = timer_create(CLOCK_MONOTONIC, &event, &signal_timer);
+    ERROR_IF(timer_create, status, == -1);
+
+    struct itimerspec value = { { 0, 10000000 }, { 0, 10000000 } };
+    status = timer_settime(signal_timer, 0, &value, NULL);
+    ERROR_IF(timer_settime, status, == -1);
+
+    while (signals < 3) {
+        sleep_ms(1);
+    }
+
+    struct itimerspec disarm = { { 0, 0 }, { 0, 0 } };
+    struct itimerspec old;
+    status = timer_settime(signal_timer, 0, &disarm, &old);
+    ERROR_IF(timer_settime, status, == -1);
+    printf("signal timer: interval %ld ns\n", old.it_interval.tv_nsec);
+
+    status = timer_gettime(signal_timer, &value);
+    ERROR_IF(timer_gettime, status, == -1);
+    printf("disarmed: %ld s %ld ns\n", (long) value.it_value.tv_sec, value.it_value.tv_nsec);
+
+    status = timer_delete(signal_timer);
+    ERROR_IF(timer_delete, status, == -1);
+
+    memset(&event, 0, sizeof(event));
+    event.sigev_notify = SIGEV_THREAD;
+    event.sigev_notify_function = notify;
+    event.sigev_value.sival_int = 42;
+
+    timer_t thread_timer;
+    status = timer_create(CLOCK_REALTIME, &event, &thread_timer);
+    ERROR_IF(timer_create, status, == -1);
+
+    struct timespec now;
+    clock_gettime(CLOCK_REALTIME, &now);
+    struct itimerspec absolute = { { 0, 0 }, { now.tv_sec, now.tv_nsec } };
+    status = timer_settime(thread_timer, TIMER_ABSTIME, &absolute, NULL);
+    ERROR_IF(timer_settime, status, == -1);
+
+    while (notified < 1) {
+        sleep_ms(1);
+    }
+    printf("thread timer: value %d, overrun %d\n", notified_value, timer_getoverrun(thread_timer));
+
+    status = timer_delete(thread_timer);
+    ERROR_IF(timer_delete, status, == -1);
+}
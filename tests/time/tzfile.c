@@ -0,0 +1,73 @@
+#include <limits.h>
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+#include <time.h>
+#include <unistd.h>
+
+#include "test_helpers.h"
+
+// Test/Zone is XMT -4:30 until 1990, EST -5 until 2010, and then follows the footer rule
+// CST6CDT,M3.2.0,M11.1.0 past its last transition, see example_zoneinfo/test.zi
+static const time_t times[] = {
+    315532800,  // 1980-01-01 00:00:00 UTC
+    946684800,  // 2000-01-01
+    1262321999, // Just before the change to CST, 2010-01-01 05:00:00
+    1262322000,
+    1268553599, // Just before the start of daylight saving time, 2010-03-14 08:00:00
+    1268553600,
+    1893456000, // 2030-01-01
+    1909094400, // 2030-07-01
+    1951369199, // Just before the end of daylight saving time, 2031-11-02 07:00:00
+    1951369200,
+};
+
+static void print_times(void) {
+    for (size_t i = 0; i < sizeof(times) / sizeof(times[0]); i++) {
+        struct tm *t = localtime(&times[i]);
+        ERROR_IF(localtime, t, == NULL);
+        printf(
+            "%ld: %d-%02d-%02d %02d:%02d:%02d isdst %d gmtoff %ld %s\n",
+            (long) times[i], t->tm_year + 1900, t->tm_mon + 1, t->tm_mday,
+            t->tm_hour, t->tm_min, t->tm_sec, t->tm_isdst, t->tm_gmtoff, t->tm_zone
+        );
+    }
+}
+
+static void use_zone(const char *tz) {
+    setenv("TZ", tz, 1);
+    tzset();
+}
+
+int main(void) {
+    setenv("TZDIR", "example_zoneinfo", 1);
+
+    puts("TZ=:Test/Zone");
+    use_zone(":Test/Zone");
+    print_times();
+
+    puts("TZ=Test/Zone");
+    use_zone("Test/Zone");
+    print_times();
+
+    char path[PATH_MAX];
+    ERROR_IF(getcwd, getcwd(path, sizeof(path) - 32), == NULL);
+    strcat(path, "/example_zoneinfo/Test/Zone");
+    puts("TZ=<absolute path>");
+    use_zone(path);
+    print_times();
+
+    // Without TZ, the zone is loaded from /etc/localtime, or is UTC if there is none
+    time_t t = 1561939200;
+    struct tm explicit, fallback;
+    use_zone(":/etc/localtime");
+    ERROR_IF(localtime_r, localtime_r(&t, &explicit), == NULL);
+    unsetenv("TZ");
+    tzset();
+    ERROR_IF(localtime_r, localtime_r(&t, &fallback), == NULL);
+    printf(
+        "/etc/localtime fallback: %d\n",
+        explicit.tm_hour == fallback.tm_hour && explicit.tm_gmtoff == fallback.tm_gmtoff
+            && strcmp(explicit.tm_zone, fallback.tm_zone) == 0
+    );
+}
@@ -0,0 +1,59 @@
+#include <stdio.h>
+#include <stdlib.h>
+#include <time.h>
+
+#include "test_helpers.h"
+
+static void print_local(time_t input) {
+    struct tm *t = localtime(&input);
+    ERROR_IF(localtime, t, == NULL);
+    printf(
+        "%ld: %d-%02d-%02d %02d:%02d:%02d isdst %d gmtoff %ld %s\n",
+        (long) input, t->tm_year + 1900, t->tm_mon + 1, t->tm_mday,
+        t->tm_hour, t->tm_min, t->tm_sec, t->tm_isdst, t->tm_gmtoff, t->tm_zone
+    );
+}
+
+static void print_mktime(int year, int mon, int mday, int hour, int isdst) {
+    struct tm t = { 0 };
+    t.tm_year = year - 1900;
+    t.tm_mon = mon - 1;
+    t.tm_mday = mday;
+    t.tm_hour = hour;
+    t.tm_isdst = isdst;
+    time_t result = mktime(&t);
+    printf(
+        "mktime %d-%02d-%02d %02d:00 isdst %d: %ld, %02d:00 isdst %d\n",
+        year, mon, mday, hour, isdst, (long) result, t.tm_hour, t.tm_isdst
+    );
+}
+
+static void use_zone(const char *tz) {
+    setenv("TZ", tz, 1);
+    tzset();
+    printf("TZ=%s: tzname %s %s, timezone %ld, daylight %d\n", tz, tzname[0], tzname[1], timezone, daylight);
+}
+
+int main(void) {
+    use_zone("EST5EDT,M3.2.0,M11.1.0");
+    print_local(1546300800); // 2019-01-01 00:00:00 UTC
+    print_local(1561939200); // 2019-07-01 00:00:00 UTC
+    print_local(1552201199); // Just before the start of daylight saving time
+    print_local(1552201200);
+    print_local(1572760799); // Just before the end of daylight saving time
+    print_local(1572760800);
+    print_mktime(2019, 1, 1, 12, -1);
+    print_mktime(2019, 7, 1, 12, -1);
+    print_mktime(2019, 7, 1, 12, 0);
+
+    use_zone("AEST-10AEDT,M10.1.0,M4.1.0/3");
+    print_local(1546300800);
+    print_local(1561939200);
+    print_mktime(2019, 1, 1, 12, -1);
+
+    use_zone("<+0530>-5:30");
+    print_local(0);
+
+    use_zone("UTC0");
+    print_local(0);
+}
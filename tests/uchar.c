@@ -0,0 +1,104 @@
+#include <locale.h>
+#include <stdio.h>
+#include <string.h>
+#include <uchar.h>
+
+#include "test_helpers.h"
+
+static const char input[] = "z\xc3\x9f\xe6\xb0\xb4\xf0\x9f\x8d\x8c";
+
+static void print_result(const char *name, size_t rc) {
+    if (rc == (size_t) -1 || rc == (size_t) -2 || rc == (size_t) -3) {
+        printf("%s: %d\n", name, (int) rc);
+    } else {
+        printf("%s: %zu\n", name, rc);
+    }
+}
+
+int main(void) {
+    // Needed for glibc, which starts in the ASCII C locale
+    setlocale(LC_ALL, "C.UTF-8");
+
+    mbstate_t state;
+    memset(&state, 0, sizeof(state));
+    const char *p = input;
+    const char *end = input + sizeof(input);
+
+    // Characters outside the BMP become surrogate pairs, the low surrogate coming with -3
+    printf("mbrtoc16:");
+    char16_t c16;
+    size_t rc;
+    while ((rc = mbrtoc16(&c16, p, end - p, &state)) != 0) {
+        ERROR_IF(mbrtoc16, rc, == (size_t) -1);
+        ERROR_IF(mbrtoc16, rc, == (size_t) -2);
+        printf(" %#x/%d", c16, (int) rc);
+        if (rc != (size_t) -3) {
+            p += rc;
+        }
+    }
+    printf("\n");
+
+    printf("mbrtoc32:");
+    char32_t c32;
+    p = input;
+    while ((rc = mbrtoc32(&c32, p, end - p, &state)) != 0) {
+        ERROR_IF(mbrtoc32, rc, > (size_t) -4);
+        printf(" %#x/%zu", c32, rc);
+        p += rc;
+    }
+    printf("\n");
+
+    printf("mbrtoc8:");
+    char8_t c8;
+    p = input;
+    while ((rc = mbrtoc8(&c8, p, end - p, &state)) != 0) {
+        ERROR_IF(mbrtoc8, rc, == (size_t) -1);
+        ERROR_IF(mbrtoc8, rc, == (size_t) -2);
+        printf(" %#x/%d", c8, (int) rc);
+        if (rc != (size_t) -3) {
+            p += rc;
+        }
+    }
+    printf("\n");
+
+    // A character split across calls is kept in the state
+    rc = mbrtoc32(&c32, "\xf0\x9f", 2, &state);
+    print_result("split start", rc);
+    printf("mbsinit: %d\n", mbsinit(&state));
+    rc = mbrtoc32(&c32, "\x8d\x8c", 2, &state);
+    print_result("split end", rc);
+    printf("split char: %#x, mbsinit: %d\n", c32, mbsinit(&state));
+
+    rc = mbrtoc16(&c16, "\xff", 1, &state);
+    print_result("invalid", rc);
+    memset(&state, 0, sizeof(state));
+
+    // Back to multibyte, surrogate pairs are joined
+    char out[16];
+    rc = c16rtomb(out, 0xd83c, &state);
+    print_result("c16rtomb high", rc);
+    rc = c16rtomb(out, 0xdf4c, &state);
+    print_result("c16rtomb low", rc);
+    printf("bytes: %02x %02x %02x %02x\n", (unsigned char) out[0], (unsigned char) out[1],
+           (unsigned char) out[2], (unsigned char) out[3]);
+    rc = c16rtomb(out, 0xdf4c, &state);
+    print_result("c16rtomb lone low", rc);
+    memset(&state, 0, sizeof(state));
+
+    rc = c32rtomb(out, 0x6c34, &state);
+    print_result("c32rtomb", rc);
+    rc = c32rtomb(out, 0xd800, &state);
+    print_result("c32rtomb surrogate", rc);
+    memset(&state, 0, sizeof(state));
+
+    // UTF-8 code units are collected until a character is complete
+    const char8_t units[] = { 0xe6, 0xb0, 0xb4 };
+    for (int i = 0; i < 3; i++) {
+        rc = c8rtomb(out, units[i], &state);
+        print_result("c8rtomb", rc);
+    }
+    printf("bytes: %02x %02x %02x\n", (unsigned char) out[0], (unsigned char) out[1],
+           (unsigned char) out[2]);
+    rc = c8rtomb(out, 0x80, &state);
+    print_result("c8rtomb invalid", rc);
+}
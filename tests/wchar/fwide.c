@@ -0,0 +1,62 @@
+#include <stdio.h>
+#include <wchar.h>
+
+#include "test_helpers.h"
+
+int main(void) {
+    FILE *f = tmpfile();
+    ERROR_IF(tmpfile, f, == NULL);
+
+    // The first wide operation orients the stream for wide characters
+    printf("new stream: %d\n", fwide(f, 0));
+    wint_t wc = fputwc(L'é', f);
+    ERROR_IF(fputwc, wc, == WEOF);
+    printf("after fputwc: %d\n", fwide(f, 0) > 0);
+    printf("fwide(-1) on a wide stream: %d\n", fwide(f, -1) > 0);
+    printf("fprintf on a wide stream: %d\n", fprintf(f, "bytes") < 0);
+
+    int status = fputws(L"→ wide\n", f);
+    ERROR_IF(fputws, status, == -1);
+    rewind(f);
+
+    // Multibyte characters are read back whole
+    wc = fgetwc(f);
+    ERROR_IF(fgetwc, wc, == WEOF);
+    printf("fgetwc: U+%04X\n", (unsigned) wc);
+
+    wc = ungetwc(L'ö', f);
+    ERROR_IF(ungetwc, wc, == WEOF);
+    printf("ungetwc: U+%04X\n", (unsigned) wc);
+
+    wchar_t line[16];
+    wchar_t *ws = fgetws(line, 16, f);
+    ERROR_IF(fgetws, ws, == NULL);
+    printf("fgetws:");
+    for (int i = 0; line[i] != L'\0'; i++) {
+        printf(" U+%04X", (unsigned) line[i]);
+    }
+    printf("\n");
+    printf("fgetwc at the end: %d\n", fgetwc(f) == WEOF);
+
+    // freopen clears the orientation
+    f = freopen(NULL, "r+", f);
+    ERROR_IF(freopen, f, == NULL);
+    printf("after freopen: %d\n", fwide(f, 0));
+
+    // The first byte operation orients the stream for bytes
+    status = fputs("bytes\n", f);
+    ERROR_IF(fputs, status, == EOF);
+    printf("after fputs: %d\n", fwide(f, 0) < 0);
+    printf("fwide(1) on a byte stream: %d\n", fwide(f, 1) < 0);
+    printf("fputwc on a byte stream: %d\n", fputwc(L'x', f) == WEOF);
+    printf("fwprintf on a byte stream: %d\n", fwprintf(f, L"wide") < 0);
+
+    // fwide can orient a stream before it's used
+    FILE *g = tmpfile();
+    ERROR_IF(tmpfile, g, == NULL);
+    printf("fwide(-5) on a new stream: %d\n", fwide(g, -5) < 0);
+    printf("fgetwc on a byte stream: %d\n", fgetwc(g) == WEOF);
+
+    fclose(g);
+    fclose(f);
+}
@@ -0,0 +1,34 @@
+#include <stdio.h>
+#include <wchar.h>
+
+#include "test_helpers.h"
+
+int main(void) {
+    int a = 0;
+    unsigned x = 0;
+    char word[16] = { 0 };
+    char set[16] = { 0 };
+    char c[8] = { 0 };
+
+    int matched = swscanf(L"12 héllo ñáb→x ff;", L"%d %s %[ñá]b→%c %x;", &a, word, set, c, &x);
+    printf("matched %d: %d \"%s\" \"%s\" '%s' %x\n", matched, a, word, set, c, x);
+
+    // Widths count wide characters
+    matched = swscanf(L"→→→ öö", L"%2s", word);
+    printf("matched %d: \"%s\"\n", matched, word);
+    matched = swscanf(L"é→ö", L"%2c", c);
+    c[5] = '\0';
+    printf("matched %d: \"%s\"\n", matched, c);
+
+    // The literal characters must match
+    matched = swscanf(L"é 1", L"è %d", &a);
+    printf("matched %d\n", matched);
+
+    FILE *f = tmpfile();
+    ERROR_IF(tmpfile, f, == NULL);
+    fputws(L"7 → ünïcödé", f);
+    rewind(f);
+    matched = fwscanf(f, L"%d → %s", &a, word);
+    printf("fwscanf matched %d: %d \"%s\"\n", matched, a, word);
+    fclose(f);
+}
@@ -0,0 +1,45 @@
+#include <locale.h>
+#include <stdio.h>
+#include <string.h>
+#include <wchar.h>
+
+#include "test_helpers.h"
+
+static int sign(int n) {
+    return (n > 0) - (n < 0);
+}
+
+static void compare(const char *name, const wchar_t *a, const wchar_t *b) {
+    wchar_t key_a[256], key_b[256];
+    size_t len_a = wcsxfrm(key_a, a, 256);
+    size_t len_b = wcsxfrm(key_b, b, 256);
+    ERROR_IF(wcsxfrm, len_a, >= 256);
+    ERROR_IF(wcsxfrm, len_b, >= 256);
+
+    int coll = sign(wcscoll(a, b));
+    printf("%s: %d\n", name, coll);
+    ERROR_IF(wcscmp, sign(wcscmp(key_a, key_b)), != coll);
+}
+
+int main(void) {
+    char *locale = setlocale(LC_ALL, "C.UTF-8");
+    ERROR_IF(setlocale, locale, == NULL);
+
+    compare("apple Apple", L"apple", L"Apple");
+    compare("Apple banana", L"Apple", L"banana");
+    compare("été ete", L"été", L"ete");
+    compare("été etf", L"été", L"etf");
+    compare("same same", L"same", L"same");
+    compare("一 z", L"一", L"z");
+
+    // The C locale compares wide characters
+    locale_t c = newlocale(LC_ALL_MASK, "C", NULL);
+    ERROR_IF(newlocale, c, == NULL);
+    printf("wcscoll_l: %d\n", sign(wcscoll_l(L"Apple", L"banana", c)));
+    printf("wcscoll_l: %d\n", sign(wcscoll_l(L"apple", L"Banana", c)));
+
+    wchar_t key[8];
+    size_t len = wcsxfrm_l(key, L"abc", 8, c);
+    printf("wcsxfrm_l: %zu %d\n", len, wcscmp(key, L"abc"));
+    freelocale(c);
+}
@@ -0,0 +1,33 @@
+#include <stdio.h>
+#include <wchar.h>
+
+int main(void) {
+    const wchar_t chars[] = {
+        L'\0', L'\n', 0x7f, 0x9b, L'a', L'~',
+        0xad,   // soft hyphen
+        0x300,  // combining grave accent
+        0x1100, // Hangul choseong kiyeok
+        0x1160, // Hangul jungseong filler
+        0x200b, // zero width space
+        0x2028, // line separator
+        0x3042, // Hiragana letter a
+        0x4e2d, // CJK ideograph
+        0xd800, // surrogate
+        0xff21, // fullwidth Latin capital letter A
+        0xff61, // halfwidth ideographic full stop
+        0x1f600, // grinning face
+        0x20000, // CJK ideograph extension B
+        0xe0001, // language tag
+        0x10ffff,
+        -1,
+    };
+    for (size_t i = 0; i < sizeof(chars) / sizeof(chars[0]); i++) {
+        printf("wcwidth(U+%04X) = %d\n", (unsigned) chars[i], wcwidth(chars[i]));
+    }
+
+    const wchar_t *text = L"abc中文é";
+    printf("wcswidth: %d\n", wcswidth(text, 100));
+    printf("wcswidth 4: %d\n", wcswidth(text, 4));
+    printf("wcswidth 0: %d\n", wcswidth(text, 0));
+    printf("wcswidth control: %d\n", wcswidth(L"ab\tc", 4));
+}
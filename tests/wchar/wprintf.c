@@ -0,0 +1,35 @@
+#include <stdio.h>
+#include <wchar.h>
+
+#include "test_helpers.h"
+
+int main(void) {
+    wprintf(L"%d %5.2f %-4s| %x %c %%\n", 42, 3.14159, "ab", 255, 'z');
+    wprintf(L"%2$s %1$s\n", "first", "second");
+
+    // Widths and precisions count wide characters
+    wprintf(L"[%5s] [%-5s] [%.2s]\n", "é→", "é→", "é→ö");
+
+    // So do the return value and %n
+    int n = 0;
+    int len = wprintf(L"ünïcödé%n\n", &n);
+    wprintf(L"wprintf: %d, %%n: %d\n", len, n);
+
+    wchar_t buf[16];
+    len = swprintf(buf, 16, L"%s=%04d", "ñ", 5);
+    ERROR_IF(swprintf, len, < 0);
+    wprintf(L"swprintf: %d \"", len);
+    fputws(buf, stdout);
+    wprintf(L"\" ");
+    for (int i = 0; i <= len; i++) {
+        wprintf(L" %x", (unsigned) buf[i]);
+    }
+    wprintf(L"\n");
+
+    // Output that doesn't fit is an error
+    len = swprintf(buf, 4, L"%s", "too long");
+    wprintf(L"swprintf too long: %d\n", len);
+
+    len = fwprintf(stdout, L"fwprintf: %s\n", "→");
+    wprintf(L"fwprintf: %d\n", len);
+}
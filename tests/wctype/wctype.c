@@ -0,0 +1,83 @@
+#include <stdio.h>
+#include <wchar.h>
+#include <wctype.h>
+
+#include "test_helpers.h"
+
+static const char *classes[] = {
+    "alnum", "alpha", "blank", "cntrl", "digit", "graph",
+    "lower", "print", "punct", "space", "upper", "xdigit",
+};
+
+static const wint_t chars[] = {
+    L'\0', L'\t', L' ', L'0', L'7', L'A', L'f', L'z', L'!', L'~', 0x7f,
+    0x85,   // next line
+    0xa0,   // no-break space
+    0xb5,   // micro sign
+    0xc9,   // Latin capital letter E with acute
+    0x130,  // Latin capital letter I with dot above
+    0x1c5,  // Latin capital letter D with small letter z with caron
+    0x3a3,  // Greek capital letter sigma
+    0x3c2,  // Greek small letter final sigma
+    0x416,  // Cyrillic capital letter zhe
+    0x5d0,  // Hebrew letter alef
+    0x663,  // Arabic-Indic digit three
+    0x2003, // em space
+    0x2028, // line separator
+    0x20ac, // euro sign
+    0x3000, // ideographic space
+    0x3042, // Hiragana letter a
+    0x4e2d, // CJK ideograph
+    0xd800, // surrogate
+    0xff21, // fullwidth Latin capital letter A
+    0xfffe, // noncharacter
+    0x1f600, // grinning face
+    0x10428, // Deseret small letter long i
+    0x110000,
+    WEOF,
+};
+
+int main(void) {
+    for (size_t i = 0; i < sizeof(chars) / sizeof(chars[0]); i++) {
+        wint_t wc = chars[i];
+        printf("U+%04X:", wc);
+        for (size_t j = 0; j < sizeof(classes) / sizeof(classes[0]); j++) {
+            if (iswctype(wc, wctype(classes[j]))) {
+                printf(" %s", classes[j]);
+            }
+        }
+        printf(" | lower U+%04X upper U+%04X\n", towlower(wc), towupper(wc));
+    }
+
+    // The named functions agree with iswctype
+    for (wint_t wc = 0; wc < 0x3000; wc++) {
+        if (!iswalnum(wc) != !iswctype(wc, wctype("alnum"))
+                || !iswalpha(wc) != !iswctype(wc, wctype("alpha"))
+                || !iswblank(wc) != !iswctype(wc, wctype("blank"))
+                || !iswcntrl(wc) != !iswctype(wc, wctype("cntrl"))
+                || !iswdigit(wc) != !iswctype(wc, wctype("digit"))
+                || !iswgraph(wc) != !iswctype(wc, wctype("graph"))
+                || !iswlower(wc) != !iswctype(wc, wctype("lower"))
+                || !iswprint(wc) != !iswctype(wc, wctype("print"))
+                || !iswpunct(wc) != !iswctype(wc, wctype("punct"))
+                || !iswspace(wc) != !iswctype(wc, wctype("space"))
+                || !iswupper(wc) != !iswctype(wc, wctype("upper"))
+                || !iswxdigit(wc) != !iswctype(wc, wctype("xdigit"))) {
+            printf("mismatch for U+%04X\n", wc);
+        }
+    }
+
+    printf("wctype(\"bogus\"): %ld\n", (long) wctype("bogus"));
+    printf("iswctype(L'a', 0): %d\n", iswctype(L'a', 0));
+
+    wctrans_t tolower = wctrans("tolower");
+    wctrans_t toupper = wctrans("toupper");
+    ERROR_IF(wctrans, tolower, == NULL);
+    ERROR_IF(wctrans, toupper, == NULL);
+    printf("wctrans(\"bogus\") is NULL: %d\n", wctrans("bogus") == NULL);
+    printf(
+        "towctrans: U+%04X U+%04X U+%04X U+%04X\n",
+        towctrans(L'q', toupper), towctrans(0x3a3, tolower),
+        towctrans(0x1f1, tolower), towctrans(0x1f3, toupper)
+    );
+}